@@ -42,7 +42,12 @@ fn dedupe(c: &mut Criterion) {
                 fields: Some(FieldMatchConfig::IgnoreFields(vec![String::from(
                     "message",
                 )])),
-                cache: CacheConfig { num_events: 4 },
+                cache: CacheConfig {
+                    num_events: 4,
+                    time_to_live_secs: None,
+                    persist: false,
+                },
+                data_dir: None,
             },
         },
         // Modification of previous where field "message" is matched.
@@ -51,7 +56,12 @@ fn dedupe(c: &mut Criterion) {
             input: fixed_stream.clone(),
             dedupe_config: DedupeConfig {
                 fields: Some(FieldMatchConfig::MatchFields(vec![String::from("message")])),
-                cache: CacheConfig { num_events: 4 },
+                cache: CacheConfig {
+                    num_events: 4,
+                    time_to_live_secs: None,
+                    persist: false,
+                },
+                data_dir: None,
             },
         },
         // Measurement where ignore fields do not exist in the event.
@@ -59,7 +69,11 @@ fn dedupe(c: &mut Criterion) {
             slug: "field_ignore_done",
             input: fixed_stream.clone(),
             dedupe_config: DedupeConfig {
-                cache: CacheConfig { num_events: 4 },
+                cache: CacheConfig {
+                    num_events: 4,
+                    time_to_live_secs: None,
+                    persist: false,
+                },
                 fields: Some(FieldMatchConfig::IgnoreFields(vec![
                     String::from("abcde"),
                     String::from("eabcd"),
@@ -67,6 +81,7 @@ fn dedupe(c: &mut Criterion) {
                     String::from("cdeab"),
                     String::from("bcdea"),
                 ])),
+                data_dir: None,
             },
         },
         // Modification of previous where match fields do not exist in the
@@ -75,7 +90,11 @@ fn dedupe(c: &mut Criterion) {
             slug: "field_match_done",
             input: fixed_stream.clone(),
             dedupe_config: DedupeConfig {
-                cache: CacheConfig { num_events: 4 },
+                cache: CacheConfig {
+                    num_events: 4,
+                    time_to_live_secs: None,
+                    persist: false,
+                },
                 fields: Some(FieldMatchConfig::MatchFields(vec![
                     String::from("abcde"),
                     String::from("eabcd"),
@@ -83,6 +102,7 @@ fn dedupe(c: &mut Criterion) {
                     String::from("cdeab"),
                     String::from("bcdea"),
                 ])),
+                data_dir: None,
             },
         },
     ] {
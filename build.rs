@@ -108,9 +108,17 @@ fn main() {
         println!(
             "cargo:rerun-if-changed=proto/opentelemetry/proto/collector/logs/v1/logs_service.proto"
         );
+        println!(
+            "cargo:rerun-if-changed=proto/opentelemetry/proto/collector/metrics/v1/metrics_service.proto"
+        );
+        println!(
+            "cargo:rerun-if-changed=proto/opentelemetry/proto/collector/trace/v1/trace_service.proto"
+        );
         println!("cargo:rerun-if-changed=proto/opentelemetry/proto/common/v1/common.proto");
         println!("cargo:rerun-if-changed=proto/opentelemetry/proto/logs/v1/logs.proto");
+        println!("cargo:rerun-if-changed=proto/opentelemetry/proto/metrics/v1/metrics.proto");
         println!("cargo:rerun-if-changed=proto/opentelemetry/proto/resource/v1/resource.proto");
+        println!("cargo:rerun-if-changed=proto/opentelemetry/proto/trace/v1/trace.proto");
 
         let mut prost_build = prost_build::Config::new();
         prost_build.btree_map(&["."]);
@@ -127,6 +135,8 @@ fn main() {
                     "proto/google/pubsub/v1/pubsub.proto",
                     "proto/vector.proto",
                     "proto/opentelemetry/proto/collector/logs/v1/logs_service.proto",
+                    "proto/opentelemetry/proto/collector/metrics/v1/metrics_service.proto",
+                    "proto/opentelemetry/proto/collector/trace/v1/trace_service.proto",
                 ],
                 &["proto/", "lib/vector-core/proto/"],
             )
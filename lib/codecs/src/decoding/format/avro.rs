@@ -0,0 +1,512 @@
+use std::{fs, path::PathBuf};
+
+use avro_rs::{types::Value as AvroValue, Schema};
+use bytes::{Buf, Bytes};
+use chrono::{TimeZone, Utc};
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use value::{Kind, Value};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent},
+    schema,
+};
+
+use super::Deserializer;
+use crate::decoding::BuildError;
+
+/// The magic byte that starts every message framed in the Confluent Schema Registry wire format.
+const CONFLUENT_MAGIC_BYTE: u8 = 0;
+
+/// The length of the Confluent Schema Registry wire format prefix: the magic byte followed by a
+/// 4-byte schema ID.
+const CONFLUENT_PREFIX_LEN: usize = 5;
+
+/// Config used to build an `AvroDeserializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AvroDeserializerConfig {
+    /// Options for the Avro deserializer.
+    pub avro: AvroDeserializerOptions,
+}
+
+impl AvroDeserializerConfig {
+    /// Creates a new `AvroDeserializerConfig`.
+    pub const fn new(avro: AvroDeserializerOptions) -> Self {
+        Self { avro }
+    }
+
+    /// Build the `AvroDeserializer` from this configuration.
+    pub fn build(&self) -> Result<AvroDeserializer, BuildError> {
+        let schema =
+            match (&self.avro.schema, &self.avro.schema_file) {
+                (Some(schema), None) => schema.clone(),
+                (None, Some(path)) => fs::read_to_string(path).map_err(|error| {
+                    format!("Failed to read Avro schema file {:?}: {}", path, error)
+                })?,
+                (Some(_), Some(_)) => return Err(
+                    "Only one of `schema` and `schema_file` can be set for the Avro deserializer."
+                        .into(),
+                ),
+                (None, None) => {
+                    return Err(
+                        "One of `schema` or `schema_file` must be set for the Avro deserializer."
+                            .into(),
+                    )
+                }
+            };
+        let schema = Schema::parse_str(&schema)
+            .map_err(|error| format!("Failed building Avro deserializer: {}", error))?;
+
+        Ok(AvroDeserializer::new(
+            schema,
+            self.avro.strip_schema_id_prefix,
+        ))
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        // TODO: Convert the Avro schema to a vector schema definition.
+        match log_namespace {
+            LogNamespace::Legacy => schema::Definition::empty_legacy_namespace()
+                .unknown_fields(Kind::any())
+                .try_with_field(
+                    log_schema().timestamp_key(),
+                    Kind::any().or_timestamp(),
+                    Some("timestamp"),
+                ),
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(Kind::any(), [log_namespace])
+            }
+        }
+    }
+}
+
+/// Apache Avro deserializer options.
+#[configurable_component]
+#[derive(Clone, Debug, Default)]
+pub struct AvroDeserializerOptions {
+    /// The Avro schema the data was written with.
+    ///
+    /// Mutually exclusive with `schema_file`.
+    pub schema: Option<String>,
+
+    /// Path to a file containing the Avro schema the data was written with.
+    ///
+    /// Mutually exclusive with `schema`.
+    pub schema_file: Option<PathBuf>,
+
+    /// Whether to strip the Confluent Schema Registry wire format prefix.
+    ///
+    /// Data produced with the Confluent serializers is prefixed by a magic byte and the 4-byte ID
+    /// of the schema in the registry, which is not part of the Avro datum.
+    #[serde(default)]
+    pub strip_schema_id_prefix: bool,
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing an Apache Avro datum.
+#[derive(Debug, Clone)]
+pub struct AvroDeserializer {
+    schema: Schema,
+    strip_schema_id_prefix: bool,
+}
+
+impl AvroDeserializer {
+    /// Creates a new `AvroDeserializer`.
+    pub const fn new(schema: Schema, strip_schema_id_prefix: bool) -> Self {
+        Self {
+            schema,
+            strip_schema_id_prefix,
+        }
+    }
+}
+
+impl Deserializer for AvroDeserializer {
+    fn parse(
+        &self,
+        mut bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_core::Result<SmallVec<[Event; 1]>> {
+        if bytes.is_empty() {
+            return Ok(smallvec![]);
+        }
+
+        if self.strip_schema_id_prefix {
+            if bytes.len() < CONFLUENT_PREFIX_LEN || bytes[0] != CONFLUENT_MAGIC_BYTE {
+                return Err("Avro datum is missing the schema registry wire format prefix.".into());
+            }
+            bytes.advance(CONFLUENT_PREFIX_LEN);
+        }
+
+        let value = avro_rs::from_avro_datum(&self.schema, &mut bytes.reader(), None)
+            .map_err(|error| format!("Error parsing Avro: {}", error))?;
+        let value = to_value(value, Some(&self.schema));
+
+        let log = match log_namespace {
+            LogNamespace::Vector => log_namespace.new_log_from_data(value),
+            LogNamespace::Legacy => {
+                let mut log = match value {
+                    Value::Object(fields) => LogEvent::from(fields),
+                    value => {
+                        return Err(
+                            format!("Expected an Avro record, found {}.", value.kind_str()).into(),
+                        )
+                    }
+                };
+                let timestamp_key = log_schema().timestamp_key();
+                if !log.contains(timestamp_key) {
+                    log.insert(timestamp_key, Utc::now());
+                }
+                log
+            }
+        };
+
+        Ok(smallvec![log.into()])
+    }
+}
+
+/// Converts an Avro value to a `Value`.
+///
+/// The schema is walked alongside the value, since it carries the scale needed to interpret
+/// decimals.
+fn to_value(value: AvroValue, schema: Option<&Schema>) -> Value {
+    match value {
+        AvroValue::Null => Value::Null,
+        AvroValue::Boolean(value) => Value::Boolean(value),
+        AvroValue::Int(value) => Value::Integer(value.into()),
+        AvroValue::Long(value) => Value::Integer(value),
+        AvroValue::Float(value) => float(f64::from(value)),
+        AvroValue::Double(value) => float(value),
+        AvroValue::Bytes(bytes) | AvroValue::Fixed(_, bytes) => Value::Bytes(bytes.into()),
+        AvroValue::String(string) | AvroValue::Enum(_, string) => Value::Bytes(string.into()),
+        AvroValue::Union(value) => {
+            // The decoded value doesn't say which branch of the union it was read with, so the
+            // branch is the one the value matches, the same way it's picked when encoding.
+            let schema = schema.and_then(|schema| match schema {
+                Schema::Union(union) => union.find_schema(&value).map(|(_, schema)| schema),
+                schema => Some(schema),
+            });
+            to_value(*value, schema)
+        }
+        AvroValue::Array(values) => {
+            let items = match schema {
+                Some(Schema::Array(items)) => Some(items.as_ref()),
+                _ => None,
+            };
+            Value::Array(
+                values
+                    .into_iter()
+                    .map(|value| to_value(value, items))
+                    .collect(),
+            )
+        }
+        AvroValue::Map(entries) => {
+            let values = match schema {
+                Some(Schema::Map(values)) => Some(values.as_ref()),
+                _ => None,
+            };
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, to_value(value, values)))
+                    .collect(),
+            )
+        }
+        AvroValue::Record(fields) => {
+            let schemas = match schema {
+                Some(Schema::Record { fields, .. }) => Some(fields),
+                _ => None,
+            };
+            Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let schema = schemas.and_then(|fields| {
+                            fields
+                                .iter()
+                                .find(|field| field.name == name)
+                                .map(|field| &field.schema)
+                        });
+                        (name, to_value(value, schema))
+                    })
+                    .collect(),
+            )
+        }
+        AvroValue::Date(days) => Utc
+            .timestamp_opt(i64::from(days) * 86_400, 0)
+            .single()
+            .map(Value::Timestamp)
+            .unwrap_or(Value::Null),
+        // There is no time-of-day kind, so these are left as offsets from midnight.
+        AvroValue::TimeMillis(millis) => Value::Integer(millis.into()),
+        AvroValue::TimeMicros(micros) => Value::Integer(micros),
+        AvroValue::TimestampMillis(millis) => Utc
+            .timestamp_millis_opt(millis)
+            .single()
+            .map(Value::Timestamp)
+            .unwrap_or(Value::Null),
+        AvroValue::TimestampMicros(micros) => {
+            Value::Timestamp(Utc.timestamp_nanos(micros.saturating_mul(1_000)))
+        }
+        AvroValue::Decimal(decimal) => {
+            let bytes = Vec::<u8>::try_from(&decimal).unwrap_or_default();
+            match decimal_scale(schema) {
+                Some(scale) => decimal_to_value(&bytes, scale),
+                None => Value::Bytes(bytes.into()),
+            }
+        }
+        AvroValue::Duration(duration) => Value::Object(
+            [
+                (
+                    "months".to_owned(),
+                    Value::Integer(u32::from(duration.months()).into()),
+                ),
+                (
+                    "days".to_owned(),
+                    Value::Integer(u32::from(duration.days()).into()),
+                ),
+                (
+                    "millis".to_owned(),
+                    Value::Integer(u32::from(duration.millis()).into()),
+                ),
+            ]
+            .into(),
+        ),
+        AvroValue::Uuid(uuid) => Value::Bytes(uuid.to_string().into()),
+    }
+}
+
+fn float(value: f64) -> Value {
+    NotNan::new(value).map(Value::Float).unwrap_or(Value::Null)
+}
+
+fn decimal_scale(schema: Option<&Schema>) -> Option<usize> {
+    match schema? {
+        Schema::Decimal { scale, .. } => Some(*scale),
+        _ => None,
+    }
+}
+
+/// Interprets the big-endian two's complement unscaled value of a decimal.
+///
+/// Decimals are converted to floats, so precision beyond that of an `f64` is lost.
+fn decimal_to_value(bytes: &[u8], scale: usize) -> Value {
+    if bytes.is_empty() || bytes.len() > 16 {
+        return Value::Bytes(bytes.to_vec().into());
+    }
+
+    let fill = if bytes[0] & 0x80 != 0 { 0xff } else { 0 };
+    let mut buffer = [fill; 16];
+    buffer[16 - bytes.len()..].copy_from_slice(bytes);
+    let unscaled = i128::from_be_bytes(buffer);
+
+    float(unscaled as f64 / 10f64.powi(scale as i32))
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn deserializer(schema: &str, strip_schema_id_prefix: bool) -> AvroDeserializer {
+        AvroDeserializerConfig::new(AvroDeserializerOptions {
+            schema: Some(schema.to_owned()),
+            schema_file: None,
+            strip_schema_id_prefix,
+        })
+        .build()
+        .unwrap()
+    }
+
+    fn encode(schema: &str, value: AvroValue) -> Vec<u8> {
+        let schema = Schema::parse_str(schema).unwrap();
+        avro_rs::to_avro_datum(&schema, value).unwrap()
+    }
+
+    const SCHEMA: &str = indoc! {r#"
+        {
+            "type": "record",
+            "name": "Log",
+            "fields": [
+                { "name": "message", "type": "string" },
+                { "name": "count", "type": "long" },
+                { "name": "ratio", "type": "double" },
+                { "name": "tags", "type": { "type": "array", "items": "string" } },
+                { "name": "host", "type": ["null", "string"] },
+                { "name": "ts", "type": { "type": "long", "logicalType": "timestamp-millis" } },
+                { "name": "day", "type": { "type": "int", "logicalType": "date" } },
+                {
+                    "name": "price",
+                    "type": { "type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2 }
+                }
+            ]
+        }
+    "#};
+
+    fn record() -> AvroValue {
+        AvroValue::Record(vec![
+            ("message".to_owned(), AvroValue::String("hello".to_owned())),
+            ("count".to_owned(), AvroValue::Long(3)),
+            ("ratio".to_owned(), AvroValue::Double(0.5)),
+            (
+                "tags".to_owned(),
+                AvroValue::Array(vec![AvroValue::String("a".to_owned())]),
+            ),
+            (
+                "host".to_owned(),
+                AvroValue::Union(Box::new(AvroValue::String("localhost".to_owned()))),
+            ),
+            (
+                "ts".to_owned(),
+                AvroValue::TimestampMillis(1_600_000_000_123),
+            ),
+            ("day".to_owned(), AvroValue::Date(18_000)),
+            (
+                "price".to_owned(),
+                AvroValue::Decimal(avro_rs::Decimal::from(vec![0x04, 0xd2])),
+            ),
+        ])
+    }
+
+    #[test]
+    fn deserialize_avro() {
+        let deserializer = deserializer(SCHEMA, false);
+        let input = Bytes::from(encode(SCHEMA, record()));
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            assert_eq!(events.len(), 1);
+
+            let log = events[0].as_log();
+            assert_eq!(log["message"], "hello".into());
+            assert_eq!(log["count"], 3.into());
+            assert_eq!(log["ratio"], Value::from_f64_or_zero(0.5));
+            assert_eq!(log["tags"], vec!["a"].into());
+            assert_eq!(log["host"], "localhost".into());
+            assert_eq!(
+                log["ts"],
+                Value::Timestamp(Utc.timestamp_millis(1_600_000_000_123))
+            );
+            assert_eq!(
+                log["day"],
+                Value::Timestamp(Utc.ymd(2019, 4, 14).and_hms(0, 0, 0))
+            );
+            assert_eq!(log["price"], Value::from_f64_or_zero(12.34));
+
+            match namespace {
+                LogNamespace::Legacy => {
+                    assert!(log.get(log_schema().timestamp_key()).is_some());
+                }
+                LogNamespace::Vector => {
+                    assert!(log.get(log_schema().timestamp_key()).is_none());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_confluent_prefix() {
+        let deserializer = deserializer(SCHEMA, true);
+
+        let mut input = vec![CONFLUENT_MAGIC_BYTE, 0, 0, 0, 42];
+        input.extend(encode(SCHEMA, record()));
+        let events = deserializer
+            .parse(input.into(), LogNamespace::Legacy)
+            .unwrap();
+        assert_eq!(events[0].as_log()["message"], "hello".into());
+
+        let input = Bytes::from(encode(SCHEMA, record()));
+        assert!(deserializer.parse(input, LogNamespace::Legacy).is_err());
+    }
+
+    #[test]
+    fn deserialize_error_invalid_datum() {
+        let deserializer = deserializer(SCHEMA, false);
+        assert!(deserializer
+            .parse(Bytes::from_static(b"\x02"), LogNamespace::Legacy)
+            .is_err());
+    }
+
+    #[test]
+    fn union_branch_matches_value() {
+        let schema = indoc! {r#"
+            {
+                "type": "record",
+                "name": "Order",
+                "fields": [
+                    {
+                        "name": "amount",
+                        "type": [
+                            "null",
+                            {
+                                "type": "record",
+                                "name": "Cents",
+                                "fields": [
+                                    {
+                                        "name": "cents",
+                                        "type": { "type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2 }
+                                    }
+                                ]
+                            },
+                            {
+                                "type": "record",
+                                "name": "Basis",
+                                "fields": [
+                                    {
+                                        "name": "basis",
+                                        "type": { "type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 4 }
+                                    }
+                                ]
+                            }
+                        ]
+                    }
+                ]
+            }
+        "#};
+        let value = AvroValue::Record(vec![(
+            "amount".to_owned(),
+            AvroValue::Union(Box::new(AvroValue::Record(vec![(
+                "basis".to_owned(),
+                AvroValue::Decimal(avro_rs::Decimal::from(vec![0x04, 0xd2])),
+            )]))),
+        )]);
+
+        let deserializer = deserializer(schema, false);
+        let input = Bytes::from(encode(schema, value));
+        let events = deserializer.parse(input, LogNamespace::Legacy).unwrap();
+
+        // The scale comes from the second branch, which the value was encoded with.
+        assert_eq!(
+            events[0].as_log()["amount.basis"],
+            Value::from_f64_or_zero(0.1234)
+        );
+    }
+
+    #[test]
+    fn negative_decimal() {
+        assert_eq!(
+            decimal_to_value(&[0xfb, 0x2e], 2),
+            Value::from_f64_or_zero(-12.34)
+        );
+    }
+
+    #[test]
+    fn build_requires_one_schema() {
+        let config = AvroDeserializerConfig::new(AvroDeserializerOptions::default());
+        assert!(config.build().is_err());
+
+        let config = AvroDeserializerConfig::new(AvroDeserializerOptions {
+            schema: Some(SCHEMA.to_owned()),
+            schema_file: Some("/dev/null".into()),
+            strip_schema_id_prefix: false,
+        });
+        assert!(config.build().is_err());
+    }
+}
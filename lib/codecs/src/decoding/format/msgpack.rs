@@ -0,0 +1,289 @@
+use bytes::{Buf, Bytes};
+use chrono::Utc;
+use ordered_float::NotNan;
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use value::{Kind, Value};
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent},
+    schema,
+};
+
+use super::Deserializer;
+use crate::msgpack::{decode_timestamp, TIMESTAMP_EXT_TYPE};
+
+/// Config used to build a `MsgpackDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MsgpackDeserializerConfig;
+
+impl MsgpackDeserializerConfig {
+    /// Build the `MsgpackDeserializer` from this configuration.
+    pub const fn build(&self) -> MsgpackDeserializer {
+        MsgpackDeserializer
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        match log_namespace {
+            LogNamespace::Legacy => schema::Definition::empty_legacy_namespace()
+                .unknown_fields(Kind::any())
+                .try_with_field(
+                    log_schema().timestamp_key(),
+                    // The MessagePack decoder will try to insert a new `timestamp`-type value into
+                    // the "timestamp_key" field, but only if that field doesn't already exist.
+                    Kind::any().or_timestamp(),
+                    Some("timestamp"),
+                ),
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(Kind::any(), [log_namespace])
+            }
+        }
+    }
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing MessagePack.
+///
+/// A frame may contain several concatenated MessagePack values, each of which becomes an event.
+/// As with JSON, a top-level array is split into one event per element.
+#[derive(Debug, Clone, Default)]
+pub struct MsgpackDeserializer;
+
+impl MsgpackDeserializer {
+    /// Creates a new `MsgpackDeserializer`.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Deserializer for MsgpackDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_core::Result<SmallVec<[Event; 1]>> {
+        let mut reader = bytes.reader();
+        let mut values = Vec::new();
+        while reader.get_ref().has_remaining() {
+            let value = rmpv::decode::read_value(&mut reader)
+                .map_err(|error| format!("Error parsing MessagePack: {}", error))?;
+            match to_value(value) {
+                Value::Array(elements) => values.extend(elements),
+                value => values.push(value),
+            }
+        }
+
+        let timestamp = Utc::now();
+        values
+            .into_iter()
+            .map(|value| {
+                let log = match log_namespace {
+                    LogNamespace::Vector => log_namespace.new_log_from_data(value),
+                    LogNamespace::Legacy => {
+                        let mut log = match value {
+                            Value::Object(fields) => LogEvent::from(fields),
+                            value => {
+                                return Err(format!(
+                                    "Expected a MessagePack map, found {}.",
+                                    value.kind_str()
+                                )
+                                .into())
+                            }
+                        };
+                        let timestamp_key = log_schema().timestamp_key();
+                        if !log.contains(timestamp_key) {
+                            log.insert(timestamp_key, timestamp);
+                        }
+                        log
+                    }
+                };
+                Ok(Event::from(log))
+            })
+            .collect()
+    }
+}
+
+fn to_value(value: rmpv::Value) -> Value {
+    match value {
+        rmpv::Value::Nil => Value::Null,
+        rmpv::Value::Boolean(value) => Value::Boolean(value),
+        rmpv::Value::Integer(value) => value
+            .as_i64()
+            .map(Value::Integer)
+            .or_else(|| value.as_f64().map(Value::from_f64_or_zero))
+            .unwrap_or(Value::Null),
+        rmpv::Value::F32(value) => NotNan::new(f64::from(value))
+            .map(Value::Float)
+            .unwrap_or(Value::Null),
+        rmpv::Value::F64(value) => NotNan::new(value).map(Value::Float).unwrap_or(Value::Null),
+        rmpv::Value::String(value) => Value::Bytes(value.into_bytes().into()),
+        rmpv::Value::Binary(value) => Value::Bytes(value.into()),
+        rmpv::Value::Array(values) => Value::Array(values.into_iter().map(to_value).collect()),
+        rmpv::Value::Map(entries) => Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key_to_string(key), to_value(value)))
+                .collect(),
+        ),
+        rmpv::Value::Ext(TIMESTAMP_EXT_TYPE, bytes) => decode_timestamp(&bytes)
+            .map(Value::Timestamp)
+            .unwrap_or_else(|| Value::Bytes(bytes.into())),
+        rmpv::Value::Ext(code, bytes) => Value::Object(
+            [
+                (
+                    "msgpack_extension_code".to_owned(),
+                    Value::Integer(code.into()),
+                ),
+                ("bytes".to_owned(), Value::Bytes(bytes.into())),
+            ]
+            .into(),
+        ),
+    }
+}
+
+/// Map keys are usually strings, but MessagePack allows any value to be used as a key.
+fn key_to_string(key: rmpv::Value) -> String {
+    match key {
+        rmpv::Value::String(key) => match key.into_str() {
+            Some(key) => key,
+            None => String::new(),
+        },
+        rmpv::Value::Binary(key) => String::from_utf8_lossy(&key).into_owned(),
+        key => to_value(key).to_string_lossy(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use vector_core::config::log_schema;
+
+    use super::*;
+
+    fn encode(value: &rmpv::Value) -> Bytes {
+        let mut bytes = Vec::new();
+        rmpv::encode::write_value(&mut bytes, value).unwrap();
+        bytes.into()
+    }
+
+    fn map(entries: Vec<(&str, rmpv::Value)>) -> rmpv::Value {
+        rmpv::Value::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (rmpv::Value::from(key), value))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn deserialize_msgpack() {
+        let timestamp = Utc.timestamp(1_600_000_000, 123);
+        let input = encode(&map(vec![
+            ("message", "hello".into()),
+            ("int", 42.into()),
+            ("float", 1.5.into()),
+            ("bool", true.into()),
+            ("null", rmpv::Value::Nil),
+            ("binary", rmpv::Value::Binary(b"\x00\x01".to_vec())),
+            ("array", rmpv::Value::Array(vec![1.into(), "two".into()])),
+            ("nested", map(vec![("key", "value".into())])),
+            (
+                "ts",
+                rmpv::Value::Ext(
+                    TIMESTAMP_EXT_TYPE,
+                    crate::msgpack::encode_timestamp(&timestamp),
+                ),
+            ),
+        ]));
+        let deserializer = MsgpackDeserializer::new();
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(input.clone(), namespace).unwrap();
+            assert_eq!(events.len(), 1);
+
+            let log = events[0].as_log();
+            assert_eq!(log["message"], "hello".into());
+            assert_eq!(log["int"], 42.into());
+            assert_eq!(log["float"], Value::from_f64_or_zero(1.5));
+            assert_eq!(log["bool"], true.into());
+            assert_eq!(log["null"], Value::Null);
+            assert_eq!(log["binary"], Value::Bytes(Bytes::from_static(b"\x00\x01")));
+            assert_eq!(log["array"], Value::Array(vec![1.into(), "two".into()]));
+            assert_eq!(log["nested.key"], "value".into());
+            assert_eq!(log["ts"], Value::Timestamp(timestamp));
+
+            match namespace {
+                LogNamespace::Legacy => {
+                    assert!(log.get(log_schema().timestamp_key()).is_some());
+                }
+                LogNamespace::Vector => {
+                    assert!(log.get(log_schema().timestamp_key()).is_none());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_array_and_concatenated_values() {
+        let mut input = encode(&rmpv::Value::Array(vec![
+            map(vec![("n", 1.into())]),
+            map(vec![("n", 2.into())]),
+        ]))
+        .to_vec();
+        input.extend_from_slice(&encode(&map(vec![("n", 3.into())])));
+
+        let events = MsgpackDeserializer::new()
+            .parse(input.into(), LogNamespace::Legacy)
+            .unwrap();
+        let numbers = events
+            .iter()
+            .map(|event| event.as_log()["n"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![1.into(), 2.into(), 3.into()]);
+    }
+
+    #[test]
+    fn deserialize_empty() {
+        let events = MsgpackDeserializer::new()
+            .parse(Bytes::new(), LogNamespace::Legacy)
+            .unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn deserialize_unknown_extension() {
+        let input = encode(&map(vec![("ext", rmpv::Value::Ext(5, vec![1, 2]))]));
+        let events = MsgpackDeserializer::new()
+            .parse(input, LogNamespace::Vector)
+            .unwrap();
+        let log = events[0].as_log();
+        assert_eq!(log["ext.msgpack_extension_code"], 5.into());
+        assert_eq!(log["ext.bytes"], Value::Bytes(Bytes::from_static(&[1, 2])));
+    }
+
+    #[test]
+    fn deserialize_error_non_map_legacy() {
+        let input = encode(&rmpv::Value::from("hello"));
+        assert!(MsgpackDeserializer::new()
+            .parse(input.clone(), LogNamespace::Legacy)
+            .is_err());
+
+        let events = MsgpackDeserializer::new()
+            .parse(input, LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(*events[0].as_log().value(), "hello".into());
+    }
+
+    #[test]
+    fn deserialize_error_truncated() {
+        let input = encode(&map(vec![("message", "hello".into())]));
+        assert!(MsgpackDeserializer::new()
+            .parse(input.slice(..input.len() - 1), LogNamespace::Legacy)
+            .is_err());
+    }
+}
@@ -0,0 +1,292 @@
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use chrono::{TimeZone, Utc};
+use ordered_float::NotNan;
+use prost_reflect::{DynamicMessage, MessageDescriptor};
+use serde::{Deserialize, Serialize};
+use smallvec::{smallvec, SmallVec};
+use value::{Kind, Value};
+use vector_config::configurable_component;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::Event,
+    schema,
+};
+
+use super::Deserializer;
+use crate::{decoding::BuildError, protobuf::get_message_descriptor};
+
+/// Config used to build a `ProtobufDeserializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProtobufDeserializerConfig {
+    /// Options for the Protobuf deserializer.
+    pub protobuf: ProtobufDeserializerOptions,
+}
+
+impl ProtobufDeserializerConfig {
+    /// Build the `ProtobufDeserializer` from this configuration.
+    pub fn build(&self) -> Result<ProtobufDeserializer, BuildError> {
+        let message_descriptor =
+            get_message_descriptor(&self.protobuf.desc_file, &self.protobuf.message_type)?;
+        Ok(ProtobufDeserializer::new(message_descriptor))
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        match log_namespace {
+            LogNamespace::Legacy => schema::Definition::empty_legacy_namespace()
+                .unknown_fields(Kind::any())
+                .try_with_field(
+                    log_schema().timestamp_key(),
+                    Kind::any().or_timestamp(),
+                    Some("timestamp"),
+                ),
+            LogNamespace::Vector => {
+                schema::Definition::new_with_default_metadata(Kind::any(), [log_namespace])
+            }
+        }
+    }
+}
+
+/// Protobuf deserializer options.
+#[configurable_component]
+#[derive(Debug, Clone)]
+pub struct ProtobufDeserializerOptions {
+    /// Path to the compiled Protobuf descriptor set.
+    ///
+    /// This is the file produced by `protoc --include_imports --descriptor_set_out=<file>`.
+    pub desc_file: PathBuf,
+
+    /// The fully qualified name of the message type to decode, e.g. `package.Message`.
+    pub message_type: String,
+}
+
+/// Deserializer that builds `Event`s from a byte frame containing a Protobuf message.
+#[derive(Debug, Clone)]
+pub struct ProtobufDeserializer {
+    message_descriptor: MessageDescriptor,
+}
+
+impl ProtobufDeserializer {
+    /// Creates a new `ProtobufDeserializer` for the given message type.
+    pub const fn new(message_descriptor: MessageDescriptor) -> Self {
+        Self { message_descriptor }
+    }
+}
+
+impl Deserializer for ProtobufDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_core::Result<SmallVec<[Event; 1]>> {
+        let message = DynamicMessage::decode(self.message_descriptor.clone(), bytes)
+            .map_err(|error| format!("Error parsing Protobuf: {:?}", error))?;
+
+        let mut log = log_namespace.new_log_from_data(message_to_value(&message));
+
+        if log_namespace == LogNamespace::Legacy {
+            let timestamp_key = log_schema().timestamp_key();
+            if !log.contains(timestamp_key) {
+                log.insert(timestamp_key, Utc::now());
+            }
+        }
+
+        Ok(smallvec![log.into()])
+    }
+}
+
+/// Converts a decoded message into an object, keyed by field name. Fields that are not present
+/// in the message are omitted, rather than being filled in with their default value.
+fn message_to_value(message: &DynamicMessage) -> Value {
+    let descriptor = message.descriptor();
+
+    if descriptor.full_name() == "google.protobuf.Timestamp" {
+        if let Some(timestamp) = timestamp_to_value(message) {
+            return timestamp;
+        }
+    }
+
+    let fields = descriptor
+        .fields()
+        .filter(|field| message.has_field(field))
+        .map(|field| {
+            let value = message.get_field(&field);
+            (field.name().to_owned(), to_value(&value, &field.kind()))
+        })
+        .collect();
+
+    Value::Object(fields)
+}
+
+fn timestamp_to_value(message: &DynamicMessage) -> Option<Value> {
+    let seconds = message.get_field_by_name("seconds")?.as_i64()?;
+    let nanos = message.get_field_by_name("nanos")?.as_i32()?;
+    Utc.timestamp_opt(seconds, nanos as u32)
+        .single()
+        .map(Value::Timestamp)
+}
+
+fn to_value(value: &prost_reflect::Value, kind: &prost_reflect::Kind) -> Value {
+    use prost_reflect::Value as Proto;
+
+    match value {
+        Proto::Bool(value) => Value::Boolean(*value),
+        Proto::I32(value) => Value::Integer(i64::from(*value)),
+        Proto::I64(value) => Value::Integer(*value),
+        Proto::U32(value) => Value::Integer(i64::from(*value)),
+        Proto::U64(value) => i64::try_from(*value)
+            .map(Value::Integer)
+            .unwrap_or_else(|_| Value::from_f64_or_zero(*value as f64)),
+        Proto::F32(value) => Value::from_f64_or_zero(f64::from(*value)),
+        Proto::F64(value) => NotNan::new(*value).map(Value::Float).unwrap_or(Value::Null),
+        Proto::String(value) => Value::Bytes(Bytes::from(value.clone())),
+        Proto::Bytes(value) => Value::Bytes(value.clone()),
+        Proto::EnumNumber(number) => match kind {
+            prost_reflect::Kind::Enum(descriptor) => descriptor
+                .get_value(*number)
+                .map(|value| Value::Bytes(Bytes::from(value.name().to_owned())))
+                .unwrap_or_else(|| Value::Integer(i64::from(*number))),
+            _ => Value::Integer(i64::from(*number)),
+        },
+        Proto::Message(message) => message_to_value(message),
+        Proto::List(values) => {
+            Value::Array(values.iter().map(|value| to_value(value, kind)).collect())
+        }
+        Proto::Map(entries) => {
+            let value_kind = match kind {
+                prost_reflect::Kind::Message(entry) => entry.map_entry_value_field().kind(),
+                _ => kind.clone(),
+            };
+            Value::Object(
+                entries
+                    .iter()
+                    .map(|(key, value)| (map_key_to_string(key), to_value(value, &value_kind)))
+                    .collect(),
+            )
+        }
+    }
+}
+
+fn map_key_to_string(key: &prost_reflect::MapKey) -> String {
+    use prost_reflect::MapKey;
+
+    match key {
+        MapKey::Bool(key) => key.to_string(),
+        MapKey::I32(key) => key.to_string(),
+        MapKey::I64(key) => key.to_string(),
+        MapKey::U32(key) => key.to_string(),
+        MapKey::U64(key) => key.to_string(),
+        MapKey::String(key) => key.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_reflect::Value as ProtoValue;
+    use vector_core::config::log_schema;
+
+    use super::*;
+    use crate::protobuf::{get_message_descriptor_from_bytes, test_util::person_descriptor_set};
+
+    fn person_descriptor() -> MessageDescriptor {
+        get_message_descriptor_from_bytes(&person_descriptor_set(), "test.Person").unwrap()
+    }
+
+    fn person() -> Bytes {
+        let descriptor = person_descriptor();
+        let mut message = DynamicMessage::new(descriptor.clone());
+        message.set_field_by_name("name", ProtoValue::String("alice".to_owned()));
+        message.set_field_by_name("id", ProtoValue::I64(42));
+        message.set_field_by_name("score", ProtoValue::F64(1.5));
+        message.set_field_by_name("active", ProtoValue::Bool(true));
+        message.set_field_by_name(
+            "tags",
+            ProtoValue::List(vec![
+                ProtoValue::String("a".to_owned()),
+                ProtoValue::String("b".to_owned()),
+            ]),
+        );
+        message.set_field_by_name("status", ProtoValue::EnumNumber(1));
+
+        let mut address = DynamicMessage::new(
+            descriptor
+                .get_field_by_name("address")
+                .unwrap()
+                .kind()
+                .as_message()
+                .unwrap()
+                .clone(),
+        );
+        address.set_field_by_name("city", ProtoValue::String("Paris".to_owned()));
+        message.set_field_by_name("address", ProtoValue::Message(address));
+
+        Bytes::from(prost::Message::encode_to_vec(&message))
+    }
+
+    #[test]
+    fn deserialize_protobuf() {
+        let deserializer = ProtobufDeserializer::new(person_descriptor());
+
+        for namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+            let events = deserializer.parse(person(), namespace).unwrap();
+            assert_eq!(events.len(), 1);
+
+            let log = events[0].as_log();
+            assert_eq!(log["name"], "alice".into());
+            assert_eq!(log["id"], 42.into());
+            assert_eq!(log["score"], Value::from_f64_or_zero(1.5));
+            assert_eq!(log["active"], true.into());
+            assert_eq!(log["tags"], vec!["a", "b"].into());
+            assert_eq!(log["status"], "ACTIVE".into());
+            assert_eq!(log["address.city"], "Paris".into());
+            assert!(!log.contains("labels"));
+            assert!(!log.contains("data"));
+
+            match namespace {
+                LogNamespace::Legacy => {
+                    assert!(log.get(log_schema().timestamp_key()).is_some());
+                }
+                LogNamespace::Vector => {
+                    assert!(log.get(log_schema().timestamp_key()).is_none());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_empty_message() {
+        let deserializer = ProtobufDeserializer::new(person_descriptor());
+
+        let events = deserializer
+            .parse(Bytes::new(), LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            *events[0].as_log().value(),
+            Value::Object(Default::default())
+        );
+    }
+
+    #[test]
+    fn deserialize_error_invalid_protobuf() {
+        let deserializer = ProtobufDeserializer::new(person_descriptor());
+
+        assert!(deserializer
+            .parse(Bytes::from_static(b"\xff\xff\xff"), LogNamespace::Vector)
+            .is_err());
+    }
+
+    #[test]
+    fn unknown_message_type() {
+        let error = get_message_descriptor_from_bytes(&person_descriptor_set(), "test.Missing")
+            .unwrap_err();
+        assert!(error.to_string().contains("test.Missing"));
+    }
+}
@@ -8,9 +8,12 @@ pub mod framing;
 use bytes::{Bytes, BytesMut};
 pub use error::StreamDecodingError;
 pub use format::{
-    BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, GelfDeserializer,
-    GelfDeserializerConfig, JsonDeserializer, JsonDeserializerConfig, NativeDeserializer,
-    NativeDeserializerConfig, NativeJsonDeserializer, NativeJsonDeserializerConfig,
+    AvroDeserializer, AvroDeserializerConfig, AvroDeserializerOptions, BoxedDeserializer,
+    BytesDeserializer, BytesDeserializerConfig, GelfDeserializer, GelfDeserializerConfig,
+    JsonDeserializer, JsonDeserializerConfig, MsgpackDeserializer, MsgpackDeserializerConfig,
+    NativeDeserializer, NativeDeserializerConfig, NativeJsonDeserializer,
+    NativeJsonDeserializerConfig, ProtobufDeserializer, ProtobufDeserializerConfig,
+    ProtobufDeserializerOptions,
 };
 #[cfg(feature = "syslog")]
 pub use format::{SyslogDeserializer, SyslogDeserializerConfig};
@@ -30,6 +33,9 @@ use vector_core::{
     schema,
 };
 
+/// An error that occurred while building a decoder.
+pub type BuildError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
 /// An error that occurred while decoding structured events from a byte stream /
 /// byte messages.
 #[derive(Debug)]
@@ -224,10 +230,17 @@ impl tokio_util::codec::Decoder for Framer {
 #[derive(Clone, Debug)]
 #[serde(tag = "codec", rename_all = "snake_case")]
 pub enum DeserializerConfig {
+    /// Configures the `AvroDeserializer`.
+    Avro {
+        /// Options for the Avro deserializer.
+        avro: AvroDeserializerOptions,
+    },
     /// Configures the `BytesDeserializer`.
     Bytes,
     /// Configures the `JsonDeserializer`.
     Json,
+    /// Configures the `MsgpackDeserializer`.
+    Msgpack,
     #[cfg(feature = "syslog")]
     /// Configures the `SyslogDeserializer`.
     Syslog,
@@ -237,6 +250,17 @@ pub enum DeserializerConfig {
     NativeJson,
     /// Configures the `GelfDeserializer`.
     Gelf,
+    /// Configures the `ProtobufDeserializer`.
+    Protobuf {
+        /// Options for the Protobuf deserializer.
+        protobuf: ProtobufDeserializerOptions,
+    },
+}
+
+impl From<AvroDeserializerConfig> for DeserializerConfig {
+    fn from(config: AvroDeserializerConfig) -> Self {
+        Self::Avro { avro: config.avro }
+    }
 }
 
 impl From<BytesDeserializerConfig> for DeserializerConfig {
@@ -251,6 +275,12 @@ impl From<JsonDeserializerConfig> for DeserializerConfig {
     }
 }
 
+impl From<MsgpackDeserializerConfig> for DeserializerConfig {
+    fn from(_: MsgpackDeserializerConfig) -> Self {
+        Self::Msgpack
+    }
+}
+
 #[cfg(feature = "syslog")]
 impl From<SyslogDeserializerConfig> for DeserializerConfig {
     fn from(_: SyslogDeserializerConfig) -> Self {
@@ -264,12 +294,24 @@ impl From<GelfDeserializerConfig> for DeserializerConfig {
     }
 }
 
+impl From<ProtobufDeserializerConfig> for DeserializerConfig {
+    fn from(config: ProtobufDeserializerConfig) -> Self {
+        Self::Protobuf {
+            protobuf: config.protobuf,
+        }
+    }
+}
+
 impl DeserializerConfig {
     /// Build the `Deserializer` from this configuration.
-    pub fn build(&self) -> Deserializer {
-        match self {
+    pub fn build(&self) -> Result<Deserializer, BuildError> {
+        Ok(match self {
+            DeserializerConfig::Avro { avro } => {
+                Deserializer::Avro(AvroDeserializerConfig::new(avro.clone()).build()?)
+            }
             DeserializerConfig::Bytes => Deserializer::Bytes(BytesDeserializerConfig.build()),
             DeserializerConfig::Json => Deserializer::Json(JsonDeserializerConfig.build()),
+            DeserializerConfig::Msgpack => Deserializer::Msgpack(MsgpackDeserializerConfig.build()),
             #[cfg(feature = "syslog")]
             DeserializerConfig::Syslog => Deserializer::Syslog(SyslogDeserializerConfig.build()),
             DeserializerConfig::Native => Deserializer::Native(NativeDeserializerConfig.build()),
@@ -277,13 +319,22 @@ impl DeserializerConfig {
                 Deserializer::NativeJson(NativeJsonDeserializerConfig.build())
             }
             DeserializerConfig::Gelf => Deserializer::Gelf(GelfDeserializerConfig.build()),
-        }
+            DeserializerConfig::Protobuf { protobuf } => Deserializer::Protobuf(
+                ProtobufDeserializerConfig {
+                    protobuf: protobuf.clone(),
+                }
+                .build()?,
+            ),
+        })
     }
 
     /// Return an appropriate default framer for the given deserializer
     pub fn default_stream_framing(&self) -> FramingConfig {
         match self {
-            DeserializerConfig::Native => FramingConfig::LengthDelimited,
+            DeserializerConfig::Avro { .. }
+            | DeserializerConfig::Msgpack
+            | DeserializerConfig::Native
+            | DeserializerConfig::Protobuf { .. } => FramingConfig::LengthDelimited,
             DeserializerConfig::Bytes
             | DeserializerConfig::Json
             | DeserializerConfig::Gelf
@@ -300,21 +351,35 @@ impl DeserializerConfig {
     /// Return the type of event build by this deserializer.
     pub fn output_type(&self) -> DataType {
         match self {
+            DeserializerConfig::Avro { avro } => {
+                AvroDeserializerConfig::new(avro.clone()).output_type()
+            }
             DeserializerConfig::Bytes => BytesDeserializerConfig.output_type(),
             DeserializerConfig::Json => JsonDeserializerConfig.output_type(),
+            DeserializerConfig::Msgpack => MsgpackDeserializerConfig.output_type(),
             #[cfg(feature = "syslog")]
             DeserializerConfig::Syslog => SyslogDeserializerConfig.output_type(),
             DeserializerConfig::Native => NativeDeserializerConfig.output_type(),
             DeserializerConfig::NativeJson => NativeJsonDeserializerConfig.output_type(),
             DeserializerConfig::Gelf => GelfDeserializerConfig.output_type(),
+            DeserializerConfig::Protobuf { protobuf } => ProtobufDeserializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .output_type(),
         }
     }
 
     /// The schema produced by the deserializer.
     pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
         match self {
+            DeserializerConfig::Avro { avro } => {
+                AvroDeserializerConfig::new(avro.clone()).schema_definition(log_namespace)
+            }
             DeserializerConfig::Bytes => BytesDeserializerConfig.schema_definition(log_namespace),
             DeserializerConfig::Json => JsonDeserializerConfig.schema_definition(log_namespace),
+            DeserializerConfig::Msgpack => {
+                MsgpackDeserializerConfig.schema_definition(log_namespace)
+            }
             #[cfg(feature = "syslog")]
             DeserializerConfig::Syslog => SyslogDeserializerConfig.schema_definition(log_namespace),
             DeserializerConfig::Native => NativeDeserializerConfig.schema_definition(log_namespace),
@@ -322,6 +387,10 @@ impl DeserializerConfig {
                 NativeJsonDeserializerConfig.schema_definition(log_namespace)
             }
             DeserializerConfig::Gelf => GelfDeserializerConfig.schema_definition(log_namespace),
+            DeserializerConfig::Protobuf { protobuf } => ProtobufDeserializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .schema_definition(log_namespace),
         }
     }
 }
@@ -329,10 +398,14 @@ impl DeserializerConfig {
 /// Parse structured events from bytes.
 #[derive(Debug, Clone)]
 pub enum Deserializer {
+    /// Uses an `AvroDeserializer` for deserialization.
+    Avro(AvroDeserializer),
     /// Uses a `BytesDeserializer` for deserialization.
     Bytes(BytesDeserializer),
     /// Uses a `JsonDeserializer` for deserialization.
     Json(JsonDeserializer),
+    /// Uses a `MsgpackDeserializer` for deserialization.
+    Msgpack(MsgpackDeserializer),
     #[cfg(feature = "syslog")]
     /// Uses a `SyslogDeserializer` for deserialization.
     Syslog(SyslogDeserializer),
@@ -344,6 +417,8 @@ pub enum Deserializer {
     Boxed(BoxedDeserializer),
     /// Uses a `GelfDeserializer` for deserialization.
     Gelf(GelfDeserializer),
+    /// Uses a `ProtobufDeserializer` for deserialization.
+    Protobuf(ProtobufDeserializer),
 }
 
 impl format::Deserializer for Deserializer {
@@ -353,14 +428,17 @@ impl format::Deserializer for Deserializer {
         log_namespace: LogNamespace,
     ) -> vector_core::Result<SmallVec<[Event; 1]>> {
         match self {
+            Deserializer::Avro(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Bytes(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Json(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Msgpack(deserializer) => deserializer.parse(bytes, log_namespace),
             #[cfg(feature = "syslog")]
             Deserializer::Syslog(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Native(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::NativeJson(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Boxed(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Gelf(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::Protobuf(deserializer) => deserializer.parse(bytes, log_namespace),
         }
     }
 }
@@ -0,0 +1,305 @@
+use bytes::BytesMut;
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use vector_config::configurable_component;
+use vector_core::{config::DataType, event::Event, schema};
+
+/// Config used to build a `CsvSerializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CsvSerializerConfig {
+    /// Options for the CSV serializer.
+    pub csv: CsvSerializerOptions,
+}
+
+impl CsvSerializerConfig {
+    /// Creates a new `CsvSerializerConfig`.
+    pub const fn new(csv: CsvSerializerOptions) -> Self {
+        Self { csv }
+    }
+
+    /// Build the `CsvSerializer` from this configuration.
+    pub fn build(&self) -> CsvSerializer {
+        CsvSerializer::new(self.csv.clone())
+    }
+
+    /// The data type of events that are accepted by `CsvSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// The quoting style to use when writing CSV fields.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvQuoteStyle {
+    /// Always quote every field.
+    Always,
+
+    /// Only quote fields which contain the delimiter, the quote character or a line terminator.
+    Necessary,
+
+    /// Quote every field which is not a number.
+    NonNumeric,
+
+    /// Never quote fields, even if doing so would produce invalid CSV.
+    Never,
+}
+
+impl Default for CsvQuoteStyle {
+    fn default() -> Self {
+        Self::Necessary
+    }
+}
+
+impl From<CsvQuoteStyle> for csv::QuoteStyle {
+    fn from(style: CsvQuoteStyle) -> Self {
+        match style {
+            CsvQuoteStyle::Always => csv::QuoteStyle::Always,
+            CsvQuoteStyle::Necessary => csv::QuoteStyle::Necessary,
+            CsvQuoteStyle::NonNumeric => csv::QuoteStyle::NonNumeric,
+            CsvQuoteStyle::Never => csv::QuoteStyle::Never,
+        }
+    }
+}
+
+/// CSV serializer options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct CsvSerializerOptions {
+    /// The fields to write, in column order.
+    ///
+    /// Fields which are missing from an event are written as empty columns. Fields of the event
+    /// which aren't listed are not written.
+    pub fields: Vec<String>,
+
+    /// The ASCII (7-bit) character that separates fields.
+    #[serde(default = "default_delimiter", with = "vector_core::serde::ascii_char")]
+    pub delimiter: u8,
+
+    /// The quoting style to use when writing fields.
+    #[serde(default)]
+    pub quote_style: CsvQuoteStyle,
+
+    /// The ASCII (7-bit) character used to quote fields.
+    #[serde(default = "default_quote", with = "vector_core::serde::ascii_char")]
+    pub quote: u8,
+
+    /// The ASCII (7-bit) character used to escape quotes inside of quoted fields.
+    ///
+    /// When this is the same as `quote`, quotes are escaped by doubling them, as per RFC 4180.
+    #[serde(default = "default_quote", with = "vector_core::serde::ascii_char")]
+    pub escape: u8,
+
+    /// Whether to write a header line with the field names.
+    ///
+    /// The header is written once at the start of every output file or object.
+    #[serde(default)]
+    pub include_header: bool,
+}
+
+const fn default_delimiter() -> u8 {
+    b','
+}
+
+const fn default_quote() -> u8 {
+    b'"'
+}
+
+impl CsvSerializerOptions {
+    /// Creates `CsvSerializerOptions` for the given fields, with the default dialect.
+    pub fn new(fields: Vec<String>) -> Self {
+        Self {
+            fields,
+            delimiter: default_delimiter(),
+            quote_style: CsvQuoteStyle::default(),
+            quote: default_quote(),
+            escape: default_quote(),
+            include_header: false,
+        }
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the CSV format.
+///
+/// Each event is written as a single record, without a trailing line terminator, since that is
+/// handled by the framer.
+#[derive(Debug, Clone)]
+pub struct CsvSerializer {
+    options: CsvSerializerOptions,
+}
+
+impl CsvSerializer {
+    /// Creates a new `CsvSerializer`.
+    pub const fn new(options: CsvSerializerOptions) -> Self {
+        Self { options }
+    }
+
+    /// Returns the header line for this serializer, if it has been configured to write one.
+    pub fn header(&self) -> Option<BytesMut> {
+        if !self.options.include_header {
+            return None;
+        }
+
+        let mut buffer = BytesMut::new();
+        self.write_record(
+            self.options.fields.iter().map(String::as_bytes),
+            &mut buffer,
+        )
+        .ok()?;
+        Some(buffer)
+    }
+
+    fn write_record<I, F>(&self, record: I, buffer: &mut BytesMut) -> Result<(), vector_core::Error>
+    where
+        I: IntoIterator<Item = F>,
+        F: AsRef<[u8]>,
+    {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(self.options.delimiter)
+            .quote_style(self.options.quote_style.into())
+            .quote(self.options.quote)
+            .double_quote(self.options.escape == self.options.quote)
+            .escape(self.options.escape)
+            .terminator(csv::Terminator::Any(b'\n'))
+            .from_writer(Vec::new());
+        writer.write_record(record)?;
+        let record = writer
+            .into_inner()
+            .map_err(|error| format!("Failed to write CSV record: {}", error.error()))?;
+
+        // Strip the line terminator, since records are separated by the framer.
+        buffer.extend_from_slice(record.strip_suffix(b"\n").unwrap_or(&record));
+        Ok(())
+    }
+}
+
+impl Encoder<Event> for CsvSerializer {
+    type Error = vector_core::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let values = self.options.fields.iter().map(|field| {
+            log.get(field.as_str())
+                .filter(|value| !value.is_null())
+                .map(|value| value.coerce_to_bytes())
+                .unwrap_or_default()
+        });
+        let values = values.collect::<Vec<_>>();
+        self.write_record(values, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use chrono::{TimeZone, Utc};
+    use vector_common::btreemap;
+    use vector_core::event::{LogEvent, Value};
+
+    use super::*;
+
+    fn fields(fields: &[&str]) -> Vec<String> {
+        fields.iter().map(|field| field.to_string()).collect()
+    }
+
+    fn serialize(options: CsvSerializerOptions, event: Event) -> String {
+        let mut serializer = CsvSerializerConfig::new(options).build();
+        let mut bytes = BytesMut::new();
+        serializer.encode(event, &mut bytes).unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    fn event() -> Event {
+        Event::Log(LogEvent::from(btreemap! {
+            "foo" => "bar",
+            "int" => 123,
+            "float" => Value::from_f64_or_zero(3.5),
+            "bool" => true,
+            "timestamp" => Utc.ymd(2022, 7, 1).and_hms(12, 0, 0),
+            "quote" => "say \"hi\"",
+            "comma" => "a,b",
+            "object" => btreemap! { "key" => "value" },
+        }))
+    }
+
+    #[test]
+    fn serialize_fields_in_order() {
+        let options = CsvSerializerOptions::new(fields(&[
+            "int",
+            "foo",
+            "missing",
+            "bool",
+            "float",
+            "timestamp",
+        ]));
+
+        assert_eq!(
+            serialize(options, event()),
+            "123,bar,,true,3.5,2022-07-01T12:00:00Z"
+        );
+    }
+
+    #[test]
+    fn serialize_quoting() {
+        let options = CsvSerializerOptions::new(fields(&["quote", "comma", "object"]));
+
+        assert_eq!(
+            serialize(options, event()),
+            r#""say ""hi""","a,b","{""key"":""value""}""#
+        );
+    }
+
+    #[test]
+    fn serialize_custom_dialect() {
+        let options = CsvSerializerOptions {
+            delimiter: b';',
+            quote_style: CsvQuoteStyle::Always,
+            quote: b'\'',
+            escape: b'\\',
+            ..CsvSerializerOptions::new(fields(&["foo", "int", "comma"]))
+        };
+
+        assert_eq!(serialize(options, event()), "'bar';'123';'a,b'");
+    }
+
+    #[test]
+    fn serialize_custom_escape() {
+        let options = CsvSerializerOptions {
+            escape: b'\\',
+            ..CsvSerializerOptions::new(fields(&["quote"]))
+        };
+
+        assert_eq!(serialize(options, event()), r#""say \"hi\"""#);
+    }
+
+    #[test]
+    fn serialize_non_numeric() {
+        let options = CsvSerializerOptions {
+            quote_style: CsvQuoteStyle::NonNumeric,
+            ..CsvSerializerOptions::new(fields(&["foo", "int"]))
+        };
+
+        assert_eq!(serialize(options, event()), r#""bar",123"#);
+    }
+
+    #[test]
+    fn header() {
+        let options = CsvSerializerOptions::new(fields(&["foo", "a,b"]));
+        assert!(CsvSerializer::new(options.clone()).header().is_none());
+
+        let options = CsvSerializerOptions {
+            include_header: true,
+            ..options
+        };
+        assert_eq!(
+            CsvSerializer::new(options).header().unwrap().as_ref(),
+            b"foo,\"a,b\""
+        );
+    }
+}
@@ -4,23 +4,29 @@
 #![deny(missing_docs)]
 
 mod avro;
+mod csv;
 mod gelf;
 mod json;
 mod logfmt;
+mod msgpack;
 mod native;
 mod native_json;
+mod protobuf;
 mod raw_message;
 mod text;
 
 use std::fmt::Debug;
 
+pub use self::csv::{CsvQuoteStyle, CsvSerializer, CsvSerializerConfig, CsvSerializerOptions};
 pub use avro::{AvroSerializer, AvroSerializerConfig, AvroSerializerOptions};
 use dyn_clone::DynClone;
 pub use gelf::{GelfSerializer, GelfSerializerConfig};
 pub use json::{JsonSerializer, JsonSerializerConfig};
 pub use logfmt::{LogfmtSerializer, LogfmtSerializerConfig};
+pub use msgpack::{MsgpackSerializer, MsgpackSerializerConfig};
 pub use native::{NativeSerializer, NativeSerializerConfig};
 pub use native_json::{NativeJsonSerializer, NativeJsonSerializerConfig};
+pub use protobuf::{ProtobufSerializer, ProtobufSerializerConfig, ProtobufSerializerOptions};
 pub use raw_message::{RawMessageSerializer, RawMessageSerializerConfig};
 pub use text::{TextSerializer, TextSerializerConfig};
 use vector_core::event::Event;
@@ -0,0 +1,140 @@
+use bytes::{BufMut, BytesMut};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use value::Value;
+use vector_core::{config::DataType, event::Event, schema};
+
+use crate::msgpack::{encode_timestamp, TIMESTAMP_EXT_TYPE};
+
+/// Config used to build a `MsgpackSerializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct MsgpackSerializerConfig;
+
+impl MsgpackSerializerConfig {
+    /// Creates a new `MsgpackSerializerConfig`.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Build the `MsgpackSerializer` from this configuration.
+    pub const fn build(&self) -> MsgpackSerializer {
+        MsgpackSerializer
+    }
+
+    /// The data type of events that are accepted by `MsgpackSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        // While technically we support `Value` variants that can't be losslessly serialized to
+        // MessagePack, we don't want to enforce that limitation to users yet.
+        schema::Requirement::empty()
+    }
+}
+
+/// Serializer that converts an `Event` to bytes using the MessagePack format.
+#[derive(Debug, Clone)]
+pub struct MsgpackSerializer;
+
+impl MsgpackSerializer {
+    /// Creates a new `MsgpackSerializer`.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Encoder<Event> for MsgpackSerializer {
+    type Error = vector_core::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let value = from_value(log.value());
+        rmpv::encode::write_value(&mut buffer.writer(), &value)?;
+        Ok(())
+    }
+}
+
+fn from_value(value: &Value) -> rmpv::Value {
+    match value {
+        Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+            Ok(string) => rmpv::Value::from(string),
+            Err(_) => rmpv::Value::Binary(bytes.to_vec()),
+        },
+        Value::Regex(regex) => rmpv::Value::from(regex.as_str()),
+        Value::Integer(value) => rmpv::Value::from(*value),
+        Value::Float(value) => rmpv::Value::F64(value.into_inner()),
+        Value::Boolean(value) => rmpv::Value::Boolean(*value),
+        Value::Timestamp(timestamp) => {
+            rmpv::Value::Ext(TIMESTAMP_EXT_TYPE, encode_timestamp(timestamp))
+        }
+        Value::Object(fields) => rmpv::Value::Map(
+            fields
+                .iter()
+                .map(|(key, value)| (rmpv::Value::from(key.as_str()), from_value(value)))
+                .collect(),
+        ),
+        Value::Array(values) => rmpv::Value::Array(values.iter().map(from_value).collect()),
+        Value::Null => rmpv::Value::Nil,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::{Buf, BytesMut};
+    use chrono::{TimeZone, Utc};
+    use vector_common::btreemap;
+    use vector_core::event::LogEvent;
+
+    use super::*;
+    use crate::{decoding::format::Deserializer, MsgpackDeserializer};
+
+    fn serialize(value: Value) -> BytesMut {
+        let mut serializer = MsgpackSerializerConfig::new().build();
+        let mut bytes = BytesMut::new();
+        serializer
+            .encode(Event::from(LogEvent::from(value)), &mut bytes)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn serialize_msgpack() {
+        let bytes = serialize(Value::from(btreemap! {
+            "message" => "hello",
+            "int" => 42,
+            "binary" => Value::Bytes(bytes::Bytes::from_static(b"\xff")),
+        }));
+
+        let value = rmpv::decode::read_value(&mut bytes.reader()).unwrap();
+        assert_eq!(
+            value,
+            rmpv::Value::Map(vec![
+                (rmpv::Value::from("binary"), rmpv::Value::Binary(vec![0xff])),
+                (rmpv::Value::from("int"), rmpv::Value::from(42)),
+                (rmpv::Value::from("message"), rmpv::Value::from("hello")),
+            ])
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let value = Value::from(btreemap! {
+            "message" => "hello",
+            "int" => -7,
+            "float" => Value::from_f64_or_zero(2.5),
+            "bool" => false,
+            "null" => Value::Null,
+            "array" => vec![Value::from(1), Value::from("two")],
+            "nested" => btreemap! { "key" => "value" },
+            "timestamp" => Utc.timestamp(1_600_000_000, 999_999_999),
+        });
+
+        let bytes = serialize(value.clone());
+        let events = MsgpackDeserializer::new()
+            .parse(bytes.freeze(), vector_core::config::LogNamespace::Vector)
+            .unwrap();
+        assert_eq!(*events[0].as_log().value(), value);
+    }
+}
@@ -0,0 +1,328 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+};
+
+use bytes::BytesMut;
+use prost::Message;
+use prost_reflect::{DynamicMessage, FieldDescriptor, Kind, MapKey, MessageDescriptor};
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use value::Value;
+use vector_config::configurable_component;
+use vector_core::{config::DataType, event::Event, schema};
+
+use crate::{encoding::BuildError, protobuf::get_message_descriptor};
+
+/// Config used to build a `ProtobufSerializer`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProtobufSerializerConfig {
+    /// Options for the Protobuf serializer.
+    pub protobuf: ProtobufSerializerOptions,
+}
+
+impl ProtobufSerializerConfig {
+    /// Build the `ProtobufSerializer` from this configuration.
+    pub fn build(&self) -> Result<ProtobufSerializer, BuildError> {
+        let message_descriptor =
+            get_message_descriptor(&self.protobuf.desc_file, &self.protobuf.message_type)?;
+        Ok(ProtobufSerializer::new(message_descriptor))
+    }
+
+    /// The data type of events that are accepted by `ProtobufSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        // TODO: Convert the message descriptor to a vector schema requirement.
+        schema::Requirement::empty()
+    }
+}
+
+/// Protobuf serializer options.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub struct ProtobufSerializerOptions {
+    /// Path to the compiled Protobuf descriptor set.
+    ///
+    /// This is the file produced by `protoc --include_imports --descriptor_set_out=<file>`.
+    pub desc_file: PathBuf,
+
+    /// The fully qualified name of the message type to encode, e.g. `package.Message`.
+    pub message_type: String,
+}
+
+/// Serializer that converts an `Event` to bytes using a Protobuf message type.
+///
+/// Fields of the event which are not part of the message type are dropped.
+#[derive(Debug, Clone)]
+pub struct ProtobufSerializer {
+    message_descriptor: MessageDescriptor,
+}
+
+impl ProtobufSerializer {
+    /// Creates a new `ProtobufSerializer` for the given message type.
+    pub const fn new(message_descriptor: MessageDescriptor) -> Self {
+        Self { message_descriptor }
+    }
+}
+
+impl Encoder<Event> for ProtobufSerializer {
+    type Error = vector_core::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+        let message = match log.value() {
+            Value::Object(fields) => encode_message(&self.message_descriptor, fields)?,
+            value => {
+                return Err(
+                    format!("Expected an object at the event root, found {:?}.", value).into(),
+                )
+            }
+        };
+        message.encode(buffer)?;
+        Ok(())
+    }
+}
+
+fn encode_message(
+    descriptor: &MessageDescriptor,
+    fields: &BTreeMap<String, Value>,
+) -> vector_core::Result<DynamicMessage> {
+    let mut message = DynamicMessage::new(descriptor.clone());
+
+    for (name, value) in fields {
+        let field = match descriptor.get_field_by_name(name) {
+            Some(field) => field,
+            None => continue,
+        };
+        if matches!(value, Value::Null) {
+            continue;
+        }
+        let value = encode_field(&field, value)?;
+        message.set_field(&field, value);
+    }
+
+    Ok(message)
+}
+
+fn encode_field(
+    field: &FieldDescriptor,
+    value: &Value,
+) -> vector_core::Result<prost_reflect::Value> {
+    if field.is_map() {
+        let entry = match field.kind() {
+            Kind::Message(entry) => entry,
+            _ => unreachable!("map fields always have a message entry type"),
+        };
+        let key_kind = entry.map_entry_key_field().kind();
+        let value_kind = entry.map_entry_value_field().kind();
+        return match value {
+            Value::Object(entries) => entries
+                .iter()
+                .map(|(key, value)| {
+                    Ok((
+                        encode_map_key(&key_kind, key)?,
+                        encode_value(field, &value_kind, value)?,
+                    ))
+                })
+                .collect::<vector_core::Result<HashMap<_, _>>>()
+                .map(prost_reflect::Value::Map),
+            _ => Err(mismatch(field, value)),
+        };
+    }
+
+    let kind = field.kind();
+    if field.is_list() {
+        return match value {
+            Value::Array(values) => values
+                .iter()
+                .map(|value| encode_value(field, &kind, value))
+                .collect::<vector_core::Result<Vec<_>>>()
+                .map(prost_reflect::Value::List),
+            value => Ok(prost_reflect::Value::List(vec![encode_value(
+                field, &kind, value,
+            )?])),
+        };
+    }
+
+    encode_value(field, &kind, value)
+}
+
+fn encode_value(
+    field: &FieldDescriptor,
+    kind: &Kind,
+    value: &Value,
+) -> vector_core::Result<prost_reflect::Value> {
+    use prost_reflect::Value as Proto;
+
+    let encoded = match (kind, value) {
+        (Kind::Double, Value::Float(value)) => Proto::F64(value.into_inner()),
+        (Kind::Double, Value::Integer(value)) => Proto::F64(*value as f64),
+        (Kind::Float, Value::Float(value)) => Proto::F32(value.into_inner() as f32),
+        (Kind::Float, Value::Integer(value)) => Proto::F32(*value as f32),
+        (Kind::Int32 | Kind::Sint32 | Kind::Sfixed32, Value::Integer(value)) => {
+            Proto::I32(i32::try_from(*value).map_err(|_| out_of_range(field, *value))?)
+        }
+        (Kind::Int64 | Kind::Sint64 | Kind::Sfixed64, Value::Integer(value)) => Proto::I64(*value),
+        (Kind::Uint32 | Kind::Fixed32, Value::Integer(value)) => {
+            Proto::U32(u32::try_from(*value).map_err(|_| out_of_range(field, *value))?)
+        }
+        (Kind::Uint64 | Kind::Fixed64, Value::Integer(value)) => {
+            Proto::U64(u64::try_from(*value).map_err(|_| out_of_range(field, *value))?)
+        }
+        (Kind::Bool, Value::Boolean(value)) => Proto::Bool(*value),
+        (Kind::String, Value::Bytes(value)) => {
+            Proto::String(String::from_utf8_lossy(value).into_owned())
+        }
+        (Kind::String, Value::Timestamp(value)) => Proto::String(value.to_rfc3339()),
+        (Kind::Bytes, Value::Bytes(value)) => Proto::Bytes(value.clone()),
+        (Kind::Enum(descriptor), Value::Bytes(name)) => {
+            let name = String::from_utf8_lossy(name);
+            let value = descriptor.get_value_by_name(&name).ok_or_else(|| {
+                format!(
+                    "Unknown value {:?} for enum field {:?}.",
+                    name,
+                    field.full_name()
+                )
+            })?;
+            Proto::EnumNumber(value.number())
+        }
+        (Kind::Enum(_), Value::Integer(number)) => {
+            Proto::EnumNumber(i32::try_from(*number).map_err(|_| out_of_range(field, *number))?)
+        }
+        (Kind::Message(descriptor), Value::Timestamp(timestamp))
+            if descriptor.full_name() == "google.protobuf.Timestamp" =>
+        {
+            let mut message = DynamicMessage::new(descriptor.clone());
+            message.set_field_by_name("seconds", Proto::I64(timestamp.timestamp()));
+            message.set_field_by_name(
+                "nanos",
+                Proto::I32(timestamp.timestamp_subsec_nanos() as i32),
+            );
+            Proto::Message(message)
+        }
+        (Kind::Message(descriptor), Value::Object(fields)) => {
+            Proto::Message(encode_message(descriptor, fields)?)
+        }
+        _ => return Err(mismatch(field, value)),
+    };
+
+    Ok(encoded)
+}
+
+fn encode_map_key(kind: &Kind, key: &str) -> vector_core::Result<MapKey> {
+    let invalid = || format!("Invalid map key {:?} for key type {:?}.", key, kind);
+    Ok(match kind {
+        Kind::String => MapKey::String(key.to_owned()),
+        Kind::Bool => MapKey::Bool(key.parse().map_err(|_| invalid())?),
+        Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => {
+            MapKey::I32(key.parse().map_err(|_| invalid())?)
+        }
+        Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => {
+            MapKey::I64(key.parse().map_err(|_| invalid())?)
+        }
+        Kind::Uint32 | Kind::Fixed32 => MapKey::U32(key.parse().map_err(|_| invalid())?),
+        Kind::Uint64 | Kind::Fixed64 => MapKey::U64(key.parse().map_err(|_| invalid())?),
+        _ => return Err(invalid().into()),
+    })
+}
+
+fn mismatch(field: &FieldDescriptor, value: &Value) -> vector_core::Error {
+    format!(
+        "Cannot encode {} value into Protobuf field {:?} of type {:?}.",
+        value.kind_str(),
+        field.full_name(),
+        field.kind()
+    )
+    .into()
+}
+
+fn out_of_range(field: &FieldDescriptor, value: i64) -> vector_core::Error {
+    format!(
+        "Value {} is out of range for Protobuf field {:?} of type {:?}.",
+        value,
+        field.full_name(),
+        field.kind()
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use vector_common::btreemap;
+    use vector_core::event::{LogEvent, Value};
+
+    use super::*;
+    use crate::protobuf::{get_message_descriptor_from_bytes, test_util::person_descriptor_set};
+
+    fn person_descriptor() -> MessageDescriptor {
+        get_message_descriptor_from_bytes(&person_descriptor_set(), "test.Person").unwrap()
+    }
+
+    fn encode(log: LogEvent) -> vector_core::Result<DynamicMessage> {
+        let mut serializer = ProtobufSerializer::new(person_descriptor());
+        let mut bytes = BytesMut::new();
+        serializer.encode(Event::from(log), &mut bytes)?;
+        Ok(DynamicMessage::decode(person_descriptor(), bytes.freeze()).unwrap())
+    }
+
+    #[test]
+    fn serialize_protobuf() {
+        let log = LogEvent::from(btreemap! {
+            "name" => "alice",
+            "id" => 42,
+            "active" => true,
+            "tags" => vec!["a", "b"],
+            "status" => "ACTIVE",
+            "address" => btreemap! { "city" => "Paris" },
+            "labels" => btreemap! { "env" => "prod" },
+            "unknown" => "dropped",
+        });
+
+        let message = encode(log).unwrap();
+        let field = |name: &str| message.get_field_by_name(name).unwrap().into_owned();
+
+        assert_eq!(field("name").as_str(), Some("alice"));
+        assert_eq!(field("id").as_i64(), Some(42));
+        assert_eq!(field("active").as_bool(), Some(true));
+        assert_eq!(field("status").as_enum_number(), Some(1));
+        assert_eq!(field("tags").as_list().map(|list| list.len()), Some(2));
+        assert_eq!(
+            field("address")
+                .as_message()
+                .and_then(|address| address.get_field_by_name("city"))
+                .and_then(|city| city.as_str().map(ToOwned::to_owned)),
+            Some("Paris".to_owned())
+        );
+        assert_eq!(
+            field("labels")
+                .as_map()
+                .and_then(|labels| labels.get(&MapKey::String("env".to_owned())).cloned())
+                .and_then(|value| value.as_str().map(ToOwned::to_owned)),
+            Some("prod".to_owned())
+        );
+    }
+
+    #[test]
+    fn serialize_type_mismatch() {
+        let log = LogEvent::from(btreemap! { "id" => "not a number" });
+        assert!(encode(log).is_err());
+    }
+
+    #[test]
+    fn serialize_unknown_enum_value() {
+        let log = LogEvent::from(btreemap! { "status" => "MISSING" });
+        assert!(encode(log).is_err());
+    }
+
+    #[test]
+    fn serialize_skips_null() {
+        let log = LogEvent::from(btreemap! { "name" => Value::Null });
+        let message = encode(log).unwrap();
+        assert!(!message.has_field_by_name("name"));
+    }
+}
@@ -8,11 +8,12 @@ use std::fmt::Debug;
 
 use bytes::BytesMut;
 pub use format::{
-    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, GelfSerializer,
-    GelfSerializerConfig, JsonSerializer, JsonSerializerConfig, LogfmtSerializer,
-    LogfmtSerializerConfig, NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer,
-    NativeSerializerConfig, RawMessageSerializer, RawMessageSerializerConfig, TextSerializer,
-    TextSerializerConfig,
+    AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CsvQuoteStyle, CsvSerializer,
+    CsvSerializerConfig, CsvSerializerOptions, GelfSerializer, GelfSerializerConfig,
+    JsonSerializer, JsonSerializerConfig, LogfmtSerializer, LogfmtSerializerConfig,
+    NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer, NativeSerializerConfig,
+    ProtobufSerializer, ProtobufSerializerConfig, ProtobufSerializerOptions, RawMessageSerializer,
+    RawMessageSerializerConfig, TextSerializer, TextSerializerConfig,
 };
 pub use framing::{
     BoxedFramer, BoxedFramingError, BytesEncoder, BytesEncoderConfig, CharacterDelimitedEncoder,
@@ -193,6 +194,12 @@ pub enum SerializerConfig {
         avro: AvroSerializerOptions,
     },
 
+    /// CSV serialization.
+    Csv {
+        /// CSV serializer options.
+        csv: CsvSerializerOptions,
+    },
+
     /// GELF serialization.
     Gelf,
 
@@ -202,12 +209,21 @@ pub enum SerializerConfig {
     /// Logfmt serialization.
     Logfmt,
 
+    /// MessagePack serialization.
+    Msgpack,
+
     /// Native Vector serialization based on Protocol Buffers.
     Native,
 
     /// Native Vector serialization based on JSON.
     NativeJson,
 
+    /// Protocol Buffers serialization, using a user-provided message type.
+    Protobuf {
+        /// Options for the Protobuf serializer.
+        protobuf: ProtobufSerializerOptions,
+    },
+
     /// No serialization.
     ///
     /// This encoding, specifically, will only encode the `message` field of a log event. Users should take care if
@@ -231,6 +247,12 @@ impl From<AvroSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<CsvSerializerConfig> for SerializerConfig {
+    fn from(config: CsvSerializerConfig) -> Self {
+        Self::Csv { csv: config.csv }
+    }
+}
+
 impl From<GelfSerializerConfig> for SerializerConfig {
     fn from(_: GelfSerializerConfig) -> Self {
         Self::Gelf
@@ -249,6 +271,12 @@ impl From<LogfmtSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<MsgpackSerializerConfig> for SerializerConfig {
+    fn from(_: MsgpackSerializerConfig) -> Self {
+        Self::Msgpack
+    }
+}
+
 impl From<NativeSerializerConfig> for SerializerConfig {
     fn from(_: NativeSerializerConfig) -> Self {
         Self::Native
@@ -261,6 +289,14 @@ impl From<NativeJsonSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<ProtobufSerializerConfig> for SerializerConfig {
+    fn from(config: ProtobufSerializerConfig) -> Self {
+        Self::Protobuf {
+            protobuf: config.protobuf,
+        }
+    }
+}
+
 impl From<RawMessageSerializerConfig> for SerializerConfig {
     fn from(_: RawMessageSerializerConfig) -> Self {
         Self::RawMessage
@@ -280,13 +316,23 @@ impl SerializerConfig {
             SerializerConfig::Avro { avro } => Ok(Serializer::Avro(
                 AvroSerializerConfig::new(avro.schema.clone()).build()?,
             )),
+            SerializerConfig::Csv { csv } => Ok(Serializer::Csv(
+                CsvSerializerConfig::new(csv.clone()).build(),
+            )),
             SerializerConfig::Gelf => Ok(Serializer::Gelf(GelfSerializerConfig::new().build())),
             SerializerConfig::Json => Ok(Serializer::Json(JsonSerializerConfig.build())),
             SerializerConfig::Logfmt => Ok(Serializer::Logfmt(LogfmtSerializerConfig.build())),
+            SerializerConfig::Msgpack => Ok(Serializer::Msgpack(MsgpackSerializerConfig.build())),
             SerializerConfig::Native => Ok(Serializer::Native(NativeSerializerConfig.build())),
             SerializerConfig::NativeJson => {
                 Ok(Serializer::NativeJson(NativeJsonSerializerConfig.build()))
             }
+            SerializerConfig::Protobuf { protobuf } => Ok(Serializer::Protobuf(
+                ProtobufSerializerConfig {
+                    protobuf: protobuf.clone(),
+                }
+                .build()?,
+            )),
             SerializerConfig::RawMessage => {
                 Ok(Serializer::RawMessage(RawMessageSerializerConfig.build()))
             }
@@ -300,11 +346,17 @@ impl SerializerConfig {
             SerializerConfig::Avro { avro } => {
                 AvroSerializerConfig::new(avro.schema.clone()).input_type()
             }
+            SerializerConfig::Csv { csv } => CsvSerializerConfig::new(csv.clone()).input_type(),
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::input_type(),
             SerializerConfig::Json => JsonSerializerConfig.input_type(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.input_type(),
+            SerializerConfig::Msgpack => MsgpackSerializerConfig.input_type(),
             SerializerConfig::Native => NativeSerializerConfig.input_type(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.input_type(),
+            SerializerConfig::Protobuf { protobuf } => ProtobufSerializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .input_type(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.input_type(),
             SerializerConfig::Text => TextSerializerConfig.input_type(),
         }
@@ -316,11 +368,19 @@ impl SerializerConfig {
             SerializerConfig::Avro { avro } => {
                 AvroSerializerConfig::new(avro.schema.clone()).schema_requirement()
             }
+            SerializerConfig::Csv { csv } => {
+                CsvSerializerConfig::new(csv.clone()).schema_requirement()
+            }
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::schema_requirement(),
             SerializerConfig::Json => JsonSerializerConfig.schema_requirement(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.schema_requirement(),
+            SerializerConfig::Msgpack => MsgpackSerializerConfig.schema_requirement(),
             SerializerConfig::Native => NativeSerializerConfig.schema_requirement(),
             SerializerConfig::NativeJson => NativeJsonSerializerConfig.schema_requirement(),
+            SerializerConfig::Protobuf { protobuf } => ProtobufSerializerConfig {
+                protobuf: protobuf.clone(),
+            }
+            .schema_requirement(),
             SerializerConfig::RawMessage => RawMessageSerializerConfig.schema_requirement(),
             SerializerConfig::Text => TextSerializerConfig.schema_requirement(),
         }
@@ -332,16 +392,22 @@ impl SerializerConfig {
 pub enum Serializer {
     /// Uses an `AvroSerializer` for serialization.
     Avro(AvroSerializer),
+    /// Uses a `CsvSerializer` for serialization.
+    Csv(CsvSerializer),
     /// Uses a `GelfSerializer` for serialization.
     Gelf(GelfSerializer),
     /// Uses a `JsonSerializer` for serialization.
     Json(JsonSerializer),
     /// Uses a `LogfmtSerializer` for serialization.
     Logfmt(LogfmtSerializer),
+    /// Uses a `MsgpackSerializer` for serialization.
+    Msgpack(MsgpackSerializer),
     /// Uses a `NativeSerializer` for serialization.
     Native(NativeSerializer),
     /// Uses a `NativeJsonSerializer` for serialization.
     NativeJson(NativeJsonSerializer),
+    /// Uses a `ProtobufSerializer` for serialization.
+    Protobuf(ProtobufSerializer),
     /// Uses a `RawMessageSerializer` for serialization.
     RawMessage(RawMessageSerializer),
     /// Uses a `TextSerializer` for serialization.
@@ -354,9 +420,12 @@ impl Serializer {
         match self {
             Serializer::Json(_) | Serializer::NativeJson(_) | Serializer::Gelf(_) => true,
             Serializer::Avro(_)
+            | Serializer::Csv(_)
             | Serializer::Logfmt(_)
+            | Serializer::Msgpack(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
+            | Serializer::Protobuf(_)
             | Serializer::RawMessage(_) => false,
         }
     }
@@ -373,14 +442,35 @@ impl Serializer {
             Serializer::Json(serializer) => serializer.to_json_value(event),
             Serializer::NativeJson(serializer) => serializer.to_json_value(event),
             Serializer::Avro(_)
+            | Serializer::Csv(_)
             | Serializer::Logfmt(_)
+            | Serializer::Msgpack(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
+            | Serializer::Protobuf(_)
             | Serializer::RawMessage(_) => {
                 panic!("Serializer does not support JSON")
             }
         }
     }
+
+    /// Get the header which is written once at the start of every output file or object, if
+    /// the serializer has one.
+    pub fn header(&self) -> Option<BytesMut> {
+        match self {
+            Serializer::Csv(serializer) => serializer.header(),
+            Serializer::Avro(_)
+            | Serializer::Gelf(_)
+            | Serializer::Json(_)
+            | Serializer::Logfmt(_)
+            | Serializer::Msgpack(_)
+            | Serializer::Native(_)
+            | Serializer::NativeJson(_)
+            | Serializer::Protobuf(_)
+            | Serializer::RawMessage(_)
+            | Serializer::Text(_) => None,
+        }
+    }
 }
 
 impl From<AvroSerializer> for Serializer {
@@ -389,6 +479,12 @@ impl From<AvroSerializer> for Serializer {
     }
 }
 
+impl From<CsvSerializer> for Serializer {
+    fn from(serializer: CsvSerializer) -> Self {
+        Self::Csv(serializer)
+    }
+}
+
 impl From<GelfSerializer> for Serializer {
     fn from(serializer: GelfSerializer) -> Self {
         Self::Gelf(serializer)
@@ -407,6 +503,12 @@ impl From<LogfmtSerializer> for Serializer {
     }
 }
 
+impl From<MsgpackSerializer> for Serializer {
+    fn from(serializer: MsgpackSerializer) -> Self {
+        Self::Msgpack(serializer)
+    }
+}
+
 impl From<NativeSerializer> for Serializer {
     fn from(serializer: NativeSerializer) -> Self {
         Self::Native(serializer)
@@ -419,6 +521,12 @@ impl From<NativeJsonSerializer> for Serializer {
     }
 }
 
+impl From<ProtobufSerializer> for Serializer {
+    fn from(serializer: ProtobufSerializer) -> Self {
+        Self::Protobuf(serializer)
+    }
+}
+
 impl From<RawMessageSerializer> for Serializer {
     fn from(serializer: RawMessageSerializer) -> Self {
         Self::RawMessage(serializer)
@@ -437,11 +545,14 @@ impl tokio_util::codec::Encoder<Event> for Serializer {
     fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
         match self {
             Serializer::Avro(serializer) => serializer.encode(event, buffer),
+            Serializer::Csv(serializer) => serializer.encode(event, buffer),
             Serializer::Gelf(serializer) => serializer.encode(event, buffer),
             Serializer::Json(serializer) => serializer.encode(event, buffer),
             Serializer::Logfmt(serializer) => serializer.encode(event, buffer),
+            Serializer::Msgpack(serializer) => serializer.encode(event, buffer),
             Serializer::Native(serializer) => serializer.encode(event, buffer),
             Serializer::NativeJson(serializer) => serializer.encode(event, buffer),
+            Serializer::Protobuf(serializer) => serializer.encode(event, buffer),
             Serializer::RawMessage(serializer) => serializer.encode(event, buffer),
             Serializer::Text(serializer) => serializer.encode(event, buffer),
         }
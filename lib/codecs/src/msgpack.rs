@@ -0,0 +1,78 @@
+//! Contains common definitions for MessagePack codec support.
+
+use chrono::{DateTime, TimeZone, Utc};
+
+/// The extension type reserved by the MessagePack specification for timestamps.
+pub(crate) const TIMESTAMP_EXT_TYPE: i8 = -1;
+
+/// Decodes the payload of a timestamp extension, in any of the 32-, 64- or 96-bit formats.
+pub(crate) fn decode_timestamp(bytes: &[u8]) -> Option<DateTime<Utc>> {
+    let (seconds, nanos) = match bytes.len() {
+        4 => (i64::from(u32::from_be_bytes(bytes.try_into().ok()?)), 0),
+        8 => {
+            let value = u64::from_be_bytes(bytes.try_into().ok()?);
+            ((value & 0x3_ffff_ffff) as i64, (value >> 34) as u32)
+        }
+        12 => (
+            i64::from_be_bytes(bytes[4..].try_into().ok()?),
+            u32::from_be_bytes(bytes[..4].try_into().ok()?),
+        ),
+        _ => return None,
+    };
+
+    if nanos >= 1_000_000_000 {
+        return None;
+    }
+    Utc.timestamp_opt(seconds, nanos).single()
+}
+
+/// Encodes the payload of a timestamp extension, using the smallest format that fits.
+pub(crate) fn encode_timestamp(timestamp: &DateTime<Utc>) -> Vec<u8> {
+    let seconds = timestamp.timestamp();
+    let nanos = timestamp.timestamp_subsec_nanos();
+
+    if seconds >> 34 == 0 {
+        let value = (u64::from(nanos) << 34) | seconds as u64;
+        if value & 0xffff_ffff_0000_0000 == 0 {
+            (value as u32).to_be_bytes().to_vec()
+        } else {
+            value.to_be_bytes().to_vec()
+        }
+    } else {
+        let mut bytes = Vec::with_capacity(12);
+        bytes.extend_from_slice(&nanos.to_be_bytes());
+        bytes.extend_from_slice(&seconds.to_be_bytes());
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_roundtrip() {
+        for timestamp in [
+            Utc.timestamp(1_600_000_000, 0),
+            Utc.timestamp(1_600_000_000, 123_456_789),
+            Utc.timestamp(-1, 500),
+            Utc.timestamp(20_000_000_000, 1),
+        ] {
+            let bytes = encode_timestamp(&timestamp);
+            assert_eq!(decode_timestamp(&bytes), Some(timestamp));
+        }
+    }
+
+    #[test]
+    fn timestamp_formats() {
+        assert_eq!(encode_timestamp(&Utc.timestamp(1, 0)).len(), 4);
+        assert_eq!(encode_timestamp(&Utc.timestamp(1, 1)).len(), 8);
+        assert_eq!(encode_timestamp(&Utc.timestamp(-1, 0)).len(), 12);
+    }
+
+    #[test]
+    fn timestamp_invalid() {
+        assert_eq!(decode_timestamp(&[0; 3]), None);
+        assert_eq!(decode_timestamp(&u64::MAX.to_be_bytes()), None);
+    }
+}
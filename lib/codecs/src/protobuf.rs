@@ -0,0 +1,159 @@
+//! Contains common definitions for Protobuf codec support.
+
+use std::{fs, path::Path};
+
+use prost_reflect::{DescriptorPool, MessageDescriptor};
+
+/// Loads the descriptor of the given message type from a compiled `FileDescriptorSet` file, as
+/// produced by `protoc --include_imports --descriptor_set_out=...`.
+pub(crate) fn get_message_descriptor(
+    desc_file: &Path,
+    message_type: &str,
+) -> vector_core::Result<MessageDescriptor> {
+    let bytes = fs::read(desc_file).map_err(|error| {
+        format!(
+            "Failed to read Protobuf descriptor set {:?}: {}",
+            desc_file, error
+        )
+    })?;
+    get_message_descriptor_from_bytes(&bytes, message_type)
+}
+
+/// Loads the descriptor of the given message type from an encoded `FileDescriptorSet`.
+pub(crate) fn get_message_descriptor_from_bytes(
+    bytes: &[u8],
+    message_type: &str,
+) -> vector_core::Result<MessageDescriptor> {
+    let pool = DescriptorPool::decode(bytes)
+        .map_err(|error| format!("Failed to parse Protobuf descriptor set: {}", error))?;
+    pool.get_message_by_name(message_type).ok_or_else(|| {
+        format!(
+            "The message type {:?} could not be found in the Protobuf descriptor set.",
+            message_type
+        )
+        .into()
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod test_util {
+    use prost::Message;
+    use prost_types::{
+        field_descriptor_proto::{Label, Type},
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        FileDescriptorProto, FileDescriptorSet,
+    };
+
+    fn field(name: &str, number: i32, kind: Type, label: Label) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_owned()),
+            number: Some(number),
+            label: Some(label as i32),
+            r#type: Some(kind as i32),
+            json_name: Some(name.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn message_field(
+        name: &str,
+        number: i32,
+        type_name: &str,
+        label: Label,
+    ) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            type_name: Some(type_name.to_owned()),
+            ..field(name, number, Type::Message, label)
+        }
+    }
+
+    /// Builds an encoded `FileDescriptorSet` containing the `test.Person` message:
+    ///
+    /// ```protobuf
+    /// syntax = "proto3";
+    /// package test;
+    ///
+    /// message Person {
+    ///   enum Status { UNKNOWN = 0; ACTIVE = 1; }
+    ///   message Address { string city = 1; }
+    ///
+    ///   string name = 1;
+    ///   int64 id = 2;
+    ///   double score = 3;
+    ///   bool active = 4;
+    ///   repeated string tags = 5;
+    ///   Status status = 6;
+    ///   Address address = 7;
+    ///   map<string, string> labels = 8;
+    ///   bytes data = 9;
+    /// }
+    /// ```
+    pub(crate) fn person_descriptor_set() -> Vec<u8> {
+        let labels_entry = DescriptorProto {
+            name: Some("LabelsEntry".to_owned()),
+            field: vec![
+                field("key", 1, Type::String, Label::Optional),
+                field("value", 2, Type::String, Label::Optional),
+            ],
+            options: Some(prost_types::MessageOptions {
+                map_entry: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let person = DescriptorProto {
+            name: Some("Person".to_owned()),
+            field: vec![
+                field("name", 1, Type::String, Label::Optional),
+                field("id", 2, Type::Int64, Label::Optional),
+                field("score", 3, Type::Double, Label::Optional),
+                field("active", 4, Type::Bool, Label::Optional),
+                field("tags", 5, Type::String, Label::Repeated),
+                FieldDescriptorProto {
+                    type_name: Some(".test.Person.Status".to_owned()),
+                    ..field("status", 6, Type::Enum, Label::Optional)
+                },
+                message_field("address", 7, ".test.Person.Address", Label::Optional),
+                message_field("labels", 8, ".test.Person.LabelsEntry", Label::Repeated),
+                field("data", 9, Type::Bytes, Label::Optional),
+            ],
+            nested_type: vec![
+                DescriptorProto {
+                    name: Some("Address".to_owned()),
+                    field: vec![field("city", 1, Type::String, Label::Optional)],
+                    ..Default::default()
+                },
+                labels_entry,
+            ],
+            enum_type: vec![EnumDescriptorProto {
+                name: Some("Status".to_owned()),
+                value: vec![
+                    EnumValueDescriptorProto {
+                        name: Some("UNKNOWN".to_owned()),
+                        number: Some(0),
+                        options: None,
+                    },
+                    EnumValueDescriptorProto {
+                        name: Some("ACTIVE".to_owned()),
+                        number: Some(1),
+                        options: None,
+                    },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        FileDescriptorSet {
+            file: vec![FileDescriptorProto {
+                name: Some("person.proto".to_owned()),
+                package: Some("test".to_owned()),
+                message_type: vec![person],
+                syntax: Some("proto3".to_owned()),
+                ..Default::default()
+            }],
+        }
+        .encode_to_vec()
+    }
+}
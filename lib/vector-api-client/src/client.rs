@@ -1,3 +1,5 @@
+use std::{fs, io, path::PathBuf};
+
 use anyhow::Context;
 use graphql_client::GraphQLQuery;
 use indoc::indoc;
@@ -7,25 +9,113 @@ use url::Url;
 pub type QueryResult<T> =
     anyhow::Result<graphql_client::Response<<T as GraphQLQuery>::ResponseData>>;
 
+/// Credentials presented to a Vector API server that requires authentication.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Auth {
+    /// Basic authentication, with the username and password encoded via base64.
+    Basic { user: String, password: String },
+    /// Bearer authentication, with the token passed as-is.
+    Bearer { token: String },
+}
+
+impl Auth {
+    /// Returns the value of the `Authorization` header carrying these credentials.
+    pub fn header_value(&self) -> String {
+        match self {
+            Self::Basic { user, password } => {
+                format!("Basic {}", base64::encode(format!("{}:{}", user, password)))
+            }
+            Self::Bearer { token } => format!("Bearer {}", token),
+        }
+    }
+}
+
+/// How the certificate of a Vector API server served over TLS is verified.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsOptions {
+    /// PEM-encoded CA certificate trusted in addition to the system's, for servers using a
+    /// self-signed or private CA certificate.
+    pub ca_file: Option<PathBuf>,
+    /// Whether to skip verifying the certificate of the server altogether.
+    pub insecure: bool,
+}
+
+impl TlsOptions {
+    /// Builds the TLS connector for `https://` and `wss://` connections.
+    pub(crate) fn connector(&self) -> io::Result<native_tls::TlsConnector> {
+        let mut builder = native_tls::TlsConnector::builder();
+        if let Some(ca_file) = &self.ca_file {
+            let pem = fs::read(ca_file).map_err(|error| {
+                io::Error::new(
+                    error.kind(),
+                    format!("Couldn't read CA file {:?}: {}", ca_file, error),
+                )
+            })?;
+            let certificate = native_tls::Certificate::from_pem(&pem).map_err(|error| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Couldn't parse CA file {:?}: {}", ca_file, error),
+                )
+            })?;
+            builder.add_root_certificate(certificate);
+        }
+        builder.danger_accept_invalid_certs(self.insecure);
+        builder
+            .build()
+            .map_err(|error| io::Error::new(io::ErrorKind::Other, error))
+    }
+}
+
 /// GraphQL query client over HTTP.
 #[derive(Debug)]
 pub struct Client {
     url: Url,
+    auth: Option<Auth>,
+    http: reqwest::Client,
 }
 
 impl Client {
     /// Returns a new GraphQL query client, bound to the provided URL.
     pub fn new(url: Url) -> Self {
-        Self { url }
+        Self {
+            url,
+            auth: None,
+            http: reqwest::Client::new(),
+        }
     }
 
-    pub async fn new_with_healthcheck(url: Url) -> Option<Self> {
+    /// Sets the credentials presented with every query.
+    pub fn with_auth(mut self, auth: Option<Auth>) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Sets how the certificate of the server is verified.
+    pub fn with_tls(mut self, tls: &TlsOptions) -> anyhow::Result<Self> {
+        self.http = reqwest::Client::builder()
+            .use_preconfigured_tls(tls.connector()?)
+            .build()
+            .context("Couldn't build the HTTP client")?;
+        Ok(self)
+    }
+
+    pub async fn new_with_healthcheck(
+        url: Url,
+        auth: Option<Auth>,
+        tls: &TlsOptions,
+    ) -> Option<Self> {
         #![allow(clippy::print_stderr)]
 
         use crate::gql::HealthQueryExt;
 
         // Create a new API client for connecting to the local/remote Vector instance.
-        let client = Self::new(url.clone());
+        let client = match Self::new(url.clone()).with_auth(auth).with_tls(tls) {
+            Ok(client) => client,
+            Err(error) => {
+                eprintln!("{:#}", error);
+                return None;
+            }
+        };
 
         // Check that the GraphQL server is reachable
         match client.health_query().await {
@@ -54,11 +144,12 @@ impl Client {
         &self,
         request_body: &graphql_client::QueryBody<T::Variables>,
     ) -> QueryResult<T> {
-        let client = reqwest::Client::new();
+        let mut request = self.http.post(self.url.clone()).json(request_body);
+        if let Some(auth) = &self.auth {
+            request = request.header(reqwest::header::AUTHORIZATION, auth.header_value());
+        }
 
-        client
-            .post(self.url.clone())
-            .json(request_body)
+        request
             .send()
             .await
             .with_context(|| {
@@ -10,11 +10,24 @@ use graphql_client::GraphQLQuery;
 )]
 pub struct MetaVersionStringQuery;
 
+/// MetaConfigSnapshotQuery returns a snapshot of the running configuration of the queried Vector
+/// instance, serialized to JSON.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/meta_config_snapshot.graphql",
+    response_derives = "Debug"
+)]
+pub struct MetaConfigSnapshotQuery;
+
 /// Extension methods for meta queries.
 #[async_trait]
 pub trait MetaQueryExt {
     /// Executes a meta version string query.
     async fn meta_version_string(&self) -> crate::QueryResult<MetaVersionStringQuery>;
+
+    /// Executes a meta config snapshot query.
+    async fn meta_config_snapshot(&self) -> crate::QueryResult<MetaConfigSnapshotQuery>;
 }
 
 #[async_trait]
@@ -26,4 +39,12 @@ impl MetaQueryExt for crate::Client {
         ))
         .await
     }
+
+    /// Executes a meta config snapshot query.
+    async fn meta_config_snapshot(&self) -> crate::QueryResult<MetaConfigSnapshotQuery> {
+        self.query::<MetaConfigSnapshotQuery>(&MetaConfigSnapshotQuery::build_query(
+            meta_config_snapshot_query::Variables,
+        ))
+        .await
+    }
 }
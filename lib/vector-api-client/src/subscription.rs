@@ -13,10 +13,20 @@ use tokio::sync::{
     mpsc, oneshot,
 };
 use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_tungstenite::{
+    connect_async_tls_with_config,
+    tungstenite::{
+        client::IntoClientRequest,
+        http::{header::AUTHORIZATION, HeaderValue},
+        Error, Message,
+    },
+    Connector,
+};
 use url::Url;
 use uuid::Uuid;
 
+use crate::{Auth, TlsOptions};
+
 /// Subscription GraphQL response, returned from an active stream.
 pub type BoxedSubscription<T> = Pin<
     Box<
@@ -173,10 +183,22 @@ impl SubscriptionClient {
 /// Connect to a new WebSocket GraphQL server endpoint, and return a `SubscriptionClient`.
 /// This method will a) connect to a ws(s):// endpoint, and perform the initial handshake, and b)
 /// set up channel forwarding to expose just the returned `Payload`s to the client.
+/// When `auth` is provided, the credentials are presented during the handshake, and `wss://`
+/// endpoints are verified according to `tls`.
 pub async fn connect_subscription_client(
     url: Url,
-) -> Result<SubscriptionClient, tokio_tungstenite::tungstenite::Error> {
-    let (ws, _) = connect_async(url).await?;
+    auth: Option<&Auth>,
+    tls: &TlsOptions,
+) -> Result<SubscriptionClient, Error> {
+    let mut request = url.into_client_request()?;
+    if let Some(auth) = auth {
+        let value = HeaderValue::from_str(&auth.header_value())
+            .map_err(|error| Error::HttpFormat(error.into()))?;
+        request.headers_mut().insert(AUTHORIZATION, value);
+    }
+
+    let connector = Connector::NativeTls(tls.connector()?);
+    let (ws, _) = connect_async_tls_with_config(request, None, Some(connector)).await?;
     let (mut ws_tx, mut ws_rx) = futures::StreamExt::split(ws);
 
     let (send_tx, mut send_rx) = mpsc::unbounded_channel::<Payload>();
@@ -10,7 +10,7 @@ use criterion::{
     Criterion, SamplingMode, Throughput,
 };
 use tokio::runtime::{Handle, Runtime};
-use vector_buffers::{BufferType, WhenFull};
+use vector_buffers::{config::CompressionAlgorithm, BufferType, WhenFull};
 
 use crate::common::{init_instrumentation, war_measurement, wtr_measurement};
 
@@ -83,6 +83,29 @@ fn create_disk_v2_variant(_max_events: usize, max_size: u64) -> BufferType {
     BufferType::DiskV2 {
         max_size: NonZeroU64::new(max_size).unwrap(),
         when_full: WhenFull::DropNewest,
+        encryption: None,
+        compression: None,
+        max_age_secs: None,
+    }
+}
+
+fn create_disk_v2_zstd_variant(_max_events: usize, max_size: u64) -> BufferType {
+    BufferType::DiskV2 {
+        max_size: NonZeroU64::new(max_size).unwrap(),
+        when_full: WhenFull::DropNewest,
+        encryption: None,
+        compression: Some(CompressionAlgorithm::Zstd),
+        max_age_secs: None,
+    }
+}
+
+fn create_disk_v2_lz4_variant(_max_events: usize, max_size: u64) -> BufferType {
+    BufferType::DiskV2 {
+        max_size: NonZeroU64::new(max_size).unwrap(),
+        when_full: WhenFull::DropNewest,
+        encryption: None,
+        compression: Some(CompressionAlgorithm::Lz4),
+        max_age_secs: None,
     }
 }
 
@@ -90,6 +113,7 @@ fn create_in_memory_variant(max_events: usize, _max_size: u64) -> BufferType {
     BufferType::Memory {
         max_events: NonZeroUsize::new(max_events).unwrap(),
         when_full: WhenFull::DropNewest,
+        max_age_secs: None,
     }
 }
 
@@ -162,6 +186,24 @@ fn write_then_read(c: &mut Criterion) {
         create_disk_v2_variant
     );
 
+    experiment!(
+        c,
+        [32, 64, 128, 256, 512, 1024],
+        "buffer-disk-v2-zstd",
+        "write-then-read",
+        wtr_measurement,
+        create_disk_v2_zstd_variant
+    );
+
+    experiment!(
+        c,
+        [32, 64, 128, 256, 512, 1024],
+        "buffer-disk-v2-lz4",
+        "write-then-read",
+        wtr_measurement,
+        create_disk_v2_lz4_variant
+    );
+
     experiment!(
         c,
         [32, 64, 128, 256, 512, 1024],
@@ -192,6 +234,24 @@ fn write_and_read(c: &mut Criterion) {
         create_disk_v2_variant
     );
 
+    experiment!(
+        c,
+        [32, 64, 128, 256, 512, 1024],
+        "buffer-disk-v2-zstd",
+        "write-and-read",
+        war_measurement,
+        create_disk_v2_zstd_variant
+    );
+
+    experiment!(
+        c,
+        [32, 64, 128, 256, 512, 1024],
+        "buffer-disk-v2-lz4",
+        "write-and-read",
+        war_measurement,
+        create_disk_v2_lz4_variant
+    );
+
     experiment!(
         c,
         [32, 64, 128, 256, 512, 1024],
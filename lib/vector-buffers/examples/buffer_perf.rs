@@ -263,6 +263,7 @@ where
             BufferType::Memory {
                 max_events: max_size_events,
                 when_full,
+                max_age_secs: None,
             }
         }
         "disk-v1" => {
@@ -283,6 +284,9 @@ where
             BufferType::DiskV2 {
                 max_size: max_size_bytes,
                 when_full,
+                encryption: None,
+                compression: None,
+                max_age_secs: None,
             }
         }
         s => panic!(
@@ -11,7 +11,10 @@ use tracing::{Instrument, Span};
 use vector_common::internal_event::emit;
 
 use crate::{
-    internal_events::{BufferCreated, BufferEventsDropped, BufferEventsReceived, BufferEventsSent},
+    internal_events::{
+        BufferCreated, BufferEventsDropped, BufferEventsEvicted, BufferEventsReceived,
+        BufferEventsSent,
+    },
     spawn_named,
 };
 
@@ -140,6 +143,18 @@ impl BufferUsageHandle {
             self.state.dropped.increment(count, byte_size);
         }
     }
+
+    /// Increments the number of events (and their total size) evicted from this buffer component to make room for
+    /// newer events.
+    pub fn increment_evicted_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
+        self.state.evicted.increment(count, byte_size);
+    }
+
+    /// Increments the number of events (and their total size) discarded by this buffer component because they were
+    /// older than the maximum age when read.
+    pub fn increment_expired_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
+        self.state.expired.increment(count, byte_size);
+    }
 }
 
 #[derive(Debug, Default)]
@@ -149,6 +164,8 @@ struct BufferUsageData {
     sent: CategoryMetrics,
     dropped: CategoryMetrics,
     dropped_intentional: CategoryMetrics,
+    evicted: CategoryMetrics,
+    expired: CategoryMetrics,
     max_size: CategoryMetrics,
 }
 
@@ -165,6 +182,8 @@ impl BufferUsageData {
         let sent = self.sent.get();
         let dropped = self.dropped.get();
         let dropped_intentional = self.dropped_intentional.get();
+        let evicted = self.evicted.get();
+        let expired = self.expired.get();
         let max_size = self.max_size.get();
 
         BufferUsageSnapshot {
@@ -176,6 +195,10 @@ impl BufferUsageData {
             dropped_event_byte_size: dropped.event_byte_size,
             dropped_event_count_intentional: dropped_intentional.event_count,
             dropped_event_byte_size_intentional: dropped_intentional.event_byte_size,
+            evicted_event_count: evicted.event_count,
+            evicted_event_byte_size: evicted.event_byte_size,
+            expired_event_count: expired.event_count,
+            expired_event_byte_size: expired.event_byte_size,
             max_size_bytes: max_size.event_byte_size,
             max_size_events: max_size
                 .event_count
@@ -196,6 +219,10 @@ pub struct BufferUsageSnapshot {
     pub dropped_event_byte_size: u64,
     pub dropped_event_count_intentional: u64,
     pub dropped_event_byte_size_intentional: u64,
+    pub evicted_event_count: u64,
+    pub evicted_event_byte_size: u64,
+    pub expired_event_count: u64,
+    pub expired_event_byte_size: u64,
     pub max_size_bytes: u64,
     pub max_size_events: usize,
 }
@@ -300,6 +327,26 @@ impl BufferUsage {
                             byte_size: dropped_intentional.event_byte_size,
                         });
                     }
+
+                    let evicted = stage.evicted.consume();
+                    if evicted.has_updates() {
+                        emit(BufferEventsEvicted {
+                            idx: stage.idx,
+                            reason: "drop_oldest",
+                            count: evicted.event_count,
+                            byte_size: evicted.event_byte_size,
+                        });
+                    }
+
+                    let expired = stage.expired.consume();
+                    if expired.has_updates() {
+                        emit(BufferEventsEvicted {
+                            idx: stage.idx,
+                            reason: "max_age",
+                            count: expired.event_count,
+                            byte_size: expired.event_byte_size,
+                        });
+                    }
                 }
             }
         };
@@ -2,22 +2,34 @@ use std::{
     fmt,
     num::{NonZeroU64, NonZeroUsize},
     path::PathBuf,
+    time::Duration,
 };
 
 use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use snafu::{ResultExt, Snafu};
 use tracing::Span;
 use vector_common::finalization::Finalizable;
+use vector_config::{
+    configurable_component,
+    schema::{finalize_schema, generate_array_schema, generate_composite_schema},
+    schemars::{gen::SchemaGenerator, schema::SchemaObject},
+    Configurable, Metadata,
+};
 
 use crate::{
     topology::{
         builder::{TopologyBuilder, TopologyError},
         channel::{BufferReceiver, BufferSender},
     },
-    variants::{DiskV1Buffer, DiskV2Buffer, MemoryBuffer},
+    variants::{
+        disk_v2::{EncryptionKeyError, RecordCipher},
+        DiskV1Buffer, DiskV2Buffer, MemoryBuffer,
+    },
     Bufferable, WhenFull,
 };
 
+pub use crate::variants::disk_v2::{CompressionAlgorithm, EncryptionAlgorithm, EncryptionConfig};
+
 #[derive(Debug, Snafu)]
 pub enum BufferBuildError {
     #[snafu(display("the configured buffer type requires `data_dir` be specified"))]
@@ -26,6 +38,8 @@ pub enum BufferBuildError {
     FailedToBuildTopology { source: TopologyError },
     #[snafu(display("`max_events` must be greater than zero"))]
     InvalidMaxEvents,
+    #[snafu(display("invalid buffer encryption key: {}", source))]
+    InvalidEncryptionKey { source: EncryptionKeyError },
 }
 
 #[derive(Deserialize, Serialize)]
@@ -38,7 +52,15 @@ enum BufferTypeKind {
     DiskV2,
 }
 
-const ALL_FIELDS: [&str; 4] = ["type", "max_events", "max_size", "when_full"];
+const ALL_FIELDS: [&str; 7] = [
+    "type",
+    "max_events",
+    "max_size",
+    "when_full",
+    "encryption",
+    "compression",
+    "max_age_secs",
+];
 
 struct BufferTypeVisitor;
 
@@ -51,6 +73,9 @@ impl BufferTypeVisitor {
         let mut max_events: Option<NonZeroUsize> = None;
         let mut max_size: Option<NonZeroU64> = None;
         let mut when_full: Option<WhenFull> = None;
+        let mut encryption: Option<EncryptionConfig> = None;
+        let mut compression: Option<CompressionAlgorithm> = None;
+        let mut max_age_secs: Option<NonZeroU64> = None;
         while let Some(key) = map.next_key::<String>()? {
             match key.as_str() {
                 "type" => {
@@ -77,6 +102,24 @@ impl BufferTypeVisitor {
                     }
                     when_full = Some(map.next_value()?);
                 }
+                "encryption" => {
+                    if encryption.is_some() {
+                        return Err(de::Error::duplicate_field("encryption"));
+                    }
+                    encryption = Some(map.next_value()?);
+                }
+                "compression" => {
+                    if compression.is_some() {
+                        return Err(de::Error::duplicate_field("compression"));
+                    }
+                    compression = Some(map.next_value()?);
+                }
+                "max_age_secs" => {
+                    if max_age_secs.is_some() {
+                        return Err(de::Error::duplicate_field("max_age_secs"));
+                    }
+                    max_age_secs = Some(map.next_value()?);
+                }
                 other => {
                     return Err(de::Error::unknown_field(other, &ALL_FIELDS));
                 }
@@ -89,12 +132,25 @@ impl BufferTypeVisitor {
                 if max_size.is_some() {
                     return Err(de::Error::unknown_field(
                         "max_size",
-                        &["type", "max_events", "when_full"],
+                        &["type", "max_events", "when_full", "max_age_secs"],
+                    ));
+                }
+                if encryption.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "encryption",
+                        &["type", "max_events", "when_full", "max_age_secs"],
+                    ));
+                }
+                if compression.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "compression",
+                        &["type", "max_events", "when_full", "max_age_secs"],
                     ));
                 }
                 Ok(BufferType::Memory {
                     max_events: max_events.unwrap_or_else(memory_buffer_default_max_events),
                     when_full,
+                    max_age_secs,
                 })
             }
             BufferTypeKind::DiskV1 => {
@@ -104,6 +160,29 @@ impl BufferTypeVisitor {
                         &["type", "max_size", "when_full"],
                     ));
                 }
+                if encryption.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "encryption",
+                        &["type", "max_size", "when_full"],
+                    ));
+                }
+                if compression.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "compression",
+                        &["type", "max_size", "when_full"],
+                    ));
+                }
+                if max_age_secs.is_some() {
+                    return Err(de::Error::unknown_field(
+                        "max_age_secs",
+                        &["type", "max_size", "when_full"],
+                    ));
+                }
+                if when_full == WhenFull::DropOldest {
+                    return Err(de::Error::custom(
+                        "`when_full` cannot be `drop_oldest` for `disk_v1` buffers",
+                    ));
+                }
                 Ok(BufferType::DiskV1 {
                     max_size: max_size.ok_or_else(|| de::Error::missing_field("max_size"))?,
                     when_full,
@@ -113,12 +192,22 @@ impl BufferTypeVisitor {
                 if max_events.is_some() {
                     return Err(de::Error::unknown_field(
                         "max_events",
-                        &["type", "max_size", "when_full"],
+                        &[
+                            "type",
+                            "max_size",
+                            "when_full",
+                            "encryption",
+                            "compression",
+                            "max_age_secs",
+                        ],
                     ));
                 }
                 Ok(BufferType::DiskV2 {
                     max_size: max_size.ok_or_else(|| de::Error::missing_field("max_size"))?,
                     when_full,
+                    encryption,
+                    compression,
+                    max_age_secs,
                 })
             }
         }
@@ -204,36 +293,92 @@ impl Serialize for BufferConfig {
     }
 }
 
+// Buffers are configured either as a single stage, or as a list of stages, so that simple buffers don't have to be
+// written as a list.
+impl Configurable for BufferConfig {
+    fn referenceable_name() -> Option<&'static str> {
+        Some("vector_buffers::BufferConfig")
+    }
+
+    fn description() -> Option<&'static str> {
+        Some("Buffer configuration, as either a single buffer stage or a list of stages.")
+    }
+
+    fn generate_schema(gen: &mut SchemaGenerator, overrides: Metadata<Self>) -> SchemaObject {
+        let mut stage_metadata = BufferType::metadata();
+        stage_metadata.set_transparent();
+
+        let single_stage = BufferType::generate_schema(gen, stage_metadata.clone());
+        let stages = generate_array_schema(gen, stage_metadata);
+
+        let mut schema = generate_composite_schema(&[single_stage, stages]);
+        finalize_schema(gen, &mut schema, overrides);
+        schema
+    }
+}
+
 pub const fn memory_buffer_default_max_events() -> NonZeroUsize {
     unsafe { NonZeroUsize::new_unchecked(500) }
 }
 
 /// A specific type of buffer stage.
-#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+#[configurable_component(no_deser)]
+#[derive(Clone, Debug, PartialEq)]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum BufferType {
     /// A buffer stage backed by an in-memory channel provided by `tokio`.
     #[serde(rename = "memory")]
     Memory {
+        /// The maximum number of events allowed in the buffer.
         #[serde(default = "memory_buffer_default_max_events")]
         max_events: NonZeroUsize,
+
+        #[configurable(derived)]
         #[serde(default)]
         when_full: WhenFull,
+
+        /// Discards buffered events older than this many seconds, instead of sending them, when set.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_age_secs: Option<NonZeroU64>,
     },
+
     /// A buffer stage backed by an on-disk database, powered by LevelDB.
     #[serde(rename = "disk_v1")]
     DiskV1 {
+        /// The maximum size of the buffer on disk, in bytes.
         max_size: NonZeroU64,
+
+        #[configurable(derived)]
         #[serde(default)]
         when_full: WhenFull,
     },
+
     /// A buffer stage backed by disk.
     #[serde(rename = "disk")]
     DiskV2 {
+        /// The maximum size of the buffer on disk, in bytes.
         max_size: NonZeroU64,
+
+        #[configurable(derived)]
         #[serde(default)]
         when_full: WhenFull,
+
+        /// Encrypts records at rest when set.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encryption: Option<EncryptionConfig>,
+
+        /// Compresses records before writing them to disk when set.
+        ///
+        /// The maximum size of the buffer applies to the compressed size of records.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        compression: Option<CompressionAlgorithm>,
+
+        /// Discards buffered events older than this many seconds, instead of sending them, when set.
+        ///
+        /// Only events written while this is set are ever discarded.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_age_secs: Option<NonZeroU64>,
     },
 }
 
@@ -253,26 +398,46 @@ impl BufferType {
     where
         T: Bufferable + Clone + Finalizable,
     {
-        match *self {
+        match self {
             BufferType::Memory {
                 when_full,
                 max_events,
+                max_age_secs,
             } => {
-                builder.stage(MemoryBuffer::new(max_events), when_full);
+                let buffer = match max_age_secs {
+                    Some(max_age_secs) => MemoryBuffer::with_max_age(
+                        *max_events,
+                        Duration::from_secs(max_age_secs.get()),
+                    ),
+                    None => MemoryBuffer::new(*max_events),
+                };
+                builder.stage(buffer, *when_full);
             }
             BufferType::DiskV1 {
                 when_full,
                 max_size,
             } => {
                 let data_dir = data_dir.ok_or(BufferBuildError::RequiresDataDir)?;
-                builder.stage(DiskV1Buffer::new(id, data_dir, max_size), when_full);
+                builder.stage(DiskV1Buffer::new(id, data_dir, *max_size), *when_full);
             }
             BufferType::DiskV2 {
                 when_full,
                 max_size,
+                encryption,
+                compression,
+                max_age_secs,
             } => {
                 let data_dir = data_dir.ok_or(BufferBuildError::RequiresDataDir)?;
-                builder.stage(DiskV2Buffer::new(id, data_dir, max_size), when_full);
+                let cipher = encryption
+                    .as_ref()
+                    .map(RecordCipher::from_config)
+                    .transpose()
+                    .context(InvalidEncryptionKeySnafu)?;
+                let max_age = max_age_secs.map(|secs| Duration::from_secs(secs.get()));
+                builder.stage(
+                    DiskV2Buffer::new(id, data_dir, *max_size, cipher, *compression, max_age),
+                    *when_full,
+                );
             }
         };
 
@@ -309,6 +474,7 @@ impl Default for BufferConfig {
             stages: vec![BufferType::Memory {
                 max_events: memory_buffer_default_max_events(),
                 when_full: WhenFull::default(),
+                max_age_secs: None,
             }],
         }
     }
@@ -359,7 +525,10 @@ impl BufferConfig {
 mod test {
     use std::num::{NonZeroU64, NonZeroUsize};
 
-    use crate::{BufferConfig, BufferType, WhenFull};
+    use crate::{
+        config::{CompressionAlgorithm, EncryptionAlgorithm, EncryptionConfig},
+        BufferConfig, BufferType, WhenFull,
+    };
 
     fn check_single_stage(source: &str, expected: BufferType) {
         let config: BufferConfig = serde_yaml::from_str(source).unwrap();
@@ -389,7 +558,7 @@ mod test {
         let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
         assert_eq!(
             error.to_string(),
-            "unknown field `foo`, expected one of `type`, `max_events`, `max_size`, `when_full` at line 1 column 4"
+            "unknown field `foo`, expected one of `type`, `max_events`, `max_size`, `when_full`, `encryption`, `compression`, `max_age_secs` at line 1 column 4"
         );
     }
 
@@ -401,7 +570,7 @@ max_events: 42
         let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
         assert_eq!(
             error.to_string(),
-            "unknown field `max_size`, expected one of `type`, `max_events`, `when_full` at line 1 column 9"
+            "unknown field `max_size`, expected one of `type`, `max_events`, `when_full`, `max_age_secs` at line 1 column 9"
         );
     }
 
@@ -414,6 +583,7 @@ max_events: 42
             BufferType::Memory {
                 max_events: NonZeroUsize::new(100).unwrap(),
                 when_full: WhenFull::Block,
+                max_age_secs: None,
             },
         );
     }
@@ -430,10 +600,12 @@ max_events: 42
                 BufferType::Memory {
                     max_events: NonZeroUsize::new(42).unwrap(),
                     when_full: WhenFull::Block,
+                    max_age_secs: None,
                 },
                 BufferType::Memory {
                     max_events: NonZeroUsize::new(100).unwrap(),
                     when_full: WhenFull::DropNewest,
+                    max_age_secs: None,
                 },
             ],
         );
@@ -459,6 +631,7 @@ max_events: 42
             BufferType::Memory {
                 max_events: NonZeroUsize::new(500).unwrap(),
                 when_full: WhenFull::Block,
+                max_age_secs: None,
             },
         );
 
@@ -470,6 +643,7 @@ max_events: 42
             BufferType::Memory {
                 max_events: NonZeroUsize::new(100).unwrap(),
                 when_full: WhenFull::Block,
+                max_age_secs: None,
             },
         );
 
@@ -481,6 +655,7 @@ max_events: 42
             BufferType::Memory {
                 max_events: NonZeroUsize::new(500).unwrap(),
                 when_full: WhenFull::DropNewest,
+                max_age_secs: None,
             },
         );
 
@@ -492,6 +667,7 @@ max_events: 42
             BufferType::Memory {
                 max_events: NonZeroUsize::new(500).unwrap(),
                 when_full: WhenFull::Overflow,
+                max_age_secs: None,
             },
         );
 
@@ -503,7 +679,123 @@ max_events: 42
             BufferType::DiskV2 {
                 max_size: NonZeroU64::new(1024).unwrap(),
                 when_full: WhenFull::Block,
+                encryption: None,
+                compression: None,
+                max_age_secs: None,
             },
         );
     }
+
+    #[test]
+    fn parse_disk_encryption() {
+        check_single_stage(
+            r#"
+          type: disk
+          max_size: 1024
+          encryption:
+            algorithm: chacha20_poly1305
+            key_file: /etc/vector/buffer.key
+            previous_keys: ["SGVsbG8gV29ybGQ="]
+          "#,
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1024).unwrap(),
+                when_full: WhenFull::Block,
+                encryption: Some(EncryptionConfig {
+                    algorithm: EncryptionAlgorithm::ChaCha20Poly1305,
+                    key: None,
+                    key_file: Some("/etc/vector/buffer.key".into()),
+                    previous_keys: vec!["SGVsbG8gV29ybGQ=".to_string()],
+                    previous_key_files: Vec::new(),
+                }),
+                compression: None,
+                max_age_secs: None,
+            },
+        );
+
+        let source = r#"
+          type: memory
+          encryption:
+            key_file: /etc/vector/buffer.key
+          "#;
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert!(error.to_string().starts_with("unknown field `encryption`"));
+    }
+
+    #[test]
+    fn parse_disk_compression() {
+        check_single_stage(
+            r#"
+          type: disk
+          max_size: 1024
+          compression: lz4
+          "#,
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1024).unwrap(),
+                when_full: WhenFull::Block,
+                encryption: None,
+                compression: Some(CompressionAlgorithm::Lz4),
+                max_age_secs: None,
+            },
+        );
+
+        let source = r#"
+          type: disk_v1
+          max_size: 1024
+          compression: zstd
+          "#;
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert!(error.to_string().starts_with("unknown field `compression`"));
+    }
+
+    #[test]
+    fn parse_drop_oldest_and_max_age() {
+        check_single_stage(
+            r#"
+          type: memory
+          when_full: drop_oldest
+          max_age_secs: 30
+          "#,
+            BufferType::Memory {
+                max_events: NonZeroUsize::new(500).unwrap(),
+                when_full: WhenFull::DropOldest,
+                max_age_secs: NonZeroU64::new(30),
+            },
+        );
+
+        check_single_stage(
+            r#"
+          type: disk
+          max_size: 1024
+          when_full: drop_oldest
+          max_age_secs: 300
+          "#,
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(1024).unwrap(),
+                when_full: WhenFull::DropOldest,
+                encryption: None,
+                compression: None,
+                max_age_secs: NonZeroU64::new(300),
+            },
+        );
+
+        let source = r#"
+          type: disk_v1
+          max_size: 1024
+          max_age_secs: 30
+          "#;
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("unknown field `max_age_secs`"));
+
+        let source = r#"
+          type: disk_v1
+          max_size: 1024
+          when_full: drop_oldest
+          "#;
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("`when_full` cannot be `drop_oldest` for `disk_v1` buffers"));
+    }
 }
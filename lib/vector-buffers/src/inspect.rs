@@ -0,0 +1,191 @@
+//! Offline inspection of disk buffers.
+//!
+//! This allows looking at what is sitting in a disk buffer without running Vector.  Buffers are
+//! only ever opened for reading: records are not acknowledged, so inspecting a buffer leaves it
+//! exactly as it was found.
+
+use std::{
+    future::Future,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use snafu::{ResultExt, Snafu};
+
+use crate::{
+    config::EncryptionConfig,
+    variants::{
+        disk_v1,
+        disk_v2::{self, EncryptionKeyError, RecordCipher},
+    },
+    Bufferable,
+};
+
+/// Error that occurred while inspecting a disk buffer.
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub(crate)))]
+pub enum InspectError {
+    #[snafu(display("no disk buffer found at {:?}", path))]
+    NotABuffer { path: PathBuf },
+    #[snafu(display("invalid buffer encryption key: {}", source))]
+    InvalidEncryptionKey { source: EncryptionKeyError },
+    #[snafu(display("failed to read {:?}: {}", path, source))]
+    Io { path: PathBuf, source: io::Error },
+    #[snafu(display("failed to load ledger: {}", reason))]
+    Ledger { reason: String },
+    #[snafu(display("failed to open database at {:?}: {}", path, source))]
+    Database {
+        path: PathBuf,
+        source: leveldb::database::error::Error,
+    },
+}
+
+/// Kind of disk buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BufferKind {
+    /// A buffer backed by LevelDB.
+    DiskV1,
+
+    /// A buffer backed by a ledger and data files.
+    DiskV2,
+}
+
+impl BufferKind {
+    /// Detects the kind of the disk buffer stored in the given directory, if any.
+    pub fn detect(path: &Path) -> Option<Self> {
+        if path.join("buffer.db").is_file() {
+            Some(BufferKind::DiskV2)
+        } else if path.join("CURRENT").is_file() {
+            Some(BufferKind::DiskV1)
+        } else {
+            None
+        }
+    }
+}
+
+/// State of the ledger of a `disk_v2` buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LedgerReport {
+    pub writer_next_record_id: u64,
+    pub writer_current_data_file_id: u16,
+    pub reader_current_data_file_id: u16,
+    pub reader_last_record_id: u64,
+}
+
+impl LedgerReport {
+    /// Gets the number of events that have been written, but not yet acknowledged.
+    pub fn unread_events(&self) -> u64 {
+        self.writer_next_record_id
+            .wrapping_sub(self.reader_last_record_id)
+            .saturating_sub(1)
+    }
+}
+
+/// Contents of a single data file of a `disk_v2` buffer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DataFileReport {
+    pub file_id: u16,
+    pub path: PathBuf,
+
+    /// Size of the data file on disk, in bytes.
+    pub size: u64,
+
+    /// Number of unread records in the data file.
+    pub records: u64,
+
+    /// Number of events in the unread records of the data file.
+    pub events: u64,
+
+    /// Number of records that were already acknowledged, but not yet deleted.
+    pub acknowledged_records: u64,
+}
+
+/// A part of a buffer that could not be read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Corruption {
+    /// Where in the buffer the corruption was found.
+    pub location: String,
+
+    /// Why it could not be read.
+    pub reason: String,
+}
+
+/// Result of inspecting a disk buffer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BufferReport {
+    pub kind: BufferKind,
+
+    /// State of the ledger, for `disk_v2` buffers.
+    pub ledger: Option<LedgerReport>,
+
+    /// Data files, for `disk_v2` buffers.
+    pub data_files: Vec<DataFileReport>,
+
+    /// Number of unread records that could be read.
+    pub records: u64,
+
+    /// Number of events in the unread records that could be read.
+    pub events: u64,
+
+    /// Size of the unread records that could be read, in bytes.
+    pub bytes: u64,
+
+    /// Parts of the buffer that could not be read.
+    pub corruptions: Vec<Corruption>,
+}
+
+impl BufferReport {
+    pub(crate) fn new(kind: BufferKind) -> Self {
+        Self {
+            kind,
+            ledger: None,
+            data_files: Vec::new(),
+            records: 0,
+            events: 0,
+            bytes: 0,
+            corruptions: Vec::new(),
+        }
+    }
+}
+
+/// Reads all unread records from the disk buffer stored in the given directory.
+///
+/// Each record is handed to `visit`, in the order it would be read by Vector, and a summary of the
+/// buffer is returned once all records have been read.  Records that can't be read are reported as
+/// corruptions, and skipped.
+///
+/// Encrypted records of `disk_v2` buffers can only be read if `encryption` holds the key they were
+/// encrypted with.
+///
+/// `disk_v1` buffers are copied to a temporary directory and read from the copy, as LevelDB locks
+/// and may write to the databases it opens. This needs as much free space as the buffer takes.
+///
+/// # Errors
+///
+/// If the directory does not contain a disk buffer, or if it can't be opened, an error variant
+/// will be returned describing the error.
+pub async fn inspect<T, F, Fut>(
+    path: &Path,
+    encryption: Option<&EncryptionConfig>,
+    visit: F,
+) -> Result<BufferReport, InspectError>
+where
+    T: Bufferable,
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    match BufferKind::detect(path) {
+        Some(BufferKind::DiskV1) => disk_v1::inspect(path, visit).await,
+        Some(BufferKind::DiskV2) => {
+            let cipher = encryption
+                .map(RecordCipher::from_config)
+                .transpose()
+                .context(InvalidEncryptionKeySnafu)?;
+            disk_v2::inspect(path, cipher.map(Arc::new), visit).await
+        }
+        None => Err(InspectError::NotABuffer {
+            path: path.to_path_buf(),
+        }),
+    }
+}
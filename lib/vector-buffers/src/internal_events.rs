@@ -89,6 +89,36 @@ impl InternalEvent for BufferEventsDropped {
     }
 }
 
+pub struct BufferEventsEvicted {
+    pub idx: usize,
+    pub count: u64,
+    pub byte_size: u64,
+    pub reason: &'static str,
+}
+
+impl InternalEvent for BufferEventsEvicted {
+    #[allow(clippy::cast_precision_loss)]
+    fn emit(self) {
+        debug!(
+            message = "Events evicted.",
+            count = %self.count,
+            reason = %self.reason,
+            stage = %self.idx,
+        );
+        counter!(
+            "buffer_evicted_events_total", self.count,
+            "reason" => self.reason,
+            "stage" => self.idx.to_string(),
+        );
+        counter!(
+            "buffer_discarded_events_total", self.count,
+            "intentional" => "true",
+        );
+        decrement_gauge!("buffer_events", self.count as f64, "stage" => self.idx.to_string());
+        decrement_gauge!("buffer_byte_size", self.byte_size as f64, "stage" => self.idx.to_string());
+    }
+}
+
 pub struct BufferReadError {
     pub error_code: &'static str,
     pub error: String,
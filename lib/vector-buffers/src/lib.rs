@@ -21,6 +21,8 @@ use encoding::Encodable;
 
 pub mod encoding;
 
+pub mod inspect;
+
 mod internal_events;
 
 #[cfg(test)]
@@ -33,14 +35,34 @@ use std::fmt::Debug;
 
 #[cfg(test)]
 use quickcheck::{Arbitrary, Gen};
-use serde::{Deserialize, Serialize};
 use vector_common::{byte_size_of::ByteSizeOf, finalization::AddBatchNotifier};
+use vector_config::configurable_component;
 
-#[derive(Deserialize, Serialize, Debug, PartialEq, Copy, Clone)]
+/// Event handling behavior when a buffer is full.
+#[configurable_component]
+#[derive(Debug, PartialEq, Copy, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum WhenFull {
+    /// Wait for free space in the buffer.
+    ///
+    /// This applies backpressure up the topology, which prevents data loss, but causes data to pile up at the edge.
     Block,
+
+    /// Drops the event instead of waiting for free space in the buffer.
+    ///
+    /// The event is lost. This should be used when performance is the highest priority.
     DropNewest,
+
+    /// Drops the oldest buffered events to make room for the new event.
+    ///
+    /// The dropped events are lost. This should be used when the freshest data matters most.
+    DropOldest,
+
+    /// Overflows to the next stage in the buffer topology.
+    ///
+    /// If the current buffer stage is full, attempt to send this event to the next buffer stage. That stage may also
+    /// be configured to overflow, and so on, but ultimately the last stage in a buffer topology must use one of the
+    /// other behaviors.
     Overflow,
 }
 
@@ -5,14 +5,18 @@ use std::{
         atomic::{AtomicUsize, Ordering},
         Arc,
     },
+    time::Duration,
 };
 
 use async_stream::stream;
 use crossbeam_queue::ArrayQueue;
 use futures::Stream;
-use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore, TryAcquireError};
+use tokio::{
+    sync::{Notify, OwnedSemaphorePermit, Semaphore, TryAcquireError},
+    time::Instant,
+};
 
-use crate::Bufferable;
+use crate::{buffer_usage_data::BufferUsageHandle, Bufferable};
 
 /// Error returned by `LimitedSender::send` when the receiver has disconnected.
 #[derive(Debug, PartialEq)]
@@ -54,12 +58,28 @@ impl<T> fmt::Display for TrySendError<T> {
 
 impl<T: fmt::Debug> std::error::Error for TrySendError<T> {}
 
+/// An item sitting in the channel.
+///
+/// The time the item was sent at is only tracked when the channel has a maximum age.
+type Entry<T> = (OwnedSemaphorePermit, Option<Instant>, T);
+
 #[derive(Debug)]
 struct Inner<T> {
-    data: Arc<ArrayQueue<(OwnedSemaphorePermit, T)>>,
+    data: Arc<ArrayQueue<Entry<T>>>,
     limit: usize,
     limiter: Arc<Semaphore>,
     read_waker: Arc<Notify>,
+    max_age: Option<Duration>,
+}
+
+impl<T> Inner<T> {
+    fn push(&self, permits: OwnedSemaphorePermit, item: T) {
+        let sent_at = self.max_age.map(|_| Instant::now());
+        self.data
+            .push((permits, sent_at, item))
+            .expect("acquired permits but channel reported being full");
+        self.read_waker.notify_one();
+    }
 }
 
 impl<T> Clone for Inner<T> {
@@ -69,6 +89,7 @@ impl<T> Clone for Inner<T> {
             limit: self.limit,
             limiter: self.limiter.clone(),
             read_waker: self.read_waker.clone(),
+            max_age: self.max_age,
         }
     }
 }
@@ -113,17 +134,65 @@ impl<T: Bufferable> LimitedSender<T> {
             Err(_) => return Err(SendError(item)),
         };
 
-        self.inner
-            .data
-            .push((permits, item))
-            .expect("acquired permits but channel reported being full");
-        self.inner.read_waker.notify_one();
+        self.inner.push(permits, item);
 
         trace!("Sent item.");
 
         Ok(())
     }
 
+    /// Sends an item into the channel, evicting the oldest items in the channel if there is not
+    /// enough capacity for it.
+    ///
+    /// The evicted items are returned, oldest first.  If the channel is empty, but still lacks the
+    /// capacity for the item, because the receiver is holding on to items it has already received,
+    /// this waits for capacity like [`send`] does.
+    ///
+    /// # Errors
+    ///
+    /// If the receiver has disconnected (does not exist anymore), then `Err(SendError)` be returned
+    /// with the given `item`.
+    pub async fn send_evicting(&mut self, item: T) -> Result<Vec<T>, SendError<T>> {
+        let permits_required = self.get_required_permits_for_item(&item);
+        let mut evicted = Vec::new();
+        let permits = loop {
+            match self
+                .inner
+                .limiter
+                .clone()
+                .try_acquire_many_owned(permits_required)
+            {
+                Ok(permits) => break permits,
+                Err(TryAcquireError::Closed) => return Err(SendError(item)),
+                Err(TryAcquireError::NoPermits) => {
+                    // Popping the oldest item releases its permits along with it, so we can simply
+                    // try again afterwards.
+                    if let Some((_permit, _sent_at, oldest)) = self.inner.data.pop() {
+                        evicted.push(oldest);
+                        continue;
+                    }
+
+                    match self
+                        .inner
+                        .limiter
+                        .clone()
+                        .acquire_many_owned(permits_required)
+                        .await
+                    {
+                        Ok(permits) => break permits,
+                        Err(_) => return Err(SendError(item)),
+                    }
+                }
+            }
+        };
+
+        self.inner.push(permits, item);
+
+        trace!(evicted = evicted.len(), "Sent item.");
+
+        Ok(evicted)
+    }
+
     /// Attempts to send an item into the channel.
     ///
     /// # Errors
@@ -150,11 +219,7 @@ impl<T: Bufferable> LimitedSender<T> {
             }
         };
 
-        self.inner
-            .data
-            .push((permits, item))
-            .expect("acquired permits but channel reported being full");
-        self.inner.read_waker.notify_one();
+        self.inner.push(permits, item);
 
         trace!("Attempt to send item succeeded.");
 
@@ -186,6 +251,7 @@ impl<T> Drop for LimitedSender<T> {
 #[derive(Debug)]
 pub struct LimitedReceiver<T> {
     inner: Inner<T>,
+    usage_handle: Option<BufferUsageHandle>,
 }
 
 impl<T: Send + 'static> LimitedReceiver<T> {
@@ -195,9 +261,13 @@ impl<T: Send + 'static> LimitedReceiver<T> {
     }
 
     pub async fn next(&mut self) -> Option<T> {
+        self.next_entry().await.map(|(_sent_at, item)| item)
+    }
+
+    async fn next_entry(&mut self) -> Option<(Option<Instant>, T)> {
         loop {
-            if let Some((_permit, item)) = self.inner.data.pop() {
-                return Some(item);
+            if let Some((_permit, sent_at, item)) = self.inner.data.pop() {
+                return Some((sent_at, item));
             }
 
             // There wasn't an item for us to pop, so see if the channel is actually closed.  If so,
@@ -223,6 +293,29 @@ impl<T: Send + 'static> LimitedReceiver<T> {
     }
 }
 
+impl<T: Bufferable> LimitedReceiver<T> {
+    /// Receives the next item that is not older than the maximum age of the channel.
+    ///
+    /// Items older than the maximum age are discarded, and tracked as expired.
+    pub(crate) async fn next_unexpired(&mut self) -> Option<T> {
+        loop {
+            let (sent_at, item) = self.next_entry().await?;
+            match (sent_at, self.inner.max_age) {
+                (Some(sent_at), Some(max_age)) if sent_at.elapsed() > max_age => {
+                    trace!("Discarding item older than the maximum age.");
+                    if let Some(usage_handle) = self.usage_handle.as_ref() {
+                        usage_handle.increment_expired_event_count_and_byte_size(
+                            item.event_count() as u64,
+                            item.size_of() as u64,
+                        );
+                    }
+                }
+                _ => return Some(item),
+            }
+        }
+    }
+}
+
 impl<T> Drop for LimitedReceiver<T> {
     fn drop(&mut self) {
         // Notify senders that the channel is now closed by closing the semaphore.  Any pending
@@ -233,30 +326,55 @@ impl<T> Drop for LimitedReceiver<T> {
 }
 
 pub fn limited<T>(limit: usize) -> (LimitedSender<T>, LimitedReceiver<T>) {
+    limited_inner(limit, None, None)
+}
+
+/// Creates a channel whose receiver discards items that were sent more than `max_age` ago.
+///
+/// Discarded items are tracked as expired in the given usage handle.
+pub(crate) fn limited_with_max_age<T>(
+    limit: usize,
+    max_age: Duration,
+    usage_handle: BufferUsageHandle,
+) -> (LimitedSender<T>, LimitedReceiver<T>) {
+    limited_inner(limit, Some(max_age), Some(usage_handle))
+}
+
+fn limited_inner<T>(
+    limit: usize,
+    max_age: Option<Duration>,
+    usage_handle: Option<BufferUsageHandle>,
+) -> (LimitedSender<T>, LimitedReceiver<T>) {
     let inner = Inner {
         data: Arc::new(ArrayQueue::new(limit)),
         limit,
         limiter: Arc::new(Semaphore::new(limit)),
         read_waker: Arc::new(Notify::new()),
+        max_age,
     };
 
     let sender = LimitedSender {
         inner: inner.clone(),
         sender_count: Arc::new(AtomicUsize::new(1)),
     };
-    let receiver = LimitedReceiver { inner };
+    let receiver = LimitedReceiver {
+        inner,
+        usage_handle,
+    };
 
     (sender, receiver)
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use tokio_test::{assert_pending, assert_ready, task::spawn};
 
-    use super::limited;
+    use super::{limited, limited_with_max_age};
     use crate::{
-        test::MultiEventRecord, topology::channel::limited_queue::SendError,
-        topology::test_util::Sample,
+        buffer_usage_data::BufferUsageHandle, test::MultiEventRecord,
+        topology::channel::limited_queue::SendError, topology::test_util::Sample,
     };
 
     #[tokio::test]
@@ -592,4 +710,52 @@ mod tests {
 
         assert_eq!(2, tx.available_capacity());
     }
+
+    #[tokio::test]
+    async fn send_evicting_drops_oldest_items() {
+        let (mut tx, mut rx) = limited(2);
+
+        assert_eq!(Ok(vec![]), tx.send_evicting(Sample(1)).await);
+        assert_eq!(Ok(vec![]), tx.send_evicting(Sample(2)).await);
+        assert_eq!(0, tx.available_capacity());
+
+        // The channel is full, so the oldest item has to make room for the new one.
+        assert_eq!(Ok(vec![Sample(1)]), tx.send_evicting(Sample(3)).await);
+
+        // Larger items evict as many items as needed to make room for them.
+        let (mut tx2, mut rx2) = limited(3);
+        for _ in 0..3 {
+            tx2.send(MultiEventRecord::new(1))
+                .await
+                .expect("send should not fail");
+        }
+        let evicted = tx2
+            .send_evicting(MultiEventRecord::new(2))
+            .await
+            .expect("send should not fail");
+        assert_eq!(2, evicted.len());
+
+        assert_eq!(Some(Sample(2)), rx.next().await);
+        assert_eq!(Some(Sample(3)), rx.next().await);
+
+        assert_eq!(Some(MultiEventRecord::new(1)), rx2.next().await);
+        assert_eq!(Some(MultiEventRecord::new(2)), rx2.next().await);
+    }
+
+    #[tokio::test]
+    async fn next_unexpired_discards_old_items() {
+        let usage_handle = BufferUsageHandle::noop();
+        let (mut tx, mut rx) =
+            limited_with_max_age(4, Duration::from_millis(10), usage_handle.clone());
+
+        tx.send(Sample(1)).await.expect("send should not fail");
+        tx.send(Sample(2)).await.expect("send should not fail");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        tx.send(Sample(3)).await.expect("send should not fail");
+
+        assert_eq!(Some(Sample(3)), rx.next_unexpired().await);
+
+        let snapshot = usage_handle.snapshot();
+        assert_eq!(2, snapshot.expired_event_count);
+    }
 }
@@ -57,7 +57,7 @@ where
 {
     pub(crate) async fn next(&mut self) -> Option<T> {
         match self {
-            ReceiverAdapter::InMemory(rx) => rx.next().await,
+            ReceiverAdapter::InMemory(rx) => rx.next_unexpired().await,
             ReceiverAdapter::DiskV1(reader) => reader.next().await,
             ReceiverAdapter::DiskV2(reader) => loop {
                 match reader.next().await {
@@ -68,6 +68,31 @@ where
         }
     }
 
+    /// Sends an item, evicting the oldest items in the buffer to make room for it if need be.
+    ///
+    /// Evicted items are returned when the buffer does not track them itself.
+    pub(crate) async fn send_evicting(&mut self, item: T) -> Result<Vec<T>, ()> {
+        match self {
+            Self::InMemory(tx) => tx.send_evicting(item).await.map_err(|_| ()),
+            Self::DiskV1(writer) => {
+                // The LevelDB-based buffer can't evict records, and configuring it to do so is
+                // rejected, so we simply wait for capacity instead.
+                writer.send(item).await;
+                Ok(Vec::new())
+            }
+            Self::DiskV2(writer) => {
+                let mut writer = writer.lock().await;
+
+                if let Err(e) = writer.write_record_evicting(item).await {
+                    // Can't really do much except panic here. :sweat:
+                    panic!("writer hit unrecoverable error during write: {}", e);
+                }
+
+                Ok(Vec::new())
+            }
+        }
+    }
+
     pub(crate) async fn try_send(&mut self, item: T) -> Result<Option<T>, ()> {
         match self {
             Self::InMemory(tx) => tx
@@ -132,16 +157,19 @@ where
 /// events when the internal channel is full.
 ///
 /// When creating a buffer sender/receiver pair, callers can specify the "when full" behavior of the
-/// sender.  This controls how events are handled when the internal channel is full.  Three modes
+/// sender.  This controls how events are handled when the internal channel is full.  Four modes
 /// are possible:
 /// - block
 /// - drop newest
+/// - drop oldest
 /// - overflow
 ///
 /// In "block" mode, callers are simply forced to wait until the channel has enough capacity to
 /// accept the event.  In "drop newest" mode, any event being sent when the channel is full will be
-/// dropped and proceed no further. In "overflow" mode, events will be sent to another buffer
-/// sender.  Callers can specify the overflow sender to use when constructing their buffers initially.
+/// dropped and proceed no further. In "drop oldest" mode, the oldest events in the channel are
+/// dropped to make room for the event being sent. In "overflow" mode, events will be sent to
+/// another buffer sender.  Callers can specify the overflow sender to use when constructing their
+/// buffers initially.
 ///
 /// TODO: We should eventually rework `BufferSender`/`BufferReceiver` so that they contain a vector
 /// of the fields we already have here, but instead of cascading via calling into `overflow`, we'd
@@ -213,6 +241,7 @@ impl<T: Bufferable> BufferSender<T> {
 
         let mut sent_to_base = true;
         let mut was_dropped = false;
+        let mut evicted = Vec::new();
         match self.when_full {
             WhenFull::Block => self.base.send(item).await?,
             WhenFull::DropNewest => {
@@ -220,6 +249,7 @@ impl<T: Bufferable> BufferSender<T> {
                     was_dropped = true;
                 }
             }
+            WhenFull::DropOldest => evicted = self.base.send_evicting(item).await?,
             WhenFull::Overflow => {
                 if let Some(item) = self.base.try_send(item).await? {
                     sent_to_base = false;
@@ -249,6 +279,13 @@ impl<T: Bufferable> BufferSender<T> {
                     );
                 }
             }
+
+            for item in &evicted {
+                instrumentation.increment_evicted_event_count_and_byte_size(
+                    item.event_count() as u64,
+                    item.size_of() as u64,
+                );
+            }
         }
 
         Ok(())
@@ -0,0 +1,98 @@
+use std::{
+    fs,
+    future::Future,
+    io,
+    path::{Path, PathBuf},
+    process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use leveldb::{
+    database::Database,
+    iterator::Iterable,
+    options::{Options, ReadOptions},
+};
+use snafu::ResultExt;
+
+use super::key::Key;
+use crate::{
+    inspect::{BufferKind, BufferReport, Corruption, DatabaseSnafu, InspectError, IoSnafu},
+    Bufferable,
+};
+
+/// A copy of a LevelDB database in a temporary directory, which is deleted when dropped.
+///
+/// LevelDB can't open a database read-only: opening it takes its lock, which fails while Vector
+/// is using the buffer, and can write to it when recovering its log. The files of the database
+/// are copied instead, without its lock, and the copy is opened.
+struct DatabaseCopy {
+    path: PathBuf,
+}
+
+impl DatabaseCopy {
+    fn new(source: &Path) -> io::Result<Self> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos())
+            .unwrap_or_default();
+        let path =
+            std::env::temp_dir().join(format!("vector-buffer-inspect-{}-{}", process::id(), nanos));
+        fs::create_dir(&path)?;
+        let copy = Self { path };
+
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+            if entry.file_name() == "LOCK" || !entry.file_type()?.is_file() {
+                continue;
+            }
+            fs::copy(entry.path(), copy.path.join(entry.file_name()))?;
+        }
+        Ok(copy)
+    }
+}
+
+impl Drop for DatabaseCopy {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Reads all records from the `disk_v1` buffer stored in `path`.
+///
+/// The buffer is read from a temporary copy, so that it is left untouched even if Vector is
+/// running. Unlike when the buffer is opened normally, undecodable records are not deleted, but
+/// only reported.
+pub(crate) async fn inspect<T, F, Fut>(
+    path: &Path,
+    mut visit: F,
+) -> Result<BufferReport, InspectError>
+where
+    T: Bufferable,
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let mut options = Options::new();
+    options.create_if_missing = false;
+
+    let copy = DatabaseCopy::new(path).context(IoSnafu { path })?;
+    let db: Database<Key> = Database::open(&copy.path, options).context(DatabaseSnafu { path })?;
+
+    let mut report = BufferReport::new(BufferKind::DiskV1);
+    for (key, value) in db.iter(ReadOptions::new()) {
+        match T::decode(T::get_metadata(), &value[..]) {
+            Ok(record) => {
+                let events = record.event_count() as u64;
+                report.records += 1;
+                report.events += events;
+                report.bytes += value.len() as u64;
+                visit(record).await;
+            }
+            Err(e) => report.corruptions.push(Corruption {
+                location: format!("key {}", key.0),
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(report)
+}
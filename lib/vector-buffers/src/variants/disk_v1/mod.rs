@@ -1,3 +1,4 @@
+mod inspect;
 mod key;
 mod reader;
 mod writer;
@@ -31,6 +32,7 @@ use snafu::{ResultExt, Snafu};
 use tokio::{sync::Notify, time::Instant};
 use vector_common::{finalizer::OrderedFinalizer, shutdown::ShutdownSignal};
 
+pub(crate) use self::inspect::inspect;
 use self::key::Key;
 pub use self::{reader::Reader, writer::Writer};
 use crate::{
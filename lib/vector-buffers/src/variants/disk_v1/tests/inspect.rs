@@ -0,0 +1,55 @@
+use std::fs;
+
+use futures::future;
+
+use super::create_default_buffer_v1;
+use crate::{
+    inspect::{inspect, BufferKind},
+    test::{with_temp_dir, SizedRecord},
+};
+
+#[tokio::test]
+async fn inspect_reads_a_copy_of_an_open_buffer() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, reader) = create_default_buffer_v1(&data_dir);
+            let records = vec![
+                SizedRecord::new(32),
+                SizedRecord::new(64),
+                SizedRecord::new(128),
+            ];
+            for record in records.iter().cloned() {
+                writer.send(record).await;
+            }
+            writer.flush();
+
+            // The buffer is still open, so its database is locked.
+            let buffer_dir = data_dir.join("disk_buffer_v1_id");
+            let files_before = fs::read_dir(&buffer_dir)
+                .expect("buffer directory should exist")
+                .count();
+
+            let mut visited = Vec::new();
+            let report = inspect::<SizedRecord, _, _>(&buffer_dir, None, |record| {
+                visited.push(record);
+                future::ready(())
+            })
+            .await
+            .expect("inspection should not fail");
+
+            assert_eq!(visited, records);
+            assert_eq!(report.kind, BufferKind::DiskV1);
+            assert_eq!(report.records, 3);
+            assert!(report.corruptions.is_empty());
+
+            let files_after = fs::read_dir(&buffer_dir)
+                .expect("buffer directory should exist")
+                .count();
+            assert_eq!(files_before, files_after);
+            drop((writer, reader));
+        }
+    })
+    .await;
+}
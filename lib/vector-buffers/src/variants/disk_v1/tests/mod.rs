@@ -12,6 +12,7 @@ use crate::{
 mod acknowledgements;
 mod basic;
 mod event_count;
+mod inspect;
 mod naming;
 mod size_limits;
 
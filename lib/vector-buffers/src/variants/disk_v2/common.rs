@@ -1,5 +1,6 @@
 use std::{
     path::{Path, PathBuf},
+    sync::Arc,
     time::Duration,
 };
 
@@ -7,6 +8,8 @@ use crc32fast::Hasher;
 use snafu::Snafu;
 
 use super::{
+    compression::CompressionAlgorithm,
+    encryption::{RecordCipher, ENVELOPE_OVERHEAD},
     io::{Filesystem, ProductionFilesystem},
     ledger::LEDGER_LEN,
     record::RECORD_HEADER_LEN,
@@ -23,6 +26,10 @@ pub const DEFAULT_MAX_RECORD_SIZE: usize = DEFAULT_MAX_DATA_FILE_SIZE;
 // what `rkyv` will do when we serialize a record.
 pub const MINIMUM_MAX_RECORD_SIZE: usize = align16(RECORD_HEADER_LEN + 1);
 
+// When records are encrypted, the encryption envelope also counts towards the record size.
+pub const MINIMUM_MAX_ENCRYPTED_RECORD_SIZE: usize =
+    align16(RECORD_HEADER_LEN + ENVELOPE_OVERHEAD + 1);
+
 // We want to ensure a reasonable time before we `fsync`/flush to disk, and 500ms should provide that for non-critical
 // workloads.
 //
@@ -153,6 +160,23 @@ pub struct DiskBufferConfig<FS> {
     /// amount of data written since the last flush would be lost.
     pub(crate) flush_interval: Duration,
 
+    /// Cipher used to encrypt records before they are written to data files.
+    ///
+    /// Records written while no cipher was configured can still be read, but encrypted records can
+    /// only be read if the cipher holds the key they were encrypted with.
+    pub(crate) cipher: Option<Arc<RecordCipher>>,
+
+    /// Algorithm used to compress records before they are written to data files.
+    ///
+    /// Records are only stored compressed when that makes them smaller, and the buffer size limits apply to the
+    /// compressed size of records.  Records can be read regardless of whether or not compression is configured.
+    pub(crate) compression: Option<CompressionAlgorithm>,
+
+    /// Maximum age of records.
+    ///
+    /// When set, records are timestamped as they are written, and records older than this are discarded when read.
+    pub(crate) max_age: Option<Duration>,
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -173,6 +197,9 @@ where
     pub(crate) max_record_size: Option<usize>,
     pub(crate) write_buffer_size: Option<usize>,
     pub(crate) flush_interval: Option<Duration>,
+    pub(crate) cipher: Option<Arc<RecordCipher>>,
+    pub(crate) compression: Option<CompressionAlgorithm>,
+    pub(crate) max_age: Option<Duration>,
     pub(crate) filesystem: FS,
 }
 
@@ -188,6 +215,9 @@ impl DiskBufferConfigBuilder {
             max_record_size: None,
             write_buffer_size: None,
             flush_interval: None,
+            cipher: None,
+            compression: None,
+            max_age: None,
             filesystem: ProductionFilesystem,
         }
     }
@@ -268,6 +298,42 @@ where
         self
     }
 
+    /// Sets the cipher used to encrypt records at rest.
+    ///
+    /// Records written while no cipher was configured can still be read, but encrypted records can
+    /// only be read if the cipher holds the key they were encrypted with.
+    ///
+    /// Defaults to no encryption.
+    #[allow(dead_code)]
+    pub fn encryption(mut self, cipher: Arc<RecordCipher>) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    /// Sets the algorithm used to compress records.
+    ///
+    /// Records are only stored compressed when that makes them smaller, and the buffer size limits apply to the
+    /// compressed size of records.  Records can be read regardless of whether or not compression is configured.
+    ///
+    /// Defaults to no compression.
+    #[allow(dead_code)]
+    pub fn compression(mut self, algorithm: CompressionAlgorithm) -> Self {
+        self.compression = Some(algorithm);
+        self
+    }
+
+    /// Sets the maximum age of records.
+    ///
+    /// When set, records are timestamped as they are written, and records older than this are discarded when read.
+    /// Records written while no maximum age was configured never expire.
+    ///
+    /// Defaults to no maximum age.
+    #[allow(dead_code)]
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -287,6 +353,9 @@ where
             max_record_size: self.max_record_size,
             write_buffer_size: self.write_buffer_size,
             flush_interval: self.flush_interval,
+            cipher: self.cipher,
+            compression: self.compression,
+            max_age: self.max_age,
             filesystem,
         }
     }
@@ -301,6 +370,9 @@ where
         let max_record_size = self.max_record_size.unwrap_or(DEFAULT_MAX_RECORD_SIZE);
         let write_buffer_size = self.write_buffer_size.unwrap_or(DEFAULT_WRITE_BUFFER_SIZE);
         let flush_interval = self.flush_interval.unwrap_or(DEFAULT_FLUSH_INTERVAL);
+        let cipher = self.cipher;
+        let compression = self.compression;
+        let max_age = self.max_age;
         let filesystem = self.filesystem;
 
         // Validate the input parameters.
@@ -356,6 +428,16 @@ where
             });
         }
 
+        if cipher.is_some() && max_record_size < MINIMUM_MAX_ENCRYPTED_RECORD_SIZE {
+            return Err(BuildError::InvalidParameter {
+                param_name: "max_record_size",
+                reason: format!(
+                    "must be greater than or equal to {} bytes when encryption is enabled",
+                    MINIMUM_MAX_ENCRYPTED_RECORD_SIZE,
+                ),
+            });
+        }
+
         let max_record_size_converted = match u64::try_from(max_record_size) {
             Ok(value) => value,
             Err(_) => {
@@ -427,6 +509,9 @@ where
             max_record_size,
             write_buffer_size,
             flush_interval,
+            cipher,
+            compression,
+            max_age,
             filesystem,
         })
     }
@@ -0,0 +1,211 @@
+use std::{borrow::Cow, io};
+
+use snafu::{ResultExt, Snafu};
+use vector_config::configurable_component;
+
+/// Flag set in the metadata of records whose payload is compressed.
+///
+/// Records are only compressed when doing so actually makes them smaller, so records without this flag are read
+/// as-is.  This also allows compression to be enabled, or disabled, on an existing buffer without losing its contents.
+pub(super) const COMPRESSED_RECORD_FLAG: u32 = 1 << 30;
+
+/// Length, in bytes, of the header of compressed payloads: algorithm, and decompressed length.
+const HEADER_LEN: usize = 1 + 4;
+
+/// Compression level used for zstd.
+///
+/// This is the default level of zstd itself, which gives a good compression ratio while still being fast enough to
+/// not become the bottleneck of the writer.
+const ZSTD_LEVEL: i32 = 3;
+
+/// Error that occurred while compressing or decompressing a record payload.
+#[derive(Debug, Snafu)]
+pub enum CompressionError {
+    #[snafu(display("failed to compress record payload: {}", source))]
+    CompressionFailed { source: io::Error },
+    #[snafu(display("failed to decompress record payload: {}", source))]
+    DecompressionFailed { source: io::Error },
+    #[snafu(display("compressed record payload is invalid: {}", reason))]
+    InvalidPayload { reason: String },
+}
+
+/// Algorithm used for compressing records.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompressionAlgorithm {
+    /// [Zstandard][zstd] compression, favoring compression ratio.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd,
+
+    /// [LZ4][lz4] compression, favoring speed.
+    ///
+    /// [lz4]: https://lz4.github.io/lz4/
+    Lz4,
+}
+
+impl CompressionAlgorithm {
+    fn as_payload_id(self) -> u8 {
+        match self {
+            CompressionAlgorithm::Zstd => 1,
+            CompressionAlgorithm::Lz4 => 2,
+        }
+    }
+
+    fn from_payload_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(CompressionAlgorithm::Zstd),
+            2 => Some(CompressionAlgorithm::Lz4),
+            _ => None,
+        }
+    }
+
+    /// Compresses `payload`, writing the compressed payload to `dst`.
+    ///
+    /// The compressed payload carries the algorithm it was compressed with, so records can still be read after the
+    /// algorithm has been changed.
+    ///
+    /// # Errors
+    ///
+    /// If the payload is too large, or the underlying compressor fails, an error variant will be returned.
+    pub(super) fn compress(
+        self,
+        payload: &[u8],
+        dst: &mut Vec<u8>,
+    ) -> Result<(), CompressionError> {
+        let decompressed_len =
+            u32::try_from(payload.len()).map_err(|_| CompressionError::CompressionFailed {
+                source: io::Error::new(io::ErrorKind::InvalidInput, "payload exceeds 4GiB"),
+            })?;
+
+        let compressed = match self {
+            CompressionAlgorithm::Zstd => zstd::bulk::compress(payload, ZSTD_LEVEL),
+            CompressionAlgorithm::Lz4 => lz4::block::compress(payload, None, false),
+        }
+        .context(CompressionFailedSnafu)?;
+
+        dst.clear();
+        dst.reserve(HEADER_LEN + compressed.len());
+        dst.push(self.as_payload_id());
+        dst.extend_from_slice(&decompressed_len.to_be_bytes());
+        dst.extend_from_slice(&compressed);
+
+        Ok(())
+    }
+}
+
+/// Decompresses the payload of a record, if it was compressed.
+///
+/// Payloads of records that weren't flagged as compressed are returned as-is.
+///
+/// # Errors
+///
+/// If the payload is malformed, or cannot be decompressed, an error variant will be returned.
+pub(super) fn decompress_payload(
+    metadata: u32,
+    payload: Cow<'_, [u8]>,
+) -> Result<Cow<'_, [u8]>, CompressionError> {
+    if metadata & COMPRESSED_RECORD_FLAG == 0 {
+        return Ok(payload);
+    }
+
+    if payload.len() < HEADER_LEN {
+        return Err(CompressionError::InvalidPayload {
+            reason: "payload shorter than header".to_string(),
+        });
+    }
+
+    let (header, compressed) = payload.split_at(HEADER_LEN);
+    let algorithm = CompressionAlgorithm::from_payload_id(header[0]).ok_or_else(|| {
+        CompressionError::InvalidPayload {
+            reason: format!("unknown algorithm {}", header[0]),
+        }
+    })?;
+    let decompressed_len = u32::from_be_bytes([header[1], header[2], header[3], header[4]]);
+
+    let decompressed = match algorithm {
+        CompressionAlgorithm::Zstd => zstd::bulk::decompress(compressed, decompressed_len as usize),
+        CompressionAlgorithm::Lz4 => {
+            // The LZ4 block API takes the decompressed length as an `i32`.
+            let decompressed_len =
+                i32::try_from(decompressed_len).map_err(|_| CompressionError::InvalidPayload {
+                    reason: format!("decompressed length {} too large", decompressed_len),
+                })?;
+            lz4::block::decompress(compressed, Some(decompressed_len))
+        }
+    }
+    .context(DecompressionFailedSnafu)?;
+
+    if decompressed.len() != decompressed_len as usize {
+        return Err(CompressionError::InvalidPayload {
+            reason: format!(
+                "expected {} bytes after decompression, got {}",
+                decompressed_len,
+                decompressed.len()
+            ),
+        });
+    }
+
+    Ok(decompressed.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compressible_payload() -> Vec<u8> {
+        b"some log line that repeats itself\n".repeat(64)
+    }
+
+    #[test]
+    fn roundtrip() {
+        let payload = compressible_payload();
+
+        for algorithm in [CompressionAlgorithm::Zstd, CompressionAlgorithm::Lz4] {
+            let mut compressed = Vec::new();
+            algorithm.compress(&payload, &mut compressed).unwrap();
+            assert!(compressed.len() < payload.len());
+
+            let decompressed =
+                decompress_payload(COMPRESSED_RECORD_FLAG, compressed.as_slice().into()).unwrap();
+            assert_eq!(decompressed.as_ref(), payload.as_slice());
+        }
+    }
+
+    #[test]
+    fn passes_through_uncompressed_payloads() {
+        let payload = compressible_payload();
+
+        let decompressed = decompress_payload(0, payload.as_slice().into()).unwrap();
+        assert!(matches!(decompressed, Cow::Borrowed(_)));
+        assert_eq!(decompressed.as_ref(), payload.as_slice());
+    }
+
+    #[test]
+    fn rejects_invalid_payloads() {
+        let payload = compressible_payload();
+        let mut compressed = Vec::new();
+        CompressionAlgorithm::Zstd
+            .compress(&payload, &mut compressed)
+            .unwrap();
+
+        let mut unknown_algorithm = compressed.clone();
+        unknown_algorithm[0] = 42;
+        assert!(matches!(
+            decompress_payload(COMPRESSED_RECORD_FLAG, unknown_algorithm.as_slice().into()),
+            Err(CompressionError::InvalidPayload { .. })
+        ));
+
+        let mut wrong_length = compressed.clone();
+        wrong_length[1..HEADER_LEN].copy_from_slice(&1u32.to_be_bytes());
+        assert!(
+            decompress_payload(COMPRESSED_RECORD_FLAG, wrong_length.as_slice().into()).is_err()
+        );
+
+        assert!(matches!(
+            decompress_payload(COMPRESSED_RECORD_FLAG, compressed[..2].into()),
+            Err(CompressionError::InvalidPayload { .. })
+        ));
+    }
+}
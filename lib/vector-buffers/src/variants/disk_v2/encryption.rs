@@ -0,0 +1,470 @@
+use std::{
+    borrow::Cow,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use aes_gcm::{
+    aead::{generic_array::GenericArray, rand_core::RngCore, AeadInPlace, KeyInit, OsRng},
+    Aes256Gcm,
+};
+use chacha20poly1305::ChaCha20Poly1305;
+use snafu::{ResultExt, Snafu};
+use vector_config::configurable_component;
+
+/// Flag set in the metadata of records whose payload is encrypted.
+///
+/// Records written before encryption was enabled don't have this flag set, and are read as-is, which allows
+/// encryption to be enabled on an existing buffer without losing its contents.
+pub(super) const ENCRYPTED_RECORD_FLAG: u32 = 1 << 31;
+
+/// Version of the envelope that encrypted payloads are wrapped in.
+const ENVELOPE_VERSION: u8 = 1;
+
+/// Length, in bytes, of the key used by all supported algorithms.
+const KEY_LEN: usize = 32;
+
+/// Length, in bytes, of the nonce used by all supported algorithms.
+const NONCE_LEN: usize = 12;
+
+/// Length, in bytes, of the authentication tag used by all supported algorithms.
+const TAG_LEN: usize = 16;
+
+/// Length, in bytes, of the envelope header: version, algorithm, and nonce.
+const ENVELOPE_HEADER_LEN: usize = 2 + NONCE_LEN;
+
+/// Number of bytes that encrypting a payload adds to it.
+pub(super) const ENVELOPE_OVERHEAD: usize = ENVELOPE_HEADER_LEN + TAG_LEN;
+
+/// Error that occurred while loading encryption keys.
+#[derive(Debug, Snafu)]
+pub enum EncryptionKeyError {
+    #[snafu(display("either `key` or `key_file` must be set, but not both"))]
+    MissingKey,
+    #[snafu(display("failed to read key file {:?}: {}", path, source))]
+    KeyFile { path: PathBuf, source: io::Error },
+    #[snafu(display("key is not valid base64: {}", source))]
+    KeyEncoding { source: base64::DecodeError },
+    #[snafu(display("key must be {} bytes long, got {} bytes", KEY_LEN, len))]
+    KeyLength { len: usize },
+}
+
+/// Error that occurred while encrypting or decrypting a record payload.
+#[derive(Debug, Snafu)]
+pub enum EncryptionError {
+    #[snafu(display("failed to encrypt record payload"))]
+    EncryptionFailed,
+    #[snafu(display("encrypted record envelope is invalid: {}", reason))]
+    InvalidEnvelope { reason: String },
+    #[snafu(display("none of the configured keys could decrypt the record"))]
+    NoMatchingKey,
+    #[snafu(display("record is encrypted, but no encryption key is configured"))]
+    NotConfigured,
+}
+
+/// Authenticated encryption algorithm used for encrypting records.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptionAlgorithm {
+    /// AES-256 in Galois/Counter Mode.
+    Aes256Gcm,
+
+    /// The `ChaCha20` stream cipher with a `Poly1305` authenticator.
+    ChaCha20Poly1305,
+}
+
+impl Default for EncryptionAlgorithm {
+    fn default() -> Self {
+        EncryptionAlgorithm::Aes256Gcm
+    }
+}
+
+impl EncryptionAlgorithm {
+    fn as_envelope_id(self) -> u8 {
+        match self {
+            EncryptionAlgorithm::Aes256Gcm => 1,
+            EncryptionAlgorithm::ChaCha20Poly1305 => 2,
+        }
+    }
+
+    fn from_envelope_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(EncryptionAlgorithm::Aes256Gcm),
+            2 => Some(EncryptionAlgorithm::ChaCha20Poly1305),
+            _ => None,
+        }
+    }
+}
+
+/// At-rest encryption configuration for disk buffers.
+///
+/// Keys are 32 bytes long, and encoded as base64. The current key is used to encrypt new records,
+/// while previous keys are only used to decrypt records written before the key was rotated.
+#[configurable_component]
+#[derive(Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EncryptionConfig {
+    /// Algorithm used to encrypt new records.
+    #[serde(default)]
+    pub algorithm: EncryptionAlgorithm,
+
+    /// The current key.
+    ///
+    /// Can be fetched from a secret backend with `SECRET[backend.name]`. Never serialized back out.
+    #[serde(default, skip_serializing)]
+    pub key: Option<String>,
+
+    /// Path to a file containing the current key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_file: Option<PathBuf>,
+
+    /// Keys that were previously used to encrypt records. Never serialized back out.
+    #[serde(default, skip_serializing)]
+    pub previous_keys: Vec<String>,
+
+    /// Paths to files containing keys that were previously used to encrypt records.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub previous_key_files: Vec<PathBuf>,
+}
+
+// Keys must never end up in logs, so we only show where they come from.
+impl fmt::Debug for EncryptionConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptionConfig")
+            .field("algorithm", &self.algorithm)
+            .field("key", &self.key.as_ref().map(|_| "**REDACTED**"))
+            .field("key_file", &self.key_file)
+            .field("previous_keys", &self.previous_keys.len())
+            .field("previous_key_files", &self.previous_key_files)
+            .finish()
+    }
+}
+
+/// A key, ready to be used with any of the supported algorithms.
+struct Key {
+    aes_256_gcm: Aes256Gcm,
+    chacha20_poly1305: ChaCha20Poly1305,
+}
+
+impl Key {
+    fn from_base64(encoded: &str) -> Result<Self, EncryptionKeyError> {
+        let bytes = base64::decode(encoded.trim()).context(KeyEncodingSnafu)?;
+        if bytes.len() != KEY_LEN {
+            return Err(EncryptionKeyError::KeyLength { len: bytes.len() });
+        }
+
+        Ok(Self {
+            aes_256_gcm: Aes256Gcm::new(GenericArray::from_slice(&bytes)),
+            chacha20_poly1305: ChaCha20Poly1305::new(GenericArray::from_slice(&bytes)),
+        })
+    }
+
+    fn from_file(path: &Path) -> Result<Self, EncryptionKeyError> {
+        let encoded = fs::read_to_string(path).context(KeyFileSnafu { path })?;
+        Self::from_base64(&encoded)
+    }
+
+    fn encrypt(
+        &self,
+        algorithm: EncryptionAlgorithm,
+        nonce: &[u8],
+        aad: &[u8],
+        buf: &mut [u8],
+    ) -> Result<Vec<u8>, aes_gcm::aead::Error> {
+        let nonce = GenericArray::from_slice(nonce);
+        match algorithm {
+            EncryptionAlgorithm::Aes256Gcm => self
+                .aes_256_gcm
+                .encrypt_in_place_detached(nonce, aad, buf)
+                .map(|tag| tag.to_vec()),
+            EncryptionAlgorithm::ChaCha20Poly1305 => self
+                .chacha20_poly1305
+                .encrypt_in_place_detached(nonce, aad, buf)
+                .map(|tag| tag.to_vec()),
+        }
+    }
+
+    fn decrypt(
+        &self,
+        algorithm: EncryptionAlgorithm,
+        nonce: &[u8],
+        aad: &[u8],
+        buf: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), aes_gcm::aead::Error> {
+        let nonce = GenericArray::from_slice(nonce);
+        let tag = GenericArray::from_slice(tag);
+        match algorithm {
+            EncryptionAlgorithm::Aes256Gcm => self
+                .aes_256_gcm
+                .decrypt_in_place_detached(nonce, aad, buf, tag),
+            EncryptionAlgorithm::ChaCha20Poly1305 => self
+                .chacha20_poly1305
+                .decrypt_in_place_detached(nonce, aad, buf, tag),
+        }
+    }
+}
+
+/// Encrypts and decrypts record payloads.
+///
+/// Payloads are wrapped in an envelope made of a version byte, an algorithm byte, a random nonce, the ciphertext, and
+/// the authentication tag. The record ID and metadata are authenticated alongside the payload, so an encrypted payload
+/// cannot be swapped with the payload of another record without being detected.
+///
+/// As the envelope is what ends up in the record, the record checksum is calculated over the ciphertext, and checksum
+/// validation works the same whether or not a record is encrypted.
+pub struct RecordCipher {
+    algorithm: EncryptionAlgorithm,
+    // The current key comes first, followed by the previous keys.
+    keys: Vec<Key>,
+}
+
+impl RecordCipher {
+    /// Creates a [`RecordCipher`] from the given configuration, loading all of the configured keys.
+    ///
+    /// # Errors
+    ///
+    /// If the current key is missing, or if any key cannot be read or is invalid, an error variant will be returned
+    /// describing the error.
+    pub fn from_config(config: &EncryptionConfig) -> Result<Self, EncryptionKeyError> {
+        let current = match (&config.key, &config.key_file) {
+            (Some(key), None) => Key::from_base64(key)?,
+            (None, Some(path)) => Key::from_file(path)?,
+            _ => return Err(EncryptionKeyError::MissingKey),
+        };
+
+        let mut keys = vec![current];
+        for key in &config.previous_keys {
+            keys.push(Key::from_base64(key)?);
+        }
+        for path in &config.previous_key_files {
+            keys.push(Key::from_file(path)?);
+        }
+
+        Ok(Self {
+            algorithm: config.algorithm,
+            keys,
+        })
+    }
+
+    /// Encrypts `payload` with the current key, writing the resulting envelope into `dst`.
+    pub(super) fn encrypt(
+        &self,
+        id: u64,
+        metadata: u32,
+        payload: &[u8],
+        dst: &mut Vec<u8>,
+    ) -> Result<(), EncryptionError> {
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+
+        dst.clear();
+        dst.reserve(payload.len() + ENVELOPE_OVERHEAD);
+        dst.push(ENVELOPE_VERSION);
+        dst.push(self.algorithm.as_envelope_id());
+        dst.extend_from_slice(&nonce);
+        dst.extend_from_slice(payload);
+
+        let tag = self.keys[0]
+            .encrypt(
+                self.algorithm,
+                &nonce,
+                &associated_data(id, metadata),
+                &mut dst[ENVELOPE_HEADER_LEN..],
+            )
+            .map_err(|_| EncryptionError::EncryptionFailed)?;
+        dst.extend_from_slice(&tag);
+
+        Ok(())
+    }
+
+    /// Decrypts the given envelope, trying the current key first and then the previous keys.
+    pub(super) fn decrypt(
+        &self,
+        id: u64,
+        metadata: u32,
+        envelope: &[u8],
+    ) -> Result<Vec<u8>, EncryptionError> {
+        if envelope.len() < ENVELOPE_OVERHEAD {
+            return Err(EncryptionError::InvalidEnvelope {
+                reason: format!("envelope too short ({} bytes)", envelope.len()),
+            });
+        }
+        if envelope[0] != ENVELOPE_VERSION {
+            return Err(EncryptionError::InvalidEnvelope {
+                reason: format!("unknown envelope version {}", envelope[0]),
+            });
+        }
+        let algorithm = EncryptionAlgorithm::from_envelope_id(envelope[1]).ok_or_else(|| {
+            EncryptionError::InvalidEnvelope {
+                reason: format!("unknown algorithm {}", envelope[1]),
+            }
+        })?;
+
+        let nonce = &envelope[2..ENVELOPE_HEADER_LEN];
+        let (ciphertext, tag) =
+            envelope[ENVELOPE_HEADER_LEN..].split_at(envelope.len() - ENVELOPE_OVERHEAD);
+        let aad = associated_data(id, metadata);
+
+        // Decryption happens in place, so each attempt starts from a fresh copy of the ciphertext.
+        let mut buf = Vec::with_capacity(ciphertext.len());
+        for key in &self.keys {
+            buf.clear();
+            buf.extend_from_slice(ciphertext);
+            if key.decrypt(algorithm, nonce, &aad, &mut buf, tag).is_ok() {
+                return Ok(buf);
+            }
+        }
+
+        Err(EncryptionError::NoMatchingKey)
+    }
+}
+
+impl fmt::Debug for RecordCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordCipher")
+            .field("algorithm", &self.algorithm)
+            .field("keys", &self.keys.len())
+            .finish()
+    }
+}
+
+fn associated_data(id: u64, metadata: u32) -> [u8; 12] {
+    let mut aad = [0u8; 12];
+    aad[..8].copy_from_slice(&id.to_be_bytes());
+    aad[8..].copy_from_slice(&metadata.to_be_bytes());
+    aad
+}
+
+/// Gets the payload of a record in its decrypted form.
+///
+/// Records without the encrypted flag are returned as-is, regardless of whether or not a cipher is configured.
+pub(super) fn decrypt_payload<'a>(
+    cipher: Option<&RecordCipher>,
+    id: u64,
+    metadata: u32,
+    payload: &'a [u8],
+) -> Result<Cow<'a, [u8]>, EncryptionError> {
+    if metadata & ENCRYPTED_RECORD_FLAG == 0 {
+        return Ok(payload.into());
+    }
+
+    let cipher = cipher.ok_or(EncryptionError::NotConfigured)?;
+    cipher.decrypt(id, metadata, payload).map(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(algorithm: EncryptionAlgorithm, key: u8, previous: &[u8]) -> EncryptionConfig {
+        EncryptionConfig {
+            algorithm,
+            key: Some(base64::encode([key; KEY_LEN])),
+            key_file: None,
+            previous_keys: previous
+                .iter()
+                .map(|key| base64::encode([*key; KEY_LEN]))
+                .collect(),
+            previous_key_files: Vec::new(),
+        }
+    }
+
+    fn encrypt(cipher: &RecordCipher, id: u64, payload: &[u8]) -> Vec<u8> {
+        let mut envelope = Vec::new();
+        cipher
+            .encrypt(id, ENCRYPTED_RECORD_FLAG, payload, &mut envelope)
+            .expect("encryption should not fail");
+        envelope
+    }
+
+    #[test]
+    fn roundtrip() {
+        for algorithm in [
+            EncryptionAlgorithm::Aes256Gcm,
+            EncryptionAlgorithm::ChaCha20Poly1305,
+        ] {
+            let cipher = RecordCipher::from_config(&config(algorithm, 1, &[])).unwrap();
+            let envelope = encrypt(&cipher, 42, b"hello world");
+
+            assert_eq!(envelope.len(), 11 + ENVELOPE_OVERHEAD);
+            assert!(!envelope.windows(11).any(|w| w == b"hello world"));
+            assert_eq!(
+                cipher
+                    .decrypt(42, ENCRYPTED_RECORD_FLAG, &envelope)
+                    .unwrap(),
+                b"hello world"
+            );
+        }
+    }
+
+    #[test]
+    fn keys_are_not_serialized() {
+        let serialized =
+            serde_yaml::to_string(&config(EncryptionAlgorithm::Aes256Gcm, 1, &[2])).unwrap();
+        assert!(!serialized.contains(&base64::encode([1; KEY_LEN])));
+        assert!(!serialized.contains(&base64::encode([2; KEY_LEN])));
+        assert!(serialized.contains("aes256_gcm"));
+    }
+
+    #[test]
+    fn decrypts_with_previous_keys() {
+        let old =
+            RecordCipher::from_config(&config(EncryptionAlgorithm::Aes256Gcm, 1, &[])).unwrap();
+        let envelope = encrypt(&old, 7, b"written before rotation");
+
+        let rotated =
+            RecordCipher::from_config(&config(EncryptionAlgorithm::ChaCha20Poly1305, 2, &[1]))
+                .unwrap();
+        assert_eq!(
+            rotated
+                .decrypt(7, ENCRYPTED_RECORD_FLAG, &envelope)
+                .unwrap(),
+            b"written before rotation"
+        );
+
+        let forgotten =
+            RecordCipher::from_config(&config(EncryptionAlgorithm::Aes256Gcm, 2, &[])).unwrap();
+        assert!(matches!(
+            forgotten.decrypt(7, ENCRYPTED_RECORD_FLAG, &envelope),
+            Err(EncryptionError::NoMatchingKey)
+        ));
+    }
+
+    #[test]
+    fn rejects_payloads_of_other_records() {
+        let cipher =
+            RecordCipher::from_config(&config(EncryptionAlgorithm::Aes256Gcm, 1, &[])).unwrap();
+        let envelope = encrypt(&cipher, 7, b"payload");
+
+        assert!(cipher.decrypt(8, ENCRYPTED_RECORD_FLAG, &envelope).is_err());
+    }
+
+    #[test]
+    fn passes_through_unencrypted_payloads() {
+        let payload = decrypt_payload(None, 1, 0, b"plaintext").unwrap();
+        assert_eq!(&payload[..], b"plaintext");
+
+        assert!(matches!(
+            decrypt_payload(None, 1, ENCRYPTED_RECORD_FLAG, b"ciphertext"),
+            Err(EncryptionError::NotConfigured)
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        let mut invalid = config(EncryptionAlgorithm::Aes256Gcm, 1, &[]);
+        invalid.key = Some(base64::encode([1; 16]));
+        assert!(matches!(
+            RecordCipher::from_config(&invalid),
+            Err(EncryptionKeyError::KeyLength { len: 16 })
+        ));
+
+        invalid.key_file = Some(PathBuf::from("/path/to/key"));
+        assert!(matches!(
+            RecordCipher::from_config(&invalid),
+            Err(EncryptionKeyError::MissingKey)
+        ));
+    }
+}
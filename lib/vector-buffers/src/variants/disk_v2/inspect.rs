@@ -0,0 +1,144 @@
+use std::{future::Future, io, path::Path, sync::Arc};
+
+use rkyv::AlignedVec;
+use snafu::ResultExt;
+use tokio::fs;
+
+use super::{
+    common::MAX_FILE_ID,
+    encryption::RecordCipher,
+    ledger::{get_data_file_path, LedgerState, LEDGER_FILE_NAME},
+    reader::RecordReader,
+    ser::try_as_archive,
+};
+use crate::{
+    inspect::{
+        BufferKind, BufferReport, Corruption, DataFileReport, InspectError, IoSnafu, LedgerReport,
+    },
+    Bufferable,
+};
+
+/// Reads the ledger of the buffer stored in `data_dir`, without locking or modifying it.
+async fn read_ledger(data_dir: &Path) -> Result<LedgerReport, InspectError> {
+    let path = data_dir.join(LEDGER_FILE_NAME);
+    let contents = fs::read(&path).await.context(IoSnafu { path })?;
+    if contents.is_empty() {
+        return Err(InspectError::Ledger {
+            reason: "ledger file is empty".to_string(),
+        });
+    }
+
+    // The ledger is normally accessed through a memory-mapped file, which is always suitably
+    // aligned, so we have to make sure our copy is as well.
+    let mut buf = AlignedVec::with_capacity(contents.len());
+    buf.extend_from_slice(&contents);
+    let state = try_as_archive::<LedgerState>(&buf).map_err(|e| InspectError::Ledger {
+        reason: e.into_inner(),
+    })?;
+
+    Ok(LedgerReport {
+        writer_next_record_id: state.get_next_writer_record_id(),
+        writer_current_data_file_id: state.get_current_writer_file_id(),
+        reader_current_data_file_id: state.get_current_reader_file_id(),
+        reader_last_record_id: state.get_last_reader_record_id(),
+    })
+}
+
+/// Reads all unread records from the `disk_v2` buffer stored in `data_dir`.
+///
+/// Data files are read from the one the reader is on, up to the one the writer is on.  Records
+/// which were acknowledged, but whose data file hasn't been deleted yet, are counted but skipped.
+pub(crate) async fn inspect<T, F, Fut>(
+    data_dir: &Path,
+    cipher: Option<Arc<RecordCipher>>,
+    mut visit: F,
+) -> Result<BufferReport, InspectError>
+where
+    T: Bufferable,
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let ledger = read_ledger(data_dir).await?;
+    let mut report = BufferReport::new(BufferKind::DiskV2);
+    report.ledger = Some(ledger);
+
+    let mut file_id = ledger.reader_current_data_file_id;
+    loop {
+        let path = get_data_file_path(data_dir, file_id);
+        match fs::File::open(&path).await {
+            Ok(file) => {
+                let size = file
+                    .metadata()
+                    .await
+                    .context(IoSnafu { path: path.clone() })?
+                    .len();
+                let mut data_file = DataFileReport {
+                    file_id,
+                    path,
+                    size,
+                    records: 0,
+                    events: 0,
+                    acknowledged_records: 0,
+                };
+
+                let mut reader = RecordReader::<_, T>::new(file, cipher.clone());
+                loop {
+                    // The writer may be in the middle of writing to the data file if Vector is
+                    // running, in which case the last record would show up as a partial write.
+                    let token = match reader.try_next_record(true).await {
+                        Ok(Some(token)) => token,
+                        Ok(None) => break,
+                        Err(e) => {
+                            // We can't tell where the next record starts, so the rest of the data
+                            // file is unreadable.
+                            report.corruptions.push(Corruption {
+                                location: format!("{}", data_file.path.display()),
+                                reason: e.to_string(),
+                            });
+                            break;
+                        }
+                    };
+
+                    let record_id = token.record_id();
+                    let record_bytes = token.record_bytes() as u64;
+                    if record_id <= ledger.reader_last_record_id {
+                        data_file.acknowledged_records += 1;
+                        continue;
+                    }
+
+                    match reader.read_record(token) {
+                        Ok(record) => {
+                            let events = record.event_count() as u64;
+                            data_file.records += 1;
+                            data_file.events += events;
+                            report.records += 1;
+                            report.events += events;
+                            report.bytes += record_bytes;
+                            visit(record).await;
+                        }
+                        Err(e) => report.corruptions.push(Corruption {
+                            location: format!(
+                                "{} (record {})",
+                                data_file.path.display(),
+                                record_id
+                            ),
+                            reason: e.to_string(),
+                        }),
+                    }
+                }
+
+                report.data_files.push(data_file);
+            }
+            // Data files are deleted once fully acknowledged, and only created when first written to.
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(source) => return Err(InspectError::Io { path, source }),
+        }
+
+        if file_id == ledger.writer_current_data_file_id {
+            break;
+        }
+        file_id = (file_id + 1) % MAX_FILE_ID;
+    }
+
+    Ok(report)
+}
@@ -1,6 +1,6 @@
 use std::{
     fmt, io, mem,
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering},
     sync::Arc,
     time::Instant,
@@ -13,7 +13,11 @@ use fslock::LockFile;
 use futures::StreamExt;
 use rkyv::{with::Atomic, Archive, Serialize};
 use snafu::{ResultExt, Snafu};
-use tokio::{fs, io::AsyncWriteExt, sync::Notify};
+use tokio::{
+    fs,
+    io::AsyncWriteExt,
+    sync::{Mutex, MutexGuard, Notify},
+};
 use vector_common::{finalizer::OrderedFinalizer, shutdown::ShutdownSignal};
 
 use super::{
@@ -27,6 +31,14 @@ use crate::buffer_usage_data::BufferUsageHandle;
 
 pub const LEDGER_LEN: usize = align16(mem::size_of::<ArchivedLedgerState>());
 
+/// Name of the ledger file within the buffer data directory.
+pub(super) const LEDGER_FILE_NAME: &str = "buffer.db";
+
+/// Gets the path of the data file with the given ID within the buffer data directory.
+pub(super) fn get_data_file_path(data_dir: &Path, file_id: u16) -> PathBuf {
+    data_dir.join(format!("buffer-data-{}.dat", file_id))
+}
+
 /// Error that occurred during calls to [`Ledger`].
 #[derive(Debug, Snafu)]
 pub enum LedgerLoadCreateError {
@@ -119,7 +131,7 @@ impl Default for LedgerState {
 }
 
 impl ArchivedLedgerState {
-    fn get_current_writer_file_id(&self) -> u16 {
+    pub(super) fn get_current_writer_file_id(&self) -> u16 {
         self.writer_current_data_file_id.load(Ordering::Acquire)
     }
 
@@ -143,7 +155,7 @@ impl ArchivedLedgerState {
         previous.wrapping_add(amount)
     }
 
-    fn get_current_reader_file_id(&self) -> u16 {
+    pub(super) fn get_current_reader_file_id(&self) -> u16 {
         self.reader_current_data_file_id.load(Ordering::Acquire)
     }
 
@@ -228,6 +240,10 @@ where
     pending_acks: AtomicU64,
     // The file ID offset of the reader past the acknowledged reader file ID.
     unacked_reader_file_id_offset: AtomicU16,
+    // Lock held while the reader opens, or the writer evicts, a data file.
+    data_files_lock: Mutex<()>,
+    // Number of events in evicted data files that the reader has yet to skip over.
+    evicted_events: AtomicU64,
     // Last flush of all unflushed files: ledger, data file, etc.
     last_flush: AtomicCell<Instant>,
     // Tracks usage data about the buffer.
@@ -350,9 +366,7 @@ where
 
     /// Gets the data file path for an arbitrary file ID.
     pub fn get_data_file_path(&self, file_id: u16) -> PathBuf {
-        self.config
-            .data_dir
-            .join(format!("buffer-data-{}.dat", file_id))
+        get_data_file_path(&self.config.data_dir, file_id)
     }
 
     /// Waits for a signal from the reader that progress has been made.
@@ -398,6 +412,42 @@ where
             .increment_sent_event_count_and_byte_size(event_count, total_record_size);
     }
 
+    /// Tracks the statistics of multiple records discarded for being older than the maximum age.
+    pub fn track_expired(&self, event_count: u64, total_record_size: u64) {
+        self.decrement_total_buffer_size(total_record_size);
+        self.usage_handle
+            .increment_expired_event_count_and_byte_size(event_count, total_record_size);
+    }
+
+    /// Tracks the statistics of a data file evicted by the writer to make room for newer records.
+    ///
+    /// The events in the data file are remembered, so that the reader doesn't mistake them for lost
+    /// events when it skips over them.
+    pub fn track_evicted_data_file(&self, event_count: u64, data_file_size: u64) {
+        self.decrement_total_buffer_size(data_file_size);
+        self.evicted_events.fetch_add(event_count, Ordering::AcqRel);
+        self.usage_handle
+            .increment_evicted_event_count_and_byte_size(event_count, data_file_size);
+    }
+
+    /// Consumes up to `skipped` events from evicted data files, returning how many of the skipped
+    /// events were not evicted.
+    pub fn consume_evicted_events(&self, skipped: u64) -> u64 {
+        let previous = self
+            .evicted_events
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                Some(n.saturating_sub(skipped))
+            })
+            .unwrap_or_else(|previous| previous);
+        skipped.saturating_sub(previous)
+    }
+
+    /// Locks the data files, preventing them from being opened by the reader, or evicted by the
+    /// writer, until the returned guard is dropped.
+    pub async fn lock_data_files(&self) -> MutexGuard<'_, ()> {
+        self.data_files_lock.lock().await
+    }
+
     /// Marks the writer as finished.
     ///
     /// If the writer was not yet marked done, `false` is returned.  Otherwise, `true` is returned,
@@ -582,7 +632,7 @@ where
         }
 
         // Open the ledger file, which may involve creating it if it doesn't yet exist.
-        let ledger_path = config.data_dir.join("buffer.db");
+        let ledger_path = config.data_dir.join(LEDGER_FILE_NAME);
         let mut ledger_handle = config
             .filesystem
             .open_file_writable(&ledger_path)
@@ -648,6 +698,8 @@ where
             writer_done: AtomicBool::new(false),
             pending_acks: AtomicU64::new(0),
             unacked_reader_file_id_offset: AtomicU16::new(0),
+            data_files_lock: Mutex::new(()),
+            evicted_events: AtomicU64::new(0),
             last_flush: AtomicCell::new(Instant::now()),
             usage_handle,
         };
@@ -733,6 +785,10 @@ where
                 "unacked_reader_file_id_offset",
                 &self.unacked_reader_file_id_offset.load(Ordering::Acquire),
             )
+            .field(
+                "evicted_events",
+                &self.evicted_events.load(Ordering::Acquire),
+            )
             .field("writer_done", &self.writer_done.load(Ordering::Acquire))
             .field("last_flush", &self.last_flush.load())
             .finish()
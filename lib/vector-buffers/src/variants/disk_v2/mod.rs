@@ -142,6 +142,7 @@ use std::{
     num::NonZeroU64,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 
 use async_trait::async_trait;
@@ -150,19 +151,28 @@ use vector_common::finalization::Finalizable;
 
 mod backed_archive;
 mod common;
+mod compression;
+mod encryption;
+mod inspect;
 mod io;
 mod ledger;
 mod reader;
 mod record;
 mod ser;
+mod timestamp;
 mod v1_migration;
 mod writer;
 
 #[cfg(test)]
 mod tests;
 
+pub(crate) use self::inspect::inspect;
 pub use self::{
     common::{DiskBufferConfig, DiskBufferConfigBuilder},
+    compression::{CompressionAlgorithm, CompressionError},
+    encryption::{
+        EncryptionAlgorithm, EncryptionConfig, EncryptionError, EncryptionKeyError, RecordCipher,
+    },
     io::{Filesystem, ProductionFilesystem},
     ledger::LedgerLoadCreateError,
     reader::{Reader, ReaderError},
@@ -268,14 +278,27 @@ pub struct DiskV2Buffer {
     id: String,
     data_dir: PathBuf,
     max_size: NonZeroU64,
+    cipher: Option<Arc<RecordCipher>>,
+    compression: Option<CompressionAlgorithm>,
+    max_age: Option<Duration>,
 }
 
 impl DiskV2Buffer {
-    pub fn new(id: String, data_dir: PathBuf, max_size: NonZeroU64) -> Self {
+    pub fn new(
+        id: String,
+        data_dir: PathBuf,
+        max_size: NonZeroU64,
+        cipher: Option<RecordCipher>,
+        compression: Option<CompressionAlgorithm>,
+        max_age: Option<Duration>,
+    ) -> Self {
         Self {
             id,
             data_dir,
             max_size,
+            cipher: cipher.map(Arc::new),
+            compression,
+            max_age,
         }
     }
 }
@@ -295,7 +318,13 @@ where
     ) -> Result<(SenderAdapter<T>, ReceiverAdapter<T>), Box<dyn Error + Send + Sync>> {
         // Attempt to migrate a disk v1 buffer based on the same data directory and buffer ID if one
         // exists. If one doesn't exist, then this method does nothing.
-        try_disk_v1_migration::<T>(self.data_dir.as_path(), self.id.as_str()).await?;
+        try_disk_v1_migration::<T>(
+            self.data_dir.as_path(),
+            self.id.as_str(),
+            self.cipher.clone(),
+            self.compression,
+        )
+        .await?;
 
         // Now that we've handled any necessary migrations, go ahead and build the buffer.
         let (writer, reader) = build_disk_v2_buffer(
@@ -303,6 +332,9 @@ where
             &self.data_dir,
             self.id.as_str(),
             self.max_size,
+            self.cipher,
+            self.compression,
+            self.max_age,
         )
         .await?;
 
@@ -315,6 +347,9 @@ async fn build_disk_v2_buffer<T>(
     data_dir: &Path,
     id: &str,
     max_size: NonZeroU64,
+    cipher: Option<Arc<RecordCipher>>,
+    compression: Option<CompressionAlgorithm>,
+    max_age: Option<Duration>,
 ) -> Result<
     (
         Writer<T, ProductionFilesystem>,
@@ -328,9 +363,18 @@ where
     usage_handle.set_buffer_limits(Some(max_size.get()), None);
 
     let buffer_path = get_disk_v2_data_dir_path(data_dir, id);
-    let config = DiskBufferConfigBuilder::from_path(buffer_path)
-        .max_buffer_size(max_size.get())
-        .build()?;
+    let mut builder =
+        DiskBufferConfigBuilder::from_path(buffer_path).max_buffer_size(max_size.get());
+    if let Some(cipher) = cipher {
+        builder = builder.encryption(cipher);
+    }
+    if let Some(algorithm) = compression {
+        builder = builder.compression(algorithm);
+    }
+    if let Some(max_age) = max_age {
+        builder = builder.max_age(max_age);
+    }
+    let config = builder.build()?;
     Buffer::from_config(config, usage_handle)
         .await
         .map_err(Into::into)
@@ -5,6 +5,7 @@ use std::{
     num::NonZeroU64,
     path::PathBuf,
     sync::Arc,
+    time::SystemTime,
 };
 
 use crc32fast::Hasher;
@@ -15,8 +16,13 @@ use vector_common::{finalization::BatchNotifier, finalizer::OrderedFinalizer};
 
 use super::{
     common::create_crc32c_hasher,
+    compression::{decompress_payload, CompressionError},
+    encryption::{decrypt_payload, EncryptionError, RecordCipher},
     ledger::Ledger,
-    record::{validate_record_archive, ArchivedRecord, Record, RecordStatus},
+    record::{
+        validate_record_archive, ArchivedRecord, Record, RecordStatus, RECORD_METADATA_FLAGS,
+    },
+    timestamp::{is_expired, strip_timestamp},
     Filesystem,
 };
 use crate::{
@@ -108,6 +114,21 @@ where
     #[snafu(display("record version not compatible: {}", reason))]
     Incompatible { reason: String },
 
+    /// The record could not be decrypted.
+    ///
+    /// This occurs when the record was encrypted with a key that is no longer configured, or when
+    /// the buffer is no longer configured to encrypt records at all.  As the checksum is validated
+    /// before decryption, this does not indicate corruption of the data file itself.
+    #[snafu(display("failed to decrypt record: {}", source))]
+    Decryption { source: EncryptionError },
+
+    /// The record could not be decompressed.
+    ///
+    /// As the checksum is validated before decompression, this indicates that the record was written
+    /// with a compression scheme that this version of Vector does not support.
+    #[snafu(display("failed to decompress record: {}", source))]
+    Decompression { source: CompressionError },
+
     /// The reader detected that a data file contains a partially-written record.
     ///
     /// Records should never be partially written to a data file (we don't split records across data
@@ -145,6 +166,8 @@ where
             ReaderError::Checksum { .. } => "checksum_mismatch",
             ReaderError::Decode { .. } => "decode_failed",
             ReaderError::Incompatible { .. } => "incompatible_record_version",
+            ReaderError::Decryption { .. } => "decryption_failed",
+            ReaderError::Decompression { .. } => "decompression_failed",
             ReaderError::PartialWrite => "partial_write",
             ReaderError::EmptyRecord => "empty_record",
         }
@@ -160,6 +183,8 @@ where
             | ReaderError::Checksum { .. }
             | ReaderError::Decode { .. }
             | ReaderError::Incompatible { .. }
+            | ReaderError::Decryption { .. }
+            | ReaderError::Decompression { .. }
             | ReaderError::PartialWrite => Some(BufferReadError { error_code, error }),
         }
     }
@@ -199,6 +224,7 @@ pub(super) struct RecordReader<R, T> {
     reader: BufReader<R>,
     aligned_buf: AlignedVec,
     checksummer: Hasher,
+    cipher: Option<Arc<RecordCipher>>,
     current_record_id: u64,
     _t: PhantomData<T>,
 }
@@ -212,11 +238,14 @@ where
     ///
     /// Internally, the reader is wrapped in a [`BufReader`], so callers should not pass in an
     /// already buffered reader.
-    pub fn new(reader: R) -> Self {
+    ///
+    /// If a cipher is given, it is used to decrypt encrypted records.
+    pub fn new(reader: R, cipher: Option<Arc<RecordCipher>>) -> Self {
         Self {
             reader: BufReader::with_capacity(256 * 1024, reader),
             aligned_buf: AlignedVec::new(),
             checksummer: create_crc32c_hasher(),
+            cipher,
             current_record_id: 0,
             _t: PhantomData,
         }
@@ -364,6 +393,26 @@ where
     /// `try_next_record`, and the `ReadToken` from _that_ call is used, this method will panic due
     /// to an out-of-order read.
     pub fn read_record(&mut self, token: ReadToken) -> Result<T, ReaderError<T>> {
+        self.read_timestamped_record(token)
+            .map(|(record, _)| record)
+    }
+
+    /// Reads the record associated with the given [`ReadToken`], along with the time it was
+    /// written at, if the record was timestamped.
+    ///
+    /// # Errors
+    ///
+    /// If an error occurs during decoding, an error variant will be returned describing the error.
+    ///
+    /// # Panics
+    ///
+    /// If a `ReadToken` is not used in a call to `read_timestamped_record` before again calling
+    /// `try_next_record`, and the `ReadToken` from _that_ call is used, this method will panic due
+    /// to an out-of-order read.
+    pub fn read_timestamped_record(
+        &mut self,
+        token: ReadToken,
+    ) -> Result<(T, Option<SystemTime>), ReaderError<T>> {
         let record_id = token.into_record_id();
         assert_eq!(
             self.current_record_id, record_id,
@@ -376,7 +425,7 @@ where
         // - `try_next_record` does all the archive checks, checksum validation, etc
         let record = unsafe { archived_root::<Record<'_>>(&self.aligned_buf) };
 
-        decode_record_payload(record)
+        decode_timestamped_record_payload(record, self.cipher.as_deref())
     }
 }
 
@@ -389,11 +438,22 @@ where
             .field("reader", &self.reader)
             .field("aligned_buf", &self.aligned_buf)
             .field("checksummer", &self.checksummer)
+            .field("cipher", &self.cipher)
             .field("current_record_id", &self.current_record_id)
             .finish()
     }
 }
 
+/// Data tracked for each record read by the [`Reader`] until it is acknowledged.
+#[derive(Clone, Copy, Debug)]
+struct RecordAckData {
+    /// Size of the record, in bytes.
+    bytes: u64,
+
+    /// Whether or not the record was discarded for being older than the maximum age.
+    expired: bool,
+}
+
 /// Reads records from the buffer.
 #[derive(Debug)]
 pub struct Reader<T, FS>
@@ -408,7 +468,7 @@ where
     data_file_record_count: u64,
     data_file_marked_record_count: u64,
     ready_to_read: bool,
-    record_acks: OrderedAcknowledgements<u64, u64>,
+    record_acks: OrderedAcknowledgements<u64, RecordAckData>,
     data_file_acks: OrderedAcknowledgements<u64, (PathBuf, u64)>,
     finalizer: OrderedFinalizer<u64>,
     _t: PhantomData<T>,
@@ -447,7 +507,13 @@ where
         self.data_file_start_record_id = None;
     }
 
-    fn track_read(&mut self, record_id: u64, record_bytes: u64, event_count: NonZeroU64) {
+    fn track_read(
+        &mut self,
+        record_id: u64,
+        record_bytes: u64,
+        event_count: NonZeroU64,
+        expired: bool,
+    ) {
         // We explicitly reduce the event count by one here in order to correctly calculate the
         // "last" record ID, which you can visualize as follows...
         //
@@ -475,9 +541,13 @@ where
         // We've done a "real" record read, so we need to track it for acknowledgement.  Check our
         // acknowledge state first to see if this is the next record ID we expected.
         self.data_file_record_count += 1;
+        let ack_data = RecordAckData {
+            bytes: record_bytes,
+            expired,
+        };
         if let Err(me) =
             self.record_acks
-                .add_marker(record_id, Some(event_count.get()), Some(record_bytes))
+                .add_marker(record_id, Some(event_count.get()), Some(ack_data))
         {
             match me {
                 MarkerError::MonotonicityViolation => {
@@ -513,40 +583,57 @@ where
         // occur at all, so we're relying on this method to correct the buffer size for us.  This is
         // why `bytes_read` is optional: when it's specified, we calculate a delta for handling
         // partial-read scenarios, otherwise, we just use the entire data file size as is.
-        let data_file = self
+        //
+        // The data file may also already be gone, if the writer evicted it to make room for newer
+        // records, in which case it already took care of the buffer size, and all that's left for
+        // us to do is to move past it.
+        match self
             .ledger
             .filesystem()
             .open_file_readable(&data_file_path)
-            .await?;
-        let metadata = data_file.metadata().await?;
-
-        let decrease_amount = bytes_read.map_or_else(
-            || metadata.len(),
-            |bytes_read| {
-                let size_delta = metadata.len() - bytes_read;
-                if size_delta > 0 {
-                    debug!(
-                        actual_file_size = metadata.len(),
-                        bytes_read,
-                        "Data file was only partially read. Adjusting buffer size to compensate.",
-                    );
+            .await
+        {
+            Ok(data_file) => {
+                let metadata = data_file.metadata().await?;
+
+                let decrease_amount = bytes_read.map_or_else(
+                    || metadata.len(),
+                    |bytes_read| {
+                        let size_delta = metadata.len() - bytes_read;
+                        if size_delta > 0 {
+                            debug!(
+                                actual_file_size = metadata.len(),
+                                bytes_read,
+                                "Data file was only partially read. Adjusting buffer size to compensate.",
+                            );
+                        }
+
+                        size_delta
+                    },
+                );
+
+                if decrease_amount > 0 {
+                    self.ledger.decrement_total_buffer_size(decrease_amount);
                 }
 
-                size_delta
-            },
-        );
+                drop(data_file);
 
-        if decrease_amount > 0 {
-            self.ledger.decrement_total_buffer_size(decrease_amount);
+                // Delete the current data file.
+                self.ledger
+                    .filesystem()
+                    .delete_file(&data_file_path)
+                    .await?;
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                debug!(
+                    data_file_path = data_file_path.to_string_lossy().as_ref(),
+                    "Data file was already evicted."
+                );
+            }
+            Err(e) => return Err(e),
         }
 
-        drop(data_file);
-
-        // Delete the current data file, and increment our actual reader file ID.
-        self.ledger
-            .filesystem()
-            .delete_file(&data_file_path)
-            .await?;
+        // Increment our actual reader file ID.
         self.ledger.increment_acked_reader_file_id();
         self.ledger.flush()?;
 
@@ -580,6 +667,8 @@ where
         let mut events_acknowledged: u64 = 0;
         let mut events_skipped: u64 = 0;
         let mut bytes_acknowledged: u64 = 0;
+        let mut events_expired: u64 = 0;
+        let mut bytes_expired: u64 = 0;
 
         let consumed_acks = self.ledger.consume_pending_acks();
         if consumed_acks > 0 {
@@ -605,15 +694,26 @@ where
                     // We got a valid marker representing a known number of events.
                     EligibleMarkerLength::Known(len) => {
                         // We specifically pass the size of the record, in bytes, as the marker data.
-                        let record_bytes = data.expect("record bytes should always be known");
+                        let RecordAckData {
+                            bytes: record_bytes,
+                            expired,
+                        } = data.expect("record bytes should always be known");
 
                         records_acknowledged = records_acknowledged.checked_add(1).expect(
                             "acknowledging more than 2^64 records at a time is obviously a bug",
                         );
-                        events_acknowledged = events_acknowledged.checked_add(len).expect(
+
+                        // Records discarded for being too old are acknowledged like any other
+                        // record, but they were never actually handed out, so we track them separately.
+                        let (events, bytes) = if expired {
+                            (&mut events_expired, &mut bytes_expired)
+                        } else {
+                            (&mut events_acknowledged, &mut bytes_acknowledged)
+                        };
+                        *events = events.checked_add(len).expect(
                             "acknowledging more than 2^64 events at a time is obviously a bug",
                         );
-                        bytes_acknowledged = bytes_acknowledged.checked_add(record_bytes).expect(
+                        *bytes = bytes.checked_add(record_bytes).expect(
                             "acknowledging more than 2^64 bytes at a time is obviously a bug",
                         );
                     }
@@ -624,10 +724,13 @@ where
             if had_eligible_records {
                 self.ledger
                     .track_reads(events_acknowledged, bytes_acknowledged);
+                if events_expired > 0 {
+                    self.ledger.track_expired(events_expired, bytes_expired);
+                }
 
-                // We need to account for skipped events, too, so that our "last reader record ID"
-                // value stays correct as we process these gap markers.
-                let last_increment_amount = events_acknowledged + events_skipped;
+                // We need to account for skipped and expired events, too, so that our "last reader
+                // record ID" value stays correct as we process these gap markers.
+                let last_increment_amount = events_acknowledged + events_expired + events_skipped;
                 self.ledger
                     .state()
                     .increment_last_reader_record_id(last_increment_amount);
@@ -636,9 +739,12 @@ where
                     .add_acknowledgements(records_acknowledged);
             }
 
-            // If any events were skipped, do our logging/metrics for that.
-            if events_skipped > 0 {
-                self.ledger.track_dropped_events(events_skipped);
+            // If any events were skipped, do our logging/metrics for that.  Events in data files
+            // that were evicted by the writer show up as skipped, too, but the writer already
+            // tracked those, so only the remainder is actually lost.
+            let events_lost = self.ledger.consume_evicted_events(events_skipped);
+            if events_lost > 0 {
+                self.ledger.track_dropped_events(events_lost);
             }
         }
 
@@ -680,6 +786,7 @@ where
                     current_buffer_size = self.ledger.get_total_buffer_size(),
                     records_acknowledged,
                     events_acknowledged,
+                    events_expired,
                     events_skipped,
                     bytes_acknowledged,
                     data_files_deleted,
@@ -755,14 +862,19 @@ where
         // we'll simply wait for the writer to signal to us that progress has been made, which
         // implies a data file existing.
         loop {
+            // We hold the data file lock while opening the data file so that the writer can't evict
+            // it from under us once we've decided to read it.
+            let data_files_guard = self.ledger.lock_data_files().await;
             let (reader_file_id, writer_file_id) = self.ledger.get_current_reader_writer_file_id();
             let data_file_path = self.ledger.get_current_reader_data_file_path();
-            let data_file = match self
+            let result = self
                 .ledger
                 .filesystem()
                 .open_file_readable(&data_file_path)
-                .await
-            {
+                .await;
+            drop(data_files_guard);
+
+            let data_file = match result {
                 Ok(data_file) => data_file,
                 Err(e) => match e.kind() {
                     ErrorKind::NotFound => {
@@ -772,6 +884,16 @@ where
                                 "Data file does not yet exist. Waiting for writer to create."
                             );
                             self.ledger.wait_for_writer().await;
+                        } else if self.ready_to_read {
+                            // The writer evicted this data file to make room for newer records, so
+                            // we move on to the next one, going through the usual motions so that
+                            // the acknowledged reader file ID only moves past it once everything
+                            // before it has been acknowledged.
+                            debug!(
+                                data_file_path = data_file_path.to_string_lossy().as_ref(),
+                                "Data file was evicted. Skipping to next data file."
+                            );
+                            self.roll_to_next_data_file();
                         } else {
                             self.ledger.increment_acked_reader_file_id();
                         }
@@ -787,7 +909,10 @@ where
                 "Opened data file for reading."
             );
 
-            self.reader = Some(RecordReader::new(data_file));
+            self.reader = Some(RecordReader::new(
+                data_file,
+                self.ledger.config().cipher.clone(),
+            ));
             return Ok(());
         }
     }
@@ -852,7 +977,8 @@ where
                 } => {
                     let record = try_as_record_archive(data_file_mmap.as_ref())
                         .expect("record was already validated");
-                    let item = match decode_record_payload::<T>(record) {
+                    let cipher = self.ledger.config().cipher.as_deref();
+                    let item = match decode_record_payload::<T>(record, cipher) {
                         Ok(item) => item,
                         // If there's an error decoding the item, just fall back to the slow path,
                         // because this file might actually be where we left off, so we don't want
@@ -926,6 +1052,9 @@ where
     /// If the writer is closed and there is no more data in the buffer, `None` is returned.
     /// Otherwise, reads the next record or waits until the next record is available.
     ///
+    /// If the buffer has a maximum age configured, records older than it are discarded rather than
+    /// returned.
+    ///
     /// # Errors
     ///
     /// If an error occurred while reading a record, an error variant will be returned describing
@@ -934,180 +1063,218 @@ where
     pub async fn next(&mut self) -> Result<Option<T>, ReaderError<T>> {
         let mut force_check_pending_data_files = false;
 
-        let token = loop {
-            // Handle any pending acknowledgements first.
-            self.handle_pending_acknowledgements(force_check_pending_data_files)
-                .await
-                .context(IoSnafu)?;
-            force_check_pending_data_files = false;
-
-            // If the writer has marked themselves as done, and the buffer has been emptied, then
-            // we're done and can return.  We have to look at something besides simply the writer
-            // being marked as done to know if we're actually done or not, and "buffer size" is better
-            // than "total records" because we update buffer size when handling acknowledgements,
-            // whether it's an individual ack or an entire file being deleted.
-            //
-            // If we used "total records", we could end up stuck in cases where we skipped
-            // corrupted records, but hadn't yet had a "good" record that we could read, since the
-            // "we skipped records due to corruption" logic requires performing valid read to
-            // detect, and calculate a valid delta from.
-            if self.ledger.is_writer_done() {
-                let total_buffer_size = self.ledger.get_total_buffer_size();
-                if total_buffer_size == 0 {
-                    return Ok(None);
+        loop {
+            let token = loop {
+                // Handle any pending acknowledgements first.
+                self.handle_pending_acknowledgements(force_check_pending_data_files)
+                    .await
+                    .context(IoSnafu)?;
+                force_check_pending_data_files = false;
+
+                // If the writer has marked themselves as done, and the buffer has been emptied, then
+                // we're done and can return.  We have to look at something besides simply the writer
+                // being marked as done to know if we're actually done or not, and "buffer size" is better
+                // than "total records" because we update buffer size when handling acknowledgements,
+                // whether it's an individual ack or an entire file being deleted.
+                //
+                // If we used "total records", we could end up stuck in cases where we skipped
+                // corrupted records, but hadn't yet had a "good" record that we could read, since the
+                // "we skipped records due to corruption" logic requires performing valid read to
+                // detect, and calculate a valid delta from.
+                if self.ledger.is_writer_done() {
+                    let total_buffer_size = self.ledger.get_total_buffer_size();
+                    if total_buffer_size == 0 {
+                        return Ok(None);
+                    }
                 }
-            }
 
-            self.ensure_ready_for_read().await.context(IoSnafu)?;
+                self.ensure_ready_for_read().await.context(IoSnafu)?;
 
-            let reader = self
-                .reader
-                .as_mut()
-                .expect("reader should exist after `ensure_ready_for_read`");
+                let reader = self
+                    .reader
+                    .as_mut()
+                    .expect("reader should exist after `ensure_ready_for_read`");
 
-            let (reader_file_id, writer_file_id) = self.ledger.get_current_reader_writer_file_id();
+                let (reader_file_id, writer_file_id) =
+                    self.ledger.get_current_reader_writer_file_id();
+
+                // Essentially: is the writer still writing to this data file or not?
+                //
+                // A necessary invariant to have to understand if the record reader should actually keep
+                // waiting for data, or if a data file had a partial write/missing data and should be skipped.
+                let is_finalized = reader_file_id != writer_file_id;
+
+                // Try reading a record, which if successful, gives us a token to actually read/get a
+                // reference to the record.  This is a slightly-tricky song-and-dance due to rustc not
+                // yet fully understanding mutable borrows when conditional control flow is involved.
+                match reader.try_next_record(is_finalized).await {
+                    // Not even enough data to read a length delimiter, so we need to wait for the
+                    // writer to signal us that there's some actual data to read.
+                    Ok(None) => {}
+                    // We got a valid record, so keep the token.
+                    Ok(Some(token)) => break token,
+                    // A length-delimited payload was read, but we failed to deserialize it as a valid
+                    // record, or we deseralized it and the checksum was invalid.  Either way, we're not
+                    // sure the rest of the data file is even valid, so roll to the next file.
+                    //
+                    // TODO: Explore the concept of putting a data file into a "one more attempt to read
+                    // a valid record" state, almost like a semi-open circuit breaker.  There's a
+                    // possibility that the length delimiter we got is valid, and all the data was
+                    // written for the record, but the data was invalid... and that if we just kept
+                    // reading, we might actually encounter a valid record.
+                    //
+                    // Theoretically, based on both the validation done by `rkyv` and the checksum, it
+                    // should be incredibly incredibly unlikely to read a valid record after getting a
+                    // corrupted record if there was missing data or more invalid data.  We use
+                    // checksumming to assert errors within a given chunk of the payload, so one payload
+                    // being corrupted doesn't always, in fact, mean that other records after it are
+                    // corrupted too.
+                    Err(e) => {
+                        // Invalid checksums and deserialization failures can't really be acted upon by
+                        // the caller, but they might be expecting a read-after-write behavior, so we
+                        // return the error to them after ensuring that we roll to the next file first.
+                        if e.is_bad_read() {
+                            self.roll_to_next_data_file();
+                        }
 
-            // Essentially: is the writer still writing to this data file or not?
-            //
-            // A necessary invariant to have to understand if the record reader should actually keep
-            // waiting for data, or if a data file had a partial write/missing data and should be skipped.
-            let is_finalized = reader_file_id != writer_file_id;
-
-            // Try reading a record, which if successful, gives us a token to actually read/get a
-            // reference to the record.  This is a slightly-tricky song-and-dance due to rustc not
-            // yet fully understanding mutable borrows when conditional control flow is involved.
-            match reader.try_next_record(is_finalized).await {
-                // Not even enough data to read a length delimiter, so we need to wait for the
-                // writer to signal us that there's some actual data to read.
-                Ok(None) => {}
-                // We got a valid record, so keep the token.
-                Ok(Some(token)) => break token,
-                // A length-delimited payload was read, but we failed to deserialize it as a valid
-                // record, or we deseralized it and the checksum was invalid.  Either way, we're not
-                // sure the rest of the data file is even valid, so roll to the next file.
+                        return Err(e);
+                    }
+                };
+
+                // Fundamentally, when `try_read_record` returns `None`, there's three possible
+                // scenarios:
+                //
+                // 1. we are entirely caught up to the writer
+                // 2. we've hit the end of the data file and need to go to the next one
+                // 3. the writer has closed/dropped/finished/etc
                 //
-                // TODO: Explore the concept of putting a data file into a "one more attempt to read
-                // a valid record" state, almost like a semi-open circuit breaker.  There's a
-                // possibility that the length delimiter we got is valid, and all the data was
-                // written for the record, but the data was invalid... and that if we just kept
-                // reading, we might actually encounter a valid record.
+                // When we're at this point, we check the reader/writer file IDs.  If the file IDs are
+                // not identical, we now know the writer has moved on.  Crucially, since we always flush
+                // our writes before waking up, including before moving to a new file, then we know that
+                // if the reader/writer were not identical at the start the loop, and `try_read_record`
+                // returned `None`, that we have hit the actual end of the reader's current data file,
+                // and need to move on.
                 //
-                // Theoretically, based on both the validation done by `rkyv` and the checksum, it
-                // should be incredibly incredibly unlikely to read a valid record after getting a
-                // corrupted record if there was missing data or more invalid data.  We use
-                // checksumming to assert errors within a given chunk of the payload, so one payload
-                // being corrupted doesn't always, in fact, mean that other records after it are
-                // corrupted too.
-                Err(e) => {
-                    // Invalid checksums and deserialization failures can't really be acted upon by
-                    // the caller, but they might be expecting a read-after-write behavior, so we
-                    // return the error to them after ensuring that we roll to the next file first.
-                    if e.is_bad_read() {
+                // If the file IDs were identical, it would imply that reader is still on the writer's
+                // current data file. We then "wait" for the writer to wake us up. It may lead to the
+                // same thing -- `try_read_record` returning `None` with an identical reader/writer file
+                // ID -- but that's OK, because it would mean we were actually waiting for the writer to
+                // make progress now.  If the wake-up was valid, due to writer progress, then, well...
+                // we'd actually be able to read data.
+                //
+                // The case of "the writer has closed/dropped/finished/etc" is handled at the top of the
+                // loop, because otherwise we could get stuck waiting for the writer after an empty
+                // `try_read_record` attempt when the writer is done and we're at the end of the file,
+                // etc.
+                if self.ready_to_read {
+                    if reader_file_id != writer_file_id {
+                        debug!(
+                            reader_file_id,
+                            writer_file_id, "Reached the end of current data file."
+                        );
+
                         self.roll_to_next_data_file();
+                        force_check_pending_data_files = true;
+                        continue;
                     }
 
-                    return Err(e);
-                }
-            };
-
-            // Fundamentally, when `try_read_record` returns `None`, there's three possible
-            // scenarios:
-            //
-            // 1. we are entirely caught up to the writer
-            // 2. we've hit the end of the data file and need to go to the next one
-            // 3. the writer has closed/dropped/finished/etc
-            //
-            // When we're at this point, we check the reader/writer file IDs.  If the file IDs are
-            // not identical, we now know the writer has moved on.  Crucially, since we always flush
-            // our writes before waking up, including before moving to a new file, then we know that
-            // if the reader/writer were not identical at the start the loop, and `try_read_record`
-            // returned `None`, that we have hit the actual end of the reader's current data file,
-            // and need to move on.
-            //
-            // If the file IDs were identical, it would imply that reader is still on the writer's
-            // current data file. We then "wait" for the writer to wake us up. It may lead to the
-            // same thing -- `try_read_record` returning `None` with an identical reader/writer file
-            // ID -- but that's OK, because it would mean we were actually waiting for the writer to
-            // make progress now.  If the wake-up was valid, due to writer progress, then, well...
-            // we'd actually be able to read data.
-            //
-            // The case of "the writer has closed/dropped/finished/etc" is handled at the top of the
-            // loop, because otherwise we could get stuck waiting for the writer after an empty
-            // `try_read_record` attempt when the writer is done and we're at the end of the file,
-            // etc.
-            if self.ready_to_read {
-                if reader_file_id != writer_file_id {
+                    self.ledger.wait_for_writer().await;
+                } else {
                     debug!(
-                        reader_file_id,
-                        writer_file_id, "Reached the end of current data file."
+                        bytes_read = self.bytes_read,
+                        "Current data file has no more data."
                     );
 
-                    self.roll_to_next_data_file();
-                    force_check_pending_data_files = true;
-                    continue;
+                    if reader_file_id == writer_file_id {
+                        // We're currently just seeking to where we left off the last time this buffer was
+                        // running, which might mean there's no records for us to read at all because we
+                        // were already caught up.  All we can do is signal to `seek_to_next_record` that
+                        // we're caught up.
+                        return Ok(None);
+                    }
                 }
+            };
 
-                self.ledger.wait_for_writer().await;
-            } else {
-                debug!(
-                    bytes_read = self.bytes_read,
-                    "Current data file has no more data."
+            // We got a read token, so our record is present in the reader, and now we can actually read
+            // it out and return it.
+            let record_id = token.record_id();
+            let record_bytes = token.record_bytes() as u64;
+
+            let reader = self
+                .reader
+                .as_mut()
+                .expect("reader should exist after `ensure_ready_for_read`");
+            let (mut record, written_at) = reader.read_timestamped_record(token)?;
+
+            let record_events: u64 = record
+                .event_count()
+                .try_into()
+                .expect("Event count for a record cannot exceed 2^64 events.");
+            let record_events = record_events
+                .try_into()
+                .map_err(|_| ReaderError::EmptyRecord)?;
+
+            // Records older than the maximum age are discarded, but we still have to track them like
+            // any other read, so that they get acknowledged in order with the records around them.
+            let expired = self.ready_to_read
+                && matches!(
+                    (written_at, self.ledger.config().max_age),
+                    (Some(written_at), Some(max_age)) if is_expired(written_at, max_age)
                 );
+            self.track_read(record_id, record_bytes, record_events, expired);
 
-                if reader_file_id == writer_file_id {
-                    // We're currently just seeking to where we left off the last time this buffer was
-                    // running, which might mean there's no records for us to read at all because we
-                    // were already caught up.  All we can do is signal to `seek_to_next_record` that
-                    // we're caught up.
-                    return Ok(None);
-                }
+            let (batch, receiver) = BatchNotifier::new_with_receiver();
+            record.add_batch_notifier(batch);
+            self.finalizer.add(record_events.get(), receiver);
+
+            if expired {
+                trace!(
+                    record_id,
+                    record_events,
+                    record_bytes,
+                    data_file_id = self.ledger.get_current_reader_file_id(),
+                    "Discarded record older than the maximum age."
+                );
+
+                // Dropping the record acknowledges it.
+                drop(record);
+                continue;
             }
-        };
 
-        // We got a read token, so our record is present in the reader, and now we can actually read
-        // it out and return it.
-        let record_id = token.record_id();
-        let record_bytes = token.record_bytes() as u64;
-
-        let reader = self
-            .reader
-            .as_mut()
-            .expect("reader should exist after `ensure_ready_for_read`");
-        let mut record = reader.read_record(token)?;
-
-        let record_events: u64 = record
-            .event_count()
-            .try_into()
-            .expect("Event count for a record cannot exceed 2^64 events.");
-        let record_events = record_events
-            .try_into()
-            .map_err(|_| ReaderError::EmptyRecord)?;
-        self.track_read(record_id, record_bytes, record_events);
-
-        let (batch, receiver) = BatchNotifier::new_with_receiver();
-        record.add_batch_notifier(batch);
-        self.finalizer.add(record_events.get(), receiver);
+            if self.ready_to_read {
+                trace!(
+                    record_id,
+                    record_events,
+                    record_bytes,
+                    data_file_id = self.ledger.get_current_reader_file_id(),
+                    "Read record."
+                );
+            }
 
-        if self.ready_to_read {
-            trace!(
-                record_id,
-                record_events,
-                record_bytes,
-                data_file_id = self.ledger.get_current_reader_file_id(),
-                "Read record."
-            );
+            return Ok(Some(record));
         }
-
-        Ok(Some(record))
     }
 }
 
 pub(crate) fn decode_record_payload<T: Bufferable>(
     record: &ArchivedRecord<'_>,
+    cipher: Option<&RecordCipher>,
 ) -> Result<T, ReaderError<T>> {
+    decode_timestamped_record_payload(record, cipher).map(|(item, _)| item)
+}
+
+/// Decodes a record, along with the time it was written at, if the record was timestamped.
+pub(crate) fn decode_timestamped_record_payload<T: Bufferable>(
+    record: &ArchivedRecord<'_>,
+    cipher: Option<&RecordCipher>,
+) -> Result<(T, Option<SystemTime>), ReaderError<T>> {
     // Try and convert the raw record metadata into the true metadata type used by `T`, and then
     // also verify that `T` is able to decode records with the metadata used for this record in particular.
-    let metadata = T::Metadata::from_u32(record.metadata()).ok_or(ReaderError::Incompatible {
+    //
+    // The encryption, compression and timestamp flags are ours, not `T`'s, so we strip them before
+    // handing the metadata over.
+    let raw_metadata = record.metadata() & !RECORD_METADATA_FLAGS;
+    let metadata = T::Metadata::from_u32(raw_metadata).ok_or(ReaderError::Incompatible {
         reason: format!("invalid metadata for {}", std::any::type_name::<T>()),
     })?;
 
@@ -1115,11 +1282,21 @@ pub(crate) fn decode_record_payload<T: Bufferable>(
         return Err(ReaderError::Incompatible {
             reason: format!(
                 "record metadata not supported (metadata: {:#036b})",
-                record.metadata()
+                raw_metadata
             ),
         });
     }
 
-    // Now we can finally try decoding.
-    T::decode(metadata, record.payload()).context(DecodeSnafu)
+    // Decrypt and decompress the payload, and split off its timestamp, if need be, and now we can
+    // finally try decoding.
+    let payload = decrypt_payload(cipher, record.id(), record.metadata(), record.payload())
+        .context(DecryptionSnafu)?;
+    let payload = decompress_payload(record.metadata(), payload).context(DecompressionSnafu)?;
+    let (payload, written_at) =
+        strip_timestamp(record.metadata(), payload).ok_or_else(|| ReaderError::Incompatible {
+            reason: "record payload is shorter than its timestamp".to_string(),
+        })?;
+    T::decode(metadata, &payload[..])
+        .context(DecodeSnafu)
+        .map(|item| (item, written_at))
 }
@@ -10,11 +10,21 @@ use rkyv::{
 
 use super::{
     common::align16,
+    compression::COMPRESSED_RECORD_FLAG,
+    encryption::ENCRYPTED_RECORD_FLAG,
     ser::{try_as_archive, DeserializeError},
+    timestamp::TIMESTAMPED_RECORD_FLAG,
 };
 
 pub const RECORD_HEADER_LEN: usize = align16(mem::size_of::<ArchivedRecord<'_>>() + 8);
 
+/// Flags set by the buffer itself in the metadata of records.
+///
+/// These describe how the payload was stored, and not how it was encoded, so they must be stripped from the metadata
+/// before it is handed over to `T`.
+pub(super) const RECORD_METADATA_FLAGS: u32 =
+    ENCRYPTED_RECORD_FLAG | COMPRESSED_RECORD_FLAG | TIMESTAMPED_RECORD_FLAG;
+
 /// Result of checking if a buffer contained a valid record.
 pub enum RecordStatus {
     /// The record was able to be read from the buffer, and the checksum is valid.
@@ -128,6 +138,11 @@ impl<'a> Record<'a> {
 }
 
 impl<'a> ArchivedRecord<'a> {
+    /// Gets the ID of this record.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
     /// Gets the metadata of this record.
     pub fn metadata(&self) -> u32 {
         self.metadata
@@ -0,0 +1,132 @@
+use std::{path::Path, sync::Arc};
+
+use vector_common::finalization::Finalizable;
+
+use super::{create_default_buffer_v2, read_next_some, FilesystemUnderTest};
+use crate::{
+    assert_buffer_is_empty, assert_buffer_records,
+    buffer_usage_data::BufferUsageHandle,
+    test::{acknowledge, with_temp_dir, SizedRecord},
+    variants::disk_v2::{
+        Buffer, CompressionAlgorithm, DiskBufferConfigBuilder, EncryptionAlgorithm,
+        EncryptionConfig, Ledger, Reader, RecordCipher, Writer,
+    },
+};
+
+async fn create_compressed_buffer_v2<P>(
+    data_dir: P,
+    algorithm: CompressionAlgorithm,
+    cipher: Option<RecordCipher>,
+) -> (
+    Writer<SizedRecord, FilesystemUnderTest>,
+    Reader<SizedRecord, FilesystemUnderTest>,
+    Arc<Ledger<FilesystemUnderTest>>,
+)
+where
+    P: AsRef<Path>,
+{
+    let mut builder = DiskBufferConfigBuilder::from_path(data_dir).compression(algorithm);
+    if let Some(cipher) = cipher {
+        builder = builder.encryption(Arc::new(cipher));
+    }
+    let config = builder.build().expect("creating buffer should not fail");
+    let usage_handle = BufferUsageHandle::noop();
+
+    Buffer::from_config_inner(config, usage_handle)
+        .await
+        .expect("should not fail to create buffer")
+}
+
+fn cipher() -> RecordCipher {
+    let config = EncryptionConfig {
+        algorithm: EncryptionAlgorithm::Aes256Gcm,
+        key: Some(base64::encode([1; 32])),
+        key_file: None,
+        previous_keys: Vec::new(),
+        previous_key_files: Vec::new(),
+    };
+
+    RecordCipher::from_config(&config).expect("key should be valid")
+}
+
+#[tokio::test]
+async fn compressed_records_roundtrip() {
+    for algorithm in [CompressionAlgorithm::Zstd, CompressionAlgorithm::Lz4] {
+        with_temp_dir(|dir| {
+            let data_dir = dir.to_path_buf();
+
+            async move {
+                let (mut writer, mut reader, ledger) =
+                    create_compressed_buffer_v2(&data_dir, algorithm, None).await;
+
+                let record = SizedRecord::new(8192);
+                let payload_len = u64::from(record.0);
+                writer
+                    .write_record(record.clone())
+                    .await
+                    .expect("write should not fail");
+                writer.flush().await.expect("flush should not fail");
+                assert_buffer_records!(ledger, 1);
+
+                // The buffer size accounts for what actually got written to disk, which is the compressed record.
+                assert!(ledger.get_total_buffer_size() < payload_len);
+
+                let mut read_record = read_next_some(&mut reader).await;
+                acknowledge(read_record.take_finalizers()).await;
+                assert_eq!(record, read_record);
+            }
+        })
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn records_stay_readable_when_compression_changes() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Write a record without compression, one with LZ4, and then one with both zstd and encryption.
+            let uncompressed_record = SizedRecord::new(64);
+            let (mut writer, reader, ledger) = create_default_buffer_v2(&data_dir).await;
+            writer
+                .write_record(uncompressed_record.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            drop((writer, reader, ledger));
+
+            let lz4_record = SizedRecord::new(4096);
+            let (mut writer, reader, ledger) =
+                create_compressed_buffer_v2(&data_dir, CompressionAlgorithm::Lz4, None).await;
+            writer
+                .write_record(lz4_record.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            drop((writer, reader, ledger));
+
+            let zstd_record = SizedRecord::new(2048);
+            let (mut writer, mut reader, ledger) =
+                create_compressed_buffer_v2(&data_dir, CompressionAlgorithm::Zstd, Some(cipher()))
+                    .await;
+            writer
+                .write_record(zstd_record.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            assert_buffer_records!(ledger, 3);
+
+            for expected in [uncompressed_record, lz4_record, zstd_record] {
+                let mut record = read_next_some(&mut reader).await;
+                acknowledge(record.take_finalizers()).await;
+                assert_eq!(expected, record);
+            }
+
+            writer.close();
+            assert_eq!(reader.next().await.expect("read should not fail"), None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
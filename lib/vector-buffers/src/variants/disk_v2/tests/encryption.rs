@@ -0,0 +1,182 @@
+use std::{path::Path, sync::Arc};
+
+use vector_common::finalization::Finalizable;
+
+use super::{create_default_buffer_v2, read_next_some, FilesystemUnderTest};
+use crate::{
+    assert_buffer_is_empty, assert_buffer_records,
+    buffer_usage_data::BufferUsageHandle,
+    test::{acknowledge, with_temp_dir, SizedRecord},
+    variants::disk_v2::{
+        Buffer, DiskBufferConfigBuilder, EncryptionAlgorithm, EncryptionConfig, Ledger, Reader,
+        RecordCipher, Writer,
+    },
+};
+
+fn cipher(algorithm: EncryptionAlgorithm, key: u8, previous_keys: &[u8]) -> RecordCipher {
+    let config = EncryptionConfig {
+        algorithm,
+        key: Some(base64::encode([key; 32])),
+        key_file: None,
+        previous_keys: previous_keys
+            .iter()
+            .map(|key| base64::encode([*key; 32]))
+            .collect(),
+        previous_key_files: Vec::new(),
+    };
+
+    RecordCipher::from_config(&config).expect("keys should be valid")
+}
+
+async fn create_encrypted_buffer_v2<P>(
+    data_dir: P,
+    cipher: RecordCipher,
+) -> (
+    Writer<SizedRecord, FilesystemUnderTest>,
+    Reader<SizedRecord, FilesystemUnderTest>,
+    Arc<Ledger<FilesystemUnderTest>>,
+)
+where
+    P: AsRef<Path>,
+{
+    let config = DiskBufferConfigBuilder::from_path(data_dir)
+        .encryption(Arc::new(cipher))
+        .build()
+        .expect("creating buffer should not fail");
+    let usage_handle = BufferUsageHandle::noop();
+
+    Buffer::from_config_inner(config, usage_handle)
+        .await
+        .expect("should not fail to create buffer")
+}
+
+fn contains_plaintext(data: &[u8], record: &SizedRecord) -> bool {
+    let payload = vec![0x42; record.0 as usize];
+    data.windows(payload.len()).any(|window| window == payload)
+}
+
+#[tokio::test]
+async fn encrypted_records_roundtrip() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, mut reader, ledger) = create_encrypted_buffer_v2(
+                &data_dir,
+                cipher(EncryptionAlgorithm::ChaCha20Poly1305, 1, &[]),
+            )
+            .await;
+
+            let record = SizedRecord::new(256);
+            writer
+                .write_record(record.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            assert_buffer_records!(ledger, 1);
+
+            // The payload must not be written to the data file in the clear.
+            let data_file = tokio::fs::read(ledger.get_current_writer_data_file_path())
+                .await
+                .expect("data file should exist");
+            assert!(!contains_plaintext(&data_file, &record));
+
+            let mut read_record = read_next_some(&mut reader).await;
+            acknowledge(read_record.take_finalizers()).await;
+            assert_eq!(record, read_record);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn records_stay_readable_across_key_rotation() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Write a record without encryption, and another one with the first key.
+            let plaintext_record = SizedRecord::new(64);
+            let (mut writer, reader, ledger) = create_default_buffer_v2(&data_dir).await;
+            writer
+                .write_record(plaintext_record.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            drop((writer, reader, ledger));
+
+            let first_record = SizedRecord::new(128);
+            let (mut writer, reader, ledger) = create_encrypted_buffer_v2(
+                &data_dir,
+                cipher(EncryptionAlgorithm::Aes256Gcm, 1, &[]),
+            )
+            .await;
+            writer
+                .write_record(first_record.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            drop((writer, reader, ledger));
+
+            // Now rotate the key, keeping the first one around to read what it encrypted. Reopening
+            // the buffer validates the last write, which was encrypted with the previous key.
+            let second_record = SizedRecord::new(192);
+            let (mut writer, mut reader, ledger) = create_encrypted_buffer_v2(
+                &data_dir,
+                cipher(EncryptionAlgorithm::ChaCha20Poly1305, 2, &[1]),
+            )
+            .await;
+            writer
+                .write_record(second_record.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            assert_buffer_records!(ledger, 3);
+
+            for expected in [plaintext_record, first_record, second_record] {
+                let mut record = read_next_some(&mut reader).await;
+                acknowledge(record.take_finalizers()).await;
+                assert_eq!(expected, record);
+            }
+
+            writer.close();
+            assert_eq!(reader.next().await.expect("read should not fail"), None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn buffer_fails_to_load_when_key_is_missing() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, reader, ledger) = create_encrypted_buffer_v2(
+                &data_dir,
+                cipher(EncryptionAlgorithm::Aes256Gcm, 1, &[]),
+            )
+            .await;
+            writer
+                .write_record(SizedRecord::new(64))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            let data_file_path = ledger.get_current_writer_data_file_path();
+            drop((writer, reader, ledger));
+
+            // Without the key, the last written record can't be decrypted when validating it, so the
+            // buffer refuses to load rather than skipping over records it cannot read.
+            let config = DiskBufferConfigBuilder::from_path(&data_dir)
+                .encryption(Arc::new(cipher(EncryptionAlgorithm::Aes256Gcm, 2, &[])))
+                .build()
+                .expect("creating buffer should not fail");
+            let result =
+                Buffer::<SizedRecord>::from_config_inner(config, BufferUsageHandle::noop()).await;
+            assert!(result.is_err());
+            assert!(data_file_path.exists());
+        }
+    })
+    .await;
+}
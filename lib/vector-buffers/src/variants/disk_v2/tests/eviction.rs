@@ -0,0 +1,203 @@
+use std::{fs, path::Path, sync::Arc, time::Duration};
+
+use super::{
+    create_buffer_v2_with_data_file_count_limit, get_minimum_data_file_size_for_record_payload,
+    read_next, read_next_some, FilesystemUnderTest,
+};
+use crate::{
+    assert_buffer_is_empty, assert_buffer_records,
+    buffer_usage_data::BufferUsageHandle,
+    test::{acknowledge, with_temp_dir, SizedRecord},
+    variants::disk_v2::{Buffer, DiskBufferConfigBuilder, Ledger, Reader, Writer},
+};
+
+async fn create_buffer_v2_with_max_age<P>(
+    data_dir: P,
+    max_age: Duration,
+) -> (
+    Writer<SizedRecord, FilesystemUnderTest>,
+    Reader<SizedRecord, FilesystemUnderTest>,
+    Arc<Ledger<FilesystemUnderTest>>,
+)
+where
+    P: AsRef<Path>,
+{
+    let config = DiskBufferConfigBuilder::from_path(data_dir)
+        .max_age(max_age)
+        .build()
+        .expect("creating buffer should not fail");
+    let usage_handle = BufferUsageHandle::noop();
+
+    Buffer::from_config_inner(config, usage_handle)
+        .await
+        .expect("should not fail to create buffer")
+}
+
+#[tokio::test]
+async fn writer_evicts_oldest_unread_data_file_when_full() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Size our data files so that each one can only hold a single record, which makes it
+            // easy to know which records live in the data file that gets evicted.
+            let records = (0..8)
+                .map(|i| SizedRecord::new(80 + (i * 2)))
+                .collect::<Vec<_>>();
+            let max_data_file_size =
+                get_minimum_data_file_size_for_record_payload(records.last().unwrap());
+            let (mut writer, mut reader, ledger) =
+                create_buffer_v2_with_data_file_count_limit(&data_dir, max_data_file_size, 4).await;
+
+            // Fill up the buffer.
+            let mut written = Vec::new();
+            for record in records.iter().cloned() {
+                if writer
+                    .try_write_record(record.clone())
+                    .await
+                    .expect("write should not fail")
+                    .is_some()
+                {
+                    break;
+                }
+                written.push(record);
+            }
+            writer.flush().await.expect("flush should not fail");
+            assert!(
+                written.len() >= 3,
+                "buffer should hold at least three data files"
+            );
+            assert_buffer_records!(ledger, written.len());
+
+            // Writing another record should evict the data file after the one the reader is on,
+            // rather than waiting for the reader.
+            let newest = SizedRecord::new(96);
+            writer
+                .write_record_evicting(newest.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+
+            let mut expected = vec![written[0].clone()];
+            expected.extend(written[2..].iter().cloned());
+            expected.push(newest);
+
+            let mut read = Vec::new();
+            while let Some(record) = read_next(&mut reader).await {
+                read.push(record.clone());
+                acknowledge(record).await;
+            }
+
+            assert_eq!(read, expected);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn writer_does_not_evict_unreadable_data_files() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let records = (0..8)
+                .map(|i| SizedRecord::new(80 + (i * 2)))
+                .collect::<Vec<_>>();
+            let max_data_file_size =
+                get_minimum_data_file_size_for_record_payload(records.last().unwrap());
+            let (mut writer, mut reader, ledger) =
+                create_buffer_v2_with_data_file_count_limit(&data_dir, max_data_file_size, 5).await;
+
+            let mut written = Vec::new();
+            for record in records.iter().cloned() {
+                if writer
+                    .try_write_record(record.clone())
+                    .await
+                    .expect("write should not fail")
+                    .is_some()
+                {
+                    break;
+                }
+                written.push(record);
+            }
+            writer.flush().await.expect("flush should not fail");
+            assert!(
+                written.len() >= 4,
+                "buffer should hold at least four data files"
+            );
+
+            // Corrupt the record in the data file after the one the reader is on, so that it can't
+            // be read when looking for a data file to evict.
+            let corrupted_path = ledger.get_data_file_path(1);
+            let mut contents = fs::read(&corrupted_path).expect("data file should exist");
+            let middle = contents.len() / 2;
+            contents[middle] ^= 0xff;
+            fs::write(&corrupted_path, contents).expect("data file should be writable");
+
+            // The corrupted data file is skipped, and the one after it is evicted instead.
+            let newest = SizedRecord::new(96);
+            writer
+                .write_record_evicting(newest.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+            assert!(corrupted_path.exists());
+            assert!(!ledger.get_data_file_path(2).exists());
+
+            let mut expected = vec![written[0].clone()];
+            expected.extend(written[3..].iter().cloned());
+            expected.push(newest);
+
+            let mut read = Vec::new();
+            while let Some(record) = read_next(&mut reader).await {
+                read.push(record.clone());
+                acknowledge(record).await;
+            }
+
+            assert_eq!(read, expected);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn reader_discards_records_older_than_max_age() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, mut reader, ledger) =
+                create_buffer_v2_with_max_age(&data_dir, Duration::from_millis(100)).await;
+
+            let old_record = SizedRecord::new(32);
+            writer
+                .write_record(old_record)
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+
+            tokio::time::sleep(Duration::from_millis(250)).await;
+
+            let new_record = SizedRecord::new(64);
+            writer
+                .write_record(new_record.clone())
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("flush should not fail");
+            writer.close();
+            assert_buffer_records!(ledger, 2);
+
+            // The old record is discarded on the way out, so the first record we get is the new one.
+            let read_record = read_next_some(&mut reader).await;
+            assert_eq!(read_record, new_record);
+            acknowledge(read_record).await;
+
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+        }
+    })
+    .await;
+}
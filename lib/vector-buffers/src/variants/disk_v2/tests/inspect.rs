@@ -0,0 +1,102 @@
+use futures::future;
+
+use super::create_default_buffer_v2;
+use crate::{
+    inspect::{inspect, BufferKind},
+    test::{with_temp_dir, SizedRecord},
+};
+
+#[tokio::test]
+async fn inspect_reports_unread_records() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, reader, ledger) = create_default_buffer_v2(&data_dir).await;
+            let records = vec![
+                SizedRecord::new(32),
+                SizedRecord::new(64),
+                SizedRecord::new(128),
+            ];
+            for record in records.iter().cloned() {
+                writer
+                    .write_record(record)
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("flush should not fail");
+            let buffer_size = ledger.get_total_buffer_size();
+            let data_file_path = ledger.get_current_writer_data_file_path();
+            drop((writer, reader, ledger));
+
+            let mut visited = Vec::new();
+            let report = inspect::<SizedRecord, _, _>(&data_dir, None, |record| {
+                visited.push(record);
+                future::ready(())
+            })
+            .await
+            .expect("inspection should not fail");
+
+            assert_eq!(visited, records);
+            assert_eq!(report.kind, BufferKind::DiskV2);
+            assert_eq!(report.records, 3);
+            assert_eq!(report.events, 3);
+            assert_eq!(report.bytes, buffer_size);
+            assert!(report.corruptions.is_empty());
+
+            let ledger = report.ledger.expect("disk_v2 buffers have a ledger");
+            assert_eq!(ledger.writer_next_record_id, 4);
+            assert_eq!(ledger.reader_last_record_id, 0);
+            assert_eq!(ledger.unread_events(), 3);
+
+            assert_eq!(report.data_files.len(), 1);
+            assert_eq!(report.data_files[0].path, data_file_path);
+            assert_eq!(report.data_files[0].records, 3);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn inspect_reports_corrupted_records() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, reader, ledger) = create_default_buffer_v2(&data_dir).await;
+            for _ in 0..3 {
+                writer
+                    .write_record(SizedRecord::new(64))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("flush should not fail");
+            let data_file_path = ledger.get_current_writer_data_file_path();
+            drop((writer, reader, ledger));
+
+            // Flip the last byte of the data file, which belongs to the last record.
+            let mut data_file = tokio::fs::read(&data_file_path)
+                .await
+                .expect("data file should exist");
+            let last = data_file.len() - 1;
+            data_file[last] ^= 0xFF;
+            tokio::fs::write(&data_file_path, &data_file)
+                .await
+                .expect("data file should be writable");
+
+            let report = inspect::<SizedRecord, _, _>(&data_dir, None, |_| future::ready(()))
+                .await
+                .expect("inspection should not fail");
+
+            assert_eq!(report.records, 2);
+            assert_eq!(report.corruptions.len(), 1);
+
+            // Inspecting must leave the buffer as it was found.
+            let after = tokio::fs::read(&data_file_path)
+                .await
+                .expect("data file should exist");
+            assert_eq!(after, data_file);
+        }
+    })
+    .await;
+}
@@ -22,6 +22,10 @@ type FilesystemUnderTest = ProductionFilesystem;
 
 mod acknowledgements;
 mod basic;
+mod compression;
+mod encryption;
+mod eviction;
+mod inspect;
 mod invariants;
 mod known_errors;
 mod model;
@@ -544,6 +544,9 @@ impl WriterModel {
             ledger.config().write_buffer_size,
             ledger.config().max_data_file_size,
             ledger.config().max_record_size,
+            None,
+            None,
+            false,
         );
 
         let mut writer = Self {
@@ -0,0 +1,105 @@
+use std::{
+    borrow::Cow,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Flag set in the metadata of records whose payload is prefixed with the time they were written at.
+///
+/// Records are only timestamped when the buffer has a maximum age configured, so records without this flag never
+/// expire.  The timestamp is added to the encoded record before it is compressed and encrypted, so it is stored in
+/// the same form as the rest of the record.
+pub(super) const TIMESTAMPED_RECORD_FLAG: u32 = 1 << 29;
+
+/// Length, in bytes, of the timestamp prefix: milliseconds since the Unix epoch, as a big-endian `u64`.
+pub(super) const TIMESTAMP_LEN: usize = 8;
+
+/// Appends the current time, as a timestamp prefix, to `buf`.
+pub(super) fn write_timestamp(buf: &mut Vec<u8>) {
+    // A clock set before the Unix epoch is treated as the epoch itself, which simply means records written while it
+    // was will expire as soon as they are read.
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| {
+            u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+        });
+    buf.extend_from_slice(&millis.to_be_bytes());
+}
+
+/// Splits the timestamp prefix off the payload of a record, if it was timestamped.
+///
+/// Payloads of records that weren't flagged as timestamped are returned as-is, without a timestamp.  If the payload
+/// is too short to hold a timestamp, `None` is returned.
+pub(super) fn strip_timestamp(
+    metadata: u32,
+    payload: Cow<'_, [u8]>,
+) -> Option<(Cow<'_, [u8]>, Option<SystemTime>)> {
+    if metadata & TIMESTAMPED_RECORD_FLAG == 0 {
+        return Some((payload, None));
+    }
+
+    if payload.len() < TIMESTAMP_LEN {
+        return None;
+    }
+
+    let millis = u64::from_be_bytes(
+        payload[..TIMESTAMP_LEN]
+            .try_into()
+            .expect("the slice is the length of a u64"),
+    );
+    let written_at = UNIX_EPOCH + Duration::from_millis(millis);
+    let payload = match payload {
+        Cow::Borrowed(payload) => Cow::Borrowed(&payload[TIMESTAMP_LEN..]),
+        Cow::Owned(mut payload) => {
+            payload.drain(..TIMESTAMP_LEN);
+            Cow::Owned(payload)
+        }
+    };
+
+    Some((payload, Some(written_at)))
+}
+
+/// Whether or not a record written at `written_at` is older than `max_age`.
+///
+/// Records written in the future, as far as the current clock is concerned, are never considered expired.
+pub(super) fn is_expired(written_at: SystemTime, max_age: Duration) -> bool {
+    written_at
+        .elapsed()
+        .map_or(false, |elapsed| elapsed > max_age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut payload = Vec::new();
+        write_timestamp(&mut payload);
+        payload.extend_from_slice(b"record");
+
+        let (stripped, written_at) =
+            strip_timestamp(TIMESTAMPED_RECORD_FLAG, payload.as_slice().into()).unwrap();
+        assert_eq!(stripped.as_ref(), b"record");
+        let written_at = written_at.expect("record should be timestamped");
+        assert!(!is_expired(written_at, Duration::from_secs(60)));
+
+        let (stripped, _) = strip_timestamp(TIMESTAMPED_RECORD_FLAG, payload.into()).unwrap();
+        assert_eq!(stripped.as_ref(), b"record");
+    }
+
+    #[test]
+    fn passes_through_untimestamped_payloads() {
+        let (stripped, written_at) = strip_timestamp(0, b"record".as_slice().into()).unwrap();
+        assert_eq!(stripped.as_ref(), b"record");
+        assert_eq!(written_at, None);
+
+        assert!(strip_timestamp(TIMESTAMPED_RECORD_FLAG, b"short".as_slice().into()).is_none());
+    }
+
+    #[test]
+    fn expires_old_records() {
+        let written_at = SystemTime::now() - Duration::from_secs(120);
+        assert!(is_expired(written_at, Duration::from_secs(60)));
+        assert!(!is_expired(written_at, Duration::from_secs(300)));
+    }
+}
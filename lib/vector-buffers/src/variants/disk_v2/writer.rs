@@ -24,10 +24,18 @@ use snafu::{ResultExt, Snafu};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use super::{
-    common::{create_crc32c_hasher, DiskBufferConfig},
+    common::{create_crc32c_hasher, DiskBufferConfig, MAX_FILE_ID},
+    compression::{
+        decompress_payload, CompressionAlgorithm, CompressionError, COMPRESSED_RECORD_FLAG,
+    },
+    encryption::{
+        decrypt_payload, EncryptionError, RecordCipher, ENCRYPTED_RECORD_FLAG, ENVELOPE_OVERHEAD,
+    },
     io::Filesystem,
     ledger::Ledger,
-    record::{validate_record_archive, Record, RecordStatus},
+    reader::{ReaderError, RecordReader},
+    record::{validate_record_archive, Record, RecordStatus, RECORD_METADATA_FLAGS},
+    timestamp::{strip_timestamp, write_timestamp, TIMESTAMPED_RECORD_FLAG, TIMESTAMP_LEN},
 };
 use crate::{
     encoding::{AsMetadata, Encodable},
@@ -107,6 +115,20 @@ where
     #[snafu(display("failed to serialize encoded record to buffer: {}", reason))]
     FailedToSerialize { reason: String },
 
+    /// The writer failed to encrypt the record.
+    ///
+    /// Only occurs when the buffer is configured to encrypt records, and the underlying cipher
+    /// reports an error, which should not happen in practice for payloads within size limits.
+    #[snafu(display("failed to encrypt encoded record: {}", source))]
+    FailedToEncrypt { source: EncryptionError },
+
+    /// The writer failed to compress the record.
+    ///
+    /// Only occurs when the buffer is configured to compress records, and the underlying compressor
+    /// reports an error, which should not happen in practice for payloads within size limits.
+    #[snafu(display("failed to compress encoded record: {}", source))]
+    FailedToCompress { source: CompressionError },
+
     /// The writer failed to validate the last written record.
     ///
     /// Specifically, for `Writer`, this can only ever be returned when creating the buffer, during
@@ -360,9 +382,14 @@ impl<W: fmt::Debug> fmt::Debug for TrackingBufWriter<W> {
 pub(super) struct RecordWriter<W, T> {
     writer: TrackingBufWriter<W>,
     encode_buf: Vec<u8>,
+    compress_buf: Vec<u8>,
+    encrypt_buf: Vec<u8>,
     ser_buf: AlignedVec,
     ser_scratch: AlignedVec,
     checksummer: Hasher,
+    cipher: Option<Arc<RecordCipher>>,
+    compression: Option<CompressionAlgorithm>,
+    timestamped: bool,
     max_record_size: usize,
     current_data_file_size: u64,
     max_data_file_size: u64,
@@ -378,19 +405,33 @@ where
     ///
     /// Internally, the writer is wrapped in a [`BufWriter`], so callers should not pass in an
     /// already buffered writer.
+    ///
+    /// When `timestamped` is true, every record is prefixed with the time it was written at, so that the reader can
+    /// discard records older than the configured maximum age.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         writer: W,
         current_data_file_size: u64,
         write_buffer_size: usize,
         max_data_file_size: u64,
         max_record_size: usize,
+        cipher: Option<Arc<RecordCipher>>,
+        compression: Option<CompressionAlgorithm>,
+        timestamped: bool,
     ) -> Self {
         // These should also be getting checked at a higher level, but we're double-checking them here to be absolutely sure.
         let max_record_size_converted = u64::try_from(max_record_size)
             .expect("Maximum record size must be less than 2^64 bytes.");
 
+        let envelope_len = if cipher.is_some() {
+            ENVELOPE_OVERHEAD
+        } else {
+            0
+        };
+        let timestamp_len = if timestamped { TIMESTAMP_LEN } else { 0 };
+
         debug_assert!(
-            max_record_size > RECORD_HEADER_LEN,
+            max_record_size > RECORD_HEADER_LEN + envelope_len + timestamp_len,
             "maximum record length must be larger than size of record header itself"
         );
         debug_assert!(
@@ -405,14 +446,22 @@ where
         // This could lead to us reducing the encode buffer size limit by slightly more than necessary, since
         // `RECORD_HEADER_LEN` might be overaligned compared to what it would be necessary when we look at the
         // encoded/serialized record... but that's OK, but it's only going to differ by 8 bytes at most.
-        let max_record_size = max_record_size - RECORD_HEADER_LEN;
+        //
+        // Likewise, when records are encrypted, the encryption envelope is part of the payload that gets serialized, so
+        // we account for it here as well, and the same goes for the timestamp prefix of timestamped records.
+        let max_record_size = max_record_size - RECORD_HEADER_LEN - envelope_len - timestamp_len;
 
         Self {
             writer: TrackingBufWriter::with_capacity(write_buffer_size, writer),
             encode_buf: Vec::with_capacity(16_384),
+            compress_buf: Vec::new(),
+            encrypt_buf: Vec::new(),
             ser_buf: AlignedVec::with_capacity(16_384),
             ser_scratch: AlignedVec::with_capacity(16_384),
             checksummer: create_crc32c_hasher(),
+            cipher,
+            compression,
+            timestamped,
             max_record_size,
             current_data_file_size,
             max_data_file_size,
@@ -467,12 +516,21 @@ where
         // the actual encoded size and then check it against the limit.
         //
         // C'est la vie.
+        //
+        // Timestamped records have the time they were written at stored in front of the encoded record, which doesn't
+        // count towards the limit as we already accounted for it when calculating the maximum record size.
+        let mut metadata = T::get_metadata().into_u32();
+        if self.timestamped {
+            write_timestamp(&mut self.encode_buf);
+            metadata |= TIMESTAMPED_RECORD_FLAG;
+        }
+        let prefix_len = self.encode_buf.len();
         let encode_result = {
             let mut encode_buf = (&mut self.encode_buf).limit(self.max_record_size);
             record.encode(&mut encode_buf)
         };
         let encoded_len = encode_result
-            .map(|_| self.encode_buf.len())
+            .map(|_| self.encode_buf.len() - prefix_len)
             .context(FailedToEncodeSnafu)?;
         if encoded_len > self.max_record_size {
             return Err(WriterError::RecordTooLarge {
@@ -480,9 +538,31 @@ where
             });
         }
 
-        let metadata = T::get_metadata().into_u32();
-        let wrapped_record =
-            Record::with_checksum(id, metadata, &self.encode_buf, &self.checksummer);
+        // When compression is enabled, we compress the encoded record, and use it as the payload if it ended up
+        // smaller, flagging the record as compressed in its metadata.  Since we only ever keep the compressed form when
+        // it's smaller, the payload can never exceed the size limit we just checked.
+        let mut payload: &[u8] = &self.encode_buf;
+        if let Some(algorithm) = self.compression {
+            algorithm
+                .compress(payload, &mut self.compress_buf)
+                .context(FailedToCompressSnafu)?;
+            if self.compress_buf.len() < payload.len() {
+                metadata |= COMPRESSED_RECORD_FLAG;
+                payload = &self.compress_buf;
+            }
+        }
+
+        // When encryption is enabled, we encrypt the payload and store the resulting envelope as the payload instead,
+        // flagging the record as encrypted in its metadata.  The checksum is then calculated over the envelope, so
+        // records can still be validated without having to decrypt them.
+        if let Some(cipher) = self.cipher.as_deref() {
+            metadata |= ENCRYPTED_RECORD_FLAG;
+            cipher
+                .encrypt(id, metadata, payload, &mut self.encrypt_buf)
+                .context(FailedToEncryptSnafu)?;
+            payload = &self.encrypt_buf;
+        }
+        let wrapped_record = Record::with_checksum(id, metadata, payload, &self.checksummer);
 
         // Push 8 dummy bytes where our length delimiter will sit.  We'll fix this up after
         // serialization.  Notably, `AlignedSerializer` will report the serializer position as
@@ -535,11 +615,12 @@ where
             // We have to decode the record back out to actually be able to give it back.  If we
             // can't decode it for some reason, this is entirely an unrecoverable error, since an
             // encoded record should always be decodable within the same process that encoded it.
-            let record = T::decode(T::get_metadata(), &self.encode_buf[..]).map_err(|_| {
-                WriterError::InconsistentState {
-                    reason: "failed to decode record immediately after encoding it".to_string(),
-                }
-            })?;
+            let record =
+                T::decode(T::get_metadata(), &self.encode_buf[prefix_len..]).map_err(|_| {
+                    WriterError::InconsistentState {
+                        reason: "failed to decode record immediately after encoding it".to_string(),
+                    }
+                })?;
 
             return Err(WriterError::DataFileFull {
                 record,
@@ -664,18 +745,36 @@ where
             }
         })?;
 
-        // Now we can actually decode it as `T`.
-        let record_metadata = T::Metadata::from_u32(wrapped_record.metadata()).ok_or(
+        // Now we can actually decode it as `T`, decrypting and decompressing it first if need be.
+        let record_metadata = T::Metadata::from_u32(
+            wrapped_record.metadata() & !RECORD_METADATA_FLAGS,
+        )
+        .ok_or(WriterError::InconsistentState {
+            reason: "failed to decode record metadata immediately after encoding it".to_string(),
+        })?;
+
+        let payload = decrypt_payload(
+            self.cipher.as_deref(),
+            wrapped_record.id(),
+            wrapped_record.metadata(),
+            wrapped_record.payload(),
+        )
+        .map_err(|_| WriterError::InconsistentState {
+            reason: "failed to decrypt record immediately after encrypting it".to_string(),
+        })?;
+        let payload = decompress_payload(wrapped_record.metadata(), payload).map_err(|_| {
             WriterError::InconsistentState {
-                reason: "failed to decode record metadata immediately after encoding it"
-                    .to_string(),
+                reason: "failed to decompress record immediately after compressing it".to_string(),
+            }
+        })?;
+        let (payload, _) = strip_timestamp(wrapped_record.metadata(), payload).ok_or(
+            WriterError::InconsistentState {
+                reason: "failed to read record timestamp immediately after writing it".to_string(),
             },
         )?;
 
-        T::decode(record_metadata, wrapped_record.payload()).map_err(|_| {
-            WriterError::InconsistentState {
-                reason: "failed to decode record immediately after encoding it".to_string(),
-            }
+        T::decode(record_metadata, &payload[..]).map_err(|_| WriterError::InconsistentState {
+            reason: "failed to decode record immediately after encoding it".to_string(),
         })
     }
 
@@ -706,6 +805,18 @@ where
     }
 }
 
+/// The first record of a data file, when looking for a data file to evict.
+enum FirstRecord {
+    /// The data file doesn't exist, or has no records yet.
+    None,
+
+    /// The ID of the first record of the data file.
+    Id(u64),
+
+    /// The first record of the data file couldn't be read, so it can't be evicted.
+    Unreadable,
+}
+
 /// Writes records to the buffer.
 #[derive(Debug)]
 pub struct Writer<T, FS>
@@ -881,11 +992,10 @@ where
                 // next writer record ID should be.
                 let record = try_as_record_archive(data_file_mmap.as_ref())
                     .expect("record was already validated");
-                let item = decode_record_payload::<T>(record).map_err(|e| {
-                    WriterError::FailedToValidate {
+                let item = decode_record_payload::<T>(record, self.config.cipher.as_deref())
+                    .map_err(|e| WriterError::FailedToValidate {
                         reason: e.to_string(),
-                    }
-                })?;
+                    })?;
 
                 // Since we have a valid record, checksum and all, see if the writer record ID
                 // in the ledger lines up with the record ID we have here.  Specifically, the record
@@ -1125,6 +1235,9 @@ where
                     self.config.write_buffer_size,
                     self.config.max_data_file_size,
                     self.config.max_record_size,
+                    self.config.cipher.clone(),
+                    self.config.compression,
+                    self.config.max_age.is_some(),
                 ));
                 self.data_file_size = data_file_size;
 
@@ -1299,6 +1412,154 @@ where
         }
     }
 
+    /// Writes a record, evicting the oldest unread data files as necessary to make room for it.
+    ///
+    /// Only data files that the reader has not yet started reading can be evicted.  If there are
+    /// none, this waits for the reader to make progress, like [`Writer::write_record`] does.
+    ///
+    /// If the record was written successfully, the number of bytes written to the data file will be
+    /// returned.
+    ///
+    /// # Errors
+    ///
+    /// If an error occurred while writing the record, or while evicting a data file, an error
+    /// variant will be returned describing the error.
+    #[instrument(skip_all, level = "debug")]
+    pub async fn write_record_evicting(&mut self, mut record: T) -> Result<usize, WriterError<T>> {
+        loop {
+            match self.try_write_record_inner(record).await? {
+                Ok(bytes_written) => return Ok(bytes_written),
+                Err(old_record) => {
+                    record = old_record;
+                    if !self.evict_oldest_data_file().await.context(IoSnafu)? {
+                        self.ledger.wait_for_reader().await;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Gets the first record in the given data file, if it exists and has any records.
+    ///
+    /// # Errors
+    ///
+    /// If an I/O error occurred while reading the data file, an error variant will be returned.
+    /// Records that can't be read for any other reason, such as corruption or a missing
+    /// decryption key, are logged and reported as [`FirstRecord::Unreadable`].
+    async fn get_first_record(&self, file_id: u16) -> io::Result<FirstRecord> {
+        let data_file_path = self.ledger.get_data_file_path(file_id);
+        let data_file = match self
+            .ledger
+            .filesystem()
+            .open_file_readable(&data_file_path)
+            .await
+        {
+            Ok(data_file) => data_file,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(FirstRecord::None),
+            Err(e) => return Err(e),
+        };
+
+        let mut reader = RecordReader::<_, T>::new(data_file, self.config.cipher.clone());
+        match reader.try_next_record(true).await {
+            Ok(Some(token)) => Ok(FirstRecord::Id(token.record_id())),
+            Ok(None) => Ok(FirstRecord::None),
+            Err(ReaderError::Io { source }) => Err(source),
+            Err(error) => {
+                warn!(
+                    data_file_path = data_file_path.to_string_lossy().as_ref(),
+                    %error,
+                    "Failed to read the first record of a data file, which can't be evicted."
+                );
+                Ok(FirstRecord::Unreadable)
+            }
+        }
+    }
+
+    /// Evicts the oldest data file that the reader has not yet started reading.
+    ///
+    /// The events in the evicted data file are accounted for as evicted, rather than lost, so that
+    /// the reader can tell the difference when it skips over them.  If there was no data file that
+    /// could be evicted, `false` is returned.
+    #[instrument(skip(self), level = "debug")]
+    async fn evict_oldest_data_file(&mut self) -> io::Result<bool> {
+        // Make sure everything we've written so far is visible in the data files, and in the ledger,
+        // so that we can figure out how many events are in the data file we evict.
+        self.flush_inner(false).await?;
+        self.flush_write_state();
+
+        let _data_files_guard = self.ledger.lock_data_files().await;
+        let (reader_file_id, writer_file_id) = self.ledger.get_current_reader_writer_file_id();
+
+        // The reader may be in the middle of the data file it's on, and the writer is still writing
+        // to its own, so only the data files in between are up for eviction.
+        if reader_file_id == writer_file_id {
+            return Ok(false);
+        }
+
+        let mut file_id = (reader_file_id + 1) % MAX_FILE_ID;
+        let mut candidate = None;
+        while file_id != writer_file_id {
+            if let FirstRecord::Id(first_record_id) = self.get_first_record(file_id).await? {
+                candidate = Some((file_id, first_record_id));
+                break;
+            }
+            file_id = (file_id + 1) % MAX_FILE_ID;
+        }
+
+        let (evicted_file_id, first_record_id) = match candidate {
+            Some(candidate) => candidate,
+            None => return Ok(false),
+        };
+
+        // The number of events in the data file is the difference between its first record ID and
+        // the first record ID of the data file that comes after it.
+        let mut next_record_id = None;
+        let mut file_id = (evicted_file_id + 1) % MAX_FILE_ID;
+        loop {
+            match self.get_first_record(file_id).await? {
+                FirstRecord::Id(record_id) => {
+                    next_record_id = Some(record_id);
+                    break;
+                }
+                // The number of events in the data file can't be known without the next one.
+                FirstRecord::Unreadable => return Ok(false),
+                FirstRecord::None => {}
+            }
+            if file_id == writer_file_id {
+                break;
+            }
+            file_id = (file_id + 1) % MAX_FILE_ID;
+        }
+        let next_record_id =
+            next_record_id.unwrap_or_else(|| self.ledger.state().get_next_writer_record_id());
+        let evicted_events = next_record_id.wrapping_sub(first_record_id);
+
+        let data_file_path = self.ledger.get_data_file_path(evicted_file_id);
+        let data_file = self
+            .ledger
+            .filesystem()
+            .open_file_readable(&data_file_path)
+            .await?;
+        let data_file_size = data_file.metadata().await?.len();
+        drop(data_file);
+        self.ledger
+            .filesystem()
+            .delete_file(&data_file_path)
+            .await?;
+
+        debug!(
+            data_file_path = data_file_path.to_string_lossy().as_ref(),
+            evicted_events,
+            data_file_size,
+            "Evicted oldest unread data file to make room for newer records."
+        );
+
+        self.ledger
+            .track_evicted_data_file(evicted_events, data_file_size);
+
+        Ok(true)
+    }
+
     #[instrument(skip(self), level = "debug")]
     async fn flush_inner(&mut self, force_full_flush: bool) -> io::Result<()> {
         // We always flush the `BufWriter` when this is called, but we don't always flush to disk or
@@ -1,4 +1,4 @@
-use std::{error::Error, num::NonZeroUsize};
+use std::{error::Error, num::NonZeroUsize, time::Duration};
 
 use async_trait::async_trait;
 
@@ -6,18 +6,31 @@ use crate::{
     buffer_usage_data::BufferUsageHandle,
     topology::{
         builder::IntoBuffer,
-        channel::{limited, ReceiverAdapter, SenderAdapter},
+        channel::{limited, limited_with_max_age, ReceiverAdapter, SenderAdapter},
     },
     Bufferable,
 };
 
 pub struct MemoryBuffer {
     capacity: NonZeroUsize,
+    max_age: Option<Duration>,
 }
 
 impl MemoryBuffer {
     pub fn new(capacity: NonZeroUsize) -> Self {
-        MemoryBuffer { capacity }
+        MemoryBuffer {
+            capacity,
+            max_age: None,
+        }
+    }
+
+    /// Creates a memory buffer which discards events that have been buffered for longer than
+    /// `max_age` instead of handing them out.
+    pub fn with_max_age(capacity: NonZeroUsize, max_age: Duration) -> Self {
+        MemoryBuffer {
+            capacity,
+            max_age: Some(max_age),
+        }
     }
 }
 
@@ -32,7 +45,10 @@ where
     ) -> Result<(SenderAdapter<T>, ReceiverAdapter<T>), Box<dyn Error + Send + Sync>> {
         usage_handle.set_buffer_limits(None, Some(self.capacity.get()));
 
-        let (tx, rx) = limited(self.capacity.get());
+        let (tx, rx) = match self.max_age {
+            None => limited(self.capacity.get()),
+            Some(max_age) => limited_with_max_age(self.capacity.get(), max_age, usage_handle),
+        };
         Ok((tx.into(), rx.into()))
     }
 }
@@ -3,7 +3,14 @@
 //! as it flows through transforms, being duplicated and merged, and
 //! then report its status when the last copy is delivered or dropped.
 
-use std::{cmp, future::Future, mem, pin::Pin, sync::Arc, task::Poll};
+use std::{
+    cmp,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::Poll,
+};
 
 use crossbeam_utils::atomic::AtomicCell;
 use futures::future::FutureExt;
@@ -86,6 +93,17 @@ impl EventFinalizers {
         }
     }
 
+    /// Updates the status of all event finalizers in the collection, and records `reason` as
+    /// the cause of that status on their underlying batches.
+    ///
+    /// Only the first reason recorded on a batch is kept.
+    pub fn update_status_with_reason(&self, status: EventStatus, reason: &str) {
+        for finalizer in &self.0 {
+            finalizer.update_status(status);
+            finalizer.batch.set_reason(reason);
+        }
+    }
+
     /// Consumes all event finalizers and updates their underlying batches immediately.
     pub fn update_sources(&mut self) {
         let finalizers = mem::take(&mut self.0);
@@ -157,15 +175,17 @@ impl Drop for EventFinalizer {
     }
 }
 
-/// A convenience newtype wrapper for the one-shot receiver for an
-/// individual batch status.
+/// A convenience wrapper for the one-shot receiver for an individual batch status.
 #[pin_project::pin_project]
-pub struct BatchStatusReceiver(oneshot::Receiver<BatchStatus>);
+pub struct BatchStatusReceiver {
+    receiver: oneshot::Receiver<BatchStatus>,
+    reason: Arc<Mutex<Option<String>>>,
+}
 
 impl Future for BatchStatusReceiver {
     type Output = BatchStatus;
     fn poll(mut self: Pin<&mut Self>, ctx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
-        match self.0.poll_unpin(ctx) {
+        match self.receiver.poll_unpin(ctx) {
             Poll::Pending => Poll::Pending,
             Poll::Ready(Ok(status)) => Poll::Ready(status),
             Poll::Ready(Err(error)) => {
@@ -184,7 +204,18 @@ impl BatchStatusReceiver {
     /// - `TryRecvError::Empty` if no value has been sent yet.
     /// - `TryRecvError::Closed` if the sender has dropped without sending a value.
     pub fn try_recv(&mut self) -> Result<BatchStatus, oneshot::error::TryRecvError> {
-        self.0.try_recv()
+        self.receiver.try_recv()
+    }
+
+    /// Returns the reason recorded for the status of the batch, if any.
+    ///
+    /// The reason is only complete once the batch status has been received.
+    #[must_use]
+    pub fn reason(&self) -> Option<String> {
+        self.reason
+            .lock()
+            .expect("batch reason lock poisoned")
+            .clone()
     }
 }
 
@@ -199,11 +230,16 @@ impl BatchNotifier {
     #[must_use]
     pub fn new_with_receiver() -> (Self, BatchStatusReceiver) {
         let (sender, receiver) = oneshot::channel();
+        let reason = Arc::default();
         let notifier = OwnedBatchNotifier {
             status: AtomicCell::new(BatchStatus::Delivered),
             notifier: Some(sender),
+            reason: Arc::clone(&reason),
         };
-        (Self(Arc::new(notifier)), BatchStatusReceiver(receiver))
+        (
+            Self(Arc::new(notifier)),
+            BatchStatusReceiver { receiver, reason },
+        )
     }
 
     /// Optionally creates a new `BatchNotifier` along with the receiver used to await its finalization status.
@@ -250,6 +286,14 @@ impl BatchNotifier {
                 .unwrap_or_else(|_| unreachable!());
         }
     }
+
+    /// Records the reason for the status of the notifier, unless one was already recorded.
+    fn set_reason(&self, reason: &str) {
+        let mut current = self.0.reason.lock().expect("batch reason lock poisoned");
+        if current.is_none() {
+            *current = Some(reason.to_string());
+        }
+    }
 }
 
 /// The non-shared data underlying the shared `BatchNotifier`
@@ -257,6 +301,7 @@ impl BatchNotifier {
 pub struct OwnedBatchNotifier {
     status: AtomicCell<BatchStatus>,
     notifier: Option<oneshot::Sender<BatchStatus>>,
+    reason: Arc<Mutex<Option<String>>>,
 }
 
 impl OwnedBatchNotifier {
@@ -428,6 +473,16 @@ mod tests {
         assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
     }
 
+    #[test]
+    fn records_first_reason() {
+        let (mut fin, mut receiver) = make_finalizer();
+        fin.update_status_with_reason(EventStatus::Rejected, "first");
+        fin.update_status_with_reason(EventStatus::Rejected, "second");
+        fin.update_sources();
+        assert_eq!(receiver.try_recv(), Ok(BatchStatus::Rejected));
+        assert_eq!(receiver.reason().as_deref(), Some("first"));
+    }
+
     #[test]
     fn clone_events() {
         let (fin1, mut receiver) = make_finalizer();
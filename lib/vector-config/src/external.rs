@@ -80,3 +80,21 @@ impl Configurable for no_proxy::NoProxy {
         schema
     }
 }
+
+impl Configurable for url::Url {
+    fn referenceable_name() -> Option<&'static str> {
+        Some("url::Url")
+    }
+
+    fn description() -> Option<&'static str> {
+        Some("A uniform resource locator (URL).")
+    }
+
+    fn generate_schema(gen: &mut SchemaGenerator, overrides: Metadata<Self>) -> SchemaObject {
+        // TODO: `url` parses URLs according to the WHATWG URL Standard, which doesn't map cleanly to a
+        // validation pattern, so we just describe a string.
+        let mut schema = generate_string_schema();
+        finalize_schema(gen, &mut schema, overrides);
+        schema
+    }
+}
@@ -78,8 +78,8 @@
 // stash metadata in the extensions for each field the same as we do for structs.
 //
 // TODO: Add support for single value metadata entries, in addition to key/value, such that for things like field metadata, we
-// can essentially define flags i.e. `docs:templateable` as a metadata value for marking a field as working with
-// Vector's template syntax, since doing `templateable = true` is weird given that we never otherwise specifically
+// can essentially define flags i.e. `docs:templatable` as a metadata value for marking a field as working with
+// Vector's template syntax, since doing `templatable = true` is weird given that we never otherwise specifically
 // disable it. In other words, we want a way to define feature flags in metadata.
 //
 // TODO: Should we add a way, and/or make it the default, that if you only supply a description of a
@@ -358,7 +358,7 @@ where
 
     let schema = T::generate_schema(&mut schema_gen, Metadata::default());
     RootSchema {
-        meta_schema: None,
+        meta_schema: schema_gen.settings().meta_schema.clone(),
         schema,
         definitions: schema_gen.take_definitions(),
     }
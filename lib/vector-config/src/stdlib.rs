@@ -3,7 +3,7 @@ use std::{
     net::SocketAddr,
     num::{
         NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64,
-        NonZeroU8,
+        NonZeroU8, NonZeroUsize,
     },
     path::PathBuf,
 };
@@ -75,6 +75,18 @@ impl Configurable for String {
     }
 }
 
+impl Configurable for char {
+    fn generate_schema(gen: &mut SchemaGenerator, overrides: Metadata<Self>) -> SchemaObject {
+        // A `char` is (de)serialized as a string holding exactly one character.
+        let mut schema = generate_string_schema();
+        let string = schema.string();
+        string.min_length = Some(1);
+        string.max_length = Some(1);
+        finalize_schema(gen, &mut schema, overrides);
+        schema
+    }
+}
+
 // Numbers.
 macro_rules! impl_configuable_numeric {
 	($($ty:ty),+) => {
@@ -95,6 +107,7 @@ impl_configuable_numeric!(
     u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, NonZeroU8, NonZeroU16,
     NonZeroU32, NonZeroU64, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64
 );
+impl_configuable_numeric!(NonZeroUsize);
 
 // Arrays and maps.
 impl<T> Configurable for Vec<T>
@@ -184,7 +184,7 @@ pub struct SimpleSinkConfig {
     #[serde(default = "default_simple_sink_encoding")]
     encoding: Encoding,
     /// The filepath to write the events to.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     output_path: Template,
     /// The tags to apply to each event.
     #[configurable(validation(length(max = 32)))]
@@ -233,7 +233,7 @@ pub struct AdvancedSinkConfig {
     #[configurable(derived)]
     tls: Option<TlsEnablableConfig>,
     /// The partition key to use for each event.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     #[serde(default = "default_partition_key")]
     partition_key: String,
     /// The tags to apply to each event.
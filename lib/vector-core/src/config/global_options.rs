@@ -1,7 +1,7 @@
-use serde::{Deserialize, Serialize};
 use snafu::{ResultExt, Snafu};
 use std::{fs::DirBuilder, path::PathBuf};
 use vector_common::TimeZone;
+use vector_config::configurable_component;
 
 use super::{proxy::ProxyConfig, AcknowledgementsConfig, LogSchema};
 use crate::serde::bool_or_struct;
@@ -27,18 +27,34 @@ pub(crate) enum DataDirError {
     },
 }
 
+/// Global configuration options.
+//
 // If this is modified, make sure those changes are reflected in the `ConfigBuilder::append` function!
-#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq)]
 #[serde(default)]
 pub struct GlobalOptions {
+    /// The directory used for persisting Vector state, such as on-disk buffers, file checkpoints, and more.
+    ///
+    /// Vector must have write permissions to this directory.
     #[serde(default = "crate::default_data_dir")]
     pub data_dir: Option<PathBuf>,
+
+    #[configurable(derived)]
     #[serde(skip_serializing_if = "crate::serde::skip_serializing_if_default")]
     pub log_schema: LogSchema,
+
+    /// The name of the time zone to apply to timestamp conversions that do not contain an explicit time zone.
+    ///
+    /// This can be overridden on a per-component basis.
     #[serde(skip_serializing_if = "crate::serde::skip_serializing_if_default")]
     pub timezone: TimeZone,
+
+    #[configurable(derived)]
     #[serde(skip_serializing_if = "crate::serde::skip_serializing_if_default")]
     pub proxy: ProxyConfig,
+
+    #[configurable(derived)]
     #[serde(
         default,
         deserialize_with = "bool_or_struct",
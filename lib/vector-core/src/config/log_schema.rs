@@ -1,5 +1,5 @@
 use once_cell::sync::{Lazy, OnceCell};
-use serde::{Deserialize, Serialize};
+use vector_config::configurable_component;
 
 static LOG_SCHEMA: OnceCell<LogSchema> = OnceCell::new();
 static LOG_SCHEMA_DEFAULT: Lazy<LogSchema> = Lazy::new(LogSchema::default);
@@ -34,17 +34,30 @@ pub fn log_schema() -> &'static LogSchema {
     LOG_SCHEMA.get().unwrap_or(&LOG_SCHEMA_DEFAULT)
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Log schema.
+///
+/// The names of the fields that hold the standard properties of log events.
+#[configurable_component]
+#[derive(Debug, Clone, PartialEq)]
 #[serde(default)]
 pub struct LogSchema {
+    /// The name of the event field to treat as the event message.
     #[serde(default = "LogSchema::default_message_key")]
     message_key: String,
+
+    /// The name of the event field to treat as the event timestamp.
     #[serde(default = "LogSchema::default_timestamp_key")]
     timestamp_key: String,
+
+    /// The name of the event field to treat as the host which sent the message.
     #[serde(default = "LogSchema::default_host_key")]
     host_key: String,
+
+    /// The name of the event field to set the source identifier in.
     #[serde(default = "LogSchema::default_source_type_key")]
     source_type_key: String,
+
+    /// The name of the event field to set the event metadata in.
     #[serde(default = "LogSchema::default_metadata_key")]
     metadata_key: String,
 }
@@ -22,6 +22,11 @@ pub trait DriverResponse {
     fn bytes_sent(&self) -> Option<BytesSent> {
         None
     }
+
+    /// The reason the events of the request were not delivered, if the service provided one.
+    fn rejection_reason(&self) -> Option<String> {
+        None
+    }
 }
 
 /// Drives the interaction between a stream of items and a service which processes them
@@ -169,11 +174,17 @@ where
         match result {
             Err(error) => {
                 error!(message = "Service call failed.", ?error, request_id);
-                finalizers.update_status(EventStatus::Rejected);
+                finalizers
+                    .update_status_with_reason(EventStatus::Rejected, &format!("{:?}", error));
             }
             Ok(response) => {
                 trace!(message = "Service call succeeded.", request_id);
-                finalizers.update_status(response.event_status());
+                match response.rejection_reason() {
+                    Some(reason) => {
+                        finalizers.update_status_with_reason(response.event_status(), &reason);
+                    }
+                    None => finalizers.update_status(response.event_status()),
+                }
                 if response.event_status() == EventStatus::Delivered {
                     if let Some(bytes_sent) = response.bytes_sent() {
                         emit(bytes_sent);
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use openssl::{memcmp, sha::sha256};
+use warp::{
+    http::{
+        header::{AUTHORIZATION, WWW_AUTHENTICATE},
+        HeaderMap, StatusCode,
+    },
+    reject::Reject,
+    Filter, Rejection, Reply,
+};
+
+use crate::http::Auth;
+
+/// Rejection for requests that didn't present valid credentials.
+#[derive(Debug)]
+struct Unauthorized {
+    challenge: &'static str,
+}
+
+impl Reject for Unauthorized {}
+
+/// Credentials accepted by the GraphQL endpoints, checked against the `Authorization` header
+/// of each request, including the WebSocket upgrade request.
+#[derive(Clone, Debug, Default)]
+pub(super) struct Credentials {
+    accepted: Arc<Vec<String>>,
+    challenge: &'static str,
+}
+
+impl Credentials {
+    /// Builds the credentials from `api.auth`. The mutation token is accepted in place of the
+    /// configured credentials, since it grants a superset of their access. Without `api.auth`,
+    /// every request is accepted.
+    pub(super) fn new(auth: Option<&Auth>, auth_token: Option<&str>) -> Self {
+        let auth = match auth {
+            Some(auth) => auth,
+            None => return Self::default(),
+        };
+
+        let challenge = match auth {
+            Auth::Basic { .. } => "Basic realm=\"Vector\"",
+            Auth::Bearer { .. } => "Bearer",
+        };
+
+        let mut map = HeaderMap::new();
+        auth.apply_headers_map(&mut map);
+        let accepted = map
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .into_iter()
+            .chain(auth_token.map(|token| format!("Bearer {}", token)))
+            .collect();
+
+        Self {
+            accepted: Arc::new(accepted),
+            challenge,
+        }
+    }
+
+    fn is_valid(&self, header: Option<&str>) -> bool {
+        self.accepted.is_empty()
+            || header.map_or(false, |header| {
+                self.accepted
+                    .iter()
+                    .any(|accepted| credentials_eq(accepted, header))
+            })
+    }
+
+    /// Filter rejecting requests that don't present any of the accepted credentials.
+    pub(super) fn filter(self) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+        warp::header::optional::<String>("authorization")
+            .and_then(move |header: Option<String>| {
+                let result = if self.is_valid(header.as_deref()) {
+                    Ok(())
+                } else {
+                    Err(warp::reject::custom(Unauthorized {
+                        challenge: self.challenge,
+                    }))
+                };
+                async move { result }
+            })
+            .untuple_one()
+    }
+}
+
+/// Compares credentials in constant time, so that the time taken doesn't reveal how much of the
+/// expected credentials a client guessed right. Both sides are hashed first, so that their length
+/// isn't revealed either.
+pub(super) fn credentials_eq(expected: &str, presented: &str) -> bool {
+    memcmp::eq(&sha256(expected.as_bytes()), &sha256(presented.as_bytes()))
+}
+
+/// Replies with `401 Unauthorized` to requests rejected by [`Credentials::filter`].
+pub(super) async fn handle_rejection(rejection: Rejection) -> Result<impl Reply, Rejection> {
+    match rejection.find::<Unauthorized>() {
+        Some(Unauthorized { challenge }) => Ok(warp::reply::with_header(
+            warp::reply::with_status("Unauthorized", StatusCode::UNAUTHORIZED),
+            WWW_AUTHENTICATE,
+            *challenge,
+        )),
+        None => Err(rejection),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_credentials() {
+        assert!(credentials_eq("Bearer secret", "Bearer secret"));
+        assert!(!credentials_eq("Bearer secret", "Bearer secreT"));
+        assert!(!credentials_eq("Bearer secret", "Bearer secret2"));
+        assert!(!credentials_eq("Bearer secret", ""));
+    }
+
+    #[test]
+    fn accepts_everything_without_auth() {
+        let credentials = Credentials::new(None, Some("token"));
+
+        assert!(credentials.is_valid(None));
+        assert!(credentials.is_valid(Some("Bearer other")));
+    }
+
+    #[test]
+    fn accepts_basic_auth_and_token() {
+        let auth = Auth::Basic {
+            user: "user".to_string(),
+            password: "password".to_string(),
+        };
+        let credentials = Credentials::new(Some(&auth), Some("token"));
+
+        assert!(credentials.is_valid(Some("Basic dXNlcjpwYXNzd29yZA==")));
+        assert!(credentials.is_valid(Some("Bearer token")));
+        assert!(!credentials.is_valid(Some("Basic dXNlcjpvdGhlcg==")));
+        assert!(!credentials.is_valid(None));
+    }
+
+    #[test]
+    fn accepts_bearer_auth() {
+        let auth = Auth::Bearer {
+            token: "secret".to_string(),
+        };
+        let credentials = Credentials::new(Some(&auth), None);
+
+        assert!(credentials.is_valid(Some("Bearer secret")));
+        assert!(!credentials.is_valid(Some("Bearer token")));
+    }
+}
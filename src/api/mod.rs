@@ -1,3 +1,4 @@
+mod auth;
 mod handler;
 mod schema;
 mod server;
@@ -0,0 +1,140 @@
+use async_graphql::{Context, Guard, Object};
+
+use crate::{
+    api::auth::credentials_eq,
+    config::ComponentKey,
+    topology::{CommandRequest, CommandTx, TopologyCommand},
+};
+
+/// The token configured with `api.auth_token`, required to run mutations.
+#[derive(Debug, Clone, Default)]
+pub struct AuthToken(pub Option<String>);
+
+/// The bearer token presented by a client in its `Authorization` header.
+#[derive(Debug, Clone, Default)]
+pub struct Bearer(pub Option<String>);
+
+impl Bearer {
+    /// Extracts the token from the value of an `Authorization` header.
+    pub fn from_header(header: Option<&str>) -> Self {
+        Self(
+            header
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .map(|token| token.trim().to_string()),
+        )
+    }
+}
+
+/// Guards mutations, and queries exposing the configuration, behind the configured auth token.
+/// Without one, they are disabled.
+pub(super) struct TokenGuard;
+
+#[async_trait::async_trait]
+impl Guard for TokenGuard {
+    async fn check(&self, ctx: &Context<'_>) -> async_graphql::Result<()> {
+        let expected = ctx
+            .data_opt::<AuthToken>()
+            .and_then(|token| token.0.as_deref());
+        let presented = ctx
+            .data_opt::<Bearer>()
+            .and_then(|bearer| bearer.0.as_deref());
+
+        match (expected, presented) {
+            (None, _) => {
+                Err("Disabled without `api.auth_token`. Set it to enable this field.".into())
+            }
+            (Some(expected), Some(presented)) if credentials_eq(expected, presented) => Ok(()),
+            _ => Err("Invalid or missing bearer token.".into()),
+        }
+    }
+}
+
+/// Sends a command to the running topology, and waits for it to be applied.
+async fn send_command(ctx: &Context<'_>, command: TopologyCommand) -> async_graphql::Result<bool> {
+    let (request, reply) = CommandRequest::new(command);
+
+    ctx.data::<CommandTx>()?
+        .send(request)
+        .map_err(|_| "The topology is not running.")?;
+    reply
+        .await
+        .map_err(|_| "The topology stopped before applying the command.")??;
+
+    Ok(true)
+}
+
+#[derive(Default)]
+pub struct ControlMutation;
+
+#[Object]
+impl ControlMutation {
+    /// Pauses a source. Its events are held back until it is resumed or the configuration is reloaded
+    #[graphql(guard = "TokenGuard")]
+    async fn pause_source(
+        &self,
+        ctx: &Context<'_>,
+        component_id: String,
+    ) -> async_graphql::Result<bool> {
+        send_command(
+            ctx,
+            TopologyCommand::PauseSource(ComponentKey::from(component_id)),
+        )
+        .await
+    }
+
+    /// Resumes a paused source
+    #[graphql(guard = "TokenGuard")]
+    async fn resume_source(
+        &self,
+        ctx: &Context<'_>,
+        component_id: String,
+    ) -> async_graphql::Result<bool> {
+        send_command(
+            ctx,
+            TopologyCommand::ResumeSource(ComponentKey::from(component_id)),
+        )
+        .await
+    }
+
+    /// Drains a sink. It delivers the events it already holds, but isn't sent new events until it is undrained or the configuration is reloaded
+    #[graphql(guard = "TokenGuard")]
+    async fn drain_sink(
+        &self,
+        ctx: &Context<'_>,
+        component_id: String,
+    ) -> async_graphql::Result<bool> {
+        send_command(
+            ctx,
+            TopologyCommand::DrainSink(ComponentKey::from(component_id)),
+        )
+        .await
+    }
+
+    /// Undrains a drained sink, so that it is sent new events again
+    #[graphql(guard = "TokenGuard")]
+    async fn undrain_sink(
+        &self,
+        ctx: &Context<'_>,
+        component_id: String,
+    ) -> async_graphql::Result<bool> {
+        send_command(
+            ctx,
+            TopologyCommand::UndrainSink(ComponentKey::from(component_id)),
+        )
+        .await
+    }
+
+    /// Reloads the configuration from disk. Returns `true` once the reload has been scheduled
+    #[graphql(guard = "TokenGuard")]
+    async fn reload_config(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        send_command(ctx, TopologyCommand::Reload).await
+    }
+
+    /// Sets the log level filter, using the same syntax as the `VECTOR_LOG` environment variable
+    #[graphql(guard = "TokenGuard")]
+    async fn set_log_level(&self, level: String) -> async_graphql::Result<bool> {
+        crate::trace::set_log_levels(&level)?;
+
+        Ok(true)
+    }
+}
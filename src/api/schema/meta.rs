@@ -1,4 +1,24 @@
+use std::sync::RwLock;
+
 use async_graphql::Object;
+use once_cell::sync::Lazy;
+
+use super::control::TokenGuard;
+use crate::config::{Config, ConfigSnapshot};
+
+/// Snapshot of the running configuration, serialized to JSON.
+static CONFIG_SNAPSHOT: Lazy<RwLock<Option<String>>> = Lazy::new(|| RwLock::new(None));
+
+/// Takes a snapshot of the configuration the API is serving, so that other configurations can be
+/// compared with it.
+pub fn update_config(config: &Config) {
+    let snapshot = serde_json::to_string(&ConfigSnapshot::new(config))
+        .expect("config snapshot should serialize to JSON. Please report.");
+    *CONFIG_SNAPSHOT
+        .write()
+        .expect("Couldn't acquire lock on the config snapshot. Please report this.") =
+        Some(snapshot);
+}
 
 #[derive(Default)]
 pub struct Meta;
@@ -14,6 +34,16 @@ impl Meta {
     async fn hostname(&self) -> Option<String> {
         crate::get_hostname().ok()
     }
+
+    /// JSON snapshot of the running configuration, used to compare it with another configuration.
+    /// Requires the bearer token configured with `api.auth_token`
+    #[graphql(guard = "TokenGuard")]
+    async fn config_snapshot(&self) -> Option<String> {
+        CONFIG_SNAPSHOT
+            .read()
+            .expect("Couldn't acquire lock on the config snapshot. Please report this.")
+            .clone()
+    }
 }
 
 #[derive(Default)]
@@ -12,8 +12,16 @@ use async_graphql_warp::{graphql_protocol, GraphQLResponse, GraphQLWebSocket};
 use tokio::sync::oneshot;
 use warp::{filters::BoxedFilter, http::Response, ws::Ws, Filter, Reply};
 
-use super::{handler, schema, ShutdownTx};
-use crate::{config, topology};
+use super::{
+    auth::{self, Credentials},
+    handler,
+    schema::{
+        self,
+        control::{AuthToken, Bearer},
+    },
+    ShutdownTx,
+};
+use crate::{config, tls::MaybeTlsSettings, topology};
 
 pub struct Server {
     _shutdown: ShutdownTx,
@@ -21,18 +29,33 @@ pub struct Server {
 }
 
 impl Server {
-    /// Start the API server. This creates the routes and spawns a Warp server. The server is
-    /// gracefully shut down when Self falls out of scope by way of the oneshot sender closing.
-    pub fn start(
+    /// Start the API server. This creates the routes and spawns a Warp server, serving over TLS
+    /// if configured. The server is gracefully shut down when Self falls out of scope by way of
+    /// the oneshot sender closing.
+    pub async fn start(
         config: &config::Config,
         watch_rx: topology::WatchRx,
         running: Arc<AtomicBool>,
-    ) -> Self {
-        let routes = make_routes(config.api.playground, watch_rx, running);
+        command_tx: topology::CommandTx,
+    ) -> crate::Result<Self> {
+        let routes = make_routes(
+            config.api.playground,
+            Credentials::new(config.api.auth.as_ref(), config.api.auth_token.as_deref()),
+            AuthToken(config.api.auth_token.clone()),
+            watch_rx,
+            running,
+            command_tx,
+        );
+
+        let tls = MaybeTlsSettings::from_config(&config.api.tls, true)?;
+        let listener = tls
+            .bind(&config.api.address.expect("No socket address"))
+            .await?;
+        let addr = listener.local_addr()?;
 
         let (_shutdown, rx) = oneshot::channel();
-        let (addr, server) = warp::serve(routes).bind_with_graceful_shutdown(
-            config.api.address.expect("No socket address"),
+        let server = warp::serve(routes).serve_incoming_with_graceful_shutdown(
+            listener.accept_stream(),
             async {
                 rx.await.ok();
             },
@@ -40,11 +63,12 @@ impl Server {
 
         // Update component schema with the config before starting the server.
         schema::components::update_config(config);
+        schema::meta::update_config(config);
 
         // Spawn the server in the background.
         tokio::spawn(server);
 
-        Self { _shutdown, addr }
+        Ok(Self { _shutdown, addr })
     }
 
     /// Returns a copy of the SocketAddr that the server was started on.
@@ -56,15 +80,28 @@ impl Server {
     /// directly involve `self`, it provides a neater API to expose an internal implementation
     /// detail than exposing the function of the sub-mod directly.
     pub fn update_config(&self, config: &config::Config) {
-        schema::components::update_config(config)
+        schema::components::update_config(config);
+        schema::meta::update_config(config);
     }
 }
 
 fn make_routes(
     playground: bool,
+    credentials: Credentials,
+    auth_token: AuthToken,
     watch_tx: topology::WatchRx,
     running: Arc<AtomicBool>,
+    command_tx: topology::CommandTx,
 ) -> BoxedFilter<(impl Reply,)> {
+    // Mutations are applied to the running topology through the command channel, and are only
+    // allowed for requests presenting the configured auth token.
+    let build_schema = move || {
+        schema::build_schema()
+            .data(auth_token.clone())
+            .data(command_tx.clone())
+            .finish()
+    };
+
     // Routes...
 
     // Health.
@@ -79,16 +116,20 @@ fn make_routes(
     // parses the required headers for GraphQL and builds per-connection context based on the
     // provided `WatchTx` channel sender. This allows GraphQL resolvers to subscribe to
     // topology changes.
-    let graphql_subscription_handler =
-        warp::ws()
-            .and(graphql_protocol())
-            .map(move |ws: Ws, protocol: WebSocketProtocols| {
-                let schema = schema::build_schema().finish();
+    let ws_schema = build_schema.clone();
+    let graphql_subscription_handler = warp::ws()
+        .and(graphql_protocol())
+        .and(warp::header::optional::<String>("authorization"))
+        .map(
+            move |ws: Ws, protocol: WebSocketProtocols, authorization: Option<String>| {
+                let schema = ws_schema();
                 let watch_tx = watch_tx.clone();
+                let bearer = Bearer::from_header(authorization.as_deref());
 
                 let reply = ws.on_upgrade(move |socket| {
                     let mut data = Data::default();
                     data.insert(watch_tx);
+                    data.insert(bearer);
 
                     GraphQLWebSocket::new(socket, schema, protocol)
                         .with_data(data)
@@ -100,22 +141,29 @@ fn make_routes(
                     "Sec-WebSocket-Protocol",
                     protocol.sec_websocket_protocol(),
                 )
-            });
+            },
+        );
 
     // Handle GraphQL queries. Headers will first be parsed to determine whether the query is
     // a subscription and if so, an attempt will be made to upgrade the connection to WebSockets.
     // All other queries will fall back to the default HTTP handler.
-    let graphql_handler = warp::path("graphql").and(graphql_subscription_handler.or(
-        async_graphql_warp::graphql(schema::build_schema().finish()).and_then(
-            |(schema, request): (Schema<_, _, _>, Request)| async move {
-                Ok::<_, Infallible>(GraphQLResponse::from(schema.execute(request).await))
-            },
-        ),
-    ));
+    let graphql_handler =
+        warp::path("graphql").and(credentials.clone().filter()).and(
+            graphql_subscription_handler.or(async_graphql_warp::graphql(build_schema())
+                .and(warp::header::optional::<String>("authorization"))
+                .and_then(
+                    |(schema, request): (Schema<_, _, _>, Request),
+                     authorization: Option<String>| async move {
+                        let request = request.data(Bearer::from_header(authorization.as_deref()));
+                        Ok::<_, Infallible>(GraphQLResponse::from(schema.execute(request).await))
+                    },
+                )),
+        );
 
     // Provide a playground for executing GraphQL queries/mutations/subscriptions.
     let graphql_playground = if playground {
         warp::path("playground")
+            .and(credentials.filter())
             .map(move || {
                 Response::builder()
                     .header("content-type", "text/html")
@@ -129,11 +177,13 @@ fn make_routes(
     };
 
     // Wire up the health + GraphQL endpoints. Provides a permissive CORS policy to allow for
-    // cross-origin interaction with the Vector API.
+    // cross-origin interaction with the Vector API. The health endpoint doesn't require
+    // credentials, so that it can be used for liveness checks.
     health
         .or(graphql_handler)
         .or(graphql_playground)
         .or(not_found)
+        .recover(auth::handle_rejection)
         .with(
             warp::cors()
                 .allow_any_origin()
@@ -146,6 +196,7 @@ fn make_routes(
                     "Access-Control-Allow-Origin",
                     "Access-Control-Request-Headers",
                     "Content-Type",
+                    "Authorization",
                     "X-Apollo-Tracing", // for Apollo GraphQL clients
                     "Pragma",
                     "Host",
@@ -22,11 +22,12 @@ use crate::service;
 #[cfg(feature = "api")]
 use crate::{api, internal_events::ApiStarted};
 use crate::{
+    buffer,
     cli::{handle_config_errors, Color, LogFormat, Opts, RootOpts, SubCommand},
     config::{self},
-    generate, graph, heartbeat, list,
+    generate, generate_schema, graph, heartbeat, list,
     signal::{self, SignalTo},
-    topology::{self, RunningTopology},
+    topology::{self, CommandRequest, RunningTopology, TopologyCommand},
     trace, unit_test, validate,
 };
 #[cfg(feature = "api-client")]
@@ -145,8 +146,10 @@ impl Application {
                 if let Some(s) = sub_command {
                     let code = match s {
                         SubCommand::Generate(g) => generate::cmd(&g),
+                        SubCommand::GenerateSchema(g) => generate_schema::cmd(&g),
                         SubCommand::Graph(g) => graph::cmd(&g),
-                        SubCommand::Config(c) => config::cmd(&c),
+                        SubCommand::Buffer(b) => buffer::cmd(&b).await,
+                        SubCommand::Config(c) => config::cmd(&c).await,
                         SubCommand::List(l) => list::cmd(&l),
                         SubCommand::Test(t) => unit_test::cmd(&t, &mut signal_handler).await,
                         #[cfg(windows)]
@@ -221,7 +224,7 @@ impl Application {
                     .ok_or(exitcode::CONFIG)?;
 
                 #[cfg(feature = "api")]
-                let api = config.api;
+                let api = config.api.clone();
 
                 let result = topology::start_validated(config, diff, pieces).await;
                 let (topology, graceful_crash) = result.ok_or(exitcode::CONFIG)?;
@@ -274,6 +277,9 @@ impl Application {
             emit!(VectorStarted);
             tokio::spawn(heartbeat::heartbeat());
 
+            // Commands sent by the API to control the running topology.
+            let (command_tx, mut command_rx) = tokio::sync::mpsc::unbounded_channel::<CommandRequest>();
+
             // Configure the API server, if applicable.
             #[cfg(feature = "api")]
             // Assigned to prevent the API terminating when falling out of scope.
@@ -281,15 +287,25 @@ impl Application {
                 use std::sync::{Arc, atomic::AtomicBool};
                 emit!(ApiStarted {
                     addr: api_config.address.unwrap(),
-                    playground: api_config.playground
+                    playground: api_config.playground,
+                    tls: api_config.tls.as_ref().and_then(|tls| tls.enabled).unwrap_or(false),
                 });
 
-                Some(api::Server::start(topology.config(), topology.watch(), Arc::<AtomicBool>::clone(&topology.running)))
+                match api::Server::start(topology.config(), topology.watch(), Arc::<AtomicBool>::clone(&topology.running), command_tx.clone()).await {
+                    Ok(api_server) => Some(api_server),
+                    Err(error) => {
+                        error!(message = "Failed to start the API server.", %error);
+                        None
+                    }
+                }
             } else {
                 info!(message="API is disabled, enable by setting `api.enabled` to `true` and use commands like `vector top`.");
                 None
             };
 
+            // Only the API holds on to the command channel.
+            drop(command_tx);
+
             let mut sources_finished = topology.sources_finished();
 
             let signal = loop {
@@ -403,6 +419,20 @@ impl Application {
                             Ok(signal) => break signal,
                         }
                     }
+                    Some(CommandRequest { command, reply }) = command_rx.recv() => {
+                        let result = match command {
+                            TopologyCommand::PauseSource(key) => topology.pause_source(&key),
+                            TopologyCommand::ResumeSource(key) => topology.resume_source(&key),
+                            TopologyCommand::DrainSink(key) => topology.drain_sink(&key),
+                            TopologyCommand::UndrainSink(key) => topology.undrain_sink(&key),
+                            TopologyCommand::Reload => signal_handler
+                                .clone_tx()
+                                .send(SignalTo::ReloadFromDisk)
+                                .map(|_| ())
+                                .map_err(|_| "Unable to schedule a reload.".to_string()),
+                        };
+                        let _ = reply.send(result);
+                    }
                     // Trigger graceful shutdown if a component crashed, or all sources have ended.
                     _ = graceful_crash.next() => break SignalTo::Shutdown,
                     _ = &mut sources_finished => break SignalTo::Shutdown,
@@ -0,0 +1,438 @@
+//! The `vector buffer` subcommand, to look at disk buffers while Vector isn't running.
+//!
+//! Buffers are only ever read: records are not acknowledged, so inspecting, exporting or replaying
+//! a buffer leaves it as it was found, and Vector picks up from the same place when restarted.
+
+use std::{
+    fmt::{self, Write as _},
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+use clap::Parser;
+use futures::future;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use vector_buffers::{
+    config::EncryptionConfig,
+    inspect::{inspect, BufferReport},
+};
+use vector_common::finalization::AddBatchNotifier;
+use vector_core::event::{BatchNotifier, BatchStatus, Event, EventArray, EventContainer};
+
+use crate::{
+    cli::handle_config_errors,
+    config::{self, ComponentKey, ProxyConfig, SinkContext},
+};
+
+/// Number of event arrays to read ahead of the sink while replaying a buffer.
+const REPLAY_READ_AHEAD: usize = 16;
+
+#[derive(Parser, Debug)]
+#[clap(rename_all = "kebab-case")]
+pub struct Opts {
+    #[clap(subcommand)]
+    sub_command: SubCommand,
+}
+
+#[derive(Parser, Debug)]
+#[clap(rename_all = "kebab-case")]
+enum SubCommand {
+    /// Report the state of a disk buffer: ledger, records, sizes and corruption.
+    Inspect(BufferOpts),
+    /// Write the events stored in a disk buffer as JSON, one per line.
+    Export(ExportOpts),
+    /// Send the events stored in a disk buffer to a sink.
+    Replay(ReplayOpts),
+}
+
+#[derive(Parser, Debug)]
+#[clap(rename_all = "kebab-case")]
+struct BufferOpts {
+    /// Path to the buffer directory.
+    ///
+    /// Disk buffers are stored in `<data_dir>/buffer/v2/<sink ID>`, and `disk_v1` buffers in
+    /// `<data_dir>/<sink ID>_id`. `disk_v1` buffers are copied to a temporary directory and read
+    /// from the copy, since LevelDB locks the databases it opens, which needs as much free space
+    /// as the buffer takes.
+    path: PathBuf,
+
+    /// Files containing the base64-encoded keys used to encrypt the buffer, if it is encrypted.
+    #[clap(long, use_value_delimiter(true))]
+    encryption_key_file: Vec<PathBuf>,
+}
+
+impl BufferOpts {
+    fn encryption(&self) -> Option<EncryptionConfig> {
+        let (key_file, previous_key_files) = self.encryption_key_file.split_first()?;
+        Some(EncryptionConfig {
+            algorithm: Default::default(),
+            key: None,
+            key_file: Some(key_file.clone()),
+            previous_keys: Vec::new(),
+            previous_key_files: previous_key_files.to_vec(),
+        })
+    }
+}
+
+#[derive(Parser, Debug)]
+#[clap(rename_all = "kebab-case")]
+struct ExportOpts {
+    #[clap(flatten)]
+    buffer: BufferOpts,
+
+    /// File to write the events to. Defaults to standard output.
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+#[clap(rename_all = "kebab-case")]
+struct ReplayOpts {
+    #[clap(flatten)]
+    buffer: BufferOpts,
+
+    /// ID of the sink to send the events to.
+    #[clap(long)]
+    sink: String,
+
+    /// Read configuration from one or more files. Wildcard paths are supported.
+    /// File format is detected from the file name.
+    /// If zero files are specified the default config path
+    /// `/etc/vector/vector.toml` will be targeted.
+    #[clap(
+        name = "config",
+        short,
+        long,
+        env = "VECTOR_CONFIG",
+        use_value_delimiter(true)
+    )]
+    paths: Vec<PathBuf>,
+
+    /// Vector config files in TOML format.
+    #[clap(name = "config-toml", long, use_value_delimiter(true))]
+    paths_toml: Vec<PathBuf>,
+
+    /// Vector config files in JSON format.
+    #[clap(name = "config-json", long, use_value_delimiter(true))]
+    paths_json: Vec<PathBuf>,
+
+    /// Vector config files in YAML format.
+    #[clap(name = "config-yaml", long, use_value_delimiter(true))]
+    paths_yaml: Vec<PathBuf>,
+
+    /// Read configuration from files in one or more directories.
+    /// File format is detected from the file name.
+    ///
+    /// Files not ending in .toml, .json, .yaml, or .yml will be ignored.
+    #[clap(
+        name = "config-dir",
+        short = 'C',
+        long,
+        env = "VECTOR_CONFIG_DIR",
+        use_value_delimiter(true)
+    )]
+    config_dirs: Vec<PathBuf>,
+}
+
+impl ReplayOpts {
+    fn paths_with_formats(&self) -> Vec<config::ConfigPath> {
+        config::merge_path_lists(vec![
+            (&self.paths, None),
+            (&self.paths_toml, Some(config::Format::Toml)),
+            (&self.paths_json, Some(config::Format::Json)),
+            (&self.paths_yaml, Some(config::Format::Yaml)),
+        ])
+        .map(|(path, hint)| config::ConfigPath::File(path, hint))
+        .chain(
+            self.config_dirs
+                .iter()
+                .map(|dir| config::ConfigPath::Dir(dir.to_path_buf())),
+        )
+        .collect()
+    }
+}
+
+pub async fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    match &opts.sub_command {
+        SubCommand::Inspect(opts) => inspect_buffer(opts).await,
+        SubCommand::Export(opts) => export(opts).await,
+        SubCommand::Replay(opts) => replay(opts).await,
+    }
+}
+
+async fn inspect_buffer(opts: &BufferOpts) -> exitcode::ExitCode {
+    let encryption = opts.encryption();
+    match inspect::<EventArray, _, _>(&opts.path, encryption.as_ref(), |_| future::ready(())).await
+    {
+        Ok(report) => {
+            #[allow(clippy::print_stdout)]
+            {
+                print!("{}", format_report(&report));
+            }
+            if report.corruptions.is_empty() {
+                exitcode::OK
+            } else {
+                exitcode::DATAERR
+            }
+        }
+        Err(error) => {
+            error!(message = "Failed to inspect buffer.", %error);
+            exitcode::IOERR
+        }
+    }
+}
+
+fn format_report(report: &BufferReport) -> String {
+    let mut out = String::new();
+    write_report(&mut out, report).expect("write to String never fails");
+    out
+}
+
+fn write_report(out: &mut String, report: &BufferReport) -> fmt::Result {
+    writeln!(out, "Buffer type: {:?}", report.kind)?;
+
+    if let Some(ledger) = &report.ledger {
+        writeln!(out, "Ledger:")?;
+        writeln!(
+            out,
+            "  writer next record ID:       {}",
+            ledger.writer_next_record_id
+        )?;
+        writeln!(
+            out,
+            "  writer current data file ID: {}",
+            ledger.writer_current_data_file_id
+        )?;
+        writeln!(
+            out,
+            "  reader current data file ID: {}",
+            ledger.reader_current_data_file_id
+        )?;
+        writeln!(
+            out,
+            "  reader last record ID:       {}",
+            ledger.reader_last_record_id
+        )?;
+        writeln!(
+            out,
+            "  unacknowledged events:       {}",
+            ledger.unread_events()
+        )?;
+    }
+
+    if !report.data_files.is_empty() {
+        writeln!(out, "Data files:")?;
+        for data_file in &report.data_files {
+            writeln!(
+                out,
+                "  {}: {} bytes, {} records ({} events), {} acknowledged records",
+                data_file.path.display(),
+                data_file.size,
+                data_file.records,
+                data_file.events,
+                data_file.acknowledged_records,
+            )?;
+        }
+    }
+
+    writeln!(
+        out,
+        "Unread: {} records, {} events, {} bytes",
+        report.records, report.events, report.bytes
+    )?;
+
+    if report.corruptions.is_empty() {
+        writeln!(out, "Corruption: none")
+    } else {
+        writeln!(out, "Corruption:")?;
+        for corruption in &report.corruptions {
+            writeln!(out, "  {}: {}", corruption.location, corruption.reason)?;
+        }
+        Ok(())
+    }
+}
+
+async fn export(opts: &ExportOpts) -> exitcode::ExitCode {
+    let mut writer: BufWriter<Box<dyn Write>> = match &opts.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => BufWriter::new(Box::new(file)),
+            Err(error) => {
+                error!(message = "Failed to create output file.", path = ?path, %error);
+                return exitcode::CANTCREAT;
+            }
+        },
+        None => BufWriter::new(Box::new(io::stdout())),
+    };
+
+    let encryption = opts.buffer.encryption();
+    let mut write_error = None;
+    let result = inspect::<EventArray, _, _>(
+        &opts.buffer.path,
+        encryption.as_ref(),
+        |events: EventArray| {
+            if write_error.is_none() {
+                write_error = events
+                    .into_events()
+                    .try_for_each(|event| write_event(&mut writer, &event))
+                    .err();
+            }
+            future::ready(())
+        },
+    )
+    .await;
+
+    if let Some(error) = write_error.or_else(|| writer.flush().err()) {
+        error!(message = "Failed to write events.", %error);
+        return exitcode::IOERR;
+    }
+
+    match result {
+        Ok(report) if report.corruptions.is_empty() => exitcode::OK,
+        Ok(report) => {
+            for corruption in report.corruptions {
+                warn!(
+                    message = "Skipped unreadable records.",
+                    location = %corruption.location,
+                    reason = %corruption.reason,
+                );
+            }
+            exitcode::DATAERR
+        }
+        Err(error) => {
+            error!(message = "Failed to export buffer.", %error);
+            exitcode::IOERR
+        }
+    }
+}
+
+fn write_event(writer: &mut impl Write, event: &Event) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, event)?;
+    writer.write_all(b"\n")
+}
+
+async fn replay(opts: &ReplayOpts) -> exitcode::ExitCode {
+    let paths = opts.paths_with_formats();
+    let paths = match config::process_paths(&paths) {
+        Some(paths) => paths,
+        None => return exitcode::CONFIG,
+    };
+    let config = match config::load_from_paths(&paths) {
+        Ok(config) => config,
+        Err(errors) => return handle_config_errors(errors),
+    };
+
+    let key = ComponentKey::from(opts.sink.as_str());
+    let sink = match config.sink(&key) {
+        Some(sink) => sink,
+        None => {
+            error!(message = "Sink not found in configuration.", sink = %key);
+            return exitcode::CONFIG;
+        }
+    };
+
+    // The sink is built the same way the topology would build it, but its healthcheck is skipped, as
+    // there's nothing to fall back to if it fails.
+    let cx = SinkContext {
+        healthcheck: sink.healthcheck(),
+        globals: config.global.clone(),
+        proxy: ProxyConfig::merge_with_env(&config.global.proxy, sink.proxy()),
+        schema: config.schema,
+    };
+    let sink = match sink.inner.build(cx).await {
+        Ok((sink, _healthcheck)) => sink,
+        Err(error) => {
+            error!(message = "Failed to build sink.", sink = %key, %error);
+            return exitcode::CONFIG;
+        }
+    };
+
+    let (tx, rx) = mpsc::channel(REPLAY_READ_AHEAD);
+    let sink = tokio::spawn(sink.run(ReceiverStream::new(rx)));
+
+    // A single batch notifier is attached to all events, so we can tell whether the sink delivered
+    // all of them once it's done.
+    let (batch, status) = BatchNotifier::new_with_receiver();
+    let encryption = opts.buffer.encryption();
+    let result = inspect::<EventArray, _, _>(
+        &opts.buffer.path,
+        encryption.as_ref(),
+        |mut events: EventArray| {
+            events.add_batch_notifier(batch.clone());
+            let tx = tx.clone();
+            async move {
+                // The sink only stops early if it failed, which we report below.
+                let _ = tx.send(events).await;
+            }
+        },
+    )
+    .await;
+    drop((tx, batch));
+
+    let report = match result {
+        Ok(report) => report,
+        Err(error) => {
+            error!(message = "Failed to read buffer.", %error);
+            return exitcode::IOERR;
+        }
+    };
+
+    if !matches!(sink.await, Ok(Ok(()))) {
+        error!(message = "Sink failed while replaying buffer.", sink = %key);
+        return exitcode::SOFTWARE;
+    }
+
+    match status.await {
+        BatchStatus::Delivered => {
+            info!(
+                message = "Replayed buffer.",
+                sink = %key,
+                events = report.events,
+                skipped_records = report.corruptions.len(),
+            );
+            if report.corruptions.is_empty() {
+                exitcode::OK
+            } else {
+                exitcode::DATAERR
+            }
+        }
+        status => {
+            error!(message = "Sink did not deliver all events.", sink = %key, ?status);
+            exitcode::UNAVAILABLE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_buffers::inspect::{BufferKind, Corruption, LedgerReport};
+
+    use super::*;
+
+    #[test]
+    fn formats_report() {
+        let report = BufferReport {
+            kind: BufferKind::DiskV2,
+            ledger: Some(LedgerReport {
+                writer_next_record_id: 11,
+                writer_current_data_file_id: 1,
+                reader_current_data_file_id: 0,
+                reader_last_record_id: 4,
+            }),
+            data_files: Vec::new(),
+            records: 5,
+            events: 6,
+            bytes: 1024,
+            corruptions: vec![Corruption {
+                location: "buffer-data-1.dat".to_string(),
+                reason: "partial write".to_string(),
+            }],
+        };
+
+        let formatted = format_report(&report);
+        assert!(formatted.contains("unacknowledged events:       6\n"));
+        assert!(formatted.contains("Unread: 5 records, 6 events, 1024 bytes\n"));
+        assert!(formatted.contains("  buffer-data-1.dat: partial write\n"));
+    }
+}
@@ -8,7 +8,9 @@ use crate::service;
 use crate::tap;
 #[cfg(feature = "api-client")]
 use crate::top;
-use crate::{config, generate, get_version, graph, list, unit_test, validate};
+use crate::{
+    buffer, config, generate, generate_schema, get_version, graph, list, unit_test, validate,
+};
 
 #[derive(Parser, Debug)]
 #[clap(rename_all = "kebab-case")]
@@ -34,6 +36,7 @@ impl Opts {
             Some(SubCommand::Validate(_))
             | Some(SubCommand::Graph(_))
             | Some(SubCommand::Generate(_))
+            | Some(SubCommand::GenerateSchema(_))
             | Some(SubCommand::List(_))
             | Some(SubCommand::Test(_)) => {
                 if self.root.verbose == 0 {
@@ -170,6 +173,60 @@ impl RootOpts {
     }
 }
 
+/// Credentials and TLS options used by the API client commands to connect to the Vector API server.
+#[cfg(feature = "api-client")]
+#[derive(Parser, Debug, Clone)]
+#[clap(rename_all = "kebab-case")]
+pub struct ApiAuthOpts {
+    /// Bearer token to authenticate with the Vector API server
+    #[clap(long, env = "VECTOR_API_TOKEN", conflicts_with = "user")]
+    token: Option<String>,
+
+    /// User to authenticate with the Vector API server, using basic authentication
+    #[clap(long, env = "VECTOR_API_USER")]
+    user: Option<String>,
+
+    /// Password to authenticate with the Vector API server, using basic authentication
+    #[clap(long, env = "VECTOR_API_PASSWORD", requires = "user")]
+    password: Option<String>,
+
+    /// PEM-encoded CA certificate to verify the certificate of the Vector API server with, in
+    /// addition to the system's. Use it when the API is served with a self-signed or private CA
+    /// certificate
+    #[clap(long, env = "VECTOR_API_CA_FILE", parse(from_os_str))]
+    ca_file: Option<PathBuf>,
+
+    /// Don't verify the certificate of the Vector API server. This is insecure, and only meant
+    /// for testing
+    #[clap(long)]
+    insecure: bool,
+}
+
+#[cfg(feature = "api-client")]
+impl ApiAuthOpts {
+    /// Returns the credentials to present to the Vector API server, if any.
+    pub fn auth(&self) -> Option<vector_api_client::Auth> {
+        match (&self.token, &self.user) {
+            (Some(token), _) => Some(vector_api_client::Auth::Bearer {
+                token: token.clone(),
+            }),
+            (None, Some(user)) => Some(vector_api_client::Auth::Basic {
+                user: user.clone(),
+                password: self.password.clone().unwrap_or_default(),
+            }),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns how the certificate of the Vector API server is verified.
+    pub fn tls(&self) -> vector_api_client::TlsOptions {
+        vector_api_client::TlsOptions {
+            ca_file: self.ca_file.clone(),
+            insecure: self.insecure,
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[clap(rename_all = "kebab-case")]
 pub enum SubCommand {
@@ -179,6 +236,9 @@ pub enum SubCommand {
     /// Generate a Vector configuration containing a list of components.
     Generate(generate::Opts),
 
+    /// Generate the JSON Schema of the Vector configuration, for use by editors and linters.
+    GenerateSchema(generate_schema::Opts),
+
     /// Output a provided Vector configuration file/dir as a single JSON object, useful for checking in to version control.
     #[clap(hide = true)]
     Config(config::Opts),
@@ -193,6 +253,9 @@ pub enum SubCommand {
     /// Output the topology as visual representation using the DOT language which can be rendered by GraphViz
     Graph(graph::Opts),
 
+    /// Inspect, export or replay the contents of a disk buffer, without running Vector.
+    Buffer(buffer::Opts),
+
     /// Display topology and metrics in the console, for a local or remote Vector instance
     #[cfg(feature = "api-client")]
     Top(top::Opts),
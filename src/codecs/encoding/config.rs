@@ -103,12 +103,17 @@ impl EncodingConfigWithFraming {
                 SinkType::StreamBased => NewlineDelimitedEncoder::new().into(),
                 SinkType::MessageBased => CharacterDelimitedEncoder::new(b',').into(),
             },
-            (None, Serializer::Avro(_) | Serializer::Native(_)) => {
-                LengthDelimitedEncoder::new().into()
-            }
             (
                 None,
-                Serializer::Gelf(_)
+                Serializer::Avro(_)
+                | Serializer::Msgpack(_)
+                | Serializer::Native(_)
+                | Serializer::Protobuf(_),
+            ) => LengthDelimitedEncoder::new().into(),
+            (
+                None,
+                Serializer::Csv(_)
+                | Serializer::Gelf(_)
                 | Serializer::Logfmt(_)
                 | Serializer::NativeJson(_)
                 | Serializer::RawMessage(_)
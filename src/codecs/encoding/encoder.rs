@@ -92,6 +92,25 @@ impl Encoder<Framer> {
         }
     }
 
+    /// Write the header of the serializer, if it has one, followed by the frame delimiter.
+    ///
+    /// This is meant to be called once at the start of every output file or object, before
+    /// any events are encoded into it. Returns whether a header was written.
+    pub fn encode_header(&mut self, buffer: &mut BytesMut) -> Result<bool, Error> {
+        let mut header = match self.serializer.header() {
+            Some(header) => header,
+            None => return Ok(false),
+        };
+
+        self.framer.encode((), &mut header).map_err(|error| {
+            emit!(EncoderFramingFailed { error: &error });
+            Error::FramingError(error)
+        })?;
+        buffer.unsplit(header);
+
+        Ok(true)
+    }
+
     /// Get the suffix that encloses a batch of events.
     pub const fn batch_suffix(&self) -> &[u8] {
         match (&self.framer, &self.serializer) {
@@ -114,6 +133,9 @@ impl Encoder<Framer> {
                 Framer::CharacterDelimited(CharacterDelimitedEncoder { delimiter: b',' }),
             ) => "application/json",
             (Serializer::Native(_), _) => "application/octet-stream",
+            (Serializer::Protobuf(_), _) => "application/x-protobuf",
+            (Serializer::Csv(_), _) => "text/csv",
+            (Serializer::Msgpack(_), _) => "application/msgpack",
             (
                 Serializer::Avro(_)
                 | Serializer::Gelf(_)
@@ -1,18 +1,39 @@
 use std::net::{Ipv4Addr, SocketAddr};
 
-use serde::{Deserialize, Serialize};
+use vector_config::configurable_component;
 
-#[derive(Debug, Deserialize, Serialize, PartialEq, Copy, Clone)]
+use crate::{http::Auth, tls::TlsEnableableConfig};
+
+/// API options.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq)]
 #[serde(default, deny_unknown_fields)]
 pub struct Options {
+    /// Whether or not the API endpoint is available.
     #[serde(default = "default_enabled")]
     pub enabled: bool,
 
+    /// The socket address to listen on for the API endpoint.
     #[serde(default = "default_address")]
     pub address: Option<SocketAddr>,
 
+    /// Whether or not to expose the GraphQL playground on the API endpoint.
     #[serde(default = "default_playground")]
     pub playground: bool,
+
+    /// Token that GraphQL mutations must present as `Authorization: Bearer <token>`. Mutations
+    /// are disabled when no token is set. The token is also accepted in place of `auth`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_token: Option<String>,
+
+    /// Credentials that clients must present to use the GraphQL endpoints.
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<Auth>,
+
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsEnableableConfig>,
 }
 
 impl Default for Options {
@@ -21,6 +42,9 @@ impl Default for Options {
             enabled: default_enabled(),
             playground: default_playground(),
             address: default_address(),
+            auth_token: None,
+            auth: None,
+            tls: None,
         }
     }
 }
@@ -65,6 +89,9 @@ impl Options {
             address,
             enabled: self.enabled | other.enabled,
             playground: self.playground & other.playground,
+            auth_token: merge_option("auth token", self.auth_token.take(), other.auth_token)?,
+            auth: merge_option("auth", self.auth.take(), other.auth)?,
+            tls: merge_option("tls", self.tls.take(), other.tls)?,
         };
 
         *self = options;
@@ -72,12 +99,25 @@ impl Options {
     }
 }
 
+/// Merges an option that may only be set once, or set identically in several places.
+fn merge_option<T: PartialEq>(name: &str, a: Option<T>, b: Option<T>) -> Result<Option<T>, String> {
+    match (a, b) {
+        (None, b) => Ok(b),
+        (Some(a), None) => Ok(Some(a)),
+        (Some(a), Some(b)) if a == b => Ok(Some(a)),
+        (Some(_), Some(_)) => Err(format!("Conflicting `api` {}.", name)),
+    }
+}
+
 #[test]
 fn bool_merge() {
     let mut a = Options {
         enabled: true,
         address: None,
         playground: false,
+        auth_token: None,
+        auth: None,
+        tls: None,
     };
 
     a.merge(Options::default()).unwrap();
@@ -88,6 +128,9 @@ fn bool_merge() {
             enabled: true,
             address: default_address(),
             playground: false,
+            auth_token: None,
+            auth: None,
+            tls: None,
         }
     );
 }
@@ -99,6 +142,9 @@ fn bind_merge() {
         enabled: true,
         address: Some(address),
         playground: true,
+        auth_token: None,
+        auth: None,
+        tls: None,
     };
 
     a.merge(Options::default()).unwrap();
@@ -109,6 +155,9 @@ fn bind_merge() {
             enabled: true,
             address: Some(address),
             playground: true,
+            auth_token: None,
+            auth: None,
+            tls: None,
         }
     );
 }
@@ -127,3 +176,49 @@ fn bind_conflict() {
 
     assert!(a.merge(b).is_err());
 }
+
+#[test]
+fn auth_token_merge() {
+    let mut a = Options {
+        auth_token: Some("secret".to_string()),
+        ..Options::default()
+    };
+
+    a.merge(Options::default()).unwrap();
+    assert_eq!(a.auth_token.as_deref(), Some("secret"));
+
+    let b = Options {
+        auth_token: Some("other".to_string()),
+        ..Options::default()
+    };
+
+    assert!(a.merge(b).is_err());
+}
+
+#[test]
+fn auth_merge() {
+    let auth = Auth::Basic {
+        user: "user".to_string(),
+        password: "password".to_string(),
+    };
+    let mut a = Options {
+        auth: Some(auth.clone()),
+        ..Options::default()
+    };
+
+    a.merge(Options {
+        auth: Some(auth.clone()),
+        ..Options::default()
+    })
+    .unwrap();
+    assert_eq!(a.auth, Some(auth));
+
+    let b = Options {
+        auth: Some(Auth::Bearer {
+            token: "token".to_string(),
+        }),
+        ..Options::default()
+    };
+
+    assert!(a.merge(b).is_err());
+}
@@ -1,10 +1,8 @@
-#[cfg(feature = "enterprise")]
 use std::collections::BTreeMap;
 use std::path::Path;
 
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "enterprise")]
 use serde_json::Value;
 use vector_core::{config::GlobalOptions, default_data_dir, transform::TransformConfig};
 
@@ -92,8 +90,7 @@ impl ConfigBuilderHash<'_> {
 ///
 /// Rather than rely on the opaque underlying serde structures, we are explicit
 /// about sorting, sacrificing a bit of potential convenience for correctness.
-#[cfg(feature = "enterprise")]
-fn to_sorted_json_string<T>(value: T) -> String
+pub(super) fn to_sorted_json_string<T>(value: T) -> String
 where
     T: Serialize,
 {
@@ -103,7 +100,6 @@ where
     serde_json::to_string(&value).expect("Should serialize Value to JSON string. Please report.")
 }
 
-#[cfg(feature = "enterprise")]
 fn sort_json_value(value: &mut Value) {
     match value {
         Value::Array(arr) => {
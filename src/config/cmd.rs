@@ -2,14 +2,21 @@ use std::path::PathBuf;
 
 use clap::Parser;
 use serde_json::Value;
+use url::Url;
 
-use super::{load_builder_from_paths, load_source_from_paths, process_paths, ConfigBuilder};
-use crate::cli::handle_config_errors;
+use super::{
+    load_builder_from_paths, load_source_from_paths, process_paths, ConfigBuilder, ConfigSnapshot,
+    ReloadPlan,
+};
+use crate::cli::{handle_config_errors, ApiAuthOpts};
 use crate::config;
 
 #[derive(Parser, Debug, Clone)]
 #[clap(rename_all = "kebab-case")]
 pub struct Opts {
+    #[clap(subcommand)]
+    sub_command: Option<SubCommand>,
+
     /// Pretty print JSON
     #[clap(short, long)]
     pretty: bool,
@@ -57,6 +64,38 @@ pub struct Opts {
     pub config_dirs: Vec<PathBuf>,
 }
 
+#[derive(Parser, Debug, Clone)]
+#[clap(rename_all = "kebab-case")]
+enum SubCommand {
+    /// Show what reloading a running configuration into a new one would change: which components
+    /// would restart, which sink buffers would be kept, and which ports would be rebound.
+    Diff(DiffOpts),
+}
+
+#[derive(Parser, Debug, Clone)]
+#[clap(rename_all = "kebab-case")]
+pub struct DiffOpts {
+    /// The running configuration, as one or more config files or directories. If none are
+    /// specified, the configuration is fetched from the API of a running Vector instance instead.
+    #[clap(long, use_value_delimiter(true), conflicts_with = "url")]
+    from: Vec<PathBuf>,
+
+    /// Vector GraphQL API server endpoint to fetch the running configuration from
+    #[clap(short, long)]
+    url: Option<Url>,
+
+    #[clap(flatten)]
+    auth: ApiAuthOpts,
+
+    /// The new configuration, as one or more config files or directories.
+    #[clap(long, required = true, use_value_delimiter(true))]
+    to: Vec<PathBuf>,
+
+    /// Output the changes as JSON
+    #[clap(long)]
+    json: bool,
+}
+
 impl Opts {
     fn paths_with_formats(&self) -> Vec<config::ConfigPath> {
         config::merge_path_lists(vec![
@@ -162,7 +201,11 @@ fn serialize_to_json(
 /// The purpose of this func is to combine user configuration after processing all paths,
 /// Pipelines expansions, etc. The JSON result of this serialization can itself be used as a config,
 /// which also makes it useful for version control or treating as a singular unit of configuration.
-pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
+pub async fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    if let Some(SubCommand::Diff(diff_opts)) = &opts.sub_command {
+        return diff(diff_opts).await;
+    }
+
     let paths = opts.paths_with_formats();
     // Start by serializing to a `ConfigBuilder`. This will leverage validation in config
     // builder fields which we'll use to error out if required.
@@ -190,6 +233,133 @@ pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
     exitcode::OK
 }
 
+/// Function used by the `vector config diff` subcommand, to explain what reloading a running
+/// configuration into a new one would change.
+async fn diff(opts: &DiffOpts) -> exitcode::ExitCode {
+    let old = if opts.from.is_empty() {
+        match fetch_snapshot(opts).await {
+            Ok(snapshot) => snapshot,
+            Err(code) => return code,
+        }
+    } else {
+        match load_snapshot(&opts.from) {
+            Ok(snapshot) => snapshot,
+            Err(errs) => return handle_config_errors(errs),
+        }
+    };
+    let new = match load_snapshot(&opts.to) {
+        Ok(snapshot) => snapshot,
+        Err(errs) => return handle_config_errors(errs),
+    };
+
+    let plan = ReloadPlan::new(&old, &new);
+    let output = if opts.json {
+        serde_json::to_string_pretty(&plan).expect("reload plan should serialize to JSON")
+    } else {
+        plan.to_string()
+    };
+
+    #[allow(clippy::print_stdout)]
+    {
+        println!("{}", output.trim_end());
+    }
+
+    exitcode::OK
+}
+
+/// Loads a snapshot of the configuration at `paths`.
+///
+/// Neither the provider nor the secret backends of the configuration are used, so that nothing
+/// is fetched from outside of the given files. Secrets are compared by their references instead.
+fn load_snapshot(paths: &[PathBuf]) -> Result<ConfigSnapshot, Vec<String>> {
+    let paths = paths
+        .iter()
+        .map(|path| {
+            if path.is_dir() {
+                config::ConfigPath::Dir(path.clone())
+            } else {
+                config::ConfigPath::File(path.clone(), None)
+            }
+        })
+        .collect::<Vec<_>>();
+    let paths = process_paths(&paths).ok_or_else(|| {
+        vec![format!(
+            "Couldn't read the configuration from {}.",
+            paths
+                .iter()
+                .map(|path| <&PathBuf>::from(path).display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )]
+    })?;
+    let (builder, _) = load_builder_from_paths(&paths)?;
+    let config = builder.build()?;
+    Ok(ConfigSnapshot::new(&config))
+}
+
+/// Fetches a snapshot of the running configuration from the API of a Vector instance.
+#[cfg(feature = "api-client")]
+async fn fetch_snapshot(opts: &DiffOpts) -> Result<ConfigSnapshot, exitcode::ExitCode> {
+    use vector_api_client::{gql::MetaQueryExt, Client};
+
+    // Use the provided URL as the Vector GraphQL API server, or default to the local port
+    // provided by the API config.
+    let url = opts.url.clone().unwrap_or_else(|| {
+        let addr = config::api::default_address().unwrap();
+        Url::parse(&*format!("http://{}/graphql", addr))
+            .expect("Couldn't parse default API URL. Please report this.")
+    });
+    let client = Client::new_with_healthcheck(url.clone(), opts.auth.auth(), &opts.auth.tls())
+        .await
+        .ok_or(exitcode::UNAVAILABLE)?;
+
+    let response = client.meta_config_snapshot().await.ok();
+    // Such as the API refusing to serve the snapshot without the auth token.
+    let errors = response
+        .as_ref()
+        .and_then(|response| response.errors.as_ref())
+        .map(|errors| {
+            errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+    let snapshot = response
+        .and_then(|response| response.data)
+        .and_then(|data| data.meta.config_snapshot);
+    match snapshot.map(|snapshot| serde_json::from_str(&snapshot)) {
+        Some(Ok(snapshot)) => Ok(snapshot),
+        Some(Err(error)) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Couldn't parse the running configuration: {}.", error);
+            }
+            Err(exitcode::SOFTWARE)
+        }
+        None => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!(
+                    "Couldn't fetch the running configuration from the Vector API at {}.{}",
+                    url,
+                    errors.map_or_else(String::new, |errors| format!(" {}", errors))
+                );
+            }
+            Err(exitcode::UNAVAILABLE)
+        }
+    }
+}
+
+#[cfg(not(feature = "api-client"))]
+async fn fetch_snapshot(_opts: &DiffOpts) -> Result<ConfigSnapshot, exitcode::ExitCode> {
+    #[allow(clippy::print_stderr)]
+    {
+        eprintln!("Fetching the running configuration requires the `api-client` feature. Use `--from` instead.");
+    }
+    Err(exitcode::USAGE)
+}
+
 #[cfg(all(test, feature = "sources", feature = "transforms", feature = "sinks"))]
 mod tests {
     use std::collections::HashMap;
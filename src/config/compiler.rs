@@ -53,6 +53,10 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
         errors.extend(output_errors);
     }
 
+    if let Err(compression_errors) = validation::check_compression(&builder) {
+        errors.extend(compression_errors);
+    }
+
     #[cfg(feature = "enterprise")]
     let version = Some(builder.sha256_hash());
 
@@ -195,6 +199,12 @@ pub(crate) fn expand_globs(config: &mut ConfigBuilder) {
                     port: output.port,
                 })
         }))
+        .chain(config.sinks.iter().flat_map(|(key, s)| {
+            s.outputs().into_iter().map(|output| OutputId {
+                component: key.clone(),
+                port: output.port,
+            })
+        }))
         .map(|output_id| output_id.to_string())
         .collect::<IndexSet<String>>();
 
@@ -1,8 +1,9 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, fmt};
 
 use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
 
-use super::{ComponentKey, Config};
+use super::{builder::to_sorted_json_string, ComponentKey, Config, Resource};
 
 #[derive(Debug)]
 pub struct ConfigDiff {
@@ -139,3 +140,413 @@ impl Difference {
         self.to_change.iter().chain(self.to_remove.iter())
     }
 }
+
+/// A condensed view of a configuration, holding just enough about each component to tell what
+/// reloading from one configuration into another would do.
+///
+/// Components are fingerprinted rather than serialized, so that a snapshot of a running instance
+/// can be shared, through the API, without exposing any of the secrets in its configuration.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ConfigSnapshot {
+    pub global: String,
+    pub sources: IndexMap<ComponentKey, ComponentSnapshot>,
+    pub transforms: IndexMap<ComponentKey, ComponentSnapshot>,
+    pub sinks: IndexMap<ComponentKey, ComponentSnapshot>,
+    pub enrichment_tables: IndexMap<ComponentKey, ComponentSnapshot>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ComponentSnapshot {
+    pub component_type: String,
+    pub fingerprint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buffer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resources: Vec<String>,
+}
+
+impl ConfigSnapshot {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            global: fingerprint(&config.global),
+            sources: config
+                .sources
+                .iter()
+                .map(|(key, source)| {
+                    let snapshot = ComponentSnapshot {
+                        component_type: source.inner.source_type().to_owned(),
+                        fingerprint: fingerprint(source),
+                        buffer: None,
+                        resources: describe_resources(source.inner.resources()),
+                    };
+                    (key.clone(), snapshot)
+                })
+                .collect(),
+            transforms: config
+                .transforms
+                .iter()
+                .map(|(key, transform)| {
+                    let snapshot = ComponentSnapshot {
+                        component_type: transform.inner.transform_type().to_owned(),
+                        fingerprint: fingerprint(transform),
+                        buffer: None,
+                        resources: Vec::new(),
+                    };
+                    (key.clone(), snapshot)
+                })
+                .collect(),
+            sinks: config
+                .sinks
+                .iter()
+                .map(|(key, sink)| {
+                    let snapshot = ComponentSnapshot {
+                        component_type: sink.inner.sink_type().to_owned(),
+                        fingerprint: fingerprint(sink),
+                        buffer: Some(fingerprint(&sink.buffer)),
+                        resources: describe_resources(sink.resources(key)),
+                    };
+                    (key.clone(), snapshot)
+                })
+                .collect(),
+            enrichment_tables: config
+                .enrichment_tables
+                .iter()
+                .map(|(key, table)| {
+                    let snapshot = ComponentSnapshot {
+                        component_type: table.inner.typetag_name().to_owned(),
+                        fingerprint: fingerprint(table),
+                        buffer: None,
+                        resources: Vec::new(),
+                    };
+                    (key.clone(), snapshot)
+                })
+                .collect(),
+        }
+    }
+}
+
+fn fingerprint<T: Serialize>(value: T) -> String {
+    let digest = openssl::sha::sha256(to_sorted_json_string(value).as_bytes());
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Disk buffers are reported along with the rest of a sink's buffer, so only the resources that
+/// are bound, such as ports, are kept.
+fn describe_resources(resources: Vec<Resource>) -> Vec<String> {
+    let mut resources = resources
+        .into_iter()
+        .filter(|resource| !matches!(resource, Resource::DiskBuffer(_)))
+        .map(|resource| resource.to_string())
+        .collect::<Vec<_>>();
+    resources.sort();
+    resources.dedup();
+    resources
+}
+
+/// What reloading a running configuration into a new one would do, component by component.
+#[derive(Debug, Serialize)]
+pub struct ReloadPlan {
+    /// Global options can't be changed by a reload, so the new configuration would be rejected.
+    pub global_changed: bool,
+    pub changes: Vec<ComponentChange>,
+    pub unchanged: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComponentChange {
+    pub kind: &'static str,
+    pub id: ComponentKey,
+    pub component_type: String,
+    pub action: ChangeAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffer: Option<BufferAction>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub binds: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rebinds: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub releases: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeAction {
+    /// The component is started.
+    Add,
+    /// The running component is stopped, and started again with its new configuration.
+    Restart,
+    /// The running component is stopped.
+    Remove,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BufferAction {
+    /// A new buffer is built for the sink.
+    Create,
+    /// The restarted sink takes over the buffer of the running sink, along with any events in it.
+    Keep,
+    /// The buffer configuration changed, so the restarted sink gets a new buffer. Events held in
+    /// memory by the running sink's buffer are lost.
+    Rebuild,
+    /// The buffer of the removed sink is dropped once the sink has stopped.
+    Drop,
+}
+
+impl ReloadPlan {
+    pub fn new(old: &ConfigSnapshot, new: &ConfigSnapshot) -> Self {
+        let mut changes = Vec::new();
+        let mut unchanged = 0;
+        for (kind, old, new) in [
+            ("source", &old.sources, &new.sources),
+            ("transform", &old.transforms, &new.transforms),
+            ("sink", &old.sinks, &new.sinks),
+            (
+                "enrichment_table",
+                &old.enrichment_tables,
+                &new.enrichment_tables,
+            ),
+        ] {
+            let diff = Difference::new(old, new);
+            unchanged += new.len() - diff.to_add.len() - diff.to_change.len();
+
+            let mut section = old
+                .keys()
+                .chain(new.keys().filter(|key| !old.contains_key(*key)))
+                .filter(|key| diff.contains(key))
+                .map(|key| {
+                    let (old, new) = (old.get(key), new.get(key));
+                    let action = if diff.is_added(key) {
+                        ChangeAction::Add
+                    } else if diff.is_removed(key) {
+                        ChangeAction::Remove
+                    } else {
+                        ChangeAction::Restart
+                    };
+                    let buffer = match (
+                        old.and_then(|c| c.buffer.as_ref()),
+                        new.and_then(|c| c.buffer.as_ref()),
+                    ) {
+                        (None, None) => None,
+                        (None, Some(_)) => Some(BufferAction::Create),
+                        (Some(_), None) => Some(BufferAction::Drop),
+                        (Some(old), Some(new)) if old == new => Some(BufferAction::Keep),
+                        (Some(_), Some(_)) => Some(BufferAction::Rebuild),
+                    };
+                    let old_resources = old.map_or(&[][..], |c| &c.resources[..]);
+                    let new_resources = new.map_or(&[][..], |c| &c.resources[..]);
+
+                    ComponentChange {
+                        kind,
+                        id: key.clone(),
+                        component_type: new
+                            .or(old)
+                            .map(|c| c.component_type.clone())
+                            .unwrap_or_default(),
+                        action,
+                        buffer,
+                        binds: difference(new_resources, old_resources),
+                        rebinds: new_resources
+                            .iter()
+                            .filter(|resource| old_resources.contains(resource))
+                            .cloned()
+                            .collect(),
+                        releases: difference(old_resources, new_resources),
+                    }
+                })
+                .collect::<Vec<_>>();
+            section.sort_by(|a, b| a.id.cmp(&b.id));
+            changes.extend(section);
+        }
+
+        Self {
+            global_changed: old.global != new.global,
+            changes,
+            unchanged,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.global_changed && self.changes.is_empty()
+    }
+}
+
+fn difference(resources: &[String], other: &[String]) -> Vec<String> {
+    resources
+        .iter()
+        .filter(|resource| !other.contains(resource))
+        .cloned()
+        .collect()
+}
+
+impl fmt::Display for ReloadPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.global_changed {
+            writeln!(
+                f,
+                "Global options changed. A reload would be rejected, so Vector has to be restarted to apply this configuration."
+            )?;
+        }
+        if self.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+
+        for change in &self.changes {
+            let (sign, verb) = match change.action {
+                ChangeAction::Add => ('+', "started"),
+                ChangeAction::Restart => ('~', "restarted"),
+                ChangeAction::Remove => ('-', "stopped"),
+            };
+            write!(
+                f,
+                "{} {} {:?} ({}): {}",
+                sign,
+                change.kind,
+                change.id.id(),
+                change.component_type,
+                verb
+            )?;
+            match change.buffer {
+                None => {}
+                Some(BufferAction::Create) => write!(f, ", with a new buffer")?,
+                Some(BufferAction::Keep) => write!(f, ", keeping its buffer")?,
+                Some(BufferAction::Rebuild) => {
+                    write!(f, ", with a new buffer as its buffer options changed")?
+                }
+                Some(BufferAction::Drop) => write!(f, ", dropping its buffer")?,
+            }
+            for (verb, resources) in [
+                ("binds", &change.binds),
+                ("rebinds", &change.rebinds),
+                ("releases", &change.releases),
+            ] {
+                if !resources.is_empty() {
+                    write!(f, "; {} {}", verb, resources.join(", "))?;
+                }
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "{} components unchanged.", self.unchanged)
+    }
+}
+
+#[cfg(all(
+    test,
+    feature = "sources-demo_logs",
+    feature = "sources-socket",
+    feature = "sinks-blackhole"
+))]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+    use crate::config::{load_from_str, Format};
+
+    fn snapshot(input: &str) -> ConfigSnapshot {
+        ConfigSnapshot::new(&load_from_str(input, Format::Toml).unwrap())
+    }
+
+    const OLD: &str = indoc! {r#"
+        [sources.demo]
+        type = "demo_logs"
+        format = "shuffle"
+        lines = ["line"]
+
+        [sources.tcp]
+        type = "socket"
+        mode = "tcp"
+        address = "0.0.0.0:9000"
+
+        [sinks.kept]
+        type = "blackhole"
+        inputs = ["demo"]
+
+        [sinks.out]
+        type = "blackhole"
+        inputs = ["demo", "tcp"]
+
+        [sinks.resized]
+        type = "blackhole"
+        inputs = ["demo"]
+
+        [sinks.gone]
+        type = "blackhole"
+        inputs = ["demo"]
+    "#};
+
+    #[test]
+    fn no_changes() {
+        let plan = ReloadPlan::new(&snapshot(OLD), &snapshot(OLD));
+        assert!(plan.is_empty());
+        assert_eq!(plan.unchanged, 6);
+        assert_eq!(plan.to_string(), "No changes.\n");
+    }
+
+    #[test]
+    fn explains_reload() {
+        let new = indoc! {r#"
+            [sources.demo]
+            type = "demo_logs"
+            format = "shuffle"
+            lines = ["line"]
+
+            [sources.tcp]
+            type = "socket"
+            mode = "tcp"
+            address = "0.0.0.0:9000"
+            shutdown_timeout_secs = 10
+
+            [sources.udp]
+            type = "socket"
+            mode = "udp"
+            address = "0.0.0.0:9001"
+
+            [sinks.kept]
+            type = "blackhole"
+            inputs = ["demo"]
+
+            [sinks.out]
+            type = "blackhole"
+            inputs = ["demo", "tcp", "udp"]
+
+            [sinks.resized]
+            type = "blackhole"
+            inputs = ["demo"]
+            buffer.max_events = 100
+        "#};
+        let plan = ReloadPlan::new(&snapshot(OLD), &snapshot(new));
+        assert!(!plan.global_changed);
+        assert_eq!(plan.unchanged, 2);
+
+        let changes = plan
+            .changes
+            .iter()
+            .map(|change| (change.id.id(), change.action, change.buffer))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                ("tcp", ChangeAction::Restart, None),
+                ("udp", ChangeAction::Add, None),
+                ("gone", ChangeAction::Remove, Some(BufferAction::Drop)),
+                ("out", ChangeAction::Restart, Some(BufferAction::Keep)),
+                (
+                    "resized",
+                    ChangeAction::Restart,
+                    Some(BufferAction::Rebuild)
+                ),
+            ]
+        );
+        assert_eq!(plan.changes[0].rebinds, vec!["tcp 0.0.0.0:9000"]);
+        assert_eq!(plan.changes[1].binds, vec!["udp 0.0.0.0:9001"]);
+    }
+
+    #[test]
+    fn global_changes_are_rejected() {
+        let new = format!("data_dir = \"/tmp/other\"\n{}", OLD);
+        let plan = ReloadPlan::new(&snapshot(OLD), &snapshot(&new));
+        assert!(plan.global_changed);
+        assert!(plan.changes.is_empty());
+        assert!(plan.to_string().starts_with("Global options changed."));
+    }
+}
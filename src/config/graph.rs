@@ -16,6 +16,7 @@ pub enum Node {
     },
     Sink {
         ty: DataType,
+        outputs: Vec<Output>,
     },
 }
 
@@ -89,6 +90,7 @@ impl Graph {
                 id.clone(),
                 Node::Sink {
                     ty: config.inner.input().data_type(),
+                    outputs: config.outputs(),
                 },
             );
         }
@@ -161,7 +163,7 @@ impl Graph {
         match self.nodes[key] {
             Node::Source { .. } => panic!("no inputs on sources"),
             Node::Transform { in_ty, .. } => in_ty,
-            Node::Sink { ty } => ty,
+            Node::Sink { ty, .. } => ty,
         }
     }
 
@@ -169,16 +171,17 @@ impl Graph {
     ///
     /// # Panics
     ///
-    /// Will panic if the given id is not present in the graph or identifies a sink, which can't
-    /// have inputs.
+    /// Will panic if the given id is not present in the graph or doesn't identify one of the
+    /// outputs of its component.
     fn get_output_type(&self, id: &OutputId) -> DataType {
         match &self.nodes[&id.component] {
-            Node::Source { outputs } | Node::Transform { outputs, .. } => outputs
+            Node::Source { outputs }
+            | Node::Transform { outputs, .. }
+            | Node::Sink { outputs, .. } => outputs
                 .iter()
                 .find(|output| output.port == id.port)
                 .map(|output| output.ty)
                 .expect("output didn't exist"),
-            Node::Sink { .. } => panic!("no outputs on sinks"),
         }
     }
 
@@ -263,14 +266,12 @@ impl Graph {
         self.nodes
             .iter()
             .flat_map(|(key, node)| match node {
-                Node::Sink { .. } => vec![],
-                Node::Source { outputs } | Node::Transform { outputs, .. } => outputs
-                    .iter()
-                    .map(|output| OutputId {
-                        component: key.clone(),
-                        port: output.port.clone(),
-                    })
-                    .collect(),
+                Node::Source { outputs }
+                | Node::Transform { outputs, .. }
+                | Node::Sink { outputs, .. } => outputs.iter().map(|output| OutputId {
+                    component: key.clone(),
+                    port: output.port.clone(),
+                }),
             })
             .collect()
     }
@@ -348,7 +349,7 @@ impl Graph {
             .into_iter()
             .filter(|path| {
                 if let Some(key) = path.last() {
-                    matches!(self.nodes.get(key), Some(Node::Sink { ty: _ }))
+                    matches!(self.nodes.get(key), Some(Node::Sink { .. }))
                 } else {
                     false
                 }
@@ -407,10 +408,26 @@ mod test {
             }
         }
 
+        fn add_sink_output(&mut self, id: &str, name: &str, ty: DataType) {
+            let id = id.into();
+            match self.nodes.get_mut(&id) {
+                Some(Node::Sink { outputs, .. }) => {
+                    outputs.push(Output::default(ty).with_port(name))
+                }
+                _ => panic!("invalid sink"),
+            }
+        }
+
         fn add_sink(&mut self, id: &str, ty: DataType, inputs: Vec<&str>) {
             let id = ComponentKey::from(id);
             let inputs = clean_inputs(inputs);
-            self.nodes.insert(id.clone(), Node::Sink { ty });
+            self.nodes.insert(
+                id.clone(),
+                Node::Sink {
+                    ty,
+                    outputs: Vec::new(),
+                },
+            );
             for from in inputs {
                 self.edges.push(Edge {
                     from,
@@ -614,6 +631,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn allows_sink_dead_letter_outputs() {
+        let mut graph = Graph::default();
+        graph.add_source("in", DataType::Log);
+        graph.add_sink("out", DataType::Log, vec!["in"]);
+        graph.add_sink_output("out", "dead_letter", DataType::Log);
+        graph.add_sink("archive", DataType::Log, vec![]);
+
+        assert_eq!(Ok(()), graph.test_add_input("archive", "out.dead_letter"));
+        assert_eq!(Ok(()), graph.typecheck());
+        graph.check_for_cycles().unwrap();
+        assert_eq!(
+            vec![vec![
+                ComponentKey::from("in"),
+                ComponentKey::from("out"),
+                ComponentKey::from("archive")
+            ]],
+            graph.paths_to_sink_from(&ComponentKey::from("in"))
+        );
+
+        graph.add_transform(
+            "retry",
+            DataType::Log,
+            DataType::Log,
+            vec!["out.dead_letter"],
+        );
+        assert_eq!(Ok(()), graph.test_add_input("out", "retry"));
+        assert!(graph.check_for_cycles().is_err());
+    }
+
     #[test]
     fn disallows_ambiguous_inputs() {
         let mut graph = Graph::default();
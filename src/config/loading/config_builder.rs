@@ -1,4 +1,4 @@
-use std::{collections::HashMap, io::Read};
+use std::{collections::HashMap, io::Read, path::PathBuf};
 
 use indexmap::IndexMap;
 use toml::value::Table;
@@ -13,6 +13,7 @@ use crate::config::{
 pub struct ConfigBuilderLoader {
     builder: ConfigBuilder,
     secrets: Option<HashMap<String, String>>,
+    imported_paths: Vec<PathBuf>,
 }
 
 impl ConfigBuilderLoader {
@@ -20,6 +21,7 @@ impl ConfigBuilderLoader {
         Self {
             builder: ConfigBuilder::default(),
             secrets: None,
+            imported_paths: Vec::new(),
         }
     }
 
@@ -27,6 +29,7 @@ impl ConfigBuilderLoader {
         Self {
             builder: ConfigBuilder::default(),
             secrets: Some(secrets),
+            imported_paths: Vec::new(),
         }
     }
 }
@@ -43,6 +46,10 @@ impl Process for ConfigBuilderLoader {
         Ok((prepared_input, warnings))
     }
 
+    fn imported_paths(&mut self) -> &mut Vec<PathBuf> {
+        &mut self.imported_paths
+    }
+
     /// Merge a TOML `Table` with a `ConfigBuilder`. Component types extend specific keys.
     fn merge(&mut self, table: Table, hint: Option<ComponentHint>) -> Result<(), Vec<String>> {
         match hint {
@@ -1,9 +1,13 @@
-use std::path::{Path, PathBuf};
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
 
+use glob::glob;
 use serde_toml_merge::merge_into_table;
 use toml::value::{Table, Value};
 
-use super::{component_name, open_file, read_dir, Format};
+use super::{component_name, open_file, read_dir, templates::expand_templates, Format};
 use crate::config::format;
 
 /// Provides a hint to the loading system of the type of components that should be found
@@ -56,6 +60,10 @@ pub(super) mod process {
         /// environment variables or perform some other pre-processing on the input.
         fn prepare<R: Read>(&mut self, input: R) -> Result<(String, Vec<String>), Vec<String>>;
 
+        /// Returns the files imported by the files loaded so far, so that they can be watched
+        /// for changes along with the config paths.
+        fn imported_paths(&mut self) -> &mut Vec<PathBuf>;
+
         /// Calls into the `prepare` method, and deserializes a `Read` to a `T`.
         fn load<R: std::io::Read, T>(
             &mut self,
@@ -166,20 +174,130 @@ pub(super) mod process {
             }
         }
 
-        /// Loads and deserializes a file into a TOML `Table`.
+        /// Loads and deserializes a file into a TOML `Table`, merging in the files it imports
+        /// and instantiating its component templates.
         fn load_file(
             &mut self,
             path: &Path,
             format: Format,
         ) -> Result<Option<(String, Table, Vec<String>)>, Vec<String>> {
             if let (Ok(name), Some(file)) = (component_name(path), open_file(path)) {
-                self.load(file, format)
-                    .map(|(value, warnings)| Some((name, value, warnings)))
+                let (mut table, mut warnings) = self.load(file, format)?;
+                warnings.extend(self.resolve_imports(path, &mut table, &mut Vec::new())?);
+                expand_templates(&mut table)?;
+                Ok(Some((name, table, warnings)))
             } else {
                 Ok(None)
             }
         }
 
+        /// Merges the files listed in the `imports` key of a `Table` loaded from `path` into it.
+        /// Imports are paths or glob patterns relative to the directory of `path`, and are
+        /// merged in order before the `Table` itself, in the same way as separate config files,
+        /// and are recorded in `imported_paths`. `stack` holds the files being imported, to
+        /// detect import cycles.
+        fn resolve_imports(
+            &mut self,
+            path: &Path,
+            table: &mut Table,
+            stack: &mut Vec<PathBuf>,
+        ) -> Result<Vec<String>, Vec<String>> {
+            let imports = match table.remove("imports") {
+                None => return Ok(Vec::new()),
+                Some(Value::Array(imports)) => imports,
+                Some(_) => {
+                    return Err(vec![format!(
+                        "The `imports` key must be an array of paths, in config file: {:?}.",
+                        path
+                    )])
+                }
+            };
+
+            let mut errors = Vec::new();
+            let mut warnings = Vec::new();
+            let mut merged = Table::new();
+            let base = path.parent().unwrap_or_else(|| Path::new("."));
+
+            stack.push(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+            for import in imports {
+                let pattern = match import {
+                    Value::String(pattern) => base.join(pattern),
+                    _ => {
+                        errors.push(format!(
+                            "The `imports` key must be an array of paths, in config file: {:?}.",
+                            path
+                        ));
+                        continue;
+                    }
+                };
+                let matches = match glob(&pattern.to_string_lossy()) {
+                    Ok(matches) => matches.filter_map(Result::ok).collect::<Vec<_>>(),
+                    Err(err) => {
+                        errors.push(format!("Invalid import: {:?}, {}.", pattern, err));
+                        continue;
+                    }
+                };
+                if matches.is_empty() {
+                    errors.push(format!("Import didn't match any file: {:?}.", pattern));
+                }
+
+                for import_path in matches {
+                    if !self.imported_paths().contains(&import_path) {
+                        self.imported_paths().push(import_path.clone());
+                    }
+                    match self.load_import(&import_path, stack) {
+                        Ok((imported, warns)) => {
+                            if let Err(err) = merge_into_table(&mut merged, imported) {
+                                errors.push(format!(
+                                    "Could not merge import: {:?}, {}.",
+                                    import_path, err
+                                ));
+                            }
+                            warnings.extend(warns);
+                        }
+                        Err(errs) => errors.extend(errs),
+                    }
+                }
+            }
+            stack.pop();
+
+            if !errors.is_empty() {
+                return Err(errors);
+            }
+            merge_into_table(&mut merged, std::mem::take(table)).map_err(|err| {
+                vec![format!(
+                    "Could not merge imports into config file: {:?}, {}.",
+                    path, err
+                )]
+            })?;
+            *table = merged;
+            Ok(warnings)
+        }
+
+        /// Loads an imported file into a TOML `Table`, resolving its own imports.
+        fn load_import(
+            &mut self,
+            path: &Path,
+            stack: &mut Vec<PathBuf>,
+        ) -> Result<(Table, Vec<String>), Vec<String>> {
+            let canonical = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+            if stack.contains(&canonical) {
+                return Err(vec![format!("Import cycle detected at: {:?}.", path)]);
+            }
+            let format = Format::from_path(path)
+                .map_err(|path| vec![format!("Unknown format of imported file: {:?}.", path)])?;
+            let file = File::open(path).map_err(|err| {
+                vec![format!(
+                    "Could not open imported file: {:?}, {}.",
+                    path, err
+                )]
+            })?;
+
+            let (mut table, mut warnings) = self.load(file, format)?;
+            warnings.extend(self.resolve_imports(path, &mut table, stack)?);
+            Ok((table, warnings))
+        }
+
         /// Loads a file, and if the path provided contains a sub-folder by the same name as the
         /// component, descend into it recursively, returning a TOML `Table`.
         fn load_file_recursive(
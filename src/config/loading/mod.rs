@@ -2,17 +2,23 @@ mod config_builder;
 mod loader;
 mod secret;
 mod source;
+mod templates;
 
 use std::{
     collections::HashMap,
     fmt::Debug,
     fs::{File, ReadDir},
     path::{Path, PathBuf},
-    sync::Mutex,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::Duration,
 };
 
 use config_builder::ConfigBuilderLoader;
 pub use config_builder::*;
+use futures::StreamExt;
 use glob::glob;
 use loader::process::Process;
 pub use loader::*;
@@ -27,6 +33,15 @@ use crate::signal;
 
 pub static CONFIG_PATHS: Lazy<Mutex<Vec<ConfigPath>>> = Lazy::new(Mutex::default);
 
+/// The files imported by the last loaded config, which are watched along with the config paths.
+pub static IMPORTED_PATHS: Lazy<Mutex<Vec<PathBuf>>> = Lazy::new(Mutex::default);
+
+/// Whether the secrets of the last loaded config expire, and so have to be renewed.
+static RENEWING_SECRETS: AtomicBool = AtomicBool::new(false);
+
+/// The delay after which renewing secrets is tried again, when retrieving them failed.
+const SECRET_RENEWAL_RETRY_DELAY: Duration = Duration::from_secs(30);
+
 pub(super) fn read_dir<P: AsRef<Path> + Debug>(path: P) -> Result<ReadDir, Vec<String>> {
     path.as_ref()
         .read_dir()
@@ -142,9 +157,18 @@ pub async fn load_from_paths_with_provider_and_secrets(
     // And then, if needed, retrieve secrets from configured backends
     let (mut builder, load_warnings) = if secrets_backends_loader.has_secrets_to_retrieve() {
         debug!(message = "Secret placeholders found, retrieving secrets from configured backends.");
-        let resolved_secrets = secrets_backends_loader
-            .retrieve(&mut signal_handler.subscribe())
-            .map_err(|e| vec![e])?;
+        let resolved_secrets =
+            match secrets_backends_loader.retrieve(&mut signal_handler.subscribe()) {
+                Ok(secrets) => secrets,
+                Err(error) => {
+                    // The running config keeps its secrets until they expire, so keep trying to renew
+                    // them until then.
+                    if RENEWING_SECRETS.load(Ordering::Relaxed) {
+                        schedule_secret_renewal(signal_handler, SECRET_RENEWAL_RETRY_DELAY);
+                    }
+                    return Err(vec![error]);
+                }
+            };
         load_builder_from_paths_with_secrets(config_paths, resolved_secrets)?
     } else {
         debug!(message = "No secret placeholder found, skipping secret resolution.");
@@ -154,6 +178,13 @@ pub async fn load_from_paths_with_provider_and_secrets(
     validation::check_provider(&builder)?;
     signal_handler.clear();
 
+    // If any of the secrets expire, reload the configuration to retrieve them again beforehand.
+    let renewal_delay = secrets_backends_loader.renewal_delay();
+    RENEWING_SECRETS.store(renewal_delay.is_some(), Ordering::Relaxed);
+    if let Some(delay) = renewal_delay {
+        schedule_secret_renewal(signal_handler, delay);
+    }
+
     // If there's a provider, overwrite the existing config builder with the remote variant.
     if let Some(mut provider) = builder.provider {
         builder = provider.build(signal_handler).await?;
@@ -173,6 +204,21 @@ pub async fn load_from_paths_with_provider_and_secrets(
     Ok(new_config)
 }
 
+/// Reloads the configuration from disk after `delay`, to retrieve its secrets again.
+fn schedule_secret_renewal(signal_handler: &mut signal::SignalHandler, delay: Duration) {
+    debug!(
+        message = "Scheduling secret renewal.",
+        delay_secs = delay.as_secs()
+    );
+    signal_handler.add(
+        futures::stream::once(async move {
+            tokio::time::sleep(delay).await;
+            signal::SignalTo::ReloadFromDisk
+        })
+        .chain(futures::stream::pending()),
+    );
+}
+
 /// Iterators over `ConfigPaths`, and processes a file/dir according to a provided `Loader`.
 fn loader_from_paths<T, L>(
     mut loader: L,
@@ -208,6 +254,14 @@ where
     }
 
     if errors.is_empty() {
+        let mut imported_paths = std::mem::take(loader.imported_paths());
+        imported_paths.sort();
+        // Ignore poison error and let the current main thread continue running to do the cleanup.
+        std::mem::drop(
+            IMPORTED_PATHS
+                .lock()
+                .map(|mut guard| *guard = imported_paths),
+        );
         Ok((loader.take(), warnings))
     } else {
         Err(errors)
@@ -332,9 +386,9 @@ fn default_config_paths() -> Vec<ConfigPath> {
 mod tests {
     use std::path::PathBuf;
 
-    use super::load_builder_from_paths;
+    use super::{load_builder_from_paths, ConfigBuilderLoader, Loader, Process};
     use crate::{
-        config::{ComponentKey, ConfigPath},
+        config::{ComponentKey, ConfigPath, Format},
         transforms::pipelines::PipelinesConfig,
     };
 
@@ -390,4 +444,59 @@ mod tests {
         let (_, warnings) = load_builder_from_paths(&configs).unwrap();
         assert!(warnings.is_empty());
     }
+
+    #[test]
+    fn load_imports_and_templates() {
+        let path = PathBuf::from(".")
+            .join("tests")
+            .join("imports")
+            .join("vector.toml");
+        let configs = vec![ConfigPath::File(path, None)];
+        let (builder, warnings) = load_builder_from_paths(&configs).unwrap();
+        assert!(warnings.is_empty());
+        assert!(builder
+            .sources
+            .contains_key(&ComponentKey::from("demo_json")));
+        assert!(builder
+            .sources
+            .contains_key(&ComponentKey::from("demo_syslog")));
+        let sink = builder
+            .sinks
+            .get(&ComponentKey::from("console_syslog"))
+            .unwrap();
+        assert_eq!(sink.inputs, vec!["demo_syslog".to_owned()]);
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn load_records_imported_paths() {
+        let dir = PathBuf::from(".").join("tests").join("imports");
+        let mut loader = ConfigBuilderLoader::new();
+        loader
+            .load_from_file(&dir.join("vector.toml"), Format::Toml)
+            .unwrap();
+        assert_eq!(
+            loader.imported_paths(),
+            &vec![
+                dir.join("fragments").join("sources.toml"),
+                dir.join("templates.yaml"),
+            ]
+        );
+    }
+
+    #[test]
+    fn load_import_cycle() {
+        let path = PathBuf::from(".")
+            .join("tests")
+            .join("imports")
+            .join("cycle")
+            .join("a.toml");
+        let configs = vec![ConfigPath::File(path, None)];
+        let errors = load_builder_from_paths(&configs).unwrap_err();
+        assert!(
+            errors[0].starts_with("Import cycle detected"),
+            "{:?}",
+            errors
+        );
+    }
 }
@@ -1,7 +1,16 @@
-use std::{collections::HashMap, io::Read};
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 
 use bytes::BytesMut;
 use futures::{executor, StreamExt};
+use http::{Method, Request};
+use hyper::Body;
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
@@ -9,15 +18,18 @@ use serde::{Deserialize, Serialize};
 use tokio::{io::AsyncWriteExt, process::Command, time};
 use tokio_util::codec;
 use toml::value::Table;
-use typetag::serde;
+use vector_config::configurable_component;
 
 use super::{loader, prepare_input};
 use crate::{
     config::{
+        format,
         loading::{deserialize_table, ComponentHint, Process},
-        ComponentKey,
+        ComponentKey, Format, ProxyConfig,
     },
+    http::HttpClient,
     signal,
+    tls::{TlsConfig, TlsSettings},
 };
 
 // The following regex aims to extract a pair of strings, the first being the secret backend name
@@ -37,12 +49,19 @@ pub trait SecretBackend: core::fmt::Debug + Send + Sync + dyn_clone::DynClone {
         secret_keys: Vec<String>,
         signal_rx: &mut signal::SignalRx,
     ) -> crate::Result<HashMap<String, String>>;
+
+    /// Returns how long the secrets from the last retrieval remain valid, if they expire.
+    fn lease_duration(&self) -> Option<Duration> {
+        None
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Default)]
 pub struct SecretBackendLoader {
     backends: IndexMap<ComponentKey, Box<dyn SecretBackend>>,
     pub(crate) secret_keys: HashMap<String, Vec<String>>,
+    #[serde(skip)]
+    imported_paths: Vec<PathBuf>,
 }
 
 impl SecretBackendLoader {
@@ -50,6 +69,7 @@ impl SecretBackendLoader {
         Self {
             backends: IndexMap::new(),
             secret_keys: HashMap::new(),
+            imported_paths: Vec::new(),
         }
     }
 
@@ -84,6 +104,16 @@ impl SecretBackendLoader {
     pub(crate) fn has_secrets_to_retrieve(&self) -> bool {
         !self.secret_keys.is_empty()
     }
+
+    /// Returns the delay after which the retrieved secrets should be retrieved again, ahead of
+    /// the expiration of the shortest of their leases.
+    pub(crate) fn renewal_delay(&self) -> Option<Duration> {
+        self.backends
+            .values()
+            .filter_map(|backend| backend.lease_duration())
+            .min()
+            .map(|lease_duration| lease_duration * 2 / 3)
+    }
 }
 
 impl Process for SecretBackendLoader {
@@ -94,6 +124,10 @@ impl Process for SecretBackendLoader {
         Ok((config_string, warnings))
     }
 
+    fn imported_paths(&mut self) -> &mut Vec<PathBuf> {
+        &mut self.imported_paths
+    }
+
     fn merge(&mut self, table: Table, _: Option<ComponentHint>) -> Result<(), Vec<String>> {
         if table.contains_key("secret") {
             let additional = deserialize_table::<SecretBackends>(table)?;
@@ -152,9 +186,14 @@ fn collect_secret_keys(input: &str, keys: &mut HashMap<String, Vec<String>>) {
     });
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-struct ExecBackend {
+/// Configuration for the `exec` secret backend.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub(crate) struct ExecBackend {
+    /// The command to run to retrieve secrets, and its arguments.
     pub command: Vec<String>,
+
+    /// The maximum time to wait for the command, in seconds.
     #[serde(default = "default_timeout_secs")]
     pub timeout: u64,
 }
@@ -288,6 +327,536 @@ async fn query_backend(
     Ok(response)
 }
 
+/// Collects the requested secrets with `get`, failing if any of them is missing or empty.
+fn collect_secrets(
+    secret_keys: Vec<String>,
+    mut get: impl FnMut(&str) -> crate::Result<Option<String>>,
+) -> crate::Result<HashMap<String, String>> {
+    let mut secrets = HashMap::new();
+    for k in secret_keys.into_iter() {
+        match get(&k)? {
+            None => return Err(format!("secret for key '{}' was not retrieved", k).into()),
+            Some(v) if v.is_empty() => {
+                return Err(format!("secret for key '{}' was empty", k).into())
+            }
+            Some(v) => {
+                secrets.insert(k, v);
+            }
+        }
+    }
+    Ok(secrets)
+}
+
+/// Configuration for the `file` secret backend.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub(crate) struct FileBackend {
+    /// The path of a JSON, TOML, or YAML file of secrets, keyed by their name.
+    pub path: PathBuf,
+}
+
+#[typetag::serde(name = "file")]
+impl SecretBackend for FileBackend {
+    fn retrieve(
+        &mut self,
+        secret_keys: Vec<String>,
+        _: &mut signal::SignalRx,
+    ) -> crate::Result<HashMap<String, String>> {
+        let file_format = Format::from_path(&self.path).map_err(|path| {
+            format!(
+                "unable to determine the format of secret file {:?} from its extension",
+                path
+            )
+        })?;
+        let contents = fs::read_to_string(&self.path)
+            .map_err(|e| format!("unable to read secret file {:?}: {}", self.path, e))?;
+        let output = format::deserialize::<HashMap<String, String>>(&contents, file_format)
+            .map_err(|e| {
+                format!(
+                    "unable to parse secret file {:?}: {}",
+                    self.path,
+                    e.join(", ")
+                )
+            })?;
+        collect_secrets(secret_keys, |k| Ok(output.get(k).cloned()))
+    }
+}
+
+/// Configuration for the `directory` secret backend.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub(crate) struct DirectoryBackend {
+    /// The path of a directory of secrets, where each file holds the secret it is named after.
+    pub path: PathBuf,
+
+    /// Whether or not to remove trailing whitespace from the secrets.
+    #[serde(default)]
+    pub remove_trailing_whitespace: bool,
+}
+
+#[typetag::serde(name = "directory")]
+impl SecretBackend for DirectoryBackend {
+    fn retrieve(
+        &mut self,
+        secret_keys: Vec<String>,
+        _: &mut signal::SignalRx,
+    ) -> crate::Result<HashMap<String, String>> {
+        collect_secrets(secret_keys, |k| {
+            // Keys can't contain path separators, but could still point outside of the directory.
+            if k == "." || k == ".." {
+                return Err(format!("secret key '{}' is not a valid file name", k).into());
+            }
+            let path = self.path.join(k);
+            match fs::read_to_string(&path) {
+                Ok(mut v) => {
+                    if self.remove_trailing_whitespace {
+                        v.truncate(v.trim_end().len());
+                    }
+                    Ok(Some(v))
+                }
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(format!("unable to read secret file {:?}: {}", path, e).into()),
+            }
+        })
+    }
+}
+
+/// Configuration for the `env` secret backend.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub(crate) struct EnvBackend {
+    /// A prefix prepended to the secret names to get the names of the environment variables.
+    #[serde(default)]
+    pub prefix: String,
+}
+
+#[typetag::serde(name = "env")]
+impl SecretBackend for EnvBackend {
+    fn retrieve(
+        &mut self,
+        secret_keys: Vec<String>,
+        _: &mut signal::SignalRx,
+    ) -> crate::Result<HashMap<String, String>> {
+        collect_secrets(secret_keys, |k| {
+            let var = format!("{}{}", self.prefix, k);
+            match env::var(&var) {
+                Ok(v) => Ok(Some(v)),
+                Err(env::VarError::NotPresent) => Ok(None),
+                Err(e) => Err(format!("unable to read environment variable {}: {}", var, e).into()),
+            }
+        })
+    }
+}
+
+/// Configuration for the `vault` secret backend.
+#[configurable_component]
+#[derive(Clone, Debug)]
+pub(crate) struct VaultBackend {
+    /// The address of the Vault server.
+    pub address: String,
+
+    /// The Vault Enterprise namespace of the secrets.
+    #[serde(default)]
+    pub namespace: Option<String>,
+
+    #[configurable(derived)]
+    pub auth: VaultAuth,
+
+    /// The mount path of the KV secrets engine.
+    #[serde(default = "default_vault_mount")]
+    pub mount: String,
+
+    /// The path of the secrets under the mount, for keys that don't name their own path.
+    #[serde(default)]
+    pub path: Option<String>,
+
+    /// The version of the KV secrets engine.
+    #[serde(default = "default_vault_kv_version")]
+    pub kv_version: u8,
+
+    #[configurable(derived)]
+    pub tls: Option<TlsConfig>,
+
+    /// The maximum time to wait for Vault, in seconds.
+    #[serde(default = "default_timeout_secs")]
+    pub timeout: u64,
+
+    #[serde(skip)]
+    lease_duration: Option<Duration>,
+}
+
+/// The method used to authenticate to Vault.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "strategy")]
+pub(crate) enum VaultAuth {
+    /// Authenticates with a Vault token.
+    Token {
+        /// The Vault token.
+        token: String,
+    },
+
+    /// Authenticates with the AppRole method.
+    #[serde(rename = "approle")]
+    AppRole {
+        /// The role ID.
+        role_id: String,
+
+        /// The secret ID.
+        secret_id: String,
+
+        /// The mount path of the AppRole method.
+        #[serde(default = "default_vault_approle_mount")]
+        mount: String,
+    },
+
+    /// Authenticates with the Kubernetes method, using the token of the service account.
+    Kubernetes {
+        /// The Vault role to log in with.
+        role: String,
+
+        /// The path of the service account token.
+        #[serde(default = "default_vault_kubernetes_token_path")]
+        token_path: PathBuf,
+
+        /// The mount path of the Kubernetes method.
+        #[serde(default = "default_vault_kubernetes_mount")]
+        mount: String,
+    },
+}
+
+fn default_vault_mount() -> String {
+    "secret".to_string()
+}
+
+const fn default_vault_kv_version() -> u8 {
+    2
+}
+
+fn default_vault_approle_mount() -> String {
+    "approle".to_string()
+}
+
+fn default_vault_kubernetes_mount() -> String {
+    "kubernetes".to_string()
+}
+
+fn default_vault_kubernetes_token_path() -> PathBuf {
+    "/var/run/secrets/kubernetes.io/serviceaccount/token".into()
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct VaultResponse {
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+    #[serde(default)]
+    lease_duration: u64,
+    #[serde(default)]
+    auth: Option<VaultAuthResponse>,
+}
+
+#[derive(Deserialize, Debug)]
+struct VaultAuthResponse {
+    client_token: String,
+    #[serde(default)]
+    lease_duration: u64,
+}
+
+/// The tokens obtained by logging in to Vault, by the backend settings they were obtained with.
+///
+/// Backends are built anew each time the configuration is loaded, so their tokens are kept here to
+/// be reused across reloads, until they expire.
+static VAULT_TOKENS: Lazy<Mutex<HashMap<String, VaultToken>>> = Lazy::new(Default::default);
+
+#[derive(Debug)]
+struct VaultToken {
+    token: String,
+    expires_at: Option<Instant>,
+}
+
+impl VaultToken {
+    /// Whether the token remains valid for at least `duration`.
+    fn is_valid_for(&self, duration: Duration) -> bool {
+        self.expires_at
+            .map_or(true, |expires_at| Instant::now() + duration < expires_at)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct VaultErrors {
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+const fn vault_lease(lease_duration: u64) -> Option<Duration> {
+    if lease_duration > 0 {
+        Some(Duration::from_secs(lease_duration))
+    } else {
+        None
+    }
+}
+
+impl VaultBackend {
+    /// Splits a secret key into the path of a secret, relative to the mount, and the field to
+    /// read from it. The last segment of the key is the field, and any preceding segments are
+    /// appended to the configured `path`.
+    fn secret_path<'a>(&self, key: &'a str) -> crate::Result<(String, &'a str)> {
+        let (relative, field) = key.rsplit_once('.').unwrap_or(("", key));
+        let path = self
+            .path
+            .iter()
+            .map(|path| path.trim_matches('/').to_string())
+            .chain(std::iter::once(relative.replace('.', "/")))
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join("/");
+        if path.is_empty() || field.is_empty() {
+            return Err(format!(
+                "secret key '{}' doesn't reference a secret path and field",
+                key
+            )
+            .into());
+        }
+        Ok((path, field))
+    }
+
+    async fn request(
+        &self,
+        client: &HttpClient,
+        method: Method,
+        path: &str,
+        token: Option<&str>,
+        body: Option<serde_json::Value>,
+    ) -> crate::Result<VaultResponse> {
+        let uri = format!("{}/v1/{}", self.address.trim_end_matches('/'), path);
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(namespace) = &self.namespace {
+            builder = builder.header("X-Vault-Namespace", namespace.as_str());
+        }
+        if let Some(token) = token {
+            builder = builder.header("X-Vault-Token", token);
+        }
+        let body = body.map_or_else(Body::empty, |body| Body::from(body.to_string()));
+
+        let response = client.send(builder.body(body)?).await?;
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await?;
+        if body.is_empty() && status.is_success() {
+            return Ok(VaultResponse::default());
+        }
+        if !status.is_success() {
+            let errors = serde_json::from_slice::<VaultErrors>(&body)
+                .map(|errors| errors.errors.join(", "))
+                .unwrap_or_default();
+            return Err(
+                format!("Vault responded to '{}' with {}: {}", path, status, errors).into(),
+            );
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// The key of the tokens obtained with the settings of this backend.
+    fn token_key(&self) -> String {
+        serde_json::to_string(&(&self.address, &self.namespace, &self.auth))
+            .expect("Vault auth settings should serialize to JSON")
+    }
+
+    /// Gets a token with the configured auth method.
+    ///
+    /// A token obtained by logging in is reused until it's about to expire, at which point it's
+    /// replaced by logging in again, and revoked.
+    async fn login(&self, client: &HttpClient) -> crate::Result<String> {
+        let (mount, body) = match &self.auth {
+            VaultAuth::Token { token } => return Ok(token.clone()),
+            VaultAuth::AppRole {
+                role_id,
+                secret_id,
+                mount,
+            } => (
+                mount,
+                serde_json::json!({ "role_id": role_id, "secret_id": secret_id }),
+            ),
+            VaultAuth::Kubernetes {
+                role,
+                token_path,
+                mount,
+            } => {
+                let jwt = fs::read_to_string(token_path).map_err(|e| {
+                    format!("unable to read Kubernetes token {:?}: {}", token_path, e)
+                })?;
+                (
+                    mount,
+                    serde_json::json!({ "role": role, "jwt": jwt.trim() }),
+                )
+            }
+        };
+
+        // The token must outlast the retrieval, which is bounded by the timeout.
+        let key = self.token_key();
+        let previous = match VAULT_TOKENS
+            .lock()
+            .expect("Vault tokens lock poisoned")
+            .get(&key)
+        {
+            Some(token) if token.is_valid_for(Duration::from_secs(self.timeout)) => {
+                return Ok(token.token.clone())
+            }
+            token => token.map(|token| token.token.clone()),
+        };
+
+        let auth = self
+            .request(
+                client,
+                Method::POST,
+                &format!("auth/{}/login", mount.trim_matches('/')),
+                None,
+                Some(body),
+            )
+            .await?
+            .auth
+            .ok_or("Vault login response didn't contain a token")?;
+        VAULT_TOKENS
+            .lock()
+            .expect("Vault tokens lock poisoned")
+            .insert(
+                key,
+                VaultToken {
+                    token: auth.client_token.clone(),
+                    expires_at: vault_lease(auth.lease_duration)
+                        .map(|lease| Instant::now() + lease),
+                },
+            );
+
+        if let Some(previous) = previous {
+            if let Err(error) = self
+                .request(
+                    client,
+                    Method::POST,
+                    "auth/token/revoke-self",
+                    Some(&previous),
+                    None,
+                )
+                .await
+            {
+                debug!(message = "Failed to revoke the previous Vault token.", %error);
+            }
+        }
+
+        Ok(auth.client_token)
+    }
+
+    /// Forgets `token`, unless it was already replaced, so that the next retrieval logs in again.
+    fn forget_token(&self, token: &str) {
+        let mut tokens = VAULT_TOKENS.lock().expect("Vault tokens lock poisoned");
+        let key = self.token_key();
+        if tokens
+            .get(&key)
+            .map_or(false, |cached| cached.token == token)
+        {
+            tokens.remove(&key);
+        }
+    }
+
+    /// Logs in and reads the secrets, returning them along with the shortest lease among them.
+    ///
+    /// Secrets of version 2 of the KV engine never have a lease.
+    async fn query(
+        &self,
+        secret_keys: Vec<String>,
+    ) -> crate::Result<(HashMap<String, String>, Option<Duration>)> {
+        let tls_settings = TlsSettings::from_options(&self.tls)?;
+        let client = HttpClient::<Body>::new(tls_settings, &ProxyConfig::from_env())?;
+        let token = self.login(&client).await?;
+        let result = self.read(&client, &token, secret_keys).await;
+        if result.is_err() {
+            // The token may have been revoked from outside of Vector.
+            self.forget_token(&token);
+        }
+        result
+    }
+
+    /// Reads the secrets with `token`.
+    async fn read(
+        &self,
+        client: &HttpClient,
+        token: &str,
+        secret_keys: Vec<String>,
+    ) -> crate::Result<(HashMap<String, String>, Option<Duration>)> {
+        let mut lease_duration = None;
+
+        let mut fetched = HashMap::<String, serde_json::Map<String, serde_json::Value>>::new();
+        for key in &secret_keys {
+            let (path, _) = self.secret_path(key)?;
+            if fetched.contains_key(&path) {
+                continue;
+            }
+            let mount = self.mount.trim_matches('/');
+            let api_path = match self.kv_version {
+                1 => format!("{}/{}", mount, path),
+                2 => format!("{}/data/{}", mount, path),
+                version => return Err(format!("unsupported KV version {}", version).into()),
+            };
+            let response = self
+                .request(client, Method::GET, &api_path, Some(token), None)
+                .await?;
+            lease_duration = lease_duration
+                .into_iter()
+                .chain(vault_lease(response.lease_duration))
+                .min();
+
+            // Version 2 of the KV engine nests the secret under the metadata of its version.
+            let data = match (self.kv_version, response.data) {
+                (2, Some(serde_json::Value::Object(mut data))) => data.remove("data"),
+                (_, data) => data,
+            };
+            match data {
+                Some(serde_json::Value::Object(data)) => {
+                    fetched.insert(path, data);
+                }
+                _ => return Err(format!("Vault secret '{}' doesn't contain any data", path).into()),
+            }
+        }
+
+        let secrets = collect_secrets(secret_keys, |k| {
+            let (path, field) = self.secret_path(k)?;
+            Ok(fetched[&path].get(field).map(|value| match value {
+                serde_json::Value::String(value) => value.clone(),
+                value => value.to_string(),
+            }))
+        })?;
+        Ok((secrets, lease_duration))
+    }
+}
+
+#[typetag::serde(name = "vault")]
+impl SecretBackend for VaultBackend {
+    fn retrieve(
+        &mut self,
+        secret_keys: Vec<String>,
+        signal_rx: &mut signal::SignalRx,
+    ) -> crate::Result<HashMap<String, String>> {
+        let backend = &*self;
+        let (secrets, lease_duration) = executor::block_on(async {
+            let timeout = time::sleep(Duration::from_secs(backend.timeout));
+            tokio::pin!(timeout);
+            tokio::select! {
+                biased;
+                Ok(signal::SignalTo::Shutdown | signal::SignalTo::Quit) = signal_rx.recv() => {
+                    Err("Secret retrieval was interrupted.".into())
+                }
+                result = backend.query(secret_keys) => result,
+                _ = &mut timeout => Err("Vault request timed-out".into()),
+            }
+        })?;
+        self.lease_duration = lease_duration;
+        Ok(secrets)
+    }
+
+    fn lease_duration(&self) -> Option<Duration> {
+        self.lease_duration
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
 struct TestBackend {
     pub replacement: String,
@@ -313,7 +882,19 @@ mod test {
 
     use indoc::indoc;
 
-    use super::{collect_secret_keys, interpolate};
+    use super::{
+        collect_secret_keys, interpolate, DirectoryBackend, EnvBackend, FileBackend, SecretBackend,
+    };
+    use crate::{signal, test_util::temp_dir};
+
+    fn retrieve(
+        backend: &mut dyn SecretBackend,
+        keys: &[&str],
+    ) -> crate::Result<HashMap<String, String>> {
+        let (_tx, mut rx): (signal::SignalTx, signal::SignalRx) =
+            tokio::sync::broadcast::channel(1);
+        backend.retrieve(keys.iter().map(|k| k.to_string()).collect(), &mut rx)
+    }
 
     #[test]
     fn replacement() {
@@ -385,4 +966,246 @@ mod test {
         assert!(second_backend_keys.contains(&"secret_key".into()));
         assert!(second_backend_keys.contains(&"secret.key".into()));
     }
+
+    #[test]
+    fn file_backend() {
+        let dir = temp_dir();
+        std::fs::create_dir_all(&dir).unwrap();
+        for (name, contents) in [
+            ("secrets.json", r#"{"api_key": "json_value", "empty": ""}"#),
+            ("secrets.yaml", "api_key: yaml_value\nempty: ''\n"),
+        ] {
+            let mut backend = FileBackend {
+                path: dir.join(name),
+            };
+            std::fs::write(&backend.path, contents).unwrap();
+
+            let secrets = retrieve(&mut backend, &["api_key", "api_key"]).unwrap();
+            assert_eq!(secrets.len(), 1);
+            assert!(secrets["api_key"].ends_with("_value"));
+            assert!(retrieve(&mut backend, &["empty"]).is_err());
+            assert!(retrieve(&mut backend, &["missing"]).is_err());
+        }
+    }
+
+    #[test]
+    fn directory_backend() {
+        let dir = temp_dir();
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("password"), "hunter2\n").unwrap();
+        std::fs::write(dir.join("db.user"), "vector").unwrap();
+
+        let mut backend = DirectoryBackend {
+            path: dir.clone(),
+            remove_trailing_whitespace: false,
+        };
+        let secrets = retrieve(&mut backend, &["password", "db.user"]).unwrap();
+        assert_eq!(secrets["password"], "hunter2\n");
+        assert_eq!(secrets["db.user"], "vector");
+        assert!(retrieve(&mut backend, &["missing"]).is_err());
+        assert!(retrieve(&mut backend, &[".."]).is_err());
+
+        backend.remove_trailing_whitespace = true;
+        let secrets = retrieve(&mut backend, &["password"]).unwrap();
+        assert_eq!(secrets["password"], "hunter2");
+
+        // Secrets are read again on every retrieval, so changes are picked up on reload.
+        std::fs::write(dir.join("password"), "hunter3").unwrap();
+        let secrets = retrieve(&mut backend, &["password"]).unwrap();
+        assert_eq!(secrets["password"], "hunter3");
+    }
+
+    #[test]
+    fn env_backend() {
+        std::env::set_var("VECTOR_TEST_SECRET_api_key", "value");
+        std::env::set_var("VECTOR_TEST_SECRET_empty", "");
+
+        let mut backend = EnvBackend {
+            prefix: "VECTOR_TEST_SECRET_".into(),
+        };
+        let secrets = retrieve(&mut backend, &["api_key"]).unwrap();
+        assert_eq!(secrets["api_key"], "value");
+        assert!(retrieve(&mut backend, &["empty"]).is_err());
+        assert!(retrieve(&mut backend, &["missing"]).is_err());
+    }
+
+    #[test]
+    fn vault_secret_path() {
+        let backend: VaultBackend = toml::from_str(indoc! {r#"
+            address = "http://127.0.0.1:8200"
+            auth = { strategy = "token", token = "token" }
+        "#})
+        .unwrap();
+        assert_eq!(
+            backend.secret_path("vector.db.password").unwrap(),
+            ("vector/db".to_string(), "password")
+        );
+        assert!(backend.secret_path("password").is_err());
+
+        let backend = VaultBackend {
+            path: Some("/teams/vector/".into()),
+            ..backend
+        };
+        assert_eq!(
+            backend.secret_path("password").unwrap(),
+            ("teams/vector".to_string(), "password")
+        );
+        assert_eq!(
+            backend.secret_path("db.password").unwrap(),
+            ("teams/vector/db".to_string(), "password")
+        );
+        assert!(backend.secret_path("db.").is_err());
+    }
+}
+
+#[cfg(all(test, feature = "vault-integration-tests"))]
+mod vault_integration_tests {
+    use std::{collections::HashMap, time::Duration};
+
+    use http::{Method, Request};
+    use hyper::Body;
+    use serde_json::json;
+
+    use super::{SecretBackend, VaultBackend, VAULT_TOKENS};
+    use crate::{config::ProxyConfig, http::HttpClient, signal, tls::TlsSettings};
+
+    const ROOT_TOKEN: &str = "vault-root-token";
+
+    fn vault_address() -> String {
+        std::env::var("VAULT_ADDR").unwrap_or_else(|_| "http://localhost:8200".into())
+    }
+
+    async fn vault_request(
+        method: Method,
+        path: &str,
+        body: serde_json::Value,
+    ) -> serde_json::Value {
+        let tls_settings = TlsSettings::from_options(&None).unwrap();
+        let client = HttpClient::<Body>::new(tls_settings, &ProxyConfig::default()).unwrap();
+        let request = Request::builder()
+            .method(method)
+            .uri(format!("{}/v1/{}", vault_address(), path))
+            .header("X-Vault-Token", ROOT_TOKEN)
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = client.send(request).await.unwrap();
+        assert!(
+            response.status().is_success(),
+            "{}: {}",
+            path,
+            response.status()
+        );
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null)
+    }
+
+    fn backend(config: &str) -> VaultBackend {
+        toml::from_str(&format!("address = {:?}\n{}", vault_address(), config)).unwrap()
+    }
+
+    async fn retrieve(
+        mut backend: VaultBackend,
+        keys: &[&str],
+    ) -> (VaultBackend, crate::Result<HashMap<String, String>>) {
+        let keys = keys.iter().map(|k| k.to_string()).collect();
+        tokio::task::spawn_blocking(move || {
+            let (_tx, mut rx): (signal::SignalTx, signal::SignalRx) =
+                tokio::sync::broadcast::channel(1);
+            let secrets = backend.retrieve(keys, &mut rx);
+            (backend, secrets)
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn kv_v2_with_token() {
+        vault_request(
+            Method::POST,
+            "secret/data/vector/db",
+            json!({ "data": { "password": "hunter2", "port": 5432 } }),
+        )
+        .await;
+
+        let backend = backend(&format!(
+            "auth = {{ strategy = \"token\", token = {:?} }}\npath = \"vector\"",
+            ROOT_TOKEN
+        ));
+        let (backend, secrets) = retrieve(backend, &["db.password", "db.port"]).await;
+        let secrets = secrets.unwrap();
+        assert_eq!(secrets["db.password"], "hunter2");
+        assert_eq!(secrets["db.port"], "5432");
+        assert_eq!(backend.lease_duration(), None);
+
+        let (_, secrets) = retrieve(backend, &["db.missing"]).await;
+        assert!(secrets.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn kv_v1_with_approle() {
+        vault_request(
+            Method::POST,
+            "sys/mounts/kv-v1",
+            json!({ "type": "kv", "options": { "version": "1" } }),
+        )
+        .await;
+        vault_request(
+            Method::POST,
+            "kv-v1/vector",
+            json!({ "token": "abc", "ttl": "1h" }),
+        )
+        .await;
+        vault_request(
+            Method::PUT,
+            "sys/policies/acl/vector",
+            json!({ "policy": "path \"kv-v1/*\" { capabilities = [\"read\"] }" }),
+        )
+        .await;
+        vault_request(
+            Method::POST,
+            "sys/auth/approle",
+            json!({ "type": "approle" }),
+        )
+        .await;
+        vault_request(
+            Method::POST,
+            "auth/approle/role/vector",
+            json!({ "token_ttl": "10m", "token_policies": ["vector"] }),
+        )
+        .await;
+        let role_id = vault_request(Method::GET, "auth/approle/role/vector/role-id", json!({}))
+            .await["data"]["role_id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let secret_id = vault_request(
+            Method::POST,
+            "auth/approle/role/vector/secret-id",
+            json!({}),
+        )
+        .await["data"]["secret_id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        let config = format!(
+            "auth = {{ strategy = \"approle\", role_id = {:?}, secret_id = {:?} }}\nmount = \"kv-v1\"\nkv_version = 1",
+            role_id, secret_id
+        );
+        let (approle, secrets) = retrieve(backend(&config), &["vector.token"]).await;
+        assert_eq!(secrets.unwrap()["vector.token"], "abc");
+        // Only the lease of the secret matters, not the one of the token it was read with.
+        assert_eq!(approle.lease_duration(), Some(Duration::from_secs(3600)));
+
+        // On reload, the backend is built anew, but the token is reused rather than logging in again.
+        let cached_token = || {
+            VAULT_TOKENS.lock().unwrap()[&approle.token_key()]
+                .token
+                .clone()
+        };
+        let token = cached_token();
+        let (_, secrets) = retrieve(backend(&config), &["vector.token"]).await;
+        assert_eq!(secrets.unwrap()["vector.token"], "abc");
+        assert_eq!(cached_token(), token);
+    }
 }
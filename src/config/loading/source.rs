@@ -1,4 +1,4 @@
-use std::io::Read;
+use std::{io::Read, path::PathBuf};
 
 use serde_toml_merge::merge_into_table;
 use toml::{map::Map, value::Table};
@@ -7,11 +7,15 @@ use super::{ComponentHint, Loader, Process};
 
 pub struct SourceLoader {
     table: Table,
+    imported_paths: Vec<PathBuf>,
 }
 
 impl SourceLoader {
     pub fn new() -> Self {
-        Self { table: Map::new() }
+        Self {
+            table: Map::new(),
+            imported_paths: Vec::new(),
+        }
     }
 }
 
@@ -27,6 +31,10 @@ impl Process for SourceLoader {
         Ok((source_string, vec![]))
     }
 
+    fn imported_paths(&mut self) -> &mut Vec<PathBuf> {
+        &mut self.imported_paths
+    }
+
     /// Merge values by combining with the internal TOML `Table`.
     fn merge(&mut self, table: Table, _hint: Option<ComponentHint>) -> Result<(), Vec<String>> {
         merge_into_table(&mut self.table, table).map_err(|e| vec![e.to_string()])
@@ -0,0 +1,312 @@
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde_toml_merge::merge_into_table;
+use toml::value::{Table, Value};
+
+// Matches a parameter placeholder in a component template, such as "PARAM[api_key]", capturing
+// the name of the parameter.
+static PARAM: Lazy<Regex> = Lazy::new(|| Regex::new(r"PARAM\[([[:word:]]+)\]").unwrap());
+
+/// The sections of a config whose components can be instantiated from a template.
+const COMPONENT_SECTIONS: [&str; 4] = ["sources", "transforms", "sinks", "enrichment_tables"];
+
+/// Instantiates the components of a loaded file that reference a template, with the `templates`
+/// defined in the file or in the files it imports.
+///
+/// A component instantiates a template with a `template` key naming it, and a `params` table
+/// whose values replace the `PARAM[<name>]` placeholders of the template. Any other keys of the
+/// component are deep-merged into the template, like separate config files are merged. Files that hold a single
+/// component, in a component directory, can instantiate a template at their root.
+pub(super) fn expand_templates(table: &mut Table) -> Result<(), Vec<String>> {
+    let templates = match table.remove("templates") {
+        None => Table::new(),
+        Some(Value::Table(templates)) => templates,
+        Some(_) => return Err(vec!["The `templates` key must be a table.".to_owned()]),
+    };
+
+    let mut errors = Vec::new();
+    if table.contains_key("template") {
+        if let Err(errs) = instantiate("<root>", table, &templates) {
+            errors.extend(errs);
+        }
+    }
+    for section in COMPONENT_SECTIONS {
+        if let Some(Value::Table(components)) = table.get_mut(section) {
+            for (name, component) in components.iter_mut() {
+                match component {
+                    Value::Table(component) if component.contains_key("template") => {
+                        if let Err(errs) = instantiate(name, component, &templates) {
+                            errors.extend(errs);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Replaces a component with an instance of the template it references.
+fn instantiate(name: &str, component: &mut Table, templates: &Table) -> Result<(), Vec<String>> {
+    let template_name = match component.remove("template") {
+        Some(Value::String(template_name)) => template_name,
+        _ => {
+            return Err(vec![format!(
+                "The `template` key of component {:?} must be the name of a template.",
+                name
+            )])
+        }
+    };
+    let mut template = match templates.get(&template_name) {
+        Some(Value::Table(template)) => template.clone(),
+        Some(_) => {
+            return Err(vec![format!(
+                "Template {:?} must be a table.",
+                template_name
+            )])
+        }
+        None => {
+            return Err(vec![format!(
+                "Component {:?} references undefined template {:?}.",
+                name, template_name
+            )])
+        }
+    };
+
+    // Templates can declare default values for their parameters.
+    let mut params = match template.remove("params") {
+        None => Table::new(),
+        Some(Value::Table(params)) => params,
+        Some(_) => {
+            return Err(vec![format!(
+                "The `params` key of template {:?} must be a table.",
+                template_name
+            )])
+        }
+    };
+    match component.remove("params") {
+        None => {}
+        Some(Value::Table(overrides)) => params.extend(overrides),
+        Some(_) => {
+            return Err(vec![format!(
+                "The `params` key of component {:?} must be a table.",
+                name
+            )])
+        }
+    }
+
+    let mut errors = Vec::new();
+    let mut instance = template
+        .into_iter()
+        .map(|(key, value)| (key, substitute(value, &params, &template_name, &mut errors)))
+        .collect::<Table>();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    merge_into_table(&mut instance, std::mem::take(component)).map_err(|err| {
+        vec![format!(
+            "Could not merge component {:?} into template {:?}, {}.",
+            name, template_name, err
+        )]
+    })?;
+    *component = instance;
+    Ok(())
+}
+
+/// Replaces the parameter placeholders in a value of a template. A string that consists of a
+/// single placeholder is replaced by the value of the parameter as-is, so that parameters can
+/// also be numbers, booleans, arrays or tables.
+fn substitute(
+    value: Value,
+    params: &Table,
+    template_name: &str,
+    errors: &mut Vec<String>,
+) -> Value {
+    match value {
+        Value::String(s) => {
+            let whole = PARAM
+                .captures(&s)
+                .filter(|caps| caps.get(0).map(|m| m.as_str().len()) == Some(s.len()));
+            if let Some(caps) = whole {
+                return lookup(&caps[1], params, template_name, errors)
+                    .cloned()
+                    .unwrap_or_else(|| Value::String(String::new()));
+            }
+
+            let substituted = PARAM.replace_all(&s, |caps: &Captures<'_>| {
+                match lookup(&caps[1], params, template_name, errors) {
+                    Some(Value::String(value)) => value.clone(),
+                    Some(Value::Integer(value)) => value.to_string(),
+                    Some(Value::Float(value)) => value.to_string(),
+                    Some(Value::Boolean(value)) => value.to_string(),
+                    Some(_) => {
+                        errors.push(format!(
+                            "Parameter {:?} of template {:?} can't be embedded in a string.",
+                            &caps[1], template_name
+                        ));
+                        String::new()
+                    }
+                    None => String::new(),
+                }
+            });
+            Value::String(substituted.into_owned())
+        }
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| substitute(value, params, template_name, errors))
+                .collect(),
+        ),
+        Value::Table(table) => Value::Table(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, substitute(value, params, template_name, errors)))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+fn lookup<'a>(
+    name: &str,
+    params: &'a Table,
+    template_name: &str,
+    errors: &mut Vec<String>,
+) -> Option<&'a Value> {
+    let value = params.get(name);
+    if value.is_none() {
+        errors.push(format!(
+            "Template {:?} requires parameter {:?}, which wasn't provided.",
+            template_name, name
+        ));
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    fn expand(input: &str) -> Result<Table, Vec<String>> {
+        let mut table = toml::from_str::<Table>(input).unwrap();
+        expand_templates(&mut table).map(|_| table)
+    }
+
+    #[test]
+    fn instantiates_templates() {
+        let table = expand(indoc! {r#"
+            [templates.team_sink]
+            type = "http"
+            inputs = "PARAM[inputs]"
+            uri = "https://PARAM[team].example.com/PARAM[port]"
+            encoding.codec = "json"
+            params.port = 443
+
+            [sinks.team_a]
+            template = "team_sink"
+            params = { team = "a", inputs = ["a_logs"] }
+
+            [sinks.team_b]
+            template = "team_sink"
+            params = { team = "b", inputs = ["b_logs"], port = 8443 }
+            encoding.codec = "text"
+        "#})
+        .unwrap();
+
+        let expected = toml::from_str::<Table>(indoc! {r#"
+            [sinks.team_a]
+            type = "http"
+            inputs = ["a_logs"]
+            uri = "https://a.example.com/443"
+            encoding.codec = "json"
+
+            [sinks.team_b]
+            type = "http"
+            inputs = ["b_logs"]
+            uri = "https://b.example.com/8443"
+            encoding.codec = "text"
+        "#})
+        .unwrap();
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn merges_nested_tables_into_templates() {
+        let table = expand(indoc! {r#"
+            [templates.console]
+            type = "console"
+            inputs = ["in"]
+            encoding.codec = "json"
+            encoding.except_fields = ["secret"]
+
+            [sinks.out]
+            template = "console"
+            encoding.timestamp_format = "unix"
+            encoding.except_fields = ["token"]
+        "#})
+        .unwrap();
+        let expected = toml::from_str::<Table>(indoc! {r#"
+            [sinks.out]
+            type = "console"
+            inputs = ["in"]
+            encoding.codec = "json"
+            encoding.except_fields = ["secret", "token"]
+            encoding.timestamp_format = "unix"
+        "#})
+        .unwrap();
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn instantiates_template_at_root() {
+        let table = expand(indoc! {r#"
+            template = "console"
+            params.inputs = ["in"]
+
+            [templates.console]
+            type = "console"
+            inputs = "PARAM[inputs]"
+        "#})
+        .unwrap();
+        let expected = toml::from_str::<Table>(indoc! {r#"
+            type = "console"
+            inputs = ["in"]
+        "#})
+        .unwrap();
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn reports_missing_templates_and_params() {
+        let errors = expand(indoc! {r#"
+            [templates.console]
+            type = "console"
+            inputs = ["PARAM[input]"]
+            encoding.codec = "PARAM[codec]"
+
+            [sinks.out]
+            template = "console"
+            params.codec = "json"
+
+            [sinks.other]
+            template = "missing"
+        "#})
+        .unwrap_err();
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert!(errors
+            .iter()
+            .any(|error| error.contains("parameter \"input\"")));
+        assert!(errors
+            .iter()
+            .any(|error| error.contains("undefined template \"missing\"")));
+    }
+}
@@ -10,6 +10,7 @@ use async_trait::async_trait;
 use component::ComponentDescription;
 use indexmap::IndexMap; // IndexMap preserves insertion order, allowing us to output errors in the same order they are present in the file
 use serde::{Deserialize, Serialize};
+use vector_config::configurable_component;
 pub use vector_core::config::{AcknowledgementsConfig, DataType, GlobalOptions, Input, Output};
 pub use vector_core::transform::{TransformConfig, TransformContext};
 
@@ -28,7 +29,7 @@ mod graph;
 mod id;
 mod loading;
 pub mod provider;
-mod schema;
+pub mod schema;
 mod sink;
 mod source;
 mod transform;
@@ -39,15 +40,18 @@ pub mod watcher;
 
 pub use builder::ConfigBuilder;
 pub use cmd::{cmd, Opts};
-pub use diff::ConfigDiff;
+pub use diff::{ConfigDiff, ConfigSnapshot, ReloadPlan};
 pub use format::{Format, FormatHint};
 pub use id::{ComponentKey, OutputId};
 pub use loading::{
     load, load_builder_from_paths, load_from_paths, load_from_paths_with_provider_and_secrets,
     load_from_str, load_source_from_paths, merge_path_lists, process_paths, SecretBackend,
-    CONFIG_PATHS,
+    CONFIG_PATHS, IMPORTED_PATHS,
+};
+pub(crate) use loading::{DirectoryBackend, EnvBackend, ExecBackend, FileBackend, VaultBackend};
+pub use sink::{
+    SinkConfig, SinkContext, SinkDescription, SinkHealthcheckOptions, SinkOuter, DEAD_LETTER_OUTPUT,
 };
-pub use sink::{SinkConfig, SinkContext, SinkDescription, SinkHealthcheckOptions, SinkOuter};
 pub use source::{SourceConfig, SourceContext, SourceDescription, SourceOuter};
 pub use transform::{TransformDescription, TransformOuter};
 pub use unit_test::{build_unit_tests, build_unit_tests_main, UnitTestResult};
@@ -214,10 +218,20 @@ impl Config {
     }
 }
 
-#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+/// Healthcheck options.
+#[configurable_component]
+#[derive(Debug, Clone, Copy)]
 #[serde(default)]
 pub struct HealthcheckOptions {
+    /// Whether or not healthchecks are enabled for all sinks.
+    ///
+    /// Can be overridden on a per-sink basis.
     pub enabled: bool,
+
+    /// Whether or not to require a sink to report as being healthy during startup.
+    ///
+    /// When enabled and a sink reports not being healthy, Vector will exit during start-up. Can be overridden by the
+    /// `--require-healthy` command-line flag.
     pub require_healthy: bool,
 }
 
@@ -1370,3 +1384,111 @@ mod pipelines_tests {
         assert!(res.is_err(), "should error");
     }
 }
+
+#[cfg(all(test, feature = "sources-stdin", feature = "sinks-splunk_hec"))]
+mod compression_tests {
+    use indoc::indoc;
+
+    use super::{load_from_str, Format};
+
+    #[test]
+    fn supported_compression() {
+        assert!(load_from_str(
+            indoc! {r#"
+                [sources.in]
+                  type = "stdin"
+
+                [sinks.out]
+                  type = "splunk_hec_logs"
+                  inputs = ["in"]
+                  endpoint = "https://localhost:8088"
+                  default_token = "token"
+                  encoding.codec = "json"
+                  compression = "gzip"
+            "#},
+            Format::Toml,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn unsupported_compression() {
+        let errors = load_from_str(
+            indoc! {r#"
+                [sources.in]
+                  type = "stdin"
+
+                [sinks.out]
+                  type = "splunk_hec_logs"
+                  inputs = ["in"]
+                  endpoint = "https://localhost:8088"
+                  default_token = "token"
+                  encoding.codec = "json"
+                  compression = "zstd"
+            "#},
+            Format::Toml,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec!["Sink out does not support `zstd` compression. Supported algorithms: `none`, `gzip`."]
+        );
+    }
+
+    fn assert_only_gzip_supported(config: &str) {
+        assert_eq!(
+            load_from_str(config, Format::Toml).unwrap_err(),
+            vec!["Sink out does not support `zstd` compression. Supported algorithms: `none`, `gzip`."]
+        );
+    }
+
+    #[cfg(feature = "sinks-aws_cloudwatch_logs")]
+    #[test]
+    fn aws_cloudwatch_logs_compression() {
+        assert_only_gzip_supported(indoc! {r#"
+            [sources.in]
+              type = "stdin"
+
+            [sinks.out]
+              type = "aws_cloudwatch_logs"
+              inputs = ["in"]
+              region = "us-east-1"
+              group_name = "group"
+              stream_name = "stream"
+              encoding.codec = "json"
+              compression = "zstd"
+        "#});
+    }
+
+    #[cfg(feature = "sinks-aws_cloudwatch_metrics")]
+    #[test]
+    fn aws_cloudwatch_metrics_compression() {
+        assert_only_gzip_supported(indoc! {r#"
+            [sources.in]
+              type = "stdin"
+
+            [sinks.out]
+              type = "aws_cloudwatch_metrics"
+              inputs = ["in"]
+              region = "us-east-1"
+              default_namespace = "service"
+              compression = "zstd"
+        "#});
+    }
+
+    #[cfg(feature = "sinks-new_relic_logs")]
+    #[test]
+    fn new_relic_logs_compression() {
+        assert_only_gzip_supported(indoc! {r#"
+            [sources.in]
+              type = "stdin"
+
+            [sinks.out]
+              type = "new_relic_logs"
+              inputs = ["in"]
+              license_key = "key"
+              compression = "zstd"
+        "#});
+    }
+}
@@ -2,10 +2,17 @@ use async_trait::async_trait;
 use component::ComponentDescription;
 use serde::{Deserialize, Serialize};
 use vector_buffers::{BufferConfig, BufferType};
-use vector_core::config::{AcknowledgementsConfig, GlobalOptions, Input};
+use vector_config::configurable_component;
+use vector_core::config::{AcknowledgementsConfig, GlobalOptions, Input, Output};
 
 use super::{component, schema, ComponentKey, ProxyConfig, Resource};
-use crate::sinks::{self, util::UriSerde};
+use crate::sinks::{
+    self,
+    util::{Compression, CompressionAlgorithm, UriSerde},
+};
+
+/// The name of the output on which a sink emits the events it rejected.
+pub const DEAD_LETTER_OUTPUT: &str = "dead_letter";
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct SinkOuter<T> {
@@ -28,6 +35,13 @@ pub struct SinkOuter<T> {
     )]
     proxy: ProxyConfig,
 
+    /// Whether events rejected by the sink are emitted on its `dead_letter` output.
+    #[serde(
+        default,
+        skip_serializing_if = "vector_core::serde::skip_serializing_if_default"
+    )]
+    pub dead_letter: bool,
+
     #[serde(flatten)]
     pub inner: Box<dyn SinkConfig>,
 }
@@ -41,6 +55,16 @@ impl<T> SinkOuter<T> {
             healthcheck_uri: None,
             inner,
             proxy: Default::default(),
+            dead_letter: false,
+        }
+    }
+
+    /// The outputs of the sink, which only has a `dead_letter` output when it is enabled.
+    pub fn outputs(&self) -> Vec<Output> {
+        if self.dead_letter {
+            vec![Output::default(self.inner.input().data_type()).with_port(DEAD_LETTER_OUTPUT)]
+        } else {
+            Vec::new()
         }
     }
 
@@ -92,14 +116,23 @@ impl<T> SinkOuter<T> {
             healthcheck: self.healthcheck,
             healthcheck_uri: self.healthcheck_uri,
             proxy: self.proxy,
+            dead_letter: self.dead_letter,
         }
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// Healthcheck configuration.
+#[configurable_component]
+#[derive(Debug, Clone)]
 #[serde(default)]
 pub struct SinkHealthcheckOptions {
+    /// Whether or not to check the health of the sink when Vector starts up.
     pub enabled: bool,
+
+    /// The full URI to make HTTP healthcheck requests to.
+    ///
+    /// This must be a valid URI, which requires at least the scheme and host. All other
+    /// components -- port, path, etc -- are allowed as well.
     pub uri: Option<UriSerde>,
 }
 
@@ -144,6 +177,18 @@ pub trait SinkConfig: core::fmt::Debug + Send + Sync {
         Vec::new()
     }
 
+    /// The compression configured for the sink, if it uses the shared `compression` option.
+    fn compression(&self) -> Option<Compression> {
+        None
+    }
+
+    /// The compression algorithms that the sink's backend accepts.
+    ///
+    /// The configured `compression` is checked against this list when the configuration is validated.
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        CompressionAlgorithm::ALL
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig>;
 }
 
@@ -187,6 +187,35 @@ pub fn check_outputs(config: &ConfigBuilder) -> Result<(), Vec<String>> {
     }
 }
 
+/// Check that each sink's configured compression is one that its backend accepts.
+pub fn check_compression(config: &ConfigBuilder) -> Result<(), Vec<String>> {
+    let errors: Vec<_> = config
+        .sinks
+        .iter()
+        .filter_map(|(key, sink)| {
+            let compression = sink.inner.compression()?;
+            let supported = sink.inner.supported_compression();
+            (!supported.contains(&compression.algorithm())).then(|| {
+                format!(
+                    "Sink {key} does not support `{}` compression. Supported algorithms: {}.",
+                    compression.algorithm(),
+                    supported
+                        .iter()
+                        .map(|algorithm| format!("`{algorithm}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 pub fn warnings(config: &Config) -> Vec<String> {
     let mut warnings = vec![];
     let mut cache = HashMap::new();
@@ -220,7 +249,15 @@ pub fn warnings(config: &Config) -> Vec<String> {
             .collect::<Vec<_>>()
     });
 
-    for (input_type, id) in transform_ids.chain(source_ids) {
+    let sink_ids = config.sinks.iter().flat_map(|(key, sink)| {
+        sink.outputs()
+            .into_iter()
+            .filter_map(|output| output.port)
+            .map(|port| ("sink", OutputId::from((key, port))))
+            .collect::<Vec<_>>()
+    });
+
+    for (input_type, id) in transform_ids.chain(source_ids).chain(sink_ids) {
         if !config
             .transforms
             .iter()
@@ -1,7 +1,7 @@
 use std::{path::PathBuf, time::Duration};
 #[cfg(unix)]
 use std::{
-    sync::mpsc::{channel, Receiver},
+    sync::mpsc::{channel, Receiver, RecvTimeoutError},
     thread,
 };
 
@@ -23,6 +23,11 @@ const CONFIG_WATCH_DELAY: std::time::Duration = std::time::Duration::from_secs(1
 #[cfg(unix)]
 const RETRY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
 
+/// How often the files imported by the loaded config are checked for additions or removals,
+/// since they only become known once the config has been loaded.
+#[cfg(unix)]
+const IMPORTS_REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
 /// Triggers SIGHUP when file on config_path changes.
 /// Accumulates file changes until no change for given duration has occurred.
 /// Has best effort guarantee of detecting all file changes from the end of
@@ -43,7 +48,20 @@ pub fn spawn_thread<'a>(
 
     thread::spawn(move || loop {
         if let Some((mut watcher, receiver)) = watcher.take() {
-            while let Ok(RawEvent { op: Ok(event), .. }) = receiver.recv() {
+            let mut imported_paths = Vec::new();
+            loop {
+                let event = match receiver.recv_timeout(IMPORTS_REFRESH_INTERVAL) {
+                    Ok(RawEvent { op: Ok(event), .. }) => event,
+                    Err(RecvTimeoutError::Timeout) => {
+                        if let Err(error) = update_imports(&mut watcher, &mut imported_paths) {
+                            error!(message = "Failed to read imported files to watch.", %error);
+                            break;
+                        }
+                        continue;
+                    }
+                    _ => break,
+                };
+
                 if event.intersects(Op::CREATE | Op::REMOVE | Op::WRITE | Op::CLOSE_WRITE) {
                     debug!(message = "Configuration file change detected.", event = ?event);
 
@@ -58,6 +76,13 @@ pub fn spawn_thread<'a>(
                         error!(message = "Failed to read files to watch.", %error);
                         break;
                     }
+                    // Imported files are re-added too, and the ones that were removed are
+                    // dropped on the next refresh of the imports.
+                    imported_paths.retain(|path| path.exists());
+                    if let Err(error) = add_paths(&mut watcher, &imported_paths) {
+                        error!(message = "Failed to read imported files to watch.", %error);
+                        break;
+                    }
 
                     debug!(message = "Reloaded paths.");
 
@@ -123,6 +148,39 @@ fn add_paths(watcher: &mut RecommendedWatcher, config_paths: &[PathBuf]) -> Resu
     Ok(())
 }
 
+/// Watches the files imported by the last loaded config, which change as the config is
+/// reloaded, in addition to the config paths.
+#[cfg(unix)]
+fn update_imports(
+    watcher: &mut RecommendedWatcher,
+    watched: &mut Vec<PathBuf>,
+) -> Result<(), Error> {
+    let imported_paths = crate::config::IMPORTED_PATHS
+        .lock()
+        .map(|guard| guard.clone())
+        .unwrap_or_default();
+    if imported_paths == *watched {
+        return Ok(());
+    }
+
+    for path in watched.iter() {
+        if !imported_paths.contains(path) {
+            // The file may have been removed, in which case it isn't watched anymore.
+            let _ = watcher.unwatch(path);
+        }
+    }
+    watched.clear();
+    for path in imported_paths {
+        // An import that was removed is reported by the next reload, and watched again once
+        // it's recreated and the config is reloaded.
+        if path.exists() {
+            watcher.watch(&path, RecursiveMode::NonRecursive)?;
+            watched.push(path);
+        }
+    }
+    Ok(())
+}
+
 #[cfg(all(test, unix, not(target_os = "macos")))] // https://github.com/vectordotdev/vector/issues/5000
 mod tests {
     use std::{fs::File, io::Write, time::Duration};
@@ -8,19 +8,25 @@ use std::{
 
 use bytes::Bytes;
 use enrichment::{Case, Condition, IndexHandle, Table};
-use serde::{Deserialize, Serialize};
 use tracing::trace;
 use value::Value;
 use vector_common::{conversion::Conversion, datetime::TimeZone};
+use vector_config::configurable_component;
 
 use crate::config::{EnrichmentTableConfig, EnrichmentTableDescription};
 
-#[derive(Deserialize, Serialize, Debug, Eq, PartialEq, Clone)]
+/// File encoding.
+#[configurable_component]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 enum Encoding {
+    /// Decodes the file as CSV (comma-separated values).
     Csv {
+        /// Whether or not the first row of the file holds the names of the columns.
         #[serde(default = "crate::serde::default_true")]
         include_headers: bool,
+
+        /// The character separating the columns.
         #[serde(default = "default_delimiter")]
         delimiter: char,
     },
@@ -35,15 +41,25 @@ impl Default for Encoding {
     }
 }
 
-#[derive(Deserialize, Serialize, Default, Debug, Eq, PartialEq, Clone)]
+/// File-specific settings.
+#[configurable_component]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 struct FileC {
+    /// The path of the file.
     path: PathBuf,
+
+    #[configurable(derived)]
     encoding: Encoding,
 }
 
-#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+/// Configuration for the `file` enrichment table.
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct FileConfig {
+    #[configurable(derived)]
     file: FileC,
+
+    /// The types of the columns of the file, keyed by their name.
     #[serde(default)]
     schema: HashMap<String, String>,
 }
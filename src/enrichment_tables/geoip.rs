@@ -5,8 +5,8 @@ use maxminddb::{
     geoip2::{City, Isp},
     MaxMindDBError, Reader,
 };
-use serde::{Deserialize, Serialize};
 use value::Value;
+use vector_config::configurable_component;
 
 use crate::config::{EnrichmentTableConfig, EnrichmentTableDescription, GenerateConfig};
 
@@ -16,9 +16,16 @@ use crate::config::{EnrichmentTableConfig, EnrichmentTableDescription, GenerateC
 const ASN_DATABASE_TYPE: &str = "GeoLite2-ASN";
 const ISP_DATABASE_TYPE: &str = "GeoIP2-ISP";
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// Configuration for the `geoip` enrichment table.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq)]
 pub struct GeoipConfig {
+    /// The path of the MaxMind GeoIP database file.
     pub path: String,
+
+    /// The locale to use when looking up names in the database.
+    ///
+    /// Valid locales are `de`, `en`, `es`, `fr`, `ja`, `pt-BR`, `ru`, and `zh-CN`.
     #[serde(default = "default_locale")]
     pub locale: String,
 }
@@ -0,0 +1,339 @@
+use std::{fs, path::PathBuf};
+
+use clap::Parser;
+use colored::*;
+use indexmap::IndexMap;
+use vector_buffers::BufferConfig;
+use vector_config::{configurable_component, schema::generate_root_schema};
+use vector_core::config::GlobalOptions;
+
+#[cfg(feature = "api")]
+use crate::config::api;
+use crate::{
+    config::{
+        schema, DirectoryBackend, EnvBackend, ExecBackend, FileBackend, HealthcheckOptions,
+        ProxyConfig, SinkHealthcheckOptions, VaultBackend,
+    },
+    providers::http::HttpConfig,
+    sinks::Sinks,
+    sources::Sources,
+    transforms::Transforms,
+};
+
+#[derive(Parser, Debug)]
+#[clap(rename_all = "kebab-case")]
+pub struct Opts {
+    /// File path to write the schema to. Writes to standard output if not set.
+    #[clap(short, long, parse(from_os_str))]
+    output_path: Option<PathBuf>,
+}
+
+// The types below only describe the configuration file, for the schema. The configuration is
+// actually loaded with the `ConfigBuilder`, where components, enrichment tables, providers and
+// secret backends are trait objects that aren't `Configurable`, so these types wrap their concrete
+// configurations in enums tagged like `typetag` does, and have to be kept in sync with
+// `ConfigBuilder` and the `*Outer` types. The tests check that every top-level key of
+// `ConfigBuilder` is described.
+
+/// The configuration of Vector.
+#[configurable_component]
+#[derive(Clone, Debug)]
+struct VectorConfig {
+    #[configurable(derived)]
+    #[serde(flatten)]
+    global: GlobalOptions,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    healthchecks: HealthcheckOptions,
+
+    /// The sources of the configuration, keyed by their ID.
+    #[serde(default)]
+    sources: IndexMap<String, SourceOuter>,
+
+    /// The transforms of the configuration, keyed by their ID.
+    #[serde(default)]
+    transforms: IndexMap<String, TransformOuter>,
+
+    /// The sinks of the configuration, keyed by their ID.
+    #[serde(default)]
+    sinks: IndexMap<String, SinkOuter>,
+
+    #[cfg(feature = "api")]
+    #[configurable(derived)]
+    #[serde(default)]
+    api: api::Options,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    schema: schema::Options,
+
+    /// The options for reporting to Datadog Observability Pipelines.
+    #[cfg(feature = "enterprise")]
+    #[serde(default)]
+    enterprise: Option<toml::Value>,
+
+    /// The enrichment tables of the configuration, keyed by their ID.
+    #[serde(default)]
+    enrichment_tables: IndexMap<String, EnrichmentTableOuter>,
+
+    /// The unit tests of the configuration.
+    #[serde(default)]
+    tests: Vec<TestDefinition>,
+
+    #[configurable(derived)]
+    provider: Option<ProviderOuter>,
+
+    /// The secret backends of the configuration, keyed by their ID.
+    #[serde(default)]
+    secret: IndexMap<String, SecretBackendOuter>,
+
+    /// Paths or glob patterns of other config files to merge into this one, relative to its directory.
+    #[serde(default)]
+    imports: Vec<String>,
+
+    /// Component templates, keyed by their name, that components can instantiate with their
+    /// `template` and `params` keys.
+    #[serde(default)]
+    templates: IndexMap<String, toml::Value>,
+}
+
+/// An enrichment table.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum EnrichmentTableOuter {
+    /// Exposes the rows of a file as an enrichment table.
+    #[cfg(feature = "enrichment-tables-file")]
+    File(#[configurable(derived)] crate::enrichment_tables::file::FileConfig),
+
+    /// Exposes a MaxMind GeoIP database as an enrichment table.
+    #[cfg(feature = "enrichment-tables-geoip")]
+    Geoip(#[configurable(derived)] crate::enrichment_tables::geoip::GeoipConfig),
+}
+
+/// A unit test.
+#[configurable_component]
+#[derive(Clone, Debug)]
+struct TestDefinition {
+    /// The name of the test.
+    name: String,
+
+    /// The event to send to a component.
+    input: Option<toml::Value>,
+
+    /// The events to send to components.
+    #[serde(default)]
+    inputs: Vec<toml::Value>,
+
+    /// The conditions that the outputs of components must meet.
+    #[serde(default)]
+    outputs: Vec<toml::Value>,
+
+    /// The components that must not output any event.
+    #[serde(default)]
+    no_outputs_from: Vec<String>,
+}
+
+/// A provider of the configuration, which replaces the configuration of the file.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ProviderOuter {
+    /// Polls the configuration from an HTTP endpoint.
+    Http(#[configurable(derived)] HttpConfig),
+}
+
+/// A secret backend.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum SecretBackendOuter {
+    /// Retrieves secrets from the output of a command.
+    Exec(#[configurable(derived)] ExecBackend),
+
+    /// Retrieves secrets from a JSON, TOML or YAML file of key/value pairs.
+    File(#[configurable(derived)] FileBackend),
+
+    /// Retrieves secrets from the files of a directory, named after the secrets.
+    Directory(#[configurable(derived)] DirectoryBackend),
+
+    /// Retrieves secrets from environment variables.
+    Env(#[configurable(derived)] EnvBackend),
+
+    /// Retrieves secrets from a HashiCorp Vault KV secrets engine.
+    Vault(#[configurable(derived)] VaultBackend),
+}
+
+/// A source.
+#[configurable_component]
+#[derive(Clone, Debug)]
+struct SourceOuter {
+    #[configurable(derived)]
+    #[serde(default)]
+    proxy: ProxyConfig,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    inner: Sources,
+}
+
+/// A transform.
+#[configurable_component]
+#[derive(Clone, Debug)]
+struct TransformOuter {
+    /// A list of upstream source or transform IDs. Wildcards (`*`) are supported.
+    #[serde(default)]
+    inputs: Vec<String>,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    inner: Transforms,
+}
+
+/// A sink.
+#[configurable_component]
+#[derive(Clone, Debug)]
+struct SinkOuter {
+    /// A list of upstream source or transform IDs. Wildcards (`*`) are supported.
+    #[serde(default)]
+    inputs: Vec<String>,
+
+    /// The full URI to make HTTP healthcheck requests to.
+    #[configurable(deprecated)]
+    healthcheck_uri: Option<String>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    healthcheck: SinkHealthcheckOptions,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    buffer: BufferConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    proxy: ProxyConfig,
+
+    /// Whether events rejected by the sink are emitted on its `dead_letter` output.
+    #[serde(default)]
+    dead_letter: bool,
+
+    #[configurable(derived)]
+    #[serde(flatten)]
+    inner: Sinks,
+}
+
+/// Renders the JSON Schema of the configuration, covering every component compiled into this build.
+pub fn generate_schema() -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&generate_root_schema::<VectorConfig>())
+}
+
+pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    let schema = match generate_schema() {
+        Ok(schema) => schema,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("{}", format!("Couldn't render the schema: {}", error).red());
+            }
+            return exitcode::SOFTWARE;
+        }
+    };
+
+    match &opts.output_path {
+        Some(path) => {
+            if let Err(error) = fs::write(path, schema) {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!(
+                        "{}",
+                        format!("Couldn't write the schema to {:?}: {}", path, error).red()
+                    );
+                }
+                return exitcode::IOERR;
+            }
+        }
+        None => {
+            #[allow(clippy::print_stdout)]
+            {
+                println!("{}", schema);
+            }
+        }
+    }
+
+    exitcode::OK
+}
+
+#[cfg(all(test, feature = "sources-stdin", feature = "sinks-file"))]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use serde_json::Value;
+
+    use super::*;
+    use crate::config::ConfigBuilder;
+
+    /// Collects the names of the properties of an object schema, following `allOf` and `$ref`.
+    fn collect_properties(root: &Value, schema: &Value, properties: &mut BTreeSet<String>) {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let name = reference.trim_start_matches("#/definitions/");
+            collect_properties(root, &root["definitions"][name], properties);
+        }
+        if let Some(object) = schema["properties"].as_object() {
+            properties.extend(object.keys().cloned());
+        }
+        if let Some(schemas) = schema["allOf"].as_array() {
+            for schema in schemas {
+                collect_properties(root, schema, properties);
+            }
+        }
+    }
+
+    #[test]
+    fn generates_schema() {
+        let schema = serde_json::from_str::<Value>(&generate_schema().unwrap()).unwrap();
+        assert!(schema["$schema"].is_string());
+
+        let definitions = schema["definitions"].as_object().unwrap();
+        let definition = |name: &str| {
+            definitions
+                .iter()
+                .find(|(key, _)| key.ends_with(name))
+                .map(|(_, definition)| definition)
+                .unwrap_or_else(|| panic!("missing definition for {}", name))
+        };
+
+        let buffer = definition("BufferType").to_string();
+        assert!(buffer.contains("max_events"));
+        assert!(buffer.contains("drop_newest"));
+
+        let global = definition("GlobalOptions");
+        assert!(global["properties"]["data_dir"]["description"].is_string());
+
+        // The `path` of the `file` sink is a template.
+        assert!(schema
+            .to_string()
+            .contains(r#""_metadata":{"templatable":true}"#));
+    }
+
+    #[test]
+    fn describes_every_top_level_key() {
+        let schema = serde_json::from_str::<Value>(&generate_schema().unwrap()).unwrap();
+        let mut properties = BTreeSet::new();
+        collect_properties(&schema, &schema, &mut properties);
+
+        let builder = serde_json::to_value(ConfigBuilder::default()).unwrap();
+        let missing = builder
+            .as_object()
+            .unwrap()
+            .keys()
+            .filter(|key| !properties.contains(*key))
+            .collect::<Vec<_>>();
+        assert!(missing.is_empty(), "missing from the schema: {:?}", missing);
+
+        // Imports and templates are resolved while loading, so they never reach the builder.
+        assert!(properties.contains("imports"));
+        assert!(properties.contains("templates"));
+    }
+}
@@ -7,11 +7,18 @@ use vector_core::internal_event::InternalEvent;
 pub struct ApiStarted {
     pub addr: SocketAddr,
     pub playground: bool,
+    pub tls: bool,
 }
 
 impl InternalEvent for ApiStarted {
     fn emit(self) {
-        let playground = &*format!("http://{}:{}/playground", self.addr.ip(), self.addr.port());
+        let scheme = if self.tls { "https" } else { "http" };
+        let playground = &*format!(
+            "{}://{}:{}/playground",
+            scheme,
+            self.addr.ip(),
+            self.addr.port()
+        );
         info!(
             message="API server running.",
             address = ?self.addr,
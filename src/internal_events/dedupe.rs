@@ -1,6 +1,8 @@
 use metrics::counter;
 use vector_core::internal_event::InternalEvent;
 
+use super::prelude::{error_stage, error_type};
+
 #[derive(Debug)]
 pub struct DedupeEventDiscarded {
     pub event: crate::event::Event,
@@ -12,3 +14,28 @@ impl InternalEvent for DedupeEventDiscarded {
         counter!("events_discarded_total", 1);
     }
 }
+
+#[derive(Debug)]
+pub struct DedupeCacheIoError<'a> {
+    pub error: std::io::Error,
+    pub path: &'a std::path::Path,
+    pub operation: &'static str,
+}
+
+impl<'a> InternalEvent for DedupeCacheIoError<'a> {
+    fn emit(self) {
+        error!(
+            message = "Failed to access the persisted dedupe cache.",
+            error = %self.error,
+            path = ?self.path,
+            operation = %self.operation,
+            error_type = error_type::IO_FAILED,
+            stage = error_stage::PROCESSING,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_type" => error_type::IO_FAILED,
+            "stage" => error_stage::PROCESSING,
+        );
+    }
+}
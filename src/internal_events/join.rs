@@ -0,0 +1,12 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct JoinMaxEventsReached;
+
+impl InternalEvent for JoinMaxEventsReached {
+    fn emit(self) {
+        debug!(message = "Too many events waiting for a match; event is unmatched right away.");
+        counter!("join_max_events_reached_total", 1);
+    }
+}
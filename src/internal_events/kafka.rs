@@ -109,6 +109,29 @@ impl InternalEvent for KafkaReadError {
     }
 }
 
+#[derive(Debug)]
+pub struct KafkaDeliveryError {
+    pub error: rdkafka::error::KafkaError,
+}
+
+impl InternalEvent for KafkaDeliveryError {
+    fn emit(self) {
+        error!(
+            message = "Failed to deliver message.",
+            error = %self.error,
+            error_code = "delivering_message",
+            error_type = error_type::WRITER_FAILED,
+            stage = error_stage::SENDING,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "delivering_message",
+            "error_type" => error_type::WRITER_FAILED,
+            "stage" => error_stage::SENDING,
+        );
+    }
+}
+
 #[derive(Debug)]
 pub struct KafkaStatisticsReceived<'a> {
     pub statistics: &'a rdkafka::Statistics,
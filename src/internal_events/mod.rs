@@ -55,6 +55,8 @@ mod http;
 pub mod http_client;
 #[cfg(feature = "sources-internal_logs")]
 mod internal_logs;
+#[cfg(feature = "transforms-join")]
+mod join;
 #[cfg(all(unix, feature = "sources-journald"))]
 mod journald;
 #[cfg(any(feature = "sources-kafka", feature = "sinks-kafka"))]
@@ -77,6 +79,8 @@ mod nats;
 #[cfg(feature = "sources-nginx_metrics")]
 mod nginx_metrics;
 mod open;
+#[cfg(feature = "sinks-opentelemetry")]
+mod opentelemetry_sink;
 #[cfg(any(
     feature = "sinks-datadog_events",
     feature = "sources-kubernetes_logs",
@@ -93,6 +97,8 @@ mod prometheus;
 mod redis;
 #[cfg(feature = "transforms-reduce")]
 mod reduce;
+#[cfg(feature = "transforms-relabel")]
+mod relabel;
 mod remap;
 mod sample;
 #[cfg(feature = "sinks-sematext")]
@@ -108,6 +114,8 @@ mod statsd_source;
 mod syslog;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 mod tag_cardinality_limit;
+#[cfg(feature = "transforms-tail_sample")]
+mod tail_sample;
 mod tcp;
 mod template;
 #[cfg(feature = "transforms-throttle")]
@@ -189,6 +197,8 @@ pub(crate) use self::geoip::*;
 pub(crate) use self::http::*;
 #[cfg(feature = "sources-internal_logs")]
 pub(crate) use self::internal_logs::*;
+#[cfg(feature = "transforms-join")]
+pub(crate) use self::join::*;
 #[cfg(all(unix, feature = "sources-journald"))]
 pub(crate) use self::journald::*;
 #[cfg(any(feature = "sources-kafka", feature = "sinks-kafka"))]
@@ -209,6 +219,8 @@ pub(crate) use self::metric_to_log::*;
 pub(crate) use self::nats::*;
 #[cfg(feature = "sources-nginx_metrics")]
 pub(crate) use self::nginx_metrics::*;
+#[cfg(feature = "sinks-opentelemetry")]
+pub(crate) use self::opentelemetry_sink::*;
 #[cfg(any(
     feature = "sinks-datadog_events",
     feature = "sources-kubernetes_logs",
@@ -224,6 +236,8 @@ pub(crate) use self::prometheus::*;
 pub(crate) use self::redis::*;
 #[cfg(feature = "transforms-reduce")]
 pub(crate) use self::reduce::*;
+#[cfg(feature = "transforms-relabel")]
+pub(crate) use self::relabel::*;
 #[cfg(feature = "transforms-remap")]
 pub(crate) use self::remap::*;
 #[cfg(feature = "transforms-sample")]
@@ -240,6 +254,8 @@ pub(crate) use self::statsd_source::*;
 pub(crate) use self::syslog::*;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 pub(crate) use self::tag_cardinality_limit::*;
+#[cfg(feature = "transforms-tail_sample")]
+pub(crate) use self::tail_sample::*;
 #[cfg(feature = "transforms-throttle")]
 pub(crate) use self::throttle::*;
 #[cfg(all(
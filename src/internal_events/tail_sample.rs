@@ -0,0 +1,26 @@
+use metrics::counter;
+use vector_core::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct TailSampleTraceDiscarded {
+    pub count: usize,
+}
+
+impl InternalEvent for TailSampleTraceDiscarded {
+    fn emit(self) {
+        trace!(message = "Trace discarded.", count = self.count);
+        counter!("events_discarded_total", self.count as u64);
+    }
+}
+
+#[derive(Debug)]
+pub struct TailSampleTraceDecidedEarly;
+
+impl InternalEvent for TailSampleTraceDecidedEarly {
+    fn emit(self) {
+        debug!(
+            message = "Too many traces waiting for a decision; deciding on the oldest one early."
+        );
+        counter!("traces_decided_early_total", 1);
+    }
+}
@@ -36,6 +36,7 @@ pub mod dns;
 pub mod docker;
 pub mod expiring_hash_map;
 pub mod generate;
+pub mod generate_schema;
 #[macro_use]
 #[allow(unreachable_pub)]
 pub mod internal_events;
@@ -44,6 +45,7 @@ pub mod internal_events;
 pub mod api;
 pub mod app;
 pub mod async_read;
+pub(crate) mod buffer;
 #[cfg(feature = "aws-config")]
 pub mod aws;
 #[allow(unreachable_pub)]
@@ -1,19 +1,32 @@
 use super::{
-    Common::{any_value::Value as PBValue, KeyValue},
+    Common::{any_value::Value as PBValue, AnyValue, ArrayValue, KeyValue, KeyValueList},
     Logs::{LogRecord, ResourceLogs, SeverityNumber},
+    Metrics::{
+        exponential_histogram_data_point::Buckets, metric::Data, number_data_point,
+        summary_data_point::ValueAtQuantile, AggregationTemporality, ExponentialHistogramDataPoint,
+        Gauge, Histogram, HistogramDataPoint, Metric as OtelMetric, NumberDataPoint,
+        ResourceMetrics, Sum, Summary, SummaryDataPoint,
+    },
     Resource as OtelResource,
+    Trace::{
+        span::{Event as SpanEvent, Link},
+        ResourceSpans, Span, Status,
+    },
 };
 use bytes::Bytes;
-use chrono::{TimeZone, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 use ordered_float::NotNan;
 use std::collections::BTreeMap;
 use value::Value;
 use vector_core::{
     config::log_schema,
-    event::{Event, LogEvent},
+    event::{
+        metric::{Bucket, Quantile},
+        Event, LogEvent, Metric, MetricKind, MetricTags, MetricValue, TraceEvent,
+    },
 };
 
-const RESOURCE_KEY: &str = "resources";
+pub const RESOURCE_KEY: &str = "resources";
 const ATTRIBUTES_KEY: &str = "attributes";
 const TRACE_ID_KEY: &str = "trace_id";
 const SPAN_ID_KEY: &str = "span_id";
@@ -22,6 +35,21 @@ const SEVERITY_NUMBER_KEY: &str = "severity_number";
 const OBSERVED_TIMESTAMP_KEY: &str = "observed_timestamp";
 const DROPPED_ATTRIBUTES_COUNT_KEY: &str = "dropped_attributes_count";
 const FLAGS_KEY: &str = "flags";
+const PARENT_SPAN_ID_KEY: &str = "parent_span_id";
+const TRACE_STATE_KEY: &str = "trace_state";
+const NAME_KEY: &str = "name";
+const KIND_KEY: &str = "kind";
+const START_TIME_KEY: &str = "start_time";
+const END_TIME_KEY: &str = "end_time";
+const TIME_KEY: &str = "time";
+const EVENTS_KEY: &str = "events";
+const LINKS_KEY: &str = "links";
+const STATUS_KEY: &str = "status";
+const MESSAGE_KEY: &str = "message";
+const CODE_KEY: &str = "code";
+const DROPPED_EVENTS_COUNT_KEY: &str = "dropped_events_count";
+const DROPPED_LINKS_COUNT_KEY: &str = "dropped_links_count";
+const INGEST_TIMESTAMP_KEY: &str = "ingest_timestamp";
 
 impl IntoIterator for ResourceLogs {
     type Item = Event;
@@ -142,3 +170,673 @@ impl From<ResourceLog> for Event {
         le.into()
     }
 }
+
+impl IntoIterator for ResourceMetrics {
+    type Item = Event;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        let resource_tags = self
+            .resource
+            .map(|resource| kv_list_into_tags(resource.attributes))
+            .unwrap_or_default();
+        self.scope_metrics
+            .into_iter()
+            .flat_map(|scope_metrics| scope_metrics.metrics)
+            .flat_map(|metric| {
+                let name = metric.name;
+                let resource_tags = &resource_tags;
+                metric_data_into_metrics(metric.data)
+                    .into_iter()
+                    .map(move |(attributes, timestamp, kind, value)| {
+                        let mut tags = resource_tags.clone();
+                        tags.extend(kv_list_into_tags(attributes));
+                        Metric::new(name.clone(), kind, value)
+                            .with_tags((!tags.is_empty()).then(|| tags))
+                            .with_timestamp(timestamp)
+                            .into()
+                    })
+                    .collect::<Vec<Self::Item>>()
+            })
+            .collect::<Vec<Self::Item>>()
+            .into_iter()
+    }
+}
+
+/// A single data point, flattened out of its OTLP metric.
+type DataPoint = (
+    Vec<KeyValue>,
+    Option<DateTime<Utc>>,
+    MetricKind,
+    MetricValue,
+);
+
+fn metric_data_into_metrics(data: Option<Data>) -> Vec<DataPoint> {
+    match data {
+        Some(Data::Gauge(gauge)) => gauge
+            .data_points
+            .into_iter()
+            .map(|point| number_point(point, MetricKind::Absolute, false))
+            .collect(),
+        Some(Data::Sum(sum)) => {
+            let kind = temporality_into_kind(sum.aggregation_temporality);
+            sum.data_points
+                .into_iter()
+                .map(|point| number_point(point, kind, sum.is_monotonic))
+                .collect()
+        }
+        Some(Data::Histogram(histogram)) => {
+            let kind = temporality_into_kind(histogram.aggregation_temporality);
+            histogram
+                .data_points
+                .into_iter()
+                .map(|point| histogram_point(point, kind))
+                .collect()
+        }
+        Some(Data::ExponentialHistogram(histogram)) => {
+            let kind = temporality_into_kind(histogram.aggregation_temporality);
+            histogram
+                .data_points
+                .into_iter()
+                .map(|point| exponential_histogram_point(point, kind))
+                .collect()
+        }
+        Some(Data::Summary(summary)) => {
+            summary.data_points.into_iter().map(summary_point).collect()
+        }
+        None => Vec::new(),
+    }
+}
+
+fn temporality_into_kind(temporality: i32) -> MetricKind {
+    if temporality == AggregationTemporality::Delta as i32 {
+        MetricKind::Incremental
+    } else {
+        MetricKind::Absolute
+    }
+}
+
+fn nanos_into_timestamp(nanos: u64) -> Option<DateTime<Utc>> {
+    (nanos > 0).then(|| Utc.timestamp_nanos(nanos as i64))
+}
+
+fn number_point(point: NumberDataPoint, kind: MetricKind, monotonic: bool) -> DataPoint {
+    let value = match point.value {
+        Some(number_data_point::Value::AsDouble(value)) => value,
+        Some(number_data_point::Value::AsInt(value)) => value as f64,
+        None => 0.0,
+    };
+    // Non-monotonic sums can go down, so they are only representable as gauges.
+    let value = if monotonic {
+        MetricValue::Counter { value }
+    } else {
+        MetricValue::Gauge { value }
+    };
+    (
+        point.attributes,
+        nanos_into_timestamp(point.time_unix_nano),
+        kind,
+        value,
+    )
+}
+
+fn histogram_point(point: HistogramDataPoint, kind: MetricKind) -> DataPoint {
+    // There is always one more bucket than there are bounds, the last one being unbounded.
+    let buckets = point
+        .bucket_counts
+        .into_iter()
+        .enumerate()
+        .map(|(index, count)| Bucket {
+            upper_limit: point
+                .explicit_bounds
+                .get(index)
+                .copied()
+                .unwrap_or(f64::INFINITY),
+            count,
+        })
+        .collect();
+    (
+        point.attributes,
+        nanos_into_timestamp(point.time_unix_nano),
+        kind,
+        MetricValue::AggregatedHistogram {
+            buckets,
+            count: point.count,
+            sum: point.sum.unwrap_or_default(),
+        },
+    )
+}
+
+fn exponential_histogram_point(
+    point: ExponentialHistogramDataPoint,
+    kind: MetricKind,
+) -> DataPoint {
+    // Bucket `index` covers `(base^index, base^(index + 1)]`, with `base = 2^(2^-scale)`.
+    let base = 2f64.powf(2f64.powi(-point.scale));
+    let mut buckets = Vec::new();
+
+    if let Some(Buckets {
+        offset,
+        bucket_counts,
+    }) = point.negative
+    {
+        // Negative buckets mirror the positive ones, so the ones furthest from zero come first.
+        buckets.extend(
+            bucket_counts
+                .into_iter()
+                .enumerate()
+                .rev()
+                .map(|(index, count)| Bucket {
+                    upper_limit: -base.powi(offset + index as i32),
+                    count,
+                }),
+        );
+    }
+
+    buckets.push(Bucket {
+        upper_limit: point.zero_threshold,
+        count: point.zero_count,
+    });
+
+    if let Some(Buckets {
+        offset,
+        bucket_counts,
+    }) = point.positive
+    {
+        buckets.extend(
+            bucket_counts
+                .into_iter()
+                .enumerate()
+                .map(|(index, count)| Bucket {
+                    upper_limit: base.powi(offset + index as i32 + 1),
+                    count,
+                }),
+        );
+    }
+
+    (
+        point.attributes,
+        nanos_into_timestamp(point.time_unix_nano),
+        kind,
+        MetricValue::AggregatedHistogram {
+            buckets,
+            count: point.count,
+            sum: point.sum.unwrap_or_default(),
+        },
+    )
+}
+
+fn summary_point(point: SummaryDataPoint) -> DataPoint {
+    let quantiles = point
+        .quantile_values
+        .into_iter()
+        .map(|quantile| Quantile {
+            quantile: quantile.quantile,
+            value: quantile.value,
+        })
+        .collect();
+    (
+        point.attributes,
+        nanos_into_timestamp(point.time_unix_nano),
+        MetricKind::Absolute,
+        MetricValue::AggregatedSummary {
+            quantiles,
+            count: point.count,
+            sum: point.sum,
+        },
+    )
+}
+
+fn kv_list_into_tags(arr: Vec<KeyValue>) -> MetricTags {
+    arr.into_iter()
+        .filter_map(|kv| {
+            kv.value
+                .and_then(|av| av.value)
+                .map(|value| (kv.key, Value::from(value).to_string_lossy()))
+        })
+        .collect()
+}
+
+impl IntoIterator for ResourceSpans {
+    type Item = Event;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        let resource = self.resource;
+        self.scope_spans
+            .into_iter()
+            .flat_map(|scope_spans| scope_spans.spans)
+            .map(|span| {
+                ResourceSpan {
+                    resource: resource.clone(),
+                    span,
+                }
+                .into()
+            })
+            .collect::<Vec<Self::Item>>()
+            .into_iter()
+    }
+}
+
+struct ResourceSpan {
+    resource: Option<OtelResource>,
+    span: Span,
+}
+
+fn bytes_into_hex(bytes: Vec<u8>) -> Value {
+    Value::Bytes(Bytes::from(hex::encode(bytes)))
+}
+
+fn nanos_into_value(nanos: u64) -> Value {
+    Value::Timestamp(Utc.timestamp_nanos(nanos as i64))
+}
+
+impl From<ResourceSpan> for Event {
+    fn from(rs: ResourceSpan) -> Self {
+        let span = rs.span;
+        let mut trace = TraceEvent::default();
+
+        trace.insert(TRACE_ID_KEY, bytes_into_hex(span.trace_id));
+        trace.insert(SPAN_ID_KEY, bytes_into_hex(span.span_id));
+        if !span.parent_span_id.is_empty() {
+            trace.insert(PARENT_SPAN_ID_KEY, bytes_into_hex(span.parent_span_id));
+        }
+        if !span.trace_state.is_empty() {
+            trace.insert(TRACE_STATE_KEY, span.trace_state);
+        }
+        trace.insert(NAME_KEY, span.name);
+        trace.insert(KIND_KEY, span.kind);
+        trace.insert(START_TIME_KEY, nanos_into_value(span.start_time_unix_nano));
+        trace.insert(END_TIME_KEY, nanos_into_value(span.end_time_unix_nano));
+
+        // optional fields
+        if let Some(resource) = rs.resource {
+            if !resource.attributes.is_empty() {
+                trace.insert(RESOURCE_KEY, kv_list_into_value(resource.attributes));
+            }
+        }
+        if !span.attributes.is_empty() {
+            trace.insert(ATTRIBUTES_KEY, kv_list_into_value(span.attributes));
+        }
+        if !span.events.is_empty() {
+            trace.insert(
+                EVENTS_KEY,
+                Value::Array(span.events.into_iter().map(Into::into).collect()),
+            );
+        }
+        if !span.links.is_empty() {
+            trace.insert(
+                LINKS_KEY,
+                Value::Array(span.links.into_iter().map(Into::into).collect()),
+            );
+        }
+        if let Some(status) = span.status {
+            trace.insert(STATUS_KEY, Value::from(status));
+        }
+
+        trace.insert(DROPPED_ATTRIBUTES_COUNT_KEY, span.dropped_attributes_count);
+        trace.insert(DROPPED_EVENTS_COUNT_KEY, span.dropped_events_count);
+        trace.insert(DROPPED_LINKS_COUNT_KEY, span.dropped_links_count);
+        trace.insert(INGEST_TIMESTAMP_KEY, Value::Timestamp(Utc::now()));
+
+        trace.into()
+    }
+}
+
+impl From<SpanEvent> for Value {
+    fn from(event: SpanEvent) -> Self {
+        BTreeMap::from([
+            (NAME_KEY.to_string(), event.name.into()),
+            (TIME_KEY.to_string(), nanos_into_value(event.time_unix_nano)),
+            (
+                ATTRIBUTES_KEY.to_string(),
+                kv_list_into_value(event.attributes),
+            ),
+            (
+                DROPPED_ATTRIBUTES_COUNT_KEY.to_string(),
+                event.dropped_attributes_count.into(),
+            ),
+        ])
+        .into()
+    }
+}
+
+impl From<Link> for Value {
+    fn from(link: Link) -> Self {
+        BTreeMap::from([
+            (TRACE_ID_KEY.to_string(), bytes_into_hex(link.trace_id)),
+            (SPAN_ID_KEY.to_string(), bytes_into_hex(link.span_id)),
+            (TRACE_STATE_KEY.to_string(), link.trace_state.into()),
+            (
+                ATTRIBUTES_KEY.to_string(),
+                kv_list_into_value(link.attributes),
+            ),
+            (
+                DROPPED_ATTRIBUTES_COUNT_KEY.to_string(),
+                link.dropped_attributes_count.into(),
+            ),
+        ])
+        .into()
+    }
+}
+
+impl From<Status> for Value {
+    fn from(status: Status) -> Self {
+        BTreeMap::from([
+            (MESSAGE_KEY.to_string(), status.message.into()),
+            (CODE_KEY.to_string(), status.code.into()),
+        ])
+        .into()
+    }
+}
+
+/// Converts a Vector value into its OTLP representation.
+///
+/// Values without an OTLP equivalent, such as timestamps, are sent as strings.
+pub fn value_into_any_value(value: Value) -> AnyValue {
+    let value = match value {
+        Value::Bytes(bytes) => Some(PBValue::StringValue(
+            String::from_utf8_lossy(&bytes).into_owned(),
+        )),
+        Value::Integer(value) => Some(PBValue::IntValue(value)),
+        Value::Float(value) => Some(PBValue::DoubleValue(value.into_inner())),
+        Value::Boolean(value) => Some(PBValue::BoolValue(value)),
+        Value::Object(map) => Some(PBValue::KvlistValue(KeyValueList {
+            values: map_into_kv_list(map),
+        })),
+        Value::Array(values) => Some(PBValue::ArrayValue(ArrayValue {
+            values: values.into_iter().map(value_into_any_value).collect(),
+        })),
+        Value::Null => None,
+        value @ (Value::Regex(_) | Value::Timestamp(_)) => {
+            Some(PBValue::StringValue(value.to_string_lossy()))
+        }
+    };
+    AnyValue { value }
+}
+
+fn map_into_kv_list(map: BTreeMap<String, Value>) -> Vec<KeyValue> {
+    map.into_iter()
+        .map(|(key, value)| KeyValue {
+            key,
+            value: Some(value_into_any_value(value)),
+        })
+        .collect()
+}
+
+/// Builds the OTLP resource out of the `resources` field of an event.
+pub fn value_into_resource(value: Value) -> OtelResource {
+    OtelResource {
+        attributes: match value {
+            Value::Object(map) => map_into_kv_list(map),
+            _ => Vec::new(),
+        },
+        dropped_attributes_count: 0,
+    }
+}
+
+fn take_string(map: &mut BTreeMap<String, Value>, key: &str) -> String {
+    map.remove(key)
+        .map(|value| value.to_string_lossy())
+        .unwrap_or_default()
+}
+
+fn take_integer(map: &mut BTreeMap<String, Value>, key: &str) -> i64 {
+    map.remove(key)
+        .and_then(|value| value.as_integer())
+        .unwrap_or_default()
+}
+
+fn take_hex(map: &mut BTreeMap<String, Value>, key: &str) -> Vec<u8> {
+    map.remove(key)
+        .and_then(|value| hex::decode(value.to_string_lossy()).ok())
+        .unwrap_or_default()
+}
+
+fn take_nanos(map: &mut BTreeMap<String, Value>, key: &str) -> u64 {
+    match map.remove(key) {
+        Some(Value::Timestamp(timestamp)) => timestamp.timestamp_nanos() as u64,
+        Some(Value::Integer(nanos)) => nanos as u64,
+        _ => 0,
+    }
+}
+
+/// Takes the `attributes` field out of the event, merging any field without an OTLP equivalent
+/// into it so that it isn't lost.
+fn take_attributes(mut map: BTreeMap<String, Value>) -> Vec<KeyValue> {
+    let mut attributes = match map.remove(ATTRIBUTES_KEY) {
+        Some(Value::Object(attributes)) => attributes,
+        _ => BTreeMap::new(),
+    };
+    attributes.extend(map);
+    map_into_kv_list(attributes)
+}
+
+impl From<LogEvent> for LogRecord {
+    fn from(log: LogEvent) -> Self {
+        let mut map = match log.into_parts().0 {
+            Value::Object(map) => map,
+            _ => BTreeMap::new(),
+        };
+        // The resource is shared by the whole batch, see `value_into_resource`.
+        map.remove(RESOURCE_KEY);
+
+        let body = map
+            .remove(log_schema().message_key())
+            .map(value_into_any_value);
+        let time_unix_nano = take_nanos(&mut map, log_schema().timestamp_key());
+        let observed_time_unix_nano = take_nanos(&mut map, OBSERVED_TIMESTAMP_KEY);
+
+        LogRecord {
+            time_unix_nano,
+            observed_time_unix_nano,
+            severity_number: take_integer(&mut map, SEVERITY_NUMBER_KEY) as i32,
+            severity_text: take_string(&mut map, SEVERITY_TEXT_KEY),
+            body,
+            dropped_attributes_count: take_integer(&mut map, DROPPED_ATTRIBUTES_COUNT_KEY) as u32,
+            flags: take_integer(&mut map, FLAGS_KEY) as u32,
+            trace_id: take_hex(&mut map, TRACE_ID_KEY),
+            span_id: take_hex(&mut map, SPAN_ID_KEY),
+            attributes: take_attributes(map),
+        }
+    }
+}
+
+impl From<TraceEvent> for Span {
+    fn from(trace: TraceEvent) -> Self {
+        let (mut map, _metadata) = trace.into_parts();
+        // The resource is shared by the whole batch, see `value_into_resource`.
+        map.remove(RESOURCE_KEY);
+        map.remove(INGEST_TIMESTAMP_KEY);
+
+        let events = match map.remove(EVENTS_KEY) {
+            Some(Value::Array(events)) => events
+                .into_iter()
+                .filter_map(|event| match event {
+                    Value::Object(mut event) => Some(SpanEvent {
+                        time_unix_nano: take_nanos(&mut event, TIME_KEY),
+                        name: take_string(&mut event, NAME_KEY),
+                        dropped_attributes_count: take_integer(
+                            &mut event,
+                            DROPPED_ATTRIBUTES_COUNT_KEY,
+                        ) as u32,
+                        attributes: take_attributes(event),
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let links = match map.remove(LINKS_KEY) {
+            Some(Value::Array(links)) => links
+                .into_iter()
+                .filter_map(|link| match link {
+                    Value::Object(mut link) => Some(Link {
+                        trace_id: take_hex(&mut link, TRACE_ID_KEY),
+                        span_id: take_hex(&mut link, SPAN_ID_KEY),
+                        trace_state: take_string(&mut link, TRACE_STATE_KEY),
+                        dropped_attributes_count: take_integer(
+                            &mut link,
+                            DROPPED_ATTRIBUTES_COUNT_KEY,
+                        ) as u32,
+                        attributes: take_attributes(link),
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let status = match map.remove(STATUS_KEY) {
+            Some(Value::Object(mut status)) => Some(Status {
+                message: take_string(&mut status, MESSAGE_KEY),
+                code: take_integer(&mut status, CODE_KEY) as i32,
+            }),
+            _ => None,
+        };
+
+        Span {
+            trace_id: take_hex(&mut map, TRACE_ID_KEY),
+            span_id: take_hex(&mut map, SPAN_ID_KEY),
+            trace_state: take_string(&mut map, TRACE_STATE_KEY),
+            parent_span_id: take_hex(&mut map, PARENT_SPAN_ID_KEY),
+            name: take_string(&mut map, NAME_KEY),
+            kind: take_integer(&mut map, KIND_KEY) as i32,
+            start_time_unix_nano: take_nanos(&mut map, START_TIME_KEY),
+            end_time_unix_nano: take_nanos(&mut map, END_TIME_KEY),
+            dropped_attributes_count: take_integer(&mut map, DROPPED_ATTRIBUTES_COUNT_KEY) as u32,
+            events,
+            dropped_events_count: take_integer(&mut map, DROPPED_EVENTS_COUNT_KEY) as u32,
+            links,
+            dropped_links_count: take_integer(&mut map, DROPPED_LINKS_COUNT_KEY) as u32,
+            status,
+            attributes: take_attributes(map),
+        }
+    }
+}
+
+impl TryFrom<Metric> for OtelMetric {
+    type Error = Metric;
+
+    /// Converts a Vector metric into an OTLP metric, failing on sets, distributions and
+    /// sketches which have no OTLP equivalent.
+    fn try_from(metric: Metric) -> Result<Self, Self::Error> {
+        let attributes = metric
+            .tags()
+            .map(|tags| {
+                tags.iter()
+                    .map(|(key, value)| KeyValue {
+                        key: key.clone(),
+                        value: Some(AnyValue {
+                            value: Some(PBValue::StringValue(value.clone())),
+                        }),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let time_unix_nano = metric
+            .timestamp()
+            .map(|timestamp| timestamp.timestamp_nanos() as u64)
+            .unwrap_or_default();
+        let aggregation_temporality = match metric.kind() {
+            MetricKind::Incremental => AggregationTemporality::Delta,
+            MetricKind::Absolute => AggregationTemporality::Cumulative,
+        } as i32;
+        let number_point = |value: f64| NumberDataPoint {
+            attributes: attributes.clone(),
+            start_time_unix_nano: 0,
+            time_unix_nano,
+            exemplars: Vec::new(),
+            flags: 0,
+            value: Some(number_data_point::Value::AsDouble(value)),
+        };
+
+        let data = match metric.value() {
+            MetricValue::Counter { value } => Data::Sum(Sum {
+                data_points: vec![number_point(*value)],
+                aggregation_temporality,
+                is_monotonic: true,
+            }),
+            MetricValue::Gauge { value } if metric.kind() == MetricKind::Absolute => {
+                Data::Gauge(Gauge {
+                    data_points: vec![number_point(*value)],
+                })
+            }
+            // Incremental gauges can only be expressed as delta non-monotonic sums.
+            MetricValue::Gauge { value } => Data::Sum(Sum {
+                data_points: vec![number_point(*value)],
+                aggregation_temporality,
+                is_monotonic: false,
+            }),
+            MetricValue::AggregatedHistogram {
+                buckets,
+                count,
+                sum,
+            } => {
+                let mut explicit_bounds = buckets
+                    .iter()
+                    .map(|bucket| bucket.upper_limit)
+                    .collect::<Vec<_>>();
+                let mut bucket_counts = buckets
+                    .iter()
+                    .map(|bucket| bucket.count)
+                    .collect::<Vec<_>>();
+                // OTLP always has an implicit unbounded last bucket.
+                if explicit_bounds.last() == Some(&f64::INFINITY) {
+                    explicit_bounds.pop();
+                } else {
+                    bucket_counts.push(0);
+                }
+                Data::Histogram(Histogram {
+                    data_points: vec![HistogramDataPoint {
+                        attributes,
+                        start_time_unix_nano: 0,
+                        time_unix_nano,
+                        count: *count,
+                        sum: Some(*sum),
+                        bucket_counts,
+                        explicit_bounds,
+                        exemplars: Vec::new(),
+                        flags: 0,
+                        min: None,
+                        max: None,
+                    }],
+                    aggregation_temporality,
+                })
+            }
+            MetricValue::AggregatedSummary {
+                quantiles,
+                count,
+                sum,
+            } => Data::Summary(Summary {
+                data_points: vec![SummaryDataPoint {
+                    attributes,
+                    start_time_unix_nano: 0,
+                    time_unix_nano,
+                    count: *count,
+                    sum: *sum,
+                    quantile_values: quantiles
+                        .iter()
+                        .map(|quantile| ValueAtQuantile {
+                            quantile: quantile.quantile,
+                            value: quantile.value,
+                        })
+                        .collect(),
+                    flags: 0,
+                }],
+            }),
+            MetricValue::Set { .. }
+            | MetricValue::Distribution { .. }
+            | MetricValue::Sketch { .. } => return Err(metric),
+        };
+
+        let name = match metric.namespace() {
+            Some(namespace) => format!("{}.{}", namespace, metric.name()),
+            None => metric.name().to_string(),
+        };
+
+        Ok(OtelMetric {
+            name,
+            description: String::new(),
+            unit: String::new(),
+            data: Some(data),
+        })
+    }
+}
@@ -3,9 +3,9 @@ use bytes::Buf;
 use futures::Stream;
 use hyper::Body;
 use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
 use tokio::time;
 use url::Url;
+use vector_config::configurable_component;
 
 use super::Result;
 use crate::{
@@ -19,8 +19,11 @@ use crate::{
     tls::{TlsConfig, TlsSettings},
 };
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// Request settings.
+#[configurable_component]
+#[derive(Clone, Debug)]
 pub struct RequestConfig {
+    /// HTTP headers to add to the request.
     #[serde(default)]
     pub headers: IndexMap<String, String>,
 }
@@ -33,14 +36,25 @@ impl Default for RequestConfig {
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// Configuration for the `http` provider.
+#[configurable_component]
+#[derive(Clone, Debug)]
 #[serde(deny_unknown_fields, default)]
 pub(crate) struct HttpConfig {
+    /// URL for the HTTP provider.
     url: Option<Url>,
+
+    #[configurable(derived)]
     request: RequestConfig,
+
+    /// How often to poll the provider, in seconds.
     poll_interval_secs: u64,
+
+    #[configurable(derived)]
     #[serde(flatten)]
     tls_options: Option<TlsConfig>,
+
+    #[configurable(derived)]
     #[serde(
         default,
         skip_serializing_if = "crate::serde::skip_serializing_if_default"
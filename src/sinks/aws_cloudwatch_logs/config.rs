@@ -21,8 +21,8 @@ use crate::{
             retry::CloudwatchRetryLogic, service::CloudwatchLogsPartitionSvc, sink::CloudwatchSink,
         },
         util::{
-            http::RequestConfig, BatchConfig, Compression, ServiceBuilderExt, SinkBatchSettings,
-            TowerRequestConfig,
+            http::RequestConfig, BatchConfig, Compression, CompressionAlgorithm, ServiceBuilderExt,
+            SinkBatchSettings, TowerRequestConfig,
         },
         Healthcheck, VectorSink,
     },
@@ -198,6 +198,14 @@ impl SinkConfig for CloudwatchLogsSinkConfig {
         "aws_cloudwatch_logs"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[CompressionAlgorithm::None, CompressionAlgorithm::Gzip]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
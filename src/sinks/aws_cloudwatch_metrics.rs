@@ -29,7 +29,8 @@ use crate::{
         batch::BatchConfig,
         buffer::metrics::{MetricNormalize, MetricNormalizer, MetricSet, MetricsBuffer},
         retries::RetryLogic,
-        Compression, EncodedEvent, PartitionBuffer, PartitionInnerBuffer, TowerRequestConfig,
+        Compression, CompressionAlgorithm, EncodedEvent, PartitionBuffer, PartitionInnerBuffer,
+        TowerRequestConfig,
     },
     tls::TlsConfig,
 };
@@ -143,6 +144,14 @@ impl SinkConfig for CloudWatchMetricsSinkConfig {
         "aws_cloudwatch_metrics"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[CompressionAlgorithm::None, CompressionAlgorithm::Gzip]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
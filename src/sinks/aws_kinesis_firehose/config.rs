@@ -191,6 +191,10 @@ impl SinkConfig for KinesisFirehoseSinkConfig {
         "aws_kinesis_firehose"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
@@ -205,6 +205,10 @@ impl SinkConfig for KinesisSinkConfig {
         "aws_kinesis_streams"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
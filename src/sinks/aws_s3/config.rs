@@ -50,7 +50,7 @@ pub struct S3SinkConfig {
     /// Prefixes are useful for partitioning objects, such as by creating an object key that
     /// stores objects under a particular "directory". If using a prefix for this purpose, it must end
     /// in `/` in order to act as a directory path: Vector will **not** add a trailing `/` automatically.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     pub key_prefix: Option<String>,
 
     /// The timestamp format for the time component of the object key.
@@ -161,6 +161,10 @@ impl SinkConfig for S3SinkConfig {
         "aws_s3"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
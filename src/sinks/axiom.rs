@@ -9,7 +9,7 @@ use crate::{
     },
     sinks::{
         elasticsearch::{ElasticsearchAuth, ElasticsearchConfig},
-        util::{http::RequestConfig, Compression},
+        util::{http::RequestConfig, Compression, CompressionAlgorithm},
         Healthcheck, VectorSink,
     },
     tls::TlsConfig,
@@ -97,6 +97,18 @@ impl SinkConfig for AxiomConfig {
         "axiom"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Zlib,
+        ]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
@@ -157,6 +157,10 @@ impl SinkConfig for AzureBlobSinkConfig {
         "azure_blob"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
@@ -92,6 +92,9 @@ impl Compression {
             Self::None => "text/plain",
             Self::Gzip(_) => "application/gzip",
             Self::Zlib(_) => "application/zlib",
+            Self::Zstd(_) => "application/zstd",
+            Self::Snappy => "application/x-snappy",
+            Self::Lz4(_) => "application/x-lz4",
         }
     }
 }
@@ -118,6 +118,10 @@ impl SinkConfig for ClickhouseConfig {
         "clickhouse"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
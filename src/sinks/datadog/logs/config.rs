@@ -16,8 +16,8 @@ use crate::{
     sinks::{
         datadog::{get_api_validate_endpoint, healthcheck, logs::service::LogApiService, Region},
         util::{
-            service::ServiceBuilderExt, BatchConfig, Compression, SinkBatchSettings,
-            TowerRequestConfig,
+            service::ServiceBuilderExt, BatchConfig, Compression, CompressionAlgorithm,
+            SinkBatchSettings, TowerRequestConfig,
         },
         Healthcheck, VectorSink,
     },
@@ -209,6 +209,18 @@ impl SinkConfig for DatadogLogsConfig {
         "datadog_logs"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Zlib,
+        ]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
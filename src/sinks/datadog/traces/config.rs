@@ -22,8 +22,8 @@ use crate::{
             },
         },
         util::{
-            service::ServiceBuilderExt, BatchConfig, Compression, Concurrency, SinkBatchSettings,
-            TowerRequestConfig,
+            service::ServiceBuilderExt, BatchConfig, Compression, CompressionAlgorithm,
+            Concurrency, SinkBatchSettings, TowerRequestConfig,
         },
         Healthcheck, UriParseSnafu, VectorSink,
     },
@@ -211,6 +211,14 @@ impl SinkConfig for DatadogTracesConfig {
         "datadog_traces"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[CompressionAlgorithm::None, CompressionAlgorithm::Gzip]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
@@ -20,6 +20,7 @@ pub struct ElasticsearchRequestBuilder {
 
 pub struct Metadata {
     finalizers: EventFinalizers,
+    document_finalizers: Vec<EventFinalizers>,
     batch_size: usize,
     events_byte_size: usize,
 }
@@ -47,8 +48,19 @@ impl RequestBuilder<Vec<ProcessedEvent>> for ElasticsearchRequestBuilder {
             .reduce(|a, b| a + b)
             .unwrap_or(0);
 
+        // Each event is a document of the bulk request, which Elasticsearch may reject on its own.
+        let document_finalizers = events
+            .iter_mut()
+            .map(Finalizable::take_finalizers)
+            .collect::<Vec<_>>();
+        let mut finalizers = EventFinalizers::default();
+        for document in &document_finalizers {
+            finalizers.merge(document.clone());
+        }
+
         let metadata = Metadata {
-            finalizers: events.take_finalizers(),
+            finalizers,
+            document_finalizers,
             batch_size: events.len(),
             events_byte_size,
         };
@@ -63,6 +75,7 @@ impl RequestBuilder<Vec<ProcessedEvent>> for ElasticsearchRequestBuilder {
         ElasticsearchRequest {
             payload: payload.into_payload(),
             finalizers: metadata.finalizers,
+            document_finalizers: metadata.document_finalizers,
             batch_size: metadata.batch_size,
             events_byte_size: metadata.events_byte_size,
         }
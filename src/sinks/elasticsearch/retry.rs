@@ -1,3 +1,5 @@
+use std::fmt;
+
 use http::StatusCode;
 use serde::Deserialize;
 
@@ -44,6 +46,12 @@ struct EsErrorDetails {
     err_type: String,
 }
 
+impl fmt::Display for EsErrorDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error type: {}, reason: {}", self.err_type, self.reason)
+    }
+}
+
 #[derive(Clone)]
 pub struct ElasticsearchRetryLogic;
 
@@ -89,14 +97,24 @@ impl RetryLogic for ElasticsearchRetryLogic {
     }
 }
 
-fn get_error_reason(body: &str) -> String {
+/// Gets the error of each document of a bulk response, in the order of the actions of the request.
+pub(super) fn get_document_errors(body: &str) -> serde_json::Result<Vec<Option<String>>> {
+    let resp = serde_json::from_str::<EsResultResponse>(body)?;
+    Ok(resp
+        .items
+        .into_iter()
+        .map(|item| item.result().error.map(|error| error.to_string()))
+        .collect())
+}
+
+pub(super) fn get_error_reason(body: &str) -> String {
     match serde_json::from_str::<EsResultResponse>(body) {
         Err(json_error) => format!(
             "some messages failed, could not parse response, error: {}",
             json_error
         ),
         Ok(resp) => match resp.items.into_iter().find_map(|item| item.result().error) {
-            Some(error) => format!("error type: {}, reason: {}", error.err_type, error.reason),
+            Some(error) => error.to_string(),
             None => format!("error response: {}", body),
         },
     }
@@ -13,7 +13,10 @@ use hyper::{service::Service, Body, Request};
 use tower::ServiceExt;
 use vector_core::{internal_event::EventsSent, stream::DriverResponse, ByteSizeOf};
 
-use crate::sinks::elasticsearch::sign_request;
+use crate::sinks::elasticsearch::{
+    retry::{get_document_errors, get_error_reason},
+    sign_request,
+};
 use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
     http::{Auth, HttpClient},
@@ -28,6 +31,9 @@ use crate::{
 pub struct ElasticsearchRequest {
     pub payload: Bytes,
     pub finalizers: EventFinalizers,
+    /// The finalizers of each document, in the order of the actions of the bulk request, used to
+    /// reject the documents that failed on their own.
+    pub document_finalizers: Vec<EventFinalizers>,
     pub batch_size: usize,
     pub events_byte_size: usize,
 }
@@ -136,6 +142,16 @@ impl DriverResponse for ElasticsearchResponse {
             output: None,
         }
     }
+
+    fn rejection_reason(&self) -> Option<String> {
+        let status = self.http_response.status();
+        let body = String::from_utf8_lossy(self.http_response.body());
+        match self.event_status {
+            EventStatus::Delivered => None,
+            _ if status.is_success() => Some(get_error_reason(&body)),
+            _ => Some(format!("response status: {}: {}", status, body)),
+        }
+    }
 }
 
 impl Service<ElasticsearchRequest> for ElasticsearchService {
@@ -147,48 +163,112 @@ impl Service<ElasticsearchRequest> for ElasticsearchService {
         Poll::Ready(Ok(()))
     }
 
-    fn call(&mut self, req: ElasticsearchRequest) -> Self::Future {
+    fn call(&mut self, mut req: ElasticsearchRequest) -> Self::Future {
         let mut http_service = self.batch_service.clone();
         Box::pin(async move {
             http_service.ready().await?;
             let batch_size = req.batch_size;
             let events_byte_size = req.events_byte_size;
+            let document_finalizers = std::mem::take(&mut req.document_finalizers);
             let http_response = http_service.call(req).await?;
-            let event_status = get_event_status(&http_response);
+            let (event_status, rejected) = get_event_status(&http_response, &document_finalizers);
             Ok(ElasticsearchResponse {
                 event_status,
                 http_response,
-                batch_size,
+                batch_size: batch_size - rejected,
                 events_byte_size,
             })
         })
     }
 }
 
-fn get_event_status(response: &Response<Bytes>) -> EventStatus {
+/// Gets the status of the request, along with the number of documents rejected on their own.
+///
+/// When only some of the documents failed, each of them is rejected with its own reason, and the
+/// request is considered delivered.
+fn get_event_status(
+    response: &Response<Bytes>,
+    document_finalizers: &[EventFinalizers],
+) -> (EventStatus, usize) {
     let status = response.status();
     if status.is_success() {
         let body = String::from_utf8_lossy(response.body());
         if body.contains("\"errors\":true") {
             emit!(ElasticsearchResponseError::new(
-                "Response containerd errors.",
+                "Response contained errors.",
                 response
             ));
-            EventStatus::Rejected
+            match get_document_errors(&body) {
+                Ok(errors) if errors.len() == document_finalizers.len() => {
+                    let mut rejected = 0;
+                    for (finalizers, error) in document_finalizers.iter().zip(errors) {
+                        if let Some(reason) = error {
+                            finalizers.update_status_with_reason(EventStatus::Rejected, &reason);
+                            rejected += 1;
+                        }
+                    }
+                    (EventStatus::Delivered, rejected)
+                }
+                _ => (EventStatus::Rejected, 0),
+            }
         } else {
-            EventStatus::Delivered
+            (EventStatus::Delivered, 0)
         }
     } else if status.is_server_error() {
         emit!(ElasticsearchResponseError::new(
             "Response wasn't successful.",
             response,
         ));
-        EventStatus::Errored
+        (EventStatus::Errored, 0)
     } else {
         emit!(ElasticsearchResponseError::new(
             "Response failed.",
             response,
         ));
-        EventStatus::Rejected
+        (EventStatus::Rejected, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use super::*;
+    use crate::event::{BatchNotifier, BatchStatus, EventFinalizer};
+
+    #[test]
+    fn rejects_failed_documents_only() {
+        let json = "{\"took\":3,\"errors\":true,\"items\":[{\"index\":{\"_index\":\"test\",\"_id\":\"1\",\"status\":201}},{\"index\":{\"_index\":\"test\",\"_id\":\"2\",\"status\":400,\"error\":{\"type\":\"mapper_parsing_exception\",\"reason\":\"failed to parse field [count]\"}}}]}";
+        let response = Response::builder()
+            .status(StatusCode::OK)
+            .body(Bytes::from(json))
+            .unwrap();
+
+        let (receivers, document_finalizers): (Vec<_>, Vec<_>) = (0..2)
+            .map(|_| {
+                let (batch, receiver) = BatchNotifier::new_with_receiver();
+                (receiver, EventFinalizers::new(EventFinalizer::new(batch)))
+            })
+            .unzip();
+
+        let (status, rejected) = get_event_status(&response, &document_finalizers);
+        assert_eq!(status, EventStatus::Delivered);
+        assert_eq!(rejected, 1);
+
+        for finalizers in &document_finalizers {
+            finalizers.update_status(status);
+        }
+        drop(document_finalizers);
+
+        let mut receivers = receivers.into_iter();
+        let mut delivered = receivers.next().unwrap();
+        assert_eq!(delivered.try_recv(), Ok(BatchStatus::Delivered));
+        assert_eq!(delivered.reason(), None);
+        let mut failed = receivers.next().unwrap();
+        assert_eq!(failed.try_recv(), Ok(BatchStatus::Rejected));
+        assert_eq!(
+            failed.reason().as_deref(),
+            Some("error type: mapper_parsing_exception, reason: failed to parse field [count]")
+        );
     }
 }
@@ -1,6 +1,6 @@
 use std::time::{Duration, Instant};
 
-use async_compression::tokio::write::GzipEncoder;
+use async_compression::tokio::write::{GzipEncoder, ZstdEncoder};
 use async_trait::async_trait;
 use bytes::{Bytes, BytesMut};
 use codecs::{
@@ -43,7 +43,7 @@ use bytes_path::BytesPath;
 #[serde(deny_unknown_fields)]
 pub struct FileSinkConfig {
     /// File name to write events to.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     pub path: Template,
 
     /// The amount of time, in seconds, that a file can be idle and stay open.
@@ -96,6 +96,9 @@ pub enum Compression {
     /// Gzip compression.
     Gzip,
 
+    /// Zstandard compression.
+    Zstd,
+
     /// No compression.
     None,
 }
@@ -109,6 +112,7 @@ impl Default for Compression {
 enum OutFile {
     Regular(File),
     Gzip(GzipEncoder<File>),
+    Zstd(ZstdEncoder<File>),
 }
 
 impl OutFile {
@@ -116,6 +120,7 @@ impl OutFile {
         match compression {
             Compression::None => OutFile::Regular(file),
             Compression::Gzip => OutFile::Gzip(GzipEncoder::new(file)),
+            Compression::Zstd => OutFile::Zstd(ZstdEncoder::new(file)),
         }
     }
 
@@ -123,6 +128,7 @@ impl OutFile {
         match self {
             OutFile::Regular(file) => file.sync_all().await,
             OutFile::Gzip(gzip) => gzip.get_mut().sync_all().await,
+            OutFile::Zstd(zstd) => zstd.get_mut().sync_all().await,
         }
     }
 
@@ -130,6 +136,7 @@ impl OutFile {
         match self {
             OutFile::Regular(file) => file.shutdown().await,
             OutFile::Gzip(gzip) => gzip.shutdown().await,
+            OutFile::Zstd(zstd) => zstd.shutdown().await,
         }
     }
 
@@ -137,6 +144,7 @@ impl OutFile {
         match self {
             OutFile::Regular(file) => file.write_all(src).await,
             OutFile::Gzip(gzip) => gzip.write_all(src).await,
+            OutFile::Zstd(zstd) => zstd.write_all(src).await,
         }
     }
 
@@ -320,7 +328,27 @@ impl FileSink {
                 }
             };
 
-            let outfile = OutFile::new(file, self.compression);
+            // Headers are only written to new files, since an existing file already has one.
+            let is_new_file = file
+                .metadata()
+                .await
+                .map(|metadata| metadata.len() == 0)
+                .unwrap_or(false);
+
+            let mut outfile = OutFile::new(file, self.compression);
+
+            if is_new_file {
+                if let Err(error) = write_header_to_file(&mut outfile, &mut self.encoder).await {
+                    emit!(FileIoError {
+                        code: "failed_writing_file",
+                        message: "Failed to write the file header.",
+                        error,
+                        path: Some(&path),
+                    });
+                    event.metadata().update_status(EventStatus::Errored);
+                    return;
+                }
+            }
 
             self.files.insert_at(path.clone(), outfile, next_deadline);
             emit!(FileOpen {
@@ -374,6 +402,20 @@ async fn open_file(path: impl AsRef<std::path::Path>) -> std::io::Result<File> {
         .await
 }
 
+async fn write_header_to_file(
+    file: &mut OutFile,
+    encoder: &mut Encoder<Framer>,
+) -> Result<(), std::io::Error> {
+    let mut buffer = BytesMut::new();
+    let has_header = encoder
+        .encode_header(&mut buffer)
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+    if has_header {
+        file.write_all(&buffer).await?;
+    }
+    Ok(())
+}
+
 async fn write_event_to_file(
     file: &mut OutFile,
     mut event: Event,
@@ -402,6 +444,7 @@ impl StreamSink<Event> for FileSink {
 mod tests {
     use std::convert::TryInto;
 
+    use codecs::encoding::{CsvSerializerConfig, CsvSerializerOptions};
     use futures::{stream, SinkExt};
     use pretty_assertions::assert_eq;
     use vector_core::{event::LogEvent, sink::VectorSink};
@@ -411,7 +454,7 @@ mod tests {
         config::log_schema,
         test_util::{
             components::{run_and_assert_sink_compliance, FILE_SINK_TAGS},
-            lines_from_file, lines_from_gzip_file, random_events_with_stream,
+            lines_from_file, lines_from_gzip_file, lines_from_zstd_file, random_events_with_stream,
             random_lines_with_stream, temp_dir, temp_file, trace_init,
         },
     };
@@ -493,6 +536,85 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn single_partition_zstd() {
+        trace_init();
+
+        let template = temp_file();
+
+        let config = FileSinkConfig {
+            path: template.clone().try_into().unwrap(),
+            idle_timeout_secs: None,
+            encoding: (None::<FramingConfig>, TextSerializerConfig::new()).into(),
+            compression: Compression::Zstd,
+            acknowledgements: Default::default(),
+        };
+
+        let sink = FileSink::new(&config).unwrap();
+        let (input, _) = random_lines_with_stream(100, 64, None);
+
+        let events = Box::pin(stream::iter(
+            input
+                .clone()
+                .into_iter()
+                .map(|e| Event::Log(LogEvent::from(e))),
+        ));
+        run_and_assert_sink_compliance(
+            VectorSink::from_event_streamsink(sink),
+            events,
+            &FILE_SINK_TAGS,
+        )
+        .await;
+
+        let output = lines_from_zstd_file(template);
+        for (input, output) in input.into_iter().zip(output) {
+            assert_eq!(input, output);
+        }
+    }
+
+    #[tokio::test]
+    async fn single_partition_csv_header() {
+        trace_init();
+
+        let template = temp_file();
+
+        let options = CsvSerializerOptions {
+            include_header: true,
+            ..CsvSerializerOptions::new(vec!["message".to_owned()])
+        };
+        let config = FileSinkConfig {
+            path: template.clone().try_into().unwrap(),
+            idle_timeout_secs: None,
+            encoding: (None::<FramingConfig>, CsvSerializerConfig::new(options)).into(),
+            compression: Compression::None,
+            acknowledgements: Default::default(),
+        };
+
+        // The second run appends to the existing file, which must not get another header.
+        let mut input = Vec::new();
+        for _ in 0..2 {
+            let sink = FileSink::new(&config).unwrap();
+            let (lines, _) = random_lines_with_stream(10, 16, None);
+            let events = Box::pin(stream::iter(
+                lines
+                    .clone()
+                    .into_iter()
+                    .map(|e| Event::Log(LogEvent::from(e))),
+            ));
+            run_and_assert_sink_compliance(
+                VectorSink::from_event_streamsink(sink),
+                events,
+                &FILE_SINK_TAGS,
+            )
+            .await;
+            input.extend(lines);
+        }
+
+        let output = lines_from_file(template);
+        assert_eq!(output[0], "message");
+        assert_eq!(output[1..], input[..]);
+    }
+
     #[tokio::test]
     async fn many_partitions() {
         trace_init();
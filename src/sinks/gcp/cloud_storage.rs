@@ -87,7 +87,7 @@ pub struct GcsSinkConfig {
     /// Prefixes are useful for partitioning objects, such as by creating an object key that
     /// stores objects under a particular "directory". If using a prefix for this purpose, it must end
     /// in `/` in order to act as a directory path: Vector will **not** add a trailing `/` automatically.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     key_prefix: Option<String>,
 
     /// The timestamp format for the time component of the object key.
@@ -215,6 +215,10 @@ impl SinkConfig for GcsSinkConfig {
         NAME
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
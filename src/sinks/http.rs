@@ -2,7 +2,6 @@ use std::io::Write;
 
 use bytes::{BufMut, Bytes, BytesMut};
 use codecs::encoding::{CharacterDelimitedEncoder, Framer, Serializer};
-use flate2::write::{GzEncoder, ZlibEncoder};
 use futures::{future, FutureExt, SinkExt};
 use http::{
     header::{self, HeaderName, HeaderValue},
@@ -25,7 +24,7 @@ use crate::{
     sinks::util::{
         self,
         http::{BatchedHttpSink, HttpEventEncoder, RequestConfig},
-        BatchConfig, Buffer, Compression, RealtimeSizeBasedDefaultBatchSettings,
+        BatchConfig, Buffer, Compression, Compressor, RealtimeSizeBasedDefaultBatchSettings,
         TowerRequestConfig, UriSerde,
     },
     tls::{TlsConfig, TlsSettings},
@@ -241,6 +240,10 @@ impl SinkConfig for HttpSinkConfig {
         "http"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
@@ -326,24 +329,14 @@ impl util::http::HttpSink for HttpSink {
             builder = builder.header("Content-Type", content_type);
         }
 
-        match self.compression {
-            Compression::Gzip(level) => {
-                builder = builder.header("Content-Encoding", "gzip");
+        if let Some(content_encoding) = self.compression.content_encoding() {
+            builder = builder.header("Content-Encoding", content_encoding);
 
-                let buffer = BytesMut::new();
-                let mut w = GzEncoder::new(buffer.writer(), level.as_flate2());
-                w.write_all(&body).expect("Writing to Vec can't fail");
-                body = w.finish().expect("Writing to Vec can't fail").into_inner();
-            }
-            Compression::Zlib(level) => {
-                builder = builder.header("Content-Encoding", "deflate");
-
-                let buffer = BytesMut::new();
-                let mut w = ZlibEncoder::new(buffer.writer(), level.as_flate2());
-                w.write_all(&body).expect("Writing to Vec can't fail");
-                body = w.finish().expect("Writing to Vec can't fail").into_inner();
-            }
-            Compression::None => {}
+            let mut compressor = Compressor::from(self.compression);
+            compressor
+                .write_all(&body)
+                .expect("Writing to Vec can't fail");
+            body = compressor.into_inner();
         }
 
         for (header, value) in self.request.headers.iter() {
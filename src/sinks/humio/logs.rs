@@ -16,7 +16,7 @@ use crate::{
             },
             logs::config::HecLogsSinkConfig,
         },
-        util::{BatchConfig, Compression, TowerRequestConfig},
+        util::{BatchConfig, Compression, CompressionAlgorithm, TowerRequestConfig},
         Healthcheck, VectorSink,
     },
     template::Template,
@@ -40,7 +40,7 @@ pub struct HumioLogsConfig {
     /// The source of events sent to this sink.
     ///
     /// Typically the filename the logs originated from. Maps to `@source` in Humio.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     pub(super) source: Option<Template>,
 
     #[configurable(derived)]
@@ -49,7 +49,7 @@ pub struct HumioLogsConfig {
     /// The type of events sent to this sink. Humio uses this as the name of the parser to use to ingest the data.
     ///
     /// If unset, Humio will default it to none.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     pub(super) event_type: Option<Template>,
 
     /// Overrides the name of the log field used to grab the hostname to send to Humio.
@@ -79,7 +79,7 @@ pub struct HumioLogsConfig {
     /// For more information, see [Humio’s Format of Data][humio_data_format].
     ///
     /// [humio_data_format]: https://docs.humio.com/integrations/data-shippers/hec/#format-of-data
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     #[serde(default)]
     pub(super) index: Option<Template>,
 
@@ -167,6 +167,14 @@ impl SinkConfig for HumioLogsConfig {
         "humio_logs"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[CompressionAlgorithm::None, CompressionAlgorithm::Gzip]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
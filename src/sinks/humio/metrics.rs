@@ -15,7 +15,7 @@ use crate::{
     event::{Event, EventArray, EventContainer},
     sinks::{
         splunk_hec::common::SplunkHecDefaultBatchSettings,
-        util::{BatchConfig, Compression, TowerRequestConfig},
+        util::{BatchConfig, Compression, CompressionAlgorithm, TowerRequestConfig},
         Healthcheck, VectorSink,
     },
     template::Template,
@@ -48,13 +48,13 @@ pub struct HumioMetricsConfig {
     /// The source of events sent to this sink.
     ///
     /// Typically the filename the metrics originated from. Maps to `@source` in Humio.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     source: Option<Template>,
 
     /// The type of events sent to this sink. Humio uses this as the name of the parser to use to ingest the data.
     ///
     /// If unset, Humio will default it to none.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     event_type: Option<Template>,
 
     /// Overrides the name of the log field used to grab the hostname to send to Humio.
@@ -84,7 +84,7 @@ pub struct HumioMetricsConfig {
     /// For more information, see [Humio’s Format of Data][humio_data_format].
     ///
     /// [humio_data_format]: https://docs.humio.com/integrations/data-shippers/hec/#format-of-data
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     #[serde(default)]
     index: Option<Template>,
 
@@ -173,6 +173,14 @@ impl SinkConfig for HumioMetricsConfig {
         "humio_metrics"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[CompressionAlgorithm::None, CompressionAlgorithm::Gzip]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
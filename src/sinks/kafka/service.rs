@@ -16,6 +16,7 @@ use vector_core::{
 
 use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
+    internal_events::KafkaDeliveryError,
     kafka::KafkaStatisticsContext,
 };
 
@@ -35,11 +36,29 @@ pub struct KafkaRequestMetadata {
 
 pub struct KafkaResponse {
     event_byte_size: usize,
+    /// The error the message couldn't be delivered with, if any.
+    delivery_error: Option<KafkaError>,
+}
+
+impl KafkaResponse {
+    /// Creates the response for a message which couldn't be delivered.
+    ///
+    /// The producer already retries transient errors internally, so a delivery error is final and
+    /// the events of the message are rejected.
+    fn rejected(event_byte_size: usize, error: KafkaError) -> Self {
+        Self {
+            event_byte_size,
+            delivery_error: Some(error),
+        }
+    }
 }
 
 impl DriverResponse for KafkaResponse {
     fn event_status(&self) -> EventStatus {
-        EventStatus::Delivered
+        match self.delivery_error {
+            None => EventStatus::Delivered,
+            Some(_) => EventStatus::Rejected,
+        }
     }
 
     fn events_sent(&self) -> EventsSent {
@@ -49,6 +68,12 @@ impl DriverResponse for KafkaResponse {
             output: None,
         }
     }
+
+    fn rejection_reason(&self) -> Option<String> {
+        self.delivery_error
+            .as_ref()
+            .map(|error| format!("Kafka delivery failed: {}", error))
+    }
 }
 
 impl Finalizable for KafkaRequest {
@@ -95,20 +120,58 @@ impl Service<KafkaRequest> for KafkaService {
             }
 
             //rdkafka will internally retry forever if the queue is full
-            let result = match kafka_producer.send(record, Timeout::Never).await {
-                Ok((_partition, _offset)) => {
-                    emit!(BytesSent {
-                        byte_size: request.body.len()
-                            + request.metadata.key.map(|x| x.len()).unwrap_or(0),
-                        protocol: "kafka"
-                    });
-                    Ok(KafkaResponse {
-                        event_byte_size: request.event_byte_size,
-                    })
-                }
-                Err((kafka_err, _original_record)) => Err(kafka_err),
-            };
+            let result: Result<KafkaResponse, KafkaError> =
+                match kafka_producer.send(record, Timeout::Never).await {
+                    Ok((_partition, _offset)) => {
+                        emit!(BytesSent {
+                            byte_size: request.body.len()
+                                + request.metadata.key.map(|x| x.len()).unwrap_or(0),
+                            protocol: "kafka"
+                        });
+                        Ok(KafkaResponse {
+                            event_byte_size: request.event_byte_size,
+                            delivery_error: None,
+                        })
+                    }
+                    Err((kafka_err, _original_record)) => {
+                        emit!(KafkaDeliveryError {
+                            error: kafka_err.clone(),
+                        });
+                        Ok(KafkaResponse::rejected(request.event_byte_size, kafka_err))
+                    }
+                };
             result
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rdkafka::types::RDKafkaErrorCode;
+
+    use super::*;
+
+    #[test]
+    fn delivery_errors_reject_events_with_reason() {
+        let response = KafkaResponse::rejected(
+            10,
+            KafkaError::MessageProduction(RDKafkaErrorCode::MessageSizeTooLarge),
+        );
+
+        assert_eq!(response.event_status(), EventStatus::Rejected);
+        let reason = response.rejection_reason().unwrap();
+        assert!(reason.starts_with("Kafka delivery failed: "));
+        assert!(reason.contains("MessageSizeTooLarge"));
+    }
+
+    #[test]
+    fn delivered_messages_have_no_rejection_reason() {
+        let response = KafkaResponse {
+            event_byte_size: 10,
+            delivery_error: None,
+        };
+
+        assert_eq!(response.event_status(), EventStatus::Delivered);
+        assert!(response.rejection_reason().is_none());
+    }
+}
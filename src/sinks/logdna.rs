@@ -38,7 +38,7 @@ pub struct LogdnaConfig {
     endpoint: Option<UriSerde>,
 
     /// The hostname that will be attached to each batch of events.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     hostname: Template,
 
     /// The MAC address that will be attached to each batch of events.
@@ -48,7 +48,7 @@ pub struct LogdnaConfig {
     ip: Option<String>,
 
     /// The tags that will be attached to each batch of events.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     tags: Option<Vec<Template>>,
 
     #[configurable(derived)]
@@ -10,7 +10,10 @@ use crate::{
     config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
     http::{Auth, HttpClient, MaybeAuth},
     sinks::{
-        util::{BatchConfig, Compression, SinkBatchSettings, TowerRequestConfig, UriSerde},
+        util::{
+            BatchConfig, Compression, CompressionAlgorithm, SinkBatchSettings, TowerRequestConfig,
+            UriSerde,
+        },
         VectorSink,
     },
     template::Template,
@@ -178,6 +181,14 @@ impl SinkConfig for LokiConfig {
         "loki"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[CompressionAlgorithm::None, CompressionAlgorithm::Gzip]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
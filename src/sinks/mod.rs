@@ -66,6 +66,8 @@ pub mod nats;
 pub mod new_relic;
 #[cfg(feature = "sinks-new_relic_logs")]
 pub mod new_relic_logs;
+#[cfg(feature = "sinks-opentelemetry")]
+pub mod opentelemetry;
 #[cfg(feature = "sinks-papertrail")]
 pub mod papertrail;
 #[cfg(feature = "sinks-prometheus")]
@@ -261,6 +263,10 @@ pub enum Sinks {
     #[cfg(feature = "sinks-new_relic_logs")]
     NewrelicLogs(#[configurable(derived)] new_relic_logs::NewRelicLogsConfig),
 
+    /// OpenTelemetry.
+    #[cfg(feature = "sinks-opentelemetry")]
+    Opentelemetry(#[configurable(derived)] opentelemetry::OpentelemetryConfig),
+
     /// Papertrail.
     #[cfg(feature = "sinks-papertrail")]
     Papertrail(#[configurable(derived)] papertrail::PapertrailConfig),
@@ -63,7 +63,7 @@ pub struct NatsSinkConfig {
     connection_name: String,
 
     /// The NATS subject to publish messages to.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     subject: String,
 
     /// The NATS URL to connect to.
@@ -15,7 +15,7 @@ use crate::{
     http::HttpClient,
     sinks::util::{
         retries::RetryLogic, service::ServiceBuilderExt, BatchConfig, Compression,
-        SinkBatchSettings, TowerRequestConfig,
+        CompressionAlgorithm, SinkBatchSettings, TowerRequestConfig,
     },
     tls::TlsSettings,
 };
@@ -178,6 +178,18 @@ impl SinkConfig for NewRelicConfig {
         "new_relic"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[
+            CompressionAlgorithm::None,
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Zlib,
+        ]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
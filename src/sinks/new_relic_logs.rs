@@ -12,7 +12,8 @@ use crate::{
     sinks::{
         http::{HttpMethod, HttpSinkConfig},
         util::{
-            http::RequestConfig, BatchConfig, Compression, SinkBatchSettings, TowerRequestConfig,
+            http::RequestConfig, BatchConfig, Compression, CompressionAlgorithm, SinkBatchSettings,
+            TowerRequestConfig,
         },
     },
 };
@@ -122,6 +123,14 @@ impl SinkConfig for NewRelicLogsConfig {
         "new_relic_logs"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[CompressionAlgorithm::None, CompressionAlgorithm::Gzip]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements)
     }
@@ -0,0 +1,175 @@
+use tower::ServiceBuilder;
+use vector_config::configurable_component;
+
+use crate::{
+    config::{
+        AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext, SinkDescription,
+    },
+    http::HttpClient,
+    sinks::{
+        opentelemetry::{
+            service::{OpentelemetryResponse, OpentelemetryService},
+            sink::OpentelemetrySink,
+            OpentelemetrySinkError,
+        },
+        util::{
+            grpc::{new_client, with_default_scheme},
+            retries::RetryLogic,
+            BatchConfig, RealtimeEventBasedDefaultBatchSettings, ServiceBuilderExt,
+            TowerRequestConfig,
+        },
+        Healthcheck, VectorSink,
+    },
+    tls::{MaybeTlsSettings, TlsEnableableConfig},
+};
+
+/// The transport used to send data to the OTLP endpoint.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Protocol {
+    /// OTLP over gRPC.
+    #[derivative(Default)]
+    Grpc,
+
+    /// OTLP over HTTP, with binary protobuf encoded payloads.
+    Http,
+}
+
+/// Configuration for the `opentelemetry` sink.
+#[configurable_component(sink)]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct OpentelemetryConfig {
+    /// The OTLP endpoint to send data to.
+    ///
+    /// With the `http` protocol, the `/v1/logs`, `/v1/metrics`, and `/v1/traces` paths are
+    /// appended to it, depending on the type of the events sent.
+    endpoint: String,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    protocol: Protocol,
+
+    /// Whether or not to compress requests.
+    ///
+    /// If set to `true`, requests will be compressed with [`gzip`][gzip_docs].
+    ///
+    /// [gzip_docs]: https://en.wikipedia.org/wiki/Gzip
+    #[serde(default)]
+    compression: bool,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<RealtimeEventBasedDefaultBatchSettings>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub request: TowerRequestConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    tls: Option<TlsEnableableConfig>,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::skip_serializing_if_default"
+    )]
+    acknowledgements: AcknowledgementsConfig,
+}
+
+inventory::submit! {
+    SinkDescription::new::<OpentelemetryConfig>("opentelemetry")
+}
+
+impl GenerateConfig for OpentelemetryConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(r#"endpoint = "127.0.0.1:4317""#).unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "opentelemetry")]
+impl SinkConfig for OpentelemetryConfig {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let tls = MaybeTlsSettings::from_config(&self.tls, false)?;
+        let uri = with_default_scheme(&self.endpoint, tls.is_tls())?;
+
+        let service = match self.protocol {
+            Protocol::Grpc => {
+                let client = new_client(&tls, cx.proxy())?;
+                OpentelemetryService::grpc(client, uri, self.compression)
+            }
+            Protocol::Http => {
+                let client = HttpClient::new(tls, cx.proxy())?;
+                OpentelemetryService::http(client, uri, self.compression)
+            }
+        };
+        let request_settings = self.request.unwrap_with(&TowerRequestConfig::default());
+        let batch_settings = self.batch.into_batcher_settings()?;
+
+        let service = ServiceBuilder::new()
+            .settings(request_settings, OpentelemetryRetryLogic)
+            .service(service);
+
+        let sink = OpentelemetrySink {
+            batch_settings,
+            service,
+        };
+
+        // OTLP doesn't define a health check endpoint.
+        Ok((
+            VectorSink::from_event_streamsink(sink),
+            Box::pin(async { Ok(()) }),
+        ))
+    }
+
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn sink_type(&self) -> &'static str {
+        "opentelemetry"
+    }
+
+    fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
+        Some(&self.acknowledgements)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct OpentelemetryRetryLogic;
+
+impl RetryLogic for OpentelemetryRetryLogic {
+    type Error = OpentelemetrySinkError;
+    type Response = OpentelemetryResponse;
+
+    fn is_retriable_error(&self, err: &Self::Error) -> bool {
+        use tonic::Code::*;
+
+        match err {
+            OpentelemetrySinkError::GrpcRequest { source } => !matches!(
+                source.code(),
+                // List taken from
+                //
+                // <https://github.com/grpc/grpc/blob/ed1b20777c69bd47e730a63271eafc1b299f6ca0/doc/statuscodes.md>
+                NotFound
+                    | InvalidArgument
+                    | AlreadyExists
+                    | PermissionDenied
+                    | OutOfRange
+                    | Unimplemented
+                    | Unauthenticated
+            ),
+            OpentelemetrySinkError::HttpRequest { source } => source.is_retriable(),
+            // <https://github.com/open-telemetry/opentelemetry-specification/blob/main/specification/protocol/otlp.md#failures-1>
+            OpentelemetrySinkError::HttpResponse { status } => {
+                matches!(status.as_u16(), 429 | 502 | 503 | 504)
+            }
+            OpentelemetrySinkError::Compression { .. } => false,
+        }
+    }
+}
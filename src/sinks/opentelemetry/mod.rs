@@ -0,0 +1,26 @@
+use snafu::Snafu;
+
+mod config;
+mod service;
+mod sink;
+
+#[cfg(test)]
+mod tests;
+
+pub use config::{OpentelemetryConfig, Protocol};
+
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub))]
+pub enum OpentelemetrySinkError {
+    #[snafu(display("Request failed: {}", source))]
+    GrpcRequest { source: tonic::Status },
+
+    #[snafu(display("Request failed: {}", source))]
+    HttpRequest { source: crate::http::HttpError },
+
+    #[snafu(display("Server responded with an error: {}", status))]
+    HttpResponse { status: http::StatusCode },
+
+    #[snafu(display("Failed to compress request: {}", source))]
+    Compression { source: std::io::Error },
+}
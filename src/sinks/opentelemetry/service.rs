@@ -0,0 +1,244 @@
+use std::{
+    io::Write,
+    task::{Context, Poll},
+};
+
+use flate2::{write::GzEncoder, Compression};
+use futures::future::BoxFuture;
+use http::{
+    header::{CONTENT_ENCODING, CONTENT_TYPE},
+    Request, Uri,
+};
+use hyper::Body;
+use prost::Message;
+use snafu::ResultExt;
+use tonic::IntoRequest;
+use vector_core::{internal_event::EventsSent, stream::DriverResponse};
+
+use crate::{
+    event::{EventFinalizers, EventStatus, Finalizable},
+    http::HttpClient,
+    internal_events::EndpointBytesSent,
+    opentelemetry::{
+        LogService::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+        MetricsService::{
+            metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest,
+        },
+        TraceService::{trace_service_client::TraceServiceClient, ExportTraceServiceRequest},
+    },
+    sinks::{
+        opentelemetry::{
+            CompressionSnafu, GrpcRequestSnafu, HttpRequestSnafu, OpentelemetrySinkError,
+        },
+        util::{
+            grpc::{GrpcHttpClient, HyperSvc},
+            uri,
+        },
+    },
+    Error,
+};
+
+/// The OTLP export request for a single batch of events sharing the same resource.
+#[derive(Clone, Debug)]
+pub enum OpentelemetryPayload {
+    Logs(ExportLogsServiceRequest),
+    Metrics(ExportMetricsServiceRequest),
+    Traces(ExportTraceServiceRequest),
+}
+
+impl OpentelemetryPayload {
+    const fn http_path(&self) -> &'static str {
+        match self {
+            Self::Logs(_) => "v1/logs",
+            Self::Metrics(_) => "v1/metrics",
+            Self::Traces(_) => "v1/traces",
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        match self {
+            Self::Logs(request) => request.encoded_len(),
+            Self::Metrics(request) => request.encoded_len(),
+            Self::Traces(request) => request.encoded_len(),
+        }
+    }
+
+    fn encode_to_vec(&self) -> Vec<u8> {
+        match self {
+            Self::Logs(request) => request.encode_to_vec(),
+            Self::Metrics(request) => request.encode_to_vec(),
+            Self::Traces(request) => request.encode_to_vec(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OpentelemetryRequest {
+    pub payload: OpentelemetryPayload,
+    pub finalizers: EventFinalizers,
+    pub events_count: usize,
+    pub events_byte_size: usize,
+}
+
+impl Finalizable for OpentelemetryRequest {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        self.finalizers.take_finalizers()
+    }
+}
+
+pub struct OpentelemetryResponse {
+    events_count: usize,
+    events_byte_size: usize,
+}
+
+impl DriverResponse for OpentelemetryResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> EventsSent {
+        EventsSent {
+            count: self.events_count,
+            byte_size: self.events_byte_size,
+            output: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Transport {
+    Grpc {
+        logs: LogsServiceClient<HyperSvc>,
+        metrics: MetricsServiceClient<HyperSvc>,
+        traces: TraceServiceClient<HyperSvc>,
+    },
+    Http {
+        client: HttpClient,
+        uri: Uri,
+        compression: bool,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub struct OpentelemetryService {
+    transport: Transport,
+    protocol: String,
+    endpoint: String,
+}
+
+impl OpentelemetryService {
+    pub fn grpc(client: GrpcHttpClient, uri: Uri, compression: bool) -> Self {
+        let (protocol, endpoint) = uri::protocol_endpoint(uri.clone());
+        let svc = HyperSvc::new(uri, client);
+        let mut logs = LogsServiceClient::new(svc.clone());
+        let mut metrics = MetricsServiceClient::new(svc.clone());
+        let mut traces = TraceServiceClient::new(svc);
+
+        if compression {
+            logs = logs.send_gzip();
+            metrics = metrics.send_gzip();
+            traces = traces.send_gzip();
+        }
+        Self {
+            transport: Transport::Grpc {
+                logs,
+                metrics,
+                traces,
+            },
+            protocol,
+            endpoint,
+        }
+    }
+
+    pub fn http(client: HttpClient, uri: Uri, compression: bool) -> Self {
+        let (protocol, endpoint) = uri::protocol_endpoint(uri.clone());
+        Self {
+            transport: Transport::Http {
+                client,
+                uri,
+                compression,
+            },
+            protocol,
+            endpoint,
+        }
+    }
+}
+
+impl tower::Service<OpentelemetryRequest> for OpentelemetryService {
+    type Response = OpentelemetryResponse;
+    type Error = Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Readiness of the gRPC clients is checked through their `export()` calls, see
+        // the `vector` sink.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: OpentelemetryRequest) -> Self::Future {
+        let service = self.clone();
+        let events_count = request.events_count;
+        let events_byte_size = request.events_byte_size;
+        let byte_size = request.payload.encoded_len();
+
+        let future = async move {
+            match service.transport {
+                Transport::Grpc {
+                    mut logs,
+                    mut metrics,
+                    mut traces,
+                } => match request.payload {
+                    OpentelemetryPayload::Logs(payload) => {
+                        logs.export(payload.into_request()).await.map(drop)
+                    }
+                    OpentelemetryPayload::Metrics(payload) => {
+                        metrics.export(payload.into_request()).await.map(drop)
+                    }
+                    OpentelemetryPayload::Traces(payload) => {
+                        traces.export(payload.into_request()).await.map(drop)
+                    }
+                }
+                .context(GrpcRequestSnafu)?,
+                Transport::Http {
+                    client,
+                    uri,
+                    compression,
+                } => {
+                    let uri = format!(
+                        "{}/{}",
+                        uri.to_string().trim_end_matches('/'),
+                        request.payload.http_path()
+                    );
+                    let mut body = request.payload.encode_to_vec();
+                    let mut builder =
+                        Request::post(uri).header(CONTENT_TYPE, "application/x-protobuf");
+                    if compression {
+                        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                        encoder.write_all(&body).context(CompressionSnafu)?;
+                        body = encoder.finish().context(CompressionSnafu)?;
+                        builder = builder.header(CONTENT_ENCODING, "gzip");
+                    }
+                    let http_request = builder.body(Body::from(body))?;
+
+                    let response = client.send(http_request).await.context(HttpRequestSnafu)?;
+                    let status = response.status();
+                    if !status.is_success() {
+                        return Err(OpentelemetrySinkError::HttpResponse { status }.into());
+                    }
+                }
+            }
+
+            emit!(EndpointBytesSent {
+                byte_size,
+                protocol: &service.protocol,
+                endpoint: &service.endpoint,
+            });
+            Ok(OpentelemetryResponse {
+                events_count,
+                events_byte_size,
+            })
+        };
+
+        Box::pin(future)
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use futures::{stream::BoxStream, StreamExt};
+use tower::Service;
+use vector_core::{
+    partition::Partitioner,
+    stream::{BatcherSettings, DriverResponse},
+    ByteSizeOf,
+};
+
+use crate::{
+    event::{Event, EventFinalizers, Finalizable, Value},
+    internal_events::OpentelemetryInvalidMetricError,
+    opentelemetry::{
+        convert::{value_into_resource, RESOURCE_KEY},
+        LogService::ExportLogsServiceRequest,
+        Logs::{ResourceLogs, ScopeLogs},
+        Metrics::{Metric as OtelMetric, ResourceMetrics, ScopeMetrics},
+        MetricsService::ExportMetricsServiceRequest,
+        Trace::{ResourceSpans, ScopeSpans},
+        TraceService::ExportTraceServiceRequest,
+    },
+    sinks::{
+        opentelemetry::service::{OpentelemetryPayload, OpentelemetryRequest},
+        util::{SinkBuilderExt, StreamSink},
+    },
+};
+
+/// Events are batched by type, and by resource for logs and traces, as every OTLP export
+/// request carries a single resource per batch of records.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum PartitionKey {
+    Logs(Option<String>),
+    Metrics,
+    Traces(Option<String>),
+}
+
+struct EventPartitioner;
+
+impl Partitioner for EventPartitioner {
+    type Item = Event;
+    type Key = PartitionKey;
+
+    fn partition(&self, item: &Self::Item) -> Self::Key {
+        match item {
+            Event::Log(log) => PartitionKey::Logs(resource_key(log.get(RESOURCE_KEY))),
+            Event::Metric(_) => PartitionKey::Metrics,
+            Event::Trace(trace) => PartitionKey::Traces(resource_key(trace.get(RESOURCE_KEY))),
+        }
+    }
+}
+
+fn resource_key(resource: Option<&Value>) -> Option<String> {
+    resource.and_then(|value| serde_json::to_string(value).ok())
+}
+
+pub struct OpentelemetrySink<S> {
+    pub batch_settings: BatcherSettings,
+    pub service: S,
+}
+
+impl<S> OpentelemetrySink<S>
+where
+    S: Service<OpentelemetryRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run_inner(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        input
+            .batched_partitioned(EventPartitioner, self.batch_settings)
+            .map(|(key, events)| build_request(key, events))
+            .into_driver(self.service)
+            .run()
+            .await
+    }
+}
+
+#[async_trait]
+impl<S> StreamSink<Event> for OpentelemetrySink<S>
+where
+    S: Service<OpentelemetryRequest> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Response: DriverResponse + Send + 'static,
+    S::Error: fmt::Debug + Into<crate::Error> + Send,
+{
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        self.run_inner(input).await
+    }
+}
+
+fn build_request(key: PartitionKey, mut events: Vec<Event>) -> OpentelemetryRequest {
+    let events_count = events.len();
+    let events_byte_size = events.size_of();
+    let mut finalizers = EventFinalizers::default();
+    for event in &mut events {
+        finalizers.merge(event.take_finalizers());
+    }
+
+    let payload = match key {
+        PartitionKey::Logs(_) => {
+            let resource = events
+                .first_mut()
+                .and_then(|event| event.as_mut_log().remove(RESOURCE_KEY))
+                .map(value_into_resource);
+            OpentelemetryPayload::Logs(ExportLogsServiceRequest {
+                resource_logs: vec![ResourceLogs {
+                    resource,
+                    scope_logs: vec![ScopeLogs {
+                        scope: None,
+                        log_records: events
+                            .into_iter()
+                            .map(|event| event.into_log().into())
+                            .collect(),
+                        schema_url: String::new(),
+                    }],
+                    schema_url: String::new(),
+                }],
+            })
+        }
+        PartitionKey::Metrics => OpentelemetryPayload::Metrics(ExportMetricsServiceRequest {
+            resource_metrics: vec![ResourceMetrics {
+                resource: None,
+                scope_metrics: vec![ScopeMetrics {
+                    scope: None,
+                    metrics: events
+                        .into_iter()
+                        .filter_map(|event| {
+                            OtelMetric::try_from(event.into_metric())
+                                .map_err(|metric| {
+                                    emit!(OpentelemetryInvalidMetricError {
+                                        value: metric.value(),
+                                        kind: &metric.kind(),
+                                    });
+                                })
+                                .ok()
+                        })
+                        .collect(),
+                    schema_url: String::new(),
+                }],
+                schema_url: String::new(),
+            }],
+        }),
+        PartitionKey::Traces(_) => {
+            let resource = events
+                .first_mut()
+                .and_then(|event| event.as_mut_trace().as_mut().remove(RESOURCE_KEY))
+                .map(value_into_resource);
+            OpentelemetryPayload::Traces(ExportTraceServiceRequest {
+                resource_spans: vec![ResourceSpans {
+                    resource,
+                    scope_spans: vec![ScopeSpans {
+                        scope: None,
+                        spans: events
+                            .into_iter()
+                            .map(|event| event.into_trace().into())
+                            .collect(),
+                        schema_url: String::new(),
+                    }],
+                    schema_url: String::new(),
+                }],
+            })
+        }
+    };
+
+    OpentelemetryRequest {
+        payload,
+        finalizers,
+        events_count,
+        events_byte_size,
+    }
+}
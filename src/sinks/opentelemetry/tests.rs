@@ -0,0 +1,189 @@
+use bytes::{BufMut, Bytes, BytesMut};
+use futures::{channel::mpsc, stream, StreamExt};
+use http::request::Parts;
+use hyper::Method;
+use prost::Message;
+use vector_core::event::{BatchNotifier, BatchStatus, Metric, MetricKind, MetricValue};
+
+use super::OpentelemetryConfig;
+use crate::{
+    config::{SinkConfig, SinkContext},
+    event::Event,
+    opentelemetry::{
+        Common::any_value,
+        LogService::{ExportLogsServiceRequest, ExportLogsServiceResponse},
+        Metrics::metric::Data,
+        MetricsService::{ExportMetricsServiceRequest, ExportMetricsServiceResponse},
+    },
+    sinks::util::test::build_test_server_generic,
+    test_util::{
+        components::{run_and_assert_sink_compliance, HTTP_SINK_TAGS},
+        next_addr, random_lines_with_stream,
+    },
+};
+
+// one byte for the compression flag plus four bytes for the length
+const GRPC_HEADER_SIZE: usize = 5;
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<OpentelemetryConfig>();
+}
+
+#[tokio::test]
+async fn deliver_logs_over_http() {
+    let num_lines = 10;
+    let in_addr = next_addr();
+
+    let config = format!(
+        r#"
+        endpoint = "http://{}"
+        protocol = "http"
+        "#,
+        in_addr
+    );
+    let config: OpentelemetryConfig = toml::from_str(&config).unwrap();
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+    let (rx, trigger, server) = build_test_server_generic(in_addr, move || {
+        hyper::Response::builder()
+            .header("content-type", "application/x-protobuf")
+            .body(hyper::Body::from(
+                ExportLogsServiceResponse {}.encode_to_vec(),
+            ))
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let (input_lines, events) = random_lines_with_stream(8, num_lines, Some(batch));
+
+    run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+    drop(trigger);
+
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let output_lines = get_received(rx, |parts, body| {
+        assert_eq!(Method::POST, parts.method);
+        assert_eq!("/v1/logs", parts.uri.path());
+        assert_eq!(
+            "application/x-protobuf",
+            parts.headers.get("content-type").unwrap().to_str().unwrap()
+        );
+
+        ExportLogsServiceRequest::decode(body)
+            .unwrap()
+            .resource_logs
+            .into_iter()
+            .flat_map(|resource_logs| resource_logs.scope_logs)
+            .flat_map(|scope_logs| scope_logs.log_records)
+            .map(|record| match record.body.and_then(|body| body.value) {
+                Some(any_value::Value::StringValue(message)) => message,
+                value => panic!("unexpected body {:?}", value),
+            })
+            .collect()
+    })
+    .await;
+
+    assert_eq!(input_lines, output_lines);
+}
+
+#[tokio::test]
+async fn deliver_metrics_over_grpc() {
+    let in_addr = next_addr();
+
+    let config = format!(r#"endpoint = "http://{}/""#, in_addr);
+    let config: OpentelemetryConfig = toml::from_str(&config).unwrap();
+    let (sink, _) = config.build(SinkContext::new_test()).await.unwrap();
+    let (rx, trigger, server) = build_test_server_generic(in_addr, move || {
+        hyper::Response::builder()
+            .header("grpc-status", "0") // OK
+            .header("content-type", "application/grpc")
+            .body(hyper::Body::from(encode_body(
+                ExportMetricsServiceResponse {
+                    partial_success: None,
+                },
+            )))
+            .unwrap()
+    });
+    tokio::spawn(server);
+
+    let metric = Metric::new(
+        "requests",
+        MetricKind::Incremental,
+        MetricValue::Counter { value: 3.0 },
+    );
+    let events = stream::iter(vec![Event::from(metric)]);
+
+    run_and_assert_sink_compliance(sink, events, &HTTP_SINK_TAGS).await;
+    drop(trigger);
+
+    let names = get_received(rx, |parts, body| {
+        assert_eq!(
+            "/opentelemetry.proto.collector.metrics.v1.MetricsService/Export",
+            parts.uri.path()
+        );
+
+        ExportMetricsServiceRequest::decode(body.slice(GRPC_HEADER_SIZE..))
+            .unwrap()
+            .resource_metrics
+            .into_iter()
+            .flat_map(|resource_metrics| resource_metrics.scope_metrics)
+            .flat_map(|scope_metrics| scope_metrics.metrics)
+            .map(|metric| {
+                assert!(matches!(
+                    metric.data,
+                    Some(Data::Sum(sum)) if sum.is_monotonic
+                ));
+                metric.name
+            })
+            .collect()
+    })
+    .await;
+
+    assert_eq!(names, vec!["requests".to_string()]);
+}
+
+async fn get_received(
+    rx: mpsc::Receiver<(Parts, Bytes)>,
+    decode: impl Fn(Parts, Bytes) -> Vec<String>,
+) -> Vec<String> {
+    rx.map(|(parts, body)| decode(parts, body))
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+// taken from <https://github.com/hyperium/tonic/blob/5aa8ae1fec27377cd4c2a41d309945d7e38087d0/examples/src/grpc-web/client.rs#L45-L75>
+fn encode_body<T>(msg: T) -> Bytes
+where
+    T: prost::Message,
+{
+    let mut buf = BytesMut::with_capacity(1024);
+
+    // first skip past the header
+    // cannot write it yet since we don't know the size of the
+    // encoded message
+    buf.reserve(GRPC_HEADER_SIZE);
+    unsafe {
+        buf.advance_mut(GRPC_HEADER_SIZE);
+    }
+
+    // write the message
+    msg.encode(&mut buf).unwrap();
+
+    // now we know the size of encoded message and can write the
+    // header
+    let len = buf.len() - GRPC_HEADER_SIZE;
+    {
+        let mut buf = &mut buf[..GRPC_HEADER_SIZE];
+
+        // compression flag, 0 means "no compression"
+        buf.put_u8(0);
+
+        buf.put_u32(len as u32);
+    }
+
+    buf.split_to(len + GRPC_HEADER_SIZE).freeze()
+}
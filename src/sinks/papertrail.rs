@@ -37,7 +37,7 @@ pub struct PapertrailConfig {
     send_buffer_bytes: Option<usize>,
 
     /// The value to use as the `process` in Papertrail.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     process: Option<Template>,
 
     #[configurable(derived)]
@@ -136,7 +136,7 @@ pub struct RedisSinkConfig {
     url: String,
 
     /// The Redis key to publish messages to.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     #[configurable(validation(length(min = 1)))]
     key: Template,
 
@@ -20,7 +20,8 @@ use crate::{
             timestamp_key, EndpointTarget, SplunkHecDefaultBatchSettings,
         },
         util::{
-            http::HttpRetryLogic, BatchConfig, Compression, ServiceBuilderExt, TowerRequestConfig,
+            http::HttpRetryLogic, BatchConfig, Compression, CompressionAlgorithm,
+            ServiceBuilderExt, TowerRequestConfig,
         },
         Healthcheck,
     },
@@ -59,13 +60,13 @@ pub struct HecLogsSinkConfig {
     /// The name of the index where to send the events to.
     ///
     /// If not specified, the default index is used.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     pub index: Option<Template>,
 
     /// The sourcetype of events sent to this sink.
     ///
     /// If unset, Splunk will default to `httpevent`.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     pub sourcetype: Option<Template>,
 
     /// The source of events sent to this sink.
@@ -73,7 +74,7 @@ pub struct HecLogsSinkConfig {
     /// This is typically the filename the logs originated from.
     ///
     /// If unset, the Splunk collector will set it.
-    #[configurable(metadata(templateable))]
+    #[configurable(metadata(templatable))]
     pub source: Option<Template>,
 
     #[configurable(derived)]
@@ -168,6 +169,14 @@ impl SinkConfig for HecLogsSinkConfig {
         "splunk_hec_logs"
     }
 
+    fn compression(&self) -> Option<Compression> {
+        Some(self.compression)
+    }
+
+    fn supported_compression(&self) -> &'static [CompressionAlgorithm] {
+        &[CompressionAlgorithm::None, CompressionAlgorithm::Gzip]
+    }
+
     fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
         Some(&self.acknowledgements.inner)
     }
@@ -47,6 +47,24 @@ pub enum Compression {
     ///
     /// [zlib]: https://en.wikipedia.org/wiki/Zlib
     Zlib(#[configurable(derived)] CompressionLevel),
+
+    /// [Zstandard][zstd] compression.
+    ///
+    /// [zstd]: https://facebook.github.io/zstd/
+    Zstd(#[configurable(derived)] CompressionLevel),
+
+    /// [Snappy][snappy] compression.
+    ///
+    /// The payload is compressed as a single raw Snappy block, which is what most HTTP receivers
+    /// expect when `Content-Encoding` is set to `snappy`.
+    ///
+    /// [snappy]: https://github.com/google/snappy
+    Snappy,
+
+    /// [LZ4][lz4] compression, using the LZ4 frame format.
+    ///
+    /// [lz4]: https://lz4.github.io/lz4/
+    Lz4(#[configurable(derived)] CompressionLevel),
 }
 
 impl Compression {
@@ -70,11 +88,34 @@ impl Compression {
         Compression::Zlib(CompressionLevel::const_default())
     }
 
+    pub const fn zstd_default() -> Compression {
+        Compression::Zstd(CompressionLevel::const_default())
+    }
+
+    pub const fn lz4_default() -> Compression {
+        Compression::Lz4(CompressionLevel::const_default())
+    }
+
+    /// Gets the compression algorithm, without any associated level.
+    pub const fn algorithm(self) -> CompressionAlgorithm {
+        match self {
+            Self::None => CompressionAlgorithm::None,
+            Self::Gzip(_) => CompressionAlgorithm::Gzip,
+            Self::Zlib(_) => CompressionAlgorithm::Zlib,
+            Self::Zstd(_) => CompressionAlgorithm::Zstd,
+            Self::Snappy => CompressionAlgorithm::Snappy,
+            Self::Lz4(_) => CompressionAlgorithm::Lz4,
+        }
+    }
+
     pub const fn content_encoding(self) -> Option<&'static str> {
         match self {
             Self::None => None,
             Self::Gzip(_) => Some("gzip"),
             Self::Zlib(_) => Some("deflate"),
+            Self::Zstd(_) => Some("zstd"),
+            Self::Snappy => Some("snappy"),
+            Self::Lz4(_) => Some("lz4"),
         }
     }
 
@@ -83,13 +124,19 @@ impl Compression {
             Self::None => "log",
             Self::Gzip(_) => "log.gz",
             Self::Zlib(_) => "log.zz",
+            Self::Zstd(_) => "log.zst",
+            Self::Snappy => "log.snappy",
+            Self::Lz4(_) => "log.lz4",
         }
     }
 
+    /// Gets the `flate2` compression level.
+    ///
+    /// Only meaningful for `gzip` and `zlib`. All other algorithms map to no compression.
     pub const fn level(self) -> flate2::Compression {
         match self {
-            Self::None => flate2::Compression::none(),
             Self::Gzip(level) | Self::Zlib(level) => level.as_flate2(),
+            _ => flate2::Compression::none(),
         }
     }
 }
@@ -100,6 +147,9 @@ impl fmt::Display for Compression {
             Compression::None => write!(f, "none"),
             Compression::Gzip(ref level) => write!(f, "gzip({})", level.as_flate2().level()),
             Compression::Zlib(ref level) => write!(f, "zlib({})", level.as_flate2().level()),
+            Compression::Zstd(ref level) => write!(f, "zstd({})", level.as_zstd()),
+            Compression::Snappy => write!(f, "snappy"),
+            Compression::Lz4(ref level) => write!(f, "lz4({})", level.as_lz4()),
         }
     }
 }
@@ -126,9 +176,12 @@ impl<'de> de::Deserialize<'de> for Compression {
                     "none" => Ok(Compression::None),
                     "gzip" => Ok(Compression::gzip_default()),
                     "zlib" => Ok(Compression::zlib_default()),
+                    "zstd" => Ok(Compression::zstd_default()),
+                    "snappy" => Ok(Compression::Snappy),
+                    "lz4" => Ok(Compression::lz4_default()),
                     _ => Err(de::Error::invalid_value(
                         de::Unexpected::Str(s),
-                        &r#""none" or "gzip" or "zlib""#,
+                        &r#""none", "gzip", "zlib", "zstd", "snappy" or "lz4""#,
                     )),
                 }
             }
@@ -158,7 +211,7 @@ impl<'de> de::Deserialize<'de> for Compression {
                     };
                 }
 
-                match algorithm
+                let compression = match algorithm
                     .ok_or_else(|| de::Error::missing_field("algorithm"))?
                     .as_str()
                 {
@@ -168,11 +221,31 @@ impl<'de> de::Deserialize<'de> for Compression {
                     },
                     "gzip" => Ok(Compression::Gzip(level.unwrap_or_default())),
                     "zlib" => Ok(Compression::Zlib(level.unwrap_or_default())),
+                    "zstd" => Ok(Compression::Zstd(level.unwrap_or_default())),
+                    "snappy" => match level {
+                        Some(_) => Err(de::Error::unknown_field("level", &[])),
+                        None => Ok(Compression::Snappy),
+                    },
+                    "lz4" => Ok(Compression::Lz4(level.unwrap_or_default())),
                     algorithm => Err(de::Error::unknown_variant(
                         algorithm,
-                        &["none", "gzip", "zlib"],
+                        &["none", "gzip", "zlib", "zstd", "snappy", "lz4"],
                     )),
+                }?;
+
+                // Numeric levels are only checked once we know which algorithm they apply to, since
+                // each algorithm has its own range of valid levels.
+                if let Some(CompressionLevel::Val(level)) = level {
+                    let (min, max, expected) = compression.algorithm().level_range();
+                    if level < min || level > max {
+                        return Err(de::Error::invalid_value(
+                            de::Unexpected::Unsigned(u64::from(level)),
+                            &expected,
+                        ));
+                    }
                 }
+
+                Ok(compression)
             }
         }
 
@@ -188,18 +261,14 @@ impl ser::Serialize for Compression {
         use ser::SerializeMap;
 
         let mut map = serializer.serialize_map(None)?;
-        let mut level = None;
-        match self {
-            Compression::None => map.serialize_entry("algorithm", "none")?,
-            Compression::Gzip(gzip_level) => {
-                map.serialize_entry("algorithm", "gzip")?;
-                level = Some(*gzip_level);
-            }
-            Compression::Zlib(zlib_level) => {
-                map.serialize_entry("algorithm", "zlib")?;
-                level = Some(*zlib_level);
-            }
-        }
+        let level = match self {
+            Compression::None | Compression::Snappy => None,
+            Compression::Gzip(level)
+            | Compression::Zlib(level)
+            | Compression::Zstd(level)
+            | Compression::Lz4(level) => Some(*level),
+        };
+        map.serialize_entry("algorithm", self.algorithm().as_str())?;
 
         // If there's a level present, and it's _not_ the default compression level, then serialize it. We already
         // handle deserializing as the default level when the level isn't explicitly specified (but `algorithm` is) so
@@ -215,34 +284,120 @@ impl ser::Serialize for Compression {
     }
 }
 
+/// Compression algorithm, without any associated compression level.
+///
+/// Used by sinks to declare which algorithms their backend accepts, so that an unsupported
+/// `compression` setting can be rejected when the configuration is validated.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CompressionAlgorithm {
+    None,
+    Gzip,
+    Zlib,
+    Zstd,
+    Snappy,
+    Lz4,
+}
+
+impl CompressionAlgorithm {
+    /// Every compression algorithm.
+    pub const ALL: &'static [CompressionAlgorithm] = &[
+        Self::None,
+        Self::Gzip,
+        Self::Zlib,
+        Self::Zstd,
+        Self::Snappy,
+        Self::Lz4,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Gzip => "gzip",
+            Self::Zlib => "zlib",
+            Self::Zstd => "zstd",
+            Self::Snappy => "snappy",
+            Self::Lz4 => "lz4",
+        }
+    }
+
+    /// Gets the inclusive range of numeric levels supported by this algorithm, along with a
+    /// human-readable description of that range.
+    const fn level_range(self) -> (u32, u32, &'static str) {
+        match self {
+            Self::None | Self::Snappy => (0, 0, "no level"),
+            Self::Gzip | Self::Zlib => (0, 9, "0, 1, 2, 3, 4, 5, 6, 7, 8 or 9"),
+            Self::Zstd => (1, 21, "an integer between 1 and 21"),
+            Self::Lz4 => (0, 12, "an integer between 0 and 12"),
+        }
+    }
+}
+
+impl fmt::Display for CompressionAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Compression level.
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-pub struct CompressionLevel(flate2::Compression);
+///
+/// The named levels are translated into the native scale of each algorithm, while numeric levels
+/// are passed through as-is.
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+pub enum CompressionLevel {
+    None,
+    #[derivative(Default)]
+    Default,
+    Best,
+    Fast,
+    Val(u32),
+}
 
 impl CompressionLevel {
     #[cfg(test)]
     const fn new(level: u32) -> Self {
-        Self(flate2::Compression::new(level))
+        Self::Val(level)
     }
 
     const fn const_default() -> Self {
-        Self(flate2::Compression::new(6))
+        Self::Default
     }
 
-    const fn none() -> Self {
-        Self(flate2::Compression::none())
+    const fn best() -> Self {
+        Self::Best
     }
 
-    const fn best() -> Self {
-        Self(flate2::Compression::best())
+    pub const fn as_flate2(self) -> flate2::Compression {
+        match self {
+            Self::None => flate2::Compression::none(),
+            Self::Default => flate2::Compression::new(6),
+            Self::Best => flate2::Compression::best(),
+            Self::Fast => flate2::Compression::fast(),
+            Self::Val(level) => flate2::Compression::new(level),
+        }
     }
 
-    const fn fast() -> Self {
-        Self(flate2::Compression::fast())
+    /// Gets the Zstandard compression level.
+    ///
+    /// Zstandard has no "uncompressed" level, so `none` maps to the fastest level.
+    pub const fn as_zstd(self) -> i32 {
+        match self {
+            Self::None | Self::Fast => 1,
+            Self::Default => zstd::DEFAULT_COMPRESSION_LEVEL,
+            Self::Best => 21,
+            Self::Val(level) => level as i32,
+        }
     }
 
-    pub const fn as_flate2(self) -> flate2::Compression {
-        self.0
+    /// Gets the LZ4 compression level.
+    ///
+    /// Level 0 is the regular fast mode, and levels 3 through 12 switch to the high-compression mode.
+    pub const fn as_lz4(self) -> u32 {
+        match self {
+            Self::None | Self::Fast | Self::Default => 0,
+            Self::Best => 12,
+            Self::Val(level) => level,
+        }
     }
 }
 
@@ -265,10 +420,10 @@ impl<'de> de::Deserialize<'de> for CompressionLevel {
                 E: de::Error,
             {
                 match s {
-                    "none" => Ok(CompressionLevel::none()),
-                    "fast" => Ok(CompressionLevel::fast()),
-                    "default" => Ok(CompressionLevel::const_default()),
-                    "best" => Ok(CompressionLevel::best()),
+                    "none" => Ok(CompressionLevel::None),
+                    "fast" => Ok(CompressionLevel::Fast),
+                    "default" => Ok(CompressionLevel::Default),
+                    "best" => Ok(CompressionLevel::Best),
                     level => {
                         return Err(de::Error::invalid_value(
                             de::Unexpected::Str(level),
@@ -282,24 +437,23 @@ impl<'de> de::Deserialize<'de> for CompressionLevel {
             where
                 E: de::Error,
             {
-                Err(de::Error::invalid_value(
-                    de::Unexpected::Other(&v.to_string()),
-                    &"0, 1, 2, 3, 4, 5, 6, 7, 8 or 9",
-                ))
+                match u64::try_from(v) {
+                    Ok(v) => self.visit_u64(v),
+                    Err(_) => Err(de::Error::invalid_value(
+                        de::Unexpected::Other(&v.to_string()),
+                        &"a non-negative integer",
+                    )),
+                }
             }
 
             fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                if v <= 9 {
-                    Ok(CompressionLevel(flate2::Compression::new(v as u32)))
-                } else {
-                    return Err(de::Error::invalid_value(
-                        de::Unexpected::Unsigned(v),
-                        &"0, 1, 2, 3, 4, 5, 6, 7, 8 or 9",
-                    ));
-                }
+                // The range of valid levels depends on the algorithm, and is checked by `Compression`.
+                u32::try_from(v).map(CompressionLevel::Val).map_err(|_| {
+                    de::Error::invalid_value(de::Unexpected::Unsigned(v), &"a compression level")
+                })
             }
         }
 
@@ -312,15 +466,12 @@ impl ser::Serialize for CompressionLevel {
     where
         S: ser::Serializer,
     {
-        const NONE: CompressionLevel = CompressionLevel::none();
-        const FAST: CompressionLevel = CompressionLevel::fast();
-        const BEST: CompressionLevel = CompressionLevel::best();
-
         match *self {
-            NONE => serializer.serialize_str("none"),
-            FAST => serializer.serialize_str("fast"),
-            BEST => serializer.serialize_str("best"),
-            level => serializer.serialize_u64(u64::from(level.0.level())),
+            CompressionLevel::None => serializer.serialize_str("none"),
+            CompressionLevel::Default => serializer.serialize_str("default"),
+            CompressionLevel::Best => serializer.serialize_str("best"),
+            CompressionLevel::Fast => serializer.serialize_str("fast"),
+            CompressionLevel::Val(level) => serializer.serialize_u64(u64::from(level)),
         }
     }
 }
@@ -345,7 +496,8 @@ impl Configurable for CompressionLevel {
             metadata.set_description(description);
         }
 
-        // Allows the user to specify any number from 0 to 9, or the constants "none", "fast", or "best".
+        // Allows the user to specify any number from 0 to 21, or the constants "none", "fast", or "best". The range
+        // that's actually valid depends on the algorithm: 0-9 for gzip/zlib, 1-21 for zstd, and 0-12 for lz4.
         //
         // TODO: Technically, we can define `integer` or `number` for a schema's instance type, which would make the
         // validation do the right thing, since as-is, while our implicit casting, everything in the schema ends up
@@ -353,7 +505,7 @@ impl Configurable for CompressionLevel {
         // then add validator support to do ranges specifically for integers vs numbers (floating-point).
         metadata.add_validation(Validation::Range {
             minimum: Some(0.0),
-            maximum: Some(9.0),
+            maximum: Some(21.0),
         });
         metadata.add_validation(Validation::Pattern(String::from("none|fast|best|default")));
 
@@ -402,6 +554,24 @@ mod test {
                 r#"{"algorithm": "zlib", "level": 8}"#,
                 Compression::Zlib(CompressionLevel::new(8)),
             ),
+            (
+                r#""zstd""#,
+                Compression::Zstd(CompressionLevel::const_default()),
+            ),
+            (
+                r#"{"algorithm": "zstd", "level": 19}"#,
+                Compression::Zstd(CompressionLevel::new(19)),
+            ),
+            (r#""snappy""#, Compression::Snappy),
+            (r#"{"algorithm": "snappy"}"#, Compression::Snappy),
+            (
+                r#""lz4""#,
+                Compression::Lz4(CompressionLevel::const_default()),
+            ),
+            (
+                r#"{"algorithm": "lz4", "level": "best"}"#,
+                Compression::Lz4(CompressionLevel::best()),
+            ),
         ];
         for (sources, result) in fixtures_valid.iter() {
             let deserialized: Result<Compression, _> = serde_json::from_str(sources);
@@ -415,11 +585,11 @@ mod test {
             ),
             (
                 r#""b42""#,
-                r#"invalid value: string "b42", expected "none" or "gzip" or "zlib" at line 1 column 5"#,
+                r#"invalid value: string "b42", expected "none", "gzip", "zlib", "zstd", "snappy" or "lz4" at line 1 column 5"#,
             ),
             (
                 r#"{"algorithm": "b42"}"#,
-                r#"unknown variant `b42`, expected one of `none`, `gzip`, `zlib` at line 1 column 20"#,
+                r#"unknown variant `b42`, expected one of `none`, `gzip`, `zlib`, `zstd`, `snappy`, `lz4` at line 1 column 20"#,
             ),
             (
                 r#"{"algorithm": "none", "level": "default"}"#,
@@ -427,7 +597,23 @@ mod test {
             ),
             (
                 r#"{"algorithm": "gzip", "level": -1}"#,
-                r#"invalid value: -1, expected 0, 1, 2, 3, 4, 5, 6, 7, 8 or 9 at line 1 column 33"#,
+                r#"invalid value: -1, expected a non-negative integer at line 1 column 33"#,
+            ),
+            (
+                r#"{"algorithm": "gzip", "level": 10}"#,
+                r#"invalid value: integer `10`, expected 0, 1, 2, 3, 4, 5, 6, 7, 8 or 9 at line 1 column 34"#,
+            ),
+            (
+                r#"{"algorithm": "zstd", "level": 0}"#,
+                r#"invalid value: integer `0`, expected an integer between 1 and 21 at line 1 column 33"#,
+            ),
+            (
+                r#"{"algorithm": "lz4", "level": 13}"#,
+                r#"invalid value: integer `13`, expected an integer between 0 and 12 at line 1 column 33"#,
+            ),
+            (
+                r#"{"algorithm": "snappy", "level": "best"}"#,
+                r#"unknown field `level`, there are no fields at line 1 column 40"#,
             ),
             (
                 r#"{"algorithm": "gzip", "level": "good"}"#,
@@ -457,6 +643,10 @@ mod test {
             Compression::Gzip(CompressionLevel::new(7)),
             Compression::Zlib(CompressionLevel::best()),
             Compression::Zlib(CompressionLevel::new(7)),
+            Compression::Zstd(CompressionLevel::const_default()),
+            Compression::Zstd(CompressionLevel::new(12)),
+            Compression::Snappy,
+            Compression::Lz4(CompressionLevel::best()),
         ];
 
         for v in fixtures_valid {
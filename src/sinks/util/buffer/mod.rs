@@ -1,9 +1,11 @@
 use std::io::Write;
 
-use bytes::{BufMut, BytesMut};
-use flate2::write::{GzEncoder, ZlibEncoder};
+use bytes::BytesMut;
 
-use super::batch::{err_event_too_large, Batch, BatchSize, PushResult};
+use super::{
+    batch::{err_event_too_large, Batch, BatchSize, PushResult},
+    Compressor,
+};
 
 pub mod compression;
 pub mod json;
@@ -11,25 +13,18 @@ pub mod metrics;
 pub mod partition;
 pub mod vec;
 
-pub use compression::Compression;
+pub use compression::{Compression, CompressionAlgorithm};
 pub use partition::{Partition, PartitionBuffer, PartitionInnerBuffer};
 
 #[derive(Debug)]
 pub struct Buffer {
-    inner: Option<InnerBuffer>,
+    inner: Option<Compressor>,
     num_items: usize,
     num_bytes: usize,
     settings: BatchSize<Self>,
     compression: Compression,
 }
 
-#[derive(Debug)]
-pub enum InnerBuffer {
-    Plain(bytes::buf::Writer<BytesMut>),
-    Gzip(GzEncoder<bytes::buf::Writer<BytesMut>>),
-    Zlib(ZlibEncoder<bytes::buf::Writer<BytesMut>>),
-}
-
 impl Buffer {
     pub const fn new(settings: BatchSize<Self>, compression: Compression) -> Self {
         Self {
@@ -41,47 +36,21 @@ impl Buffer {
         }
     }
 
-    fn buffer(&mut self) -> &mut InnerBuffer {
+    fn buffer(&mut self) -> &mut Compressor {
         let bytes = self.settings.bytes;
         let compression = self.compression;
-        self.inner.get_or_insert_with(|| {
-            let writer = BytesMut::with_capacity(bytes).writer();
-            match compression {
-                Compression::None => InnerBuffer::Plain(writer),
-                Compression::Gzip(level) => {
-                    InnerBuffer::Gzip(GzEncoder::new(writer, level.as_flate2()))
-                }
-                Compression::Zlib(level) => {
-                    InnerBuffer::Zlib(ZlibEncoder::new(writer, level.as_flate2()))
-                }
-            }
-        })
+        self.inner
+            .get_or_insert_with(|| Compressor::with_capacity(compression, bytes))
     }
 
     pub fn push(&mut self, input: &[u8]) {
         self.num_items += 1;
-        match self.buffer() {
-            InnerBuffer::Plain(inner) => {
-                inner.write_all(input).unwrap();
-            }
-            InnerBuffer::Gzip(inner) => {
-                inner.write_all(input).unwrap();
-            }
-            InnerBuffer::Zlib(inner) => {
-                inner.write_all(input).unwrap();
-            }
-        }
+        self.buffer().write_all(input).unwrap();
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.inner
-            .as_ref()
-            .map(|inner| match inner {
-                InnerBuffer::Plain(inner) => inner.get_ref().is_empty(),
-                InnerBuffer::Gzip(inner) => inner.get_ref().get_ref().is_empty(),
-                InnerBuffer::Zlib(inner) => inner.get_ref().get_ref().is_empty(),
-            })
-            .unwrap_or(true)
+    pub const fn is_empty(&self) -> bool {
+        // Some encoders hold on to their input until they're flushed, so the written bytes can't be relied on.
+        self.num_items == 0
     }
 }
 
@@ -117,15 +86,7 @@ impl Batch for Buffer {
 
     fn finish(self) -> Self::Output {
         match self.inner {
-            Some(InnerBuffer::Plain(inner)) => inner.into_inner(),
-            Some(InnerBuffer::Gzip(inner)) => inner
-                .finish()
-                .expect("This can't fail because the inner writer is a Vec")
-                .into_inner(),
-            Some(InnerBuffer::Zlib(inner)) => inner
-                .finish()
-                .expect("This can't fail because the inner writer is a Vec")
-                .into_inner(),
+            Some(inner) => inner.into_inner(),
             None => BytesMut::new(),
         }
     }
@@ -205,4 +166,15 @@ mod test {
         .take(100_000)
         .flatten()));
     }
+
+    #[test]
+    fn buffered_compression_is_not_empty() {
+        for compression in [Compression::zstd_default(), Compression::lz4_default()] {
+            let mut buffer = Buffer::new(BatchSettings::<Buffer>::default().size, compression);
+            assert!(buffer.is_empty());
+
+            buffer.push(b"event");
+            assert!(!buffer.is_empty(), "{} buffer is empty", compression);
+        }
+    }
 }
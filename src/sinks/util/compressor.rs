@@ -1,34 +1,77 @@
-use std::io;
+use std::{fmt, io};
 
 use bytes::{BufMut, BytesMut};
 use flate2::write::{GzEncoder, ZlibEncoder};
 
 use super::Compression;
 
+const DEFAULT_CAPACITY: usize = 1_024;
+
 enum Writer {
     Plain(bytes::buf::Writer<BytesMut>),
     Gzip(GzEncoder<bytes::buf::Writer<BytesMut>>),
     Zlib(ZlibEncoder<bytes::buf::Writer<BytesMut>>),
+    Zstd(zstd::stream::write::Encoder<'static, bytes::buf::Writer<BytesMut>>),
+    // Snappy is written as a single raw block, which can only be produced once we have the entire
+    // payload, so the input is buffered as-is and compressed when the writer is finished.
+    Snappy(bytes::buf::Writer<BytesMut>),
+    Lz4(lz4::Encoder<bytes::buf::Writer<BytesMut>>),
 }
 
 impl Writer {
+    fn new(compression: Compression, capacity: usize) -> Self {
+        let writer = BytesMut::with_capacity(capacity).writer();
+        match compression {
+            Compression::None => Writer::Plain(writer),
+            Compression::Gzip(level) => Writer::Gzip(GzEncoder::new(writer, level.as_flate2())),
+            Compression::Zlib(level) => Writer::Zlib(ZlibEncoder::new(writer, level.as_flate2())),
+            Compression::Zstd(level) => Writer::Zstd(
+                zstd::stream::write::Encoder::new(writer, level.as_zstd())
+                    .expect("zstd encoder should not fail to initialize"),
+            ),
+            Compression::Snappy => Writer::Snappy(writer),
+            Compression::Lz4(level) => Writer::Lz4(
+                lz4::EncoderBuilder::new()
+                    .level(level.as_lz4())
+                    .build(writer)
+                    .expect("lz4 encoder should not fail to initialize"),
+            ),
+        }
+    }
+
     pub fn get_ref(&self) -> &BytesMut {
         match self {
             Writer::Plain(inner) => inner.get_ref(),
             Writer::Gzip(inner) => inner.get_ref().get_ref(),
             Writer::Zlib(inner) => inner.get_ref().get_ref(),
+            Writer::Zstd(inner) => inner.get_ref().get_ref(),
+            Writer::Snappy(inner) => inner.get_ref(),
+            Writer::Lz4(inner) => inner.writer().get_ref(),
         }
     }
+
+    fn finish(self) -> io::Result<BytesMut> {
+        let buf = match self {
+            Writer::Plain(writer) => writer,
+            Writer::Gzip(writer) => writer.finish()?,
+            Writer::Zlib(writer) => writer.finish()?,
+            Writer::Zstd(writer) => writer.finish()?,
+            Writer::Snappy(writer) => return compress_snappy(&writer.into_inner()),
+            Writer::Lz4(writer) => {
+                let (writer, result) = writer.finish();
+                result?;
+                writer
+            }
+        }
+        .into_inner();
+
+        Ok(buf)
+    }
 }
 
 impl From<Compression> for Writer {
     fn from(compression: Compression) -> Self {
-        let writer = BytesMut::with_capacity(1_024).writer();
-        match compression {
-            Compression::None => Writer::Plain(writer),
-            Compression::Gzip(level) => Writer::Gzip(GzEncoder::new(writer, level.as_flate2())),
-            Compression::Zlib(level) => Writer::Zlib(ZlibEncoder::new(writer, level.as_flate2())),
-        }
+        Writer::new(compression, DEFAULT_CAPACITY)
     }
 }
 
@@ -39,6 +82,9 @@ impl io::Write for Writer {
             Writer::Plain(inner_buf) => inner_buf.write(buf),
             Writer::Gzip(writer) => writer.write(buf),
             Writer::Zlib(writer) => writer.write(buf),
+            Writer::Zstd(writer) => writer.write(buf),
+            Writer::Snappy(inner_buf) => inner_buf.write(buf),
+            Writer::Lz4(writer) => writer.write(buf),
         }
     }
 
@@ -47,10 +93,20 @@ impl io::Write for Writer {
             Writer::Plain(writer) => writer.flush(),
             Writer::Gzip(writer) => writer.flush(),
             Writer::Zlib(writer) => writer.flush(),
+            Writer::Zstd(writer) => writer.flush(),
+            Writer::Snappy(writer) => writer.flush(),
+            Writer::Lz4(writer) => writer.flush(),
         }
     }
 }
 
+fn compress_snappy(input: &[u8]) -> io::Result<BytesMut> {
+    let mut output = BytesMut::zeroed(snap::raw::max_compress_len(input.len()));
+    let compressed_len = snap::raw::Encoder::new().compress(input, &mut output)?;
+    output.truncate(compressed_len);
+    Ok(output)
+}
+
 /// Simple compressor implementation based on [`Compression`].
 ///
 /// Users can acquire a `Compressor` via [`Compressor::from`] based on the desired compression scheme.
@@ -60,6 +116,14 @@ pub struct Compressor {
 }
 
 impl Compressor {
+    /// Creates a new `Compressor` whose underlying buffer has at least the given capacity.
+    pub fn with_capacity(compression: Compression, capacity: usize) -> Self {
+        Compressor {
+            compression,
+            inner: Writer::new(compression, capacity),
+        }
+    }
+
     /// Gets a mutable reference to the underlying buffer.
     pub fn get_ref(&self) -> &BytesMut {
         self.inner.get_ref()
@@ -84,14 +148,7 @@ impl Compressor {
     /// If the compressor encounters an I/O error while finalizing the payload, an error
     /// variant will be returned.
     pub fn finish(self) -> io::Result<BytesMut> {
-        let buf = match self.inner {
-            Writer::Plain(writer) => writer,
-            Writer::Gzip(writer) => writer.finish()?,
-            Writer::Zlib(writer) => writer.finish()?,
-        }
-        .into_inner();
-
-        Ok(buf)
+        self.inner.finish()
     }
 
     /// Consumes the compressor, returning the internal buffer used by the compressor.
@@ -104,16 +161,10 @@ impl Compressor {
     ///
     /// Consider using `finish` if catching these scenarios is important.
     pub fn into_inner(self) -> BytesMut {
-        match self.inner {
-            Writer::Plain(writer) => writer,
-            Writer::Gzip(writer) => writer
-                .finish()
-                .expect("gzip writer should not fail to finish"),
-            Writer::Zlib(writer) => writer
-                .finish()
-                .expect("zlib writer should not fail to finish"),
-        }
-        .into_inner()
+        let compression = self.compression;
+        self.inner
+            .finish()
+            .unwrap_or_else(|_| panic!("{} writer should not fail to finish", compression))
     }
 }
 
@@ -136,3 +187,65 @@ impl From<Compression> for Compressor {
         }
     }
 }
+
+impl fmt::Debug for Compressor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Compressor")
+            .field("compression", &self.compression)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use super::{Compression, Compressor};
+
+    const INPUT: &[u8] = b"It's going down, I'm yelling timber, You better move, you better dance";
+
+    fn compress(compression: Compression) -> Vec<u8> {
+        let mut compressor = Compressor::from(compression);
+        for _ in 0..100 {
+            compressor.write_all(INPUT).unwrap();
+        }
+        compressor.finish().unwrap().to_vec()
+    }
+
+    fn expected() -> Vec<u8> {
+        INPUT.repeat(100)
+    }
+
+    #[test]
+    fn zstd() {
+        let compressed = compress(Compression::zstd_default());
+        assert!(compressed.len() < expected().len());
+
+        let decompressed = zstd::stream::decode_all(&compressed[..]).unwrap();
+        assert_eq!(decompressed, expected());
+    }
+
+    #[test]
+    fn snappy() {
+        let compressed = compress(Compression::Snappy);
+        assert!(compressed.len() < expected().len());
+
+        let decompressed = snap::raw::Decoder::new()
+            .decompress_vec(&compressed)
+            .unwrap();
+        assert_eq!(decompressed, expected());
+    }
+
+    #[test]
+    fn lz4() {
+        let compressed = compress(Compression::lz4_default());
+        assert!(compressed.len() < expected().len());
+
+        let mut decompressed = Vec::new();
+        lz4::Decoder::new(&compressed[..])
+            .unwrap()
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, expected());
+    }
+}
@@ -13,6 +13,17 @@ pub trait Encoder<T> {
     ///
     /// If an I/O error is encountered while encoding the input, an error variant will be returned.
     fn encode_input(&self, input: T, writer: &mut dyn io::Write) -> io::Result<usize>;
+
+    /// Encodes the header that starts every output file or object into the provided writer.
+    ///
+    /// Most encodings don't have a header, in which case nothing is written.
+    ///
+    /// # Errors
+    ///
+    /// If an I/O error is encountered while encoding the header, an error variant will be returned.
+    fn encode_header(&self, _writer: &mut dyn io::Write) -> io::Result<usize> {
+        Ok(0)
+    }
 }
 
 impl Encoder<Vec<Event>> for (Transformer, crate::codecs::Encoder<Framer>) {
@@ -51,6 +62,16 @@ impl Encoder<Vec<Event>> for (Transformer, crate::codecs::Encoder<Framer>) {
 
         Ok(bytes_written)
     }
+
+    fn encode_header(&self, writer: &mut dyn io::Write) -> io::Result<usize> {
+        let mut encoder = self.1.clone();
+        let mut bytes = BytesMut::new();
+        encoder
+            .encode_header(&mut bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        writer.write_all(&bytes)?;
+        Ok(bytes.len())
+    }
 }
 
 impl Encoder<Event> for (Transformer, crate::codecs::Encoder<()>) {
@@ -99,7 +120,8 @@ mod tests {
     use std::collections::BTreeMap;
 
     use codecs::{
-        CharacterDelimitedEncoder, JsonSerializer, NewlineDelimitedEncoder, TextSerializer,
+        encoding::CsvSerializerOptions, CharacterDelimitedEncoder, CsvSerializer, JsonSerializer,
+        NewlineDelimitedEncoder, TextSerializer,
     };
     use value::Value;
     use vector_core::event::LogEvent;
@@ -309,4 +331,60 @@ mod tests {
 
         assert_eq!(String::from_utf8(writer).unwrap(), r#"value"#);
     }
+
+    #[test]
+    fn test_encode_header_csv() {
+        let options = CsvSerializerOptions {
+            include_header: true,
+            ..CsvSerializerOptions::new(vec!["key".to_owned(), "other".to_owned()])
+        };
+        let encoding = (
+            Transformer::default(),
+            crate::codecs::Encoder::<Framer>::new(
+                NewlineDelimitedEncoder::new().into(),
+                CsvSerializer::new(options).into(),
+            ),
+        );
+
+        let mut writer = Vec::new();
+        let written = encoding.encode_header(&mut writer).unwrap();
+        let written = written
+            + encoding
+                .encode_input(
+                    vec![
+                        Event::Log(LogEvent::from(BTreeMap::from([(
+                            String::from("key"),
+                            Value::from("value1"),
+                        )]))),
+                        Event::Log(LogEvent::from(BTreeMap::from([(
+                            String::from("other"),
+                            Value::from("value2"),
+                        )]))),
+                    ],
+                    &mut writer,
+                )
+                .unwrap();
+        assert_eq!(written, 25);
+
+        assert_eq!(
+            String::from_utf8(writer).unwrap(),
+            "key,other\nvalue1,\n,value2"
+        );
+    }
+
+    #[test]
+    fn test_encode_header_none() {
+        let encoding = (
+            Transformer::default(),
+            crate::codecs::Encoder::<Framer>::new(
+                NewlineDelimitedEncoder::new().into(),
+                JsonSerializer::new().into(),
+            ),
+        );
+
+        let mut writer = Vec::new();
+        let written = encoding.encode_header(&mut writer).unwrap();
+        assert_eq!(written, 0);
+        assert!(writer.is_empty());
+    }
 }
@@ -0,0 +1,112 @@
+//! Shared plumbing for sinks talking to gRPC services through a `tonic` generated client.
+
+use std::task::{Context, Poll};
+
+use futures::future::BoxFuture;
+use http::Uri;
+use hyper::client::HttpConnector;
+use hyper_openssl::HttpsConnector;
+use hyper_proxy::ProxyConnector;
+use tonic::body::BoxBody;
+
+use crate::{
+    config::ProxyConfig,
+    tls::{tls_connector_builder, MaybeTlsSettings},
+};
+
+pub type GrpcHttpClient = hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>;
+
+/// grpc doesn't like an address without a scheme, so we default to http or https if one isn't
+/// specified in the address.
+pub fn with_default_scheme(address: &str, tls: bool) -> crate::Result<Uri> {
+    let uri: Uri = address.parse()?;
+    if uri.scheme().is_none() {
+        // Default the scheme to http or https.
+        let mut parts = uri.into_parts();
+
+        parts.scheme = if tls {
+            Some(
+                "https"
+                    .parse()
+                    .unwrap_or_else(|_| unreachable!("https should be valid")),
+            )
+        } else {
+            Some(
+                "http"
+                    .parse()
+                    .unwrap_or_else(|_| unreachable!("http should be valid")),
+            )
+        };
+
+        if parts.path_and_query.is_none() {
+            parts.path_and_query = Some(
+                "/".parse()
+                    .unwrap_or_else(|_| unreachable!("root should be valid")),
+            );
+        }
+        Ok(Uri::from_parts(parts)?)
+    } else {
+        Ok(uri)
+    }
+}
+
+pub fn new_client(
+    tls_settings: &MaybeTlsSettings,
+    proxy_config: &ProxyConfig,
+) -> crate::Result<GrpcHttpClient> {
+    let mut http = HttpConnector::new();
+    http.enforce_http(false);
+
+    let tls = tls_connector_builder(tls_settings)?;
+    let mut https = HttpsConnector::with_connector(http, tls)?;
+
+    let settings = tls_settings.tls().cloned();
+    https.set_callback(move |c, _uri| {
+        if let Some(settings) = &settings {
+            settings.apply_connect_configuration(c);
+        }
+
+        Ok(())
+    });
+
+    let mut proxy = ProxyConnector::new(https).unwrap();
+    proxy_config.configure(&mut proxy)?;
+
+    Ok(hyper::Client::builder().http2_only(true).build(proxy))
+}
+
+/// Transport for `tonic` clients which sends every request to the configured endpoint.
+#[derive(Clone, Debug)]
+pub struct HyperSvc {
+    uri: Uri,
+    client: GrpcHttpClient,
+}
+
+impl HyperSvc {
+    pub const fn new(uri: Uri, client: GrpcHttpClient) -> Self {
+        Self { uri, client }
+    }
+}
+
+impl tower::Service<hyper::Request<BoxBody>> for HyperSvc {
+    type Response = hyper::Response<hyper::Body>;
+    type Error = hyper::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, mut req: hyper::Request<BoxBody>) -> Self::Future {
+        let uri = Uri::builder()
+            .scheme(self.uri.scheme().unwrap().clone())
+            .authority(self.uri.authority().unwrap().clone())
+            .path_and_query(req.uri().path_and_query().unwrap().clone())
+            .build()
+            .unwrap();
+
+        *req.uri_mut() = uri;
+
+        Box::pin(self.client.request(req))
+    }
+}
@@ -420,6 +420,11 @@ impl<T: fmt::Debug> sink::Response for http::Response<T> {
     fn is_transient(&self) -> bool {
         self.status().is_server_error()
     }
+
+    fn rejection_reason(&self) -> Option<String> {
+        (!self.is_successful() && !self.is_transient())
+            .then(|| format!("response status: {}", self.status()))
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -546,6 +551,26 @@ mod test {
     use super::*;
     use crate::{config::ProxyConfig, test_util::next_addr};
 
+    #[test]
+    fn util_http_response_rejection_reason() {
+        let response_200 = Response::builder().status(200).body(Bytes::new()).unwrap();
+        let response_400 = Response::builder().status(400).body(Bytes::new()).unwrap();
+        let response_413 = Response::builder().status(413).body(Bytes::new()).unwrap();
+        let response_503 = Response::builder().status(503).body(Bytes::new()).unwrap();
+
+        assert_eq!(sink::Response::rejection_reason(&response_200), None);
+        assert_eq!(
+            sink::Response::rejection_reason(&response_400).as_deref(),
+            Some("response status: 400 Bad Request")
+        );
+        assert_eq!(
+            sink::Response::rejection_reason(&response_413).as_deref(),
+            Some("response status: 413 Payload Too Large")
+        );
+        // Server errors are retried, and only end up errored rather than rejected.
+        assert_eq!(sink::Response::rejection_reason(&response_503), None);
+    }
+
     #[test]
     fn util_http_retry_logic() {
         let logic = HttpRetryLogic;
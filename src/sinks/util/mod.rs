@@ -4,6 +4,8 @@ pub mod buffer;
 pub mod builder;
 pub mod compressor;
 pub mod encoding;
+#[cfg(any(feature = "sinks-vector", feature = "sinks-opentelemetry"))]
+pub mod grpc;
 pub mod http;
 pub mod metadata;
 pub mod normalizer;
@@ -34,7 +36,7 @@ pub use buffer::{
     json::{BoxedRawValue, JsonArrayBuffer},
     partition::Partition,
     vec::{EncodedLength, VecBuffer},
-    Buffer, Compression, PartitionBuffer, PartitionInnerBuffer,
+    Buffer, Compression, CompressionAlgorithm, PartitionBuffer, PartitionInnerBuffer,
 };
 pub use builder::SinkBuilderExt;
 pub use compressor::Compressor;
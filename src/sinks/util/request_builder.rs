@@ -73,6 +73,9 @@ pub trait RequestBuilder<Input> {
         // of clash-y with `Self::Metadata`.
         let mut compressor = Compressor::from(self.compression());
         let is_compressed = compressor.is_compressed();
+        // Every request produces a new payload, so encodings with a header (e.g. CSV) get one
+        // at the start of each object.
+        let _ = self.encoder().encode_header(&mut compressor)?;
         let _ = self.encoder().encode_input(events, &mut compressor)?;
 
         let payload = compressor.into_inner().freeze();
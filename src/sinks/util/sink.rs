@@ -440,8 +440,17 @@ where
             .call(items)
             .err_into()
             .map(move |result| {
+                let reason = match &result {
+                    Ok(response) => response.rejection_reason(),
+                    Err(_) => None,
+                };
                 let status = result_status(result);
-                finalizers.update_status(status);
+                match reason {
+                    Some(reason) if status == EventStatus::Rejected => {
+                        finalizers.update_status_with_reason(status, &reason);
+                    }
+                    _ => finalizers.update_status(status),
+                }
                 if status == EventStatus::Delivered {
                     emit!(EventsSent {
                         count,
@@ -545,6 +554,11 @@ pub trait Response: fmt::Debug {
     fn is_transient(&self) -> bool {
         true
     }
+
+    /// The reason the events of the request were rejected, if the response provides one.
+    fn rejection_reason(&self) -> Option<String> {
+        None
+    }
 }
 
 impl Response for () {}
@@ -640,6 +654,48 @@ mod tests {
         assert_eq!(ack_counter.load(Relaxed), 22 * 23 / 2);
     }
 
+    #[derive(Debug)]
+    struct RejectedResponse;
+
+    impl Response for RejectedResponse {
+        fn is_successful(&self) -> bool {
+            false
+        }
+
+        fn is_transient(&self) -> bool {
+            false
+        }
+
+        fn rejection_reason(&self) -> Option<String> {
+            Some("bad request".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_sink_records_rejection_reason() {
+        let svc = tower::service_fn(|_| future::ok::<_, std::io::Error>(RejectedResponse));
+        let mut batch_settings = BatchSettings::default();
+        batch_settings.size.bytes = 9999;
+        batch_settings.size.events = 10;
+
+        let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+        let event = EncodedEvent {
+            item: Request(1, EventFinalizers::default()),
+            finalizers: EventFinalizers::new(EventFinalizer::new(batch)),
+            byte_size: 0,
+        };
+
+        let buffered = BatchSink::new(svc, VecBuffer::new(batch_settings.size), TIMEOUT);
+        buffered
+            .sink_map_err(drop)
+            .send_all(&mut stream::iter(Some(Ok(event))))
+            .await
+            .unwrap();
+
+        assert_eq!((&mut receiver).await, BatchStatus::Rejected);
+        assert_eq!(receiver.reason().as_deref(), Some("bad request"));
+    }
+
     #[tokio::test]
     async fn batch_sink_acking_unordered() {
         let ack_counter = Counter::default();
@@ -2,12 +2,9 @@ use std::task::{Context, Poll};
 
 use futures::{future::BoxFuture, TryFutureExt};
 use http::Uri;
-use hyper::client::HttpConnector;
-use hyper_openssl::HttpsConnector;
-use hyper_proxy::ProxyConnector;
 use prost::Message;
 use proto_event::EventWrapper;
-use tonic::{body::BoxBody, IntoRequest};
+use tonic::IntoRequest;
 use vector_core::{
     event::proto as proto_event, internal_event::EventsSent, stream::DriverResponse,
 };
@@ -16,7 +13,13 @@ use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
     internal_events::EndpointBytesSent,
     proto::vector as proto_vector,
-    sinks::{util::uri, vector::v2::VectorSinkError},
+    sinks::{
+        util::{
+            grpc::{GrpcHttpClient, HyperSvc},
+            uri,
+        },
+        vector::v2::VectorSinkError,
+    },
     Error,
 };
 
@@ -60,16 +63,9 @@ impl Finalizable for VectorRequest {
 }
 
 impl VectorService {
-    pub fn new(
-        hyper_client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
-        uri: Uri,
-        compression: bool,
-    ) -> Self {
+    pub fn new(hyper_client: GrpcHttpClient, uri: Uri, compression: bool) -> Self {
         let (protocol, endpoint) = uri::protocol_endpoint(uri.clone());
-        let mut proto_client = proto_vector::Client::new(HyperSvc {
-            uri,
-            client: hyper_client,
-        });
+        let mut proto_client = proto_vector::Client::new(HyperSvc::new(uri, hyper_client));
 
         if compression {
             proto_client = proto_client.send_gzip();
@@ -127,32 +123,3 @@ impl tower::Service<VectorRequest> for VectorService {
         Box::pin(future)
     }
 }
-
-#[derive(Clone, Debug)]
-pub struct HyperSvc {
-    uri: Uri,
-    client: hyper::Client<ProxyConnector<HttpsConnector<HttpConnector>>, BoxBody>,
-}
-
-impl tower::Service<hyper::Request<BoxBody>> for HyperSvc {
-    type Response = hyper::Response<hyper::Body>;
-    type Error = hyper::Error;
-    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
-
-    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        Poll::Ready(Ok(()))
-    }
-
-    fn call(&mut self, mut req: hyper::Request<BoxBody>) -> Self::Future {
-        let uri = Uri::builder()
-            .scheme(self.uri.scheme().unwrap().clone())
-            .authority(self.uri.authority().unwrap().clone())
-            .path_and_query(req.uri().path_and_query().unwrap().clone())
-            .build()
-            .unwrap();
-
-        *req.uri_mut() = uri;
-
-        Box::pin(self.client.request(req))
-    }
-}
@@ -103,7 +103,7 @@ impl SourceConfig for AwsKinesisFirehoseConfig {
             self.decoding.clone(),
             LogNamespace::Legacy,
         )
-        .build();
+        .build()?;
         let acknowledgements = cx.do_acknowledgements(&self.acknowledgements);
 
         let svc = filters::firehose(
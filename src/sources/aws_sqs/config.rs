@@ -97,7 +97,7 @@ impl SourceConfig for AwsSqsConfig {
             self.decoding.clone(),
             LogNamespace::Legacy,
         )
-        .build();
+        .build()?;
         let acknowledgements = cx.do_acknowledgements(&self.acknowledgements);
 
         Ok(Box::pin(
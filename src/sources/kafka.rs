@@ -213,7 +213,7 @@ impl SourceConfig for KafkaSourceConfig {
             self.decoding.clone(),
             LogNamespace::Legacy,
         )
-        .build();
+        .build()?;
         let acknowledgements = cx.do_acknowledgements(&self.acknowledgements);
 
         Ok(Box::pin(kafka_source(
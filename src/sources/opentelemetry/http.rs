@@ -0,0 +1,161 @@
+use std::net::SocketAddr;
+
+use bytes::Bytes;
+use futures::FutureExt;
+use prost::Message;
+use tonic::{Code, Status};
+use tracing::Span;
+use vector_core::event::Event;
+use warp::{
+    filters::BoxedFilter, http::StatusCode, reject::Rejection, reply::Response, Filter, Reply,
+};
+
+use super::{log::Service, LOGS, METRICS, TRACES};
+use crate::{
+    opentelemetry::{
+        LogService::{ExportLogsServiceRequest, ExportLogsServiceResponse},
+        MetricsService::{ExportMetricsServiceRequest, ExportMetricsServiceResponse},
+        TraceService::{ExportTraceServiceRequest, ExportTraceServiceResponse},
+    },
+    shutdown::ShutdownSignal,
+    sources::util::{decode, ErrorMessage},
+    tls::MaybeTlsSettings,
+};
+
+pub(super) async fn run_http_server(
+    address: SocketAddr,
+    tls_settings: MaybeTlsSettings,
+    service: Service,
+    shutdown: ShutdownSignal,
+) -> crate::Result<()> {
+    let listener = tls_settings.bind(&address).await?;
+    let span = Span::current();
+    let routes = build_warp_filter(service)
+        .with(warp::trace(move |_info| span.clone()))
+        .recover(|r: Rejection| async move {
+            if let Some(e_msg) = r.find::<ErrorMessage>() {
+                let json = warp::reply::json(e_msg);
+                Ok(warp::reply::with_status(json, e_msg.status_code()))
+            } else {
+                // other internal error - will return 500 internal server error
+                Err(r)
+            }
+        });
+
+    info!(message = "Building HTTP server.", address = %address);
+
+    warp::serve(routes)
+        .serve_incoming_with_graceful_shutdown(listener.accept_stream(), shutdown.map(|_| ()))
+        .await;
+
+    Ok(())
+}
+
+fn build_warp_filter(service: Service) -> BoxedFilter<(Response,)> {
+    let logs = build_export_filter(
+        "logs",
+        LOGS,
+        service.clone(),
+        |request: ExportLogsServiceRequest| {
+            request
+                .resource_logs
+                .into_iter()
+                .flat_map(|v| v.into_iter())
+                .collect()
+        },
+        || ExportLogsServiceResponse {},
+    );
+    let metrics = build_export_filter(
+        "metrics",
+        METRICS,
+        service.clone(),
+        |request: ExportMetricsServiceRequest| {
+            request
+                .resource_metrics
+                .into_iter()
+                .flat_map(|v| v.into_iter())
+                .collect()
+        },
+        || ExportMetricsServiceResponse {
+            partial_success: None,
+        },
+    );
+    let traces = build_export_filter(
+        "traces",
+        TRACES,
+        service,
+        |request: ExportTraceServiceRequest| {
+            request
+                .resource_spans
+                .into_iter()
+                .flat_map(|v| v.into_iter())
+                .collect()
+        },
+        || ExportTraceServiceResponse {
+            partial_success: None,
+        },
+    );
+
+    logs.or(metrics).unify().or(traces).unify().boxed()
+}
+
+/// Builds the filter for a single OTLP/HTTP endpoint, which accepts a binary protobuf
+/// encoded export request at `/v1/<path>` and forwards its events to the given output.
+fn build_export_filter<Req, Resp>(
+    path: &'static str,
+    output: &'static str,
+    service: Service,
+    into_events: fn(Req) -> Vec<Event>,
+    response: fn() -> Resp,
+) -> BoxedFilter<(Response,)>
+where
+    Req: Message + Default + 'static,
+    Resp: Message + 'static,
+{
+    warp::post()
+        .and(warp::path("v1"))
+        .and(warp::path(path))
+        .and(warp::path::end())
+        .and(warp::header::optional::<String>("content-encoding"))
+        .and(warp::body::bytes())
+        .and_then(move |encoding_header: Option<String>, body: Bytes| {
+            let service = service.clone();
+            async move {
+                let events = decode(&encoding_header, body)
+                    .and_then(|body| {
+                        Req::decode(body).map_err(|error| {
+                            ErrorMessage::new(
+                                StatusCode::BAD_REQUEST,
+                                format!("Could not decode request: {}", error),
+                            )
+                        })
+                    })
+                    .map(into_events)
+                    .map_err(warp::reject::custom)?;
+
+                service
+                    .handle_events(output, events)
+                    .await
+                    .map_err(|status| warp::reject::custom(status_into_error(status)))?;
+
+                Ok::<_, Rejection>(
+                    warp::reply::with_header(
+                        response().encode_to_vec(),
+                        "content-type",
+                        "application/x-protobuf",
+                    )
+                    .into_response(),
+                )
+            }
+        })
+        .boxed()
+}
+
+fn status_into_error(status: Status) -> ErrorMessage {
+    let code = match status.code() {
+        Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        Code::DataLoss => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    ErrorMessage::new(code, status.message().to_string())
+}
@@ -21,19 +21,14 @@ pub(crate) struct Service {
     pub(crate) acknowledgements: bool,
 }
 
-#[tonic::async_trait]
-impl LogsService for Service {
-    async fn export(
+impl Service {
+    /// Forwards the given events to the named output, waiting on their delivery if
+    /// acknowledgements are enabled.
+    pub(super) async fn handle_events(
         &self,
-        request: Request<ExportLogsServiceRequest>,
-    ) -> Result<Response<ExportLogsServiceResponse>, Status> {
-        let mut events: Vec<Event> = request
-            .into_inner()
-            .resource_logs
-            .into_iter()
-            .flat_map(|v| v.into_iter())
-            .collect();
-
+        output: &str,
+        mut events: Vec<Event>,
+    ) -> Result<(), Status> {
         let count = events.len();
         let byte_size = events.size_of();
 
@@ -43,14 +38,31 @@ impl LogsService for Service {
 
         self.pipeline
             .clone()
-            .send_batch_named(LOGS, events)
+            .send_batch_named(output, events)
             .map_err(|error| {
                 let message = error.to_string();
                 emit!(StreamClosedError { error, count });
                 Status::unavailable(message)
             })
             .and_then(|_| handle_batch_status(receiver))
-            .await?;
+            .await
+    }
+}
+
+#[tonic::async_trait]
+impl LogsService for Service {
+    async fn export(
+        &self,
+        request: Request<ExportLogsServiceRequest>,
+    ) -> Result<Response<ExportLogsServiceResponse>, Status> {
+        let events: Vec<Event> = request
+            .into_inner()
+            .resource_logs
+            .into_iter()
+            .flat_map(|v| v.into_iter())
+            .collect();
+
+        self.handle_events(LOGS, events).await?;
         Ok(Response::new(ExportLogsServiceResponse {}))
     }
 }
@@ -0,0 +1,28 @@
+use tonic::{Request, Response, Status};
+use vector_core::event::Event;
+
+use super::{log::Service, METRICS};
+use crate::opentelemetry::MetricsService::{
+    metrics_service_server::MetricsService, ExportMetricsServiceRequest,
+    ExportMetricsServiceResponse,
+};
+
+#[tonic::async_trait]
+impl MetricsService for Service {
+    async fn export(
+        &self,
+        request: Request<ExportMetricsServiceRequest>,
+    ) -> Result<Response<ExportMetricsServiceResponse>, Status> {
+        let events: Vec<Event> = request
+            .into_inner()
+            .resource_metrics
+            .into_iter()
+            .flat_map(|v| v.into_iter())
+            .collect();
+
+        self.handle_events(METRICS, events).await?;
+        Ok(Response::new(ExportMetricsServiceResponse {
+            partial_success: None,
+        }))
+    }
+}
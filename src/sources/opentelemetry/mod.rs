@@ -1,11 +1,14 @@
 #[cfg(test)]
 mod tests;
 
+mod http;
 mod log;
+mod metrics;
+mod trace;
 
 use std::net::SocketAddr;
 
-use futures::TryFutureExt;
+use futures::{future::try_join, FutureExt};
 use vector_config::configurable_component;
 use vector_core::config::LogNamespace;
 
@@ -14,22 +17,28 @@ use crate::{
         AcknowledgementsConfig, DataType, GenerateConfig, Output, Resource, SourceConfig,
         SourceContext, SourceDescription,
     },
-    opentelemetry::LogService::logs_service_server::LogsServiceServer,
+    opentelemetry::{
+        LogService::logs_service_server::LogsServiceServer,
+        MetricsService::metrics_service_server::MetricsServiceServer,
+        TraceService::trace_service_server::TraceServiceServer,
+    },
     serde::bool_or_struct,
-    sources::{util::grpc::run_grpc_server, Source},
+    sources::{util::grpc::run_grpc_server_with_routes, Source},
     tls::{MaybeTlsSettings, TlsEnableableConfig},
 };
 
-use self::log::Service;
+use self::{http::run_http_server, log::Service};
 
 pub const LOGS: &str = "logs";
+pub const METRICS: &str = "metrics";
+pub const TRACES: &str = "traces";
 
 /// Configuration for the `opentelemetry` source.
 #[configurable_component(source)]
 #[derive(Clone, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct OpentelemetryConfig {
-    /// The address to listen for connections on.
+    /// The address to listen for gRPC connections on.
     ///
     /// It _must_ include a port.
     address: SocketAddr,
@@ -38,16 +47,39 @@ pub struct OpentelemetryConfig {
     #[serde(default)]
     tls: Option<TlsEnableableConfig>,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    http: Option<HttpConfig>,
+
     #[configurable(derived)]
     #[serde(default, deserialize_with = "bool_or_struct")]
     acknowledgements: AcknowledgementsConfig,
 }
 
+/// Configuration for the OTLP/HTTP endpoint.
+///
+/// When set, logs, metrics, and traces are also accepted as binary protobuf payloads on
+/// `/v1/logs`, `/v1/metrics`, and `/v1/traces`.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct HttpConfig {
+    /// The address to listen for HTTP connections on.
+    ///
+    /// It _must_ include a port.
+    address: SocketAddr,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    tls: Option<TlsEnableableConfig>,
+}
+
 impl GenerateConfig for OpentelemetryConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
             address: "0.0.0.0:4317".parse().unwrap(),
             tls: Default::default(),
+            http: Default::default(),
             acknowledgements: Default::default(),
         })
         .unwrap()
@@ -62,23 +94,47 @@ inventory::submit! {
 #[typetag::serde(name = "opentelemetry")]
 impl SourceConfig for OpentelemetryConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<Source> {
-        let tls_settings = MaybeTlsSettings::from_config(&self.tls, true)?;
         let acknowledgements = cx.do_acknowledgements(&self.acknowledgements);
-        let service = LogsServiceServer::new(Service {
+        let service = Service {
             pipeline: cx.out,
             acknowledgements,
-        })
-        .accept_gzip();
-        let source =
-            run_grpc_server(self.address, tls_settings, service, cx.shutdown).map_err(|error| {
+        };
+
+        let grpc_tls_settings = MaybeTlsSettings::from_config(&self.tls, true)?;
+        let grpc_service = service.clone();
+        let grpc_source = run_grpc_server_with_routes(
+            self.address,
+            grpc_tls_settings,
+            |server| {
+                server
+                    .add_service(LogsServiceServer::new(grpc_service.clone()).accept_gzip())
+                    .add_service(MetricsServiceServer::new(grpc_service.clone()).accept_gzip())
+                    .add_service(TraceServiceServer::new(grpc_service).accept_gzip())
+            },
+            cx.shutdown.clone(),
+        );
+
+        let http_source = match &self.http {
+            Some(http) => {
+                let http_tls_settings = MaybeTlsSettings::from_config(&http.tls, true)?;
+                run_http_server(http.address, http_tls_settings, service, cx.shutdown).boxed()
+            }
+            None => futures::future::ok(()).boxed(),
+        };
+
+        Ok(Box::pin(try_join(grpc_source, http_source).map(|result| {
+            result.map(|_| ()).map_err(|error| {
                 error!(message = "Source future failed.", %error);
-            });
-
-        Ok(Box::pin(source))
+            })
+        })))
     }
 
     fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<Output> {
-        vec![Output::default(DataType::Log).with_port(LOGS)]
+        vec![
+            Output::default(DataType::Log).with_port(LOGS),
+            Output::default(DataType::Metric).with_port(METRICS),
+            Output::default(DataType::Trace).with_port(TRACES),
+        ]
     }
 
     fn source_type(&self) -> &'static str {
@@ -86,7 +142,11 @@ impl SourceConfig for OpentelemetryConfig {
     }
 
     fn resources(&self) -> Vec<Resource> {
-        vec![Resource::tcp(self.address)]
+        let mut resources = vec![Resource::tcp(self.address)];
+        if let Some(http) = &self.http {
+            resources.push(Resource::tcp(http.address));
+        }
+        resources
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -1,13 +1,26 @@
 use crate::{
     config::{SourceConfig, SourceContext},
-    event::{into_event_stream, Event, EventStatus, LogEvent, Value},
+    event::{
+        into_event_stream,
+        metric::{Bucket, Metric, MetricKind, MetricValue},
+        Event, EventStatus, LogEvent, TraceEvent, Value,
+    },
     opentelemetry::{
         Common::{any_value, AnyValue, KeyValue},
         LogService::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
         Logs::{LogRecord, ResourceLogs, ScopeLogs},
+        Metrics::{
+            metric::Data, number_data_point, AggregationTemporality, Histogram, HistogramDataPoint,
+            Metric as OtelMetric, NumberDataPoint, ResourceMetrics, ScopeMetrics, Sum,
+        },
+        MetricsService::{
+            metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest,
+        },
         Resource as OtelResource,
+        Trace::{span::Event as SpanEvent, ResourceSpans, ScopeSpans, Span, Status},
+        TraceService::{trace_service_client::TraceServiceClient, ExportTraceServiceRequest},
     },
-    sources::opentelemetry::{OpentelemetryConfig, LOGS},
+    sources::opentelemetry::{OpentelemetryConfig, LOGS, METRICS, TRACES},
     test_util::{
         self,
         components::{assert_source_compliance, SOURCE_TAGS},
@@ -17,6 +30,7 @@ use crate::{
 use chrono::{TimeZone, Utc};
 use futures::Stream;
 use futures_util::StreamExt;
+use prost::Message;
 use std::collections::BTreeMap;
 use tonic::Request;
 
@@ -31,7 +45,7 @@ async fn receive_message() {
         let addr = test_util::next_addr();
         let config = format!(r#"address = "{}""#, addr);
         let source: OpentelemetryConfig = toml::from_str(&config).unwrap();
-        let (sender, logs_output, _) = new_source(EventStatus::Delivered);
+        let (sender, logs_output, _, _) = new_source(EventStatus::Delivered);
         let server = source
             .build(SourceContext::new_test(sender, None))
             .await
@@ -111,18 +125,321 @@ async fn receive_message() {
     .await;
 }
 
+#[tokio::test]
+async fn receive_metrics() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let addr = test_util::next_addr();
+        let config = format!(r#"address = "{}""#, addr);
+        let source: OpentelemetryConfig = toml::from_str(&config).unwrap();
+        let (sender, _, metrics_output, _) = new_source(EventStatus::Delivered);
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for_tcp(addr).await;
+
+        let mut client = MetricsServiceClient::connect(format!("http://{}", addr))
+            .await
+            .unwrap();
+        let req = Request::new(ExportMetricsServiceRequest {
+            resource_metrics: vec![ResourceMetrics {
+                resource: Some(OtelResource {
+                    attributes: vec![string_kv("host", "localhost")],
+                    dropped_attributes_count: 0,
+                }),
+                scope_metrics: vec![ScopeMetrics {
+                    scope: None,
+                    metrics: vec![
+                        OtelMetric {
+                            name: "requests".into(),
+                            description: String::new(),
+                            unit: String::new(),
+                            data: Some(Data::Sum(Sum {
+                                data_points: vec![NumberDataPoint {
+                                    attributes: vec![string_kv("code", "200")],
+                                    start_time_unix_nano: 0,
+                                    time_unix_nano: 1,
+                                    exemplars: vec![],
+                                    flags: 0,
+                                    value: Some(number_data_point::Value::AsInt(3)),
+                                }],
+                                aggregation_temporality: AggregationTemporality::Delta as i32,
+                                is_monotonic: true,
+                            })),
+                        },
+                        OtelMetric {
+                            name: "latency".into(),
+                            description: String::new(),
+                            unit: String::new(),
+                            data: Some(Data::Histogram(Histogram {
+                                data_points: vec![HistogramDataPoint {
+                                    attributes: vec![],
+                                    start_time_unix_nano: 0,
+                                    time_unix_nano: 2,
+                                    count: 3,
+                                    sum: Some(4.5),
+                                    bucket_counts: vec![1, 2],
+                                    explicit_bounds: vec![1.0],
+                                    exemplars: vec![],
+                                    flags: 0,
+                                    min: None,
+                                    max: None,
+                                }],
+                                aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                            })),
+                        },
+                    ],
+                    schema_url: "v1".into(),
+                }],
+                schema_url: "v1".into(),
+            }],
+        });
+        let _ = client.export(req).await;
+        let output = test_util::collect_ready(metrics_output).await;
+        assert_eq!(output.len(), 2);
+
+        let expected_counter = Metric::new(
+            "requests",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 3.0 },
+        )
+        .with_tags(Some(
+            vec![
+                ("host".to_string(), "localhost".to_string()),
+                ("code".to_string(), "200".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+        ))
+        .with_timestamp(Some(Utc.timestamp_nanos(1)));
+        assert_eq!(output[0].as_metric(), &expected_counter);
+
+        let expected_histogram = Metric::new(
+            "latency",
+            MetricKind::Absolute,
+            MetricValue::AggregatedHistogram {
+                buckets: vec![
+                    Bucket {
+                        upper_limit: 1.0,
+                        count: 1,
+                    },
+                    Bucket {
+                        upper_limit: f64::INFINITY,
+                        count: 2,
+                    },
+                ],
+                count: 3,
+                sum: 4.5,
+            },
+        )
+        .with_tags(Some(
+            vec![("host".to_string(), "localhost".to_string())]
+                .into_iter()
+                .collect(),
+        ))
+        .with_timestamp(Some(Utc.timestamp_nanos(2)));
+        assert_eq!(output[1].as_metric(), &expected_histogram);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn receive_traces() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let addr = test_util::next_addr();
+        let config = format!(r#"address = "{}""#, addr);
+        let source: OpentelemetryConfig = toml::from_str(&config).unwrap();
+        let (sender, _, _, traces_output) = new_source(EventStatus::Delivered);
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for_tcp(addr).await;
+
+        let mut client = TraceServiceClient::connect(format!("http://{}", addr))
+            .await
+            .unwrap();
+        let req = Request::new(ExportTraceServiceRequest {
+            resource_spans: vec![ResourceSpans {
+                resource: Some(OtelResource {
+                    attributes: vec![string_kv("res_key", "res_val")],
+                    dropped_attributes_count: 0,
+                }),
+                scope_spans: vec![ScopeSpans {
+                    scope: None,
+                    spans: vec![Span {
+                        trace_id: str_into_hex_bytes("4ac52aadf321c2e531db005df08792f5"),
+                        span_id: str_into_hex_bytes("0b9e4bda2a55530d"),
+                        trace_state: String::new(),
+                        parent_span_id: str_into_hex_bytes("b7ad6b7169203331"),
+                        name: "GET /".into(),
+                        kind: 2,
+                        start_time_unix_nano: 1,
+                        end_time_unix_nano: 2,
+                        attributes: vec![string_kv("attr_key", "attr_val")],
+                        dropped_attributes_count: 0,
+                        events: vec![SpanEvent {
+                            time_unix_nano: 3,
+                            name: "event".into(),
+                            attributes: vec![],
+                            dropped_attributes_count: 0,
+                        }],
+                        dropped_events_count: 0,
+                        links: vec![],
+                        dropped_links_count: 0,
+                        status: Some(Status {
+                            message: "ok".into(),
+                            code: 1,
+                        }),
+                    }],
+                    schema_url: "v1".into(),
+                }],
+                schema_url: "v1".into(),
+            }],
+        });
+        let _ = client.export(req).await;
+        let mut output = test_util::collect_ready(traces_output).await;
+        assert_eq!(output.len(), 1);
+        let mut actual_trace = output.pop().unwrap().into_trace();
+        assert!(actual_trace.as_mut().remove("ingest_timestamp").is_some());
+
+        let expect_trace = TraceEvent::from(vec_into_btmap(vec![
+            ("trace_id", "4ac52aadf321c2e531db005df08792f5".into()),
+            ("span_id", "0b9e4bda2a55530d".into()),
+            ("parent_span_id", "b7ad6b7169203331".into()),
+            ("name", "GET /".into()),
+            ("kind", 2.into()),
+            ("start_time", Utc.timestamp_nanos(1).into()),
+            ("end_time", Utc.timestamp_nanos(2).into()),
+            (
+                "attributes",
+                Value::Object(vec_into_btmap(vec![("attr_key", "attr_val".into())])),
+            ),
+            (
+                "resources",
+                Value::Object(vec_into_btmap(vec![("res_key", "res_val".into())])),
+            ),
+            (
+                "events",
+                Value::Array(vec![Value::Object(vec_into_btmap(vec![
+                    ("name", "event".into()),
+                    ("time", Utc.timestamp_nanos(3).into()),
+                    ("attributes", Value::Object(BTreeMap::new())),
+                    ("dropped_attributes_count", 0.into()),
+                ]))]),
+            ),
+            (
+                "status",
+                Value::Object(vec_into_btmap(vec![
+                    ("message", "ok".into()),
+                    ("code", 1.into()),
+                ])),
+            ),
+            ("dropped_attributes_count", 0.into()),
+            ("dropped_events_count", 0.into()),
+            ("dropped_links_count", 0.into()),
+        ]));
+        assert_eq!(actual_trace, expect_trace);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn receive_http_logs() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let grpc_addr = test_util::next_addr();
+        let http_addr = test_util::next_addr();
+        let config = format!(
+            r#"
+            address = "{}"
+
+            [http]
+            address = "{}"
+            "#,
+            grpc_addr, http_addr
+        );
+        let source: OpentelemetryConfig = toml::from_str(&config).unwrap();
+        let (sender, logs_output, _, _) = new_source(EventStatus::Delivered);
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for_tcp(http_addr).await;
+
+        let body = ExportLogsServiceRequest {
+            resource_logs: vec![ResourceLogs {
+                resource: None,
+                scope_logs: vec![ScopeLogs {
+                    scope: None,
+                    log_records: vec![LogRecord {
+                        time_unix_nano: 1,
+                        observed_time_unix_nano: 2,
+                        severity_number: 0,
+                        severity_text: String::new(),
+                        body: Some(AnyValue {
+                            value: Some(any_value::Value::StringValue("log body".into())),
+                        }),
+                        attributes: vec![],
+                        dropped_attributes_count: 0,
+                        flags: 0,
+                        trace_id: vec![],
+                        span_id: vec![],
+                    }],
+                    schema_url: "v1".into(),
+                }],
+                schema_url: "v1".into(),
+            }],
+        }
+        .encode_to_vec();
+
+        let response = reqwest::Client::new()
+            .post(format!("http://{}/v1/logs", http_addr))
+            .header("content-type", "application/x-protobuf")
+            .body(body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let mut output = test_util::collect_ready(logs_output).await;
+        assert_eq!(output.len(), 1);
+        let actual_event = output.pop().unwrap();
+        assert_eq!(actual_event.as_log()["message"], "log body".into());
+    })
+    .await;
+}
+
 fn new_source(
     status: EventStatus,
 ) -> (
     SourceSender,
     impl Stream<Item = Event>,
     impl Stream<Item = Event>,
+    impl Stream<Item = Event>,
 ) {
-    let (mut sender, recv) = SourceSender::new_test_finalize(status);
+    let (mut sender, _) = SourceSender::new_test_finalize(status);
     let logs_output = sender
         .add_outputs(status, LOGS.to_string())
         .flat_map(into_event_stream);
-    (sender, logs_output, recv)
+    let metrics_output = sender
+        .add_outputs(status, METRICS.to_string())
+        .flat_map(into_event_stream);
+    let traces_output = sender
+        .add_outputs(status, TRACES.to_string())
+        .flat_map(into_event_stream);
+    (sender, logs_output, metrics_output, traces_output)
+}
+
+fn string_kv(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.into(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.into())),
+        }),
+    }
 }
 
 fn str_into_hex_bytes(s: &str) -> Vec<u8> {
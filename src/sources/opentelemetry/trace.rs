@@ -0,0 +1,27 @@
+use tonic::{Request, Response, Status};
+use vector_core::event::Event;
+
+use super::{log::Service, TRACES};
+use crate::opentelemetry::TraceService::{
+    trace_service_server::TraceService, ExportTraceServiceRequest, ExportTraceServiceResponse,
+};
+
+#[tonic::async_trait]
+impl TraceService for Service {
+    async fn export(
+        &self,
+        request: Request<ExportTraceServiceRequest>,
+    ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+        let events: Vec<Event> = request
+            .into_inner()
+            .resource_spans
+            .into_iter()
+            .flat_map(|v| v.into_iter())
+            .collect();
+
+        self.handle_events(TRACES, events).await?;
+        Ok(Response::new(ExportTraceServiceResponse {
+            partial_success: None,
+        }))
+    }
+}
@@ -160,7 +160,7 @@ impl SourceConfig for RedisSourceConfig {
             self.decoding.clone(),
             LogNamespace::Legacy,
         )
-        .build();
+        .build()?;
 
         match self.data_type {
             DataTypeConfig::List => {
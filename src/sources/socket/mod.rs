@@ -127,7 +127,8 @@ impl SourceConfig for SocketConfig {
                     }
                 };
 
-                let decoder = DecodingConfig::new(framing, decoding, LogNamespace::Legacy).build();
+                let decoder =
+                    DecodingConfig::new(framing, decoding, LogNamespace::Legacy).build()?;
 
                 let tcp = tcp::RawTcpSource::new(config.clone(), decoder);
                 let tls_config = config.tls().as_ref().map(|tls| tls.tls_config.clone());
@@ -158,7 +159,7 @@ impl SourceConfig for SocketConfig {
                     config.decoding().clone(),
                     LogNamespace::Legacy,
                 )
-                .build();
+                .build()?;
                 Ok(udp::udp(config, host_key, decoder, cx.shutdown, cx.out))
             }
             #[cfg(unix)]
@@ -171,7 +172,7 @@ impl SourceConfig for SocketConfig {
                     config.decoding.clone(),
                     LogNamespace::Legacy,
                 )
-                .build();
+                .build()?;
                 unix::unix_datagram(
                     config.path,
                     config.socket_file_mode,
@@ -207,7 +208,8 @@ impl SourceConfig for SocketConfig {
                     }
                 };
 
-                let decoder = DecodingConfig::new(framing, decoding, LogNamespace::Legacy).build();
+                let decoder =
+                    DecodingConfig::new(framing, decoding, LogNamespace::Legacy).build()?;
 
                 let host_key = config
                     .host_key
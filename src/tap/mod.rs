@@ -16,6 +16,9 @@ pub struct Opts {
     #[clap(short, long)]
     url: Option<Url>,
 
+    #[clap(flatten)]
+    auth: crate::cli::ApiAuthOpts,
+
     /// Maximum number of events to sample each interval
     #[clap(default_value = "100", short = 'l', long)]
     limit: u32,
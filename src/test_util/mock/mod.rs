@@ -6,7 +6,7 @@ use vector_core::event::EventArray;
 use crate::SourceSender;
 
 use self::{
-    sinks::{BasicSinkConfig, ErrorSinkConfig, PanicSinkConfig},
+    sinks::{BasicSinkConfig, ErrorSinkConfig, PanicSinkConfig, RejectingSinkConfig},
     sources::{BasicSourceConfig, ErrorSourceConfig, PanicSourceConfig},
     transforms::BasicTransformConfig,
 };
@@ -76,3 +76,7 @@ pub fn error_sink() -> ErrorSinkConfig {
 pub fn panic_sink() -> PanicSinkConfig {
     PanicSinkConfig::default()
 }
+
+pub fn rejecting_sink(reason: &str) -> RejectingSinkConfig {
+    RejectingSinkConfig::new(reason)
+}
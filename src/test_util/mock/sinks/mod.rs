@@ -6,3 +6,6 @@ pub use self::error::ErrorSinkConfig;
 
 mod panic;
 pub use self::panic::PanicSinkConfig;
+
+mod rejecting;
+pub use self::rejecting::RejectingSinkConfig;
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use futures_util::{future::ok, stream::BoxStream, FutureExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use vector_core::{
+    config::{AcknowledgementsConfig, Input},
+    event::{Event, EventStatus},
+    sink::{StreamSink, VectorSink},
+};
+
+use crate::{
+    config::{SinkConfig, SinkContext, SinkDescription},
+    sinks::Healthcheck,
+};
+
+/// A test sink that rejects every event it receives, giving `reason` as the cause.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RejectingSinkConfig {
+    reason: String,
+}
+
+impl_generate_config_from_default!(RejectingSinkConfig);
+
+inventory::submit! {
+    SinkDescription::new::<RejectingSinkConfig>("rejecting_sink")
+}
+
+impl RejectingSinkConfig {
+    pub fn new(reason: &str) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+#[async_trait]
+#[typetag::serde(name = "rejecting_sink")]
+impl SinkConfig for RejectingSinkConfig {
+    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let sink = RejectingSink {
+            reason: self.reason.clone(),
+        };
+        Ok((VectorSink::from_event_streamsink(sink), ok(()).boxed()))
+    }
+
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn sink_type(&self) -> &'static str {
+        "rejecting_sink"
+    }
+
+    fn acknowledgements(&self) -> Option<&AcknowledgementsConfig> {
+        None
+    }
+}
+
+struct RejectingSink {
+    reason: String,
+}
+
+#[async_trait]
+impl StreamSink<Event> for RejectingSink {
+    async fn run(self: Box<Self>, mut input: BoxStream<'_, Event>) -> Result<(), ()> {
+        while let Some(event) = input.next().await {
+            event
+                .metadata()
+                .finalizers()
+                .update_status_with_reason(EventStatus::Rejected, &self.reason);
+        }
+
+        Ok(())
+    }
+}
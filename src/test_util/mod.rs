@@ -404,6 +404,17 @@ pub fn lines_from_gzip_file<P: AsRef<Path>>(path: P) -> Vec<String> {
     output.lines().map(|s| s.to_owned()).collect()
 }
 
+pub fn lines_from_zstd_file<P: AsRef<Path>>(path: P) -> Vec<String> {
+    trace!(message = "Reading zstd file.", path = %path.as_ref().display());
+    let file = File::open(path).unwrap();
+    let decoded = zstd::stream::decode_all(file).unwrap();
+    String::from_utf8(decoded)
+        .unwrap()
+        .lines()
+        .map(|s| s.to_owned())
+        .collect()
+}
+
 pub fn runtime() -> runtime::Runtime {
     runtime::Builder::new_multi_thread()
         .enable_all()
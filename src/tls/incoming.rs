@@ -126,7 +126,7 @@ impl MaybeTlsListener {
         })
     }
 
-    #[cfg(feature = "listenfd")]
+    #[cfg(any(feature = "listenfd", feature = "api"))]
     pub(crate) fn local_addr(&self) -> Result<SocketAddr, std::io::Error> {
         self.listener.local_addr()
     }
@@ -43,7 +43,7 @@ pub const TEST_PEM_CLIENT_KEY_PATH: &str =
 
 /// Configures the TLS options for incoming/outgoing connections.
 #[configurable_component]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct TlsEnableableConfig {
     /// Whether or not to require TLS for incoming/outgoing connections.
     ///
@@ -83,7 +83,7 @@ pub struct TlsSourceConfig {
 
 /// Standard TLS options.
 #[configurable_component]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct TlsConfig {
     /// Enables certificate verification.
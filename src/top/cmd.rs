@@ -37,7 +37,9 @@ pub async fn cmd(opts: &super::Opts) -> exitcode::ExitCode {
     });
 
     // Create a new API client for connecting to the local/remote Vector instance.
-    let client = match Client::new_with_healthcheck(url.clone()).await {
+    let auth = opts.auth.auth();
+    let tls = opts.auth.tls();
+    let client = match Client::new_with_healthcheck(url.clone(), auth.clone(), &tls).await {
         Some(client) => client,
         None => return exitcode::UNAVAILABLE,
     };
@@ -73,13 +75,14 @@ pub async fn cmd(opts: &super::Opts) -> exitcode::ExitCode {
             };
             let _ = tx.send(EventType::InitializeState(state)).await;
 
-            let subscription_client = match connect_subscription_client(ws_url.clone()).await {
-                Ok(c) => c,
-                Err(_) => {
-                    tokio::time::sleep(Duration::from_millis(RECONNECT_DELAY)).await;
-                    continue;
-                }
-            };
+            let subscription_client =
+                match connect_subscription_client(ws_url.clone(), auth.as_ref(), &tls).await {
+                    Ok(c) => c,
+                    Err(_) => {
+                        tokio::time::sleep(Duration::from_millis(RECONNECT_DELAY)).await;
+                        continue;
+                    }
+                };
 
             // Subscribe to updated metrics
             let finished =
@@ -19,6 +19,9 @@ pub struct Opts {
     #[clap(short, long)]
     url: Option<Url>,
 
+    #[clap(flatten)]
+    auth: crate::cli::ApiAuthOpts,
+
     /// Humanize metrics, using numeric suffixes - e.g. 1,100 = 1.10 k, 1,000,000 = 1.00 M
     #[clap(short = 'H', long)]
     human_metrics: bool,
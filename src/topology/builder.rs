@@ -28,6 +28,7 @@ use vector_core::{
 };
 
 use super::{
+    dead_letter::dead_letter,
     fanout::{self, Fanout},
     schema,
     task::{Task, TaskOutput},
@@ -36,7 +37,7 @@ use super::{
 use crate::{
     config::{
         ComponentKey, DataType, Input, Output, OutputId, ProxyConfig, SinkContext, SourceContext,
-        TransformContext, TransformOuter,
+        TransformContext, TransformOuter, DEAD_LETTER_OUTPUT,
     },
     event::{EventArray, EventContainer},
     internal_events::EventsReceived,
@@ -331,6 +332,7 @@ pub async fn build_pieces(
 
         let typetag = sink.inner.sink_type();
         let input_type = sink.inner.input().data_type();
+        let enable_dead_letter = sink.dead_letter;
 
         if config.schema.enabled {
             // At this point, we've validated that all transforms are valid, including any
@@ -384,6 +386,20 @@ pub async fn build_pieces(
             Ok(built) => built,
         };
 
+        // Events rejected by the sink are emitted on its dead-letter output, if it's enabled.
+        let (dead_letter_tracker, dead_letter_pump) = if enable_dead_letter {
+            let (fanout, control) = Fanout::new();
+            outputs.insert(
+                key.clone(),
+                HashMap::from([(Some(DEAD_LETTER_OUTPUT.to_string()), control)]),
+            );
+            let (tracker, pump) = dead_letter(key.clone(), fanout);
+            (Some(tracker), Some(pump))
+        } else {
+            (None, None)
+        };
+        let dead_letter_task_name = format!(">> {} ({}, dead letter) >>", typetag, key.id());
+
         let (trigger, tripwire) = Tripwire::new();
 
         let sink = async move {
@@ -401,19 +417,39 @@ pub async fn build_pieces(
 
             let mut rx = wrap(rx);
 
-            sink.run(
-                rx.by_ref()
-                    .filter(|events: &EventArray| ready(filter_events_type(events, input_type)))
-                    .inspect(|events| {
-                        emit!(EventsReceived {
-                            count: events.len(),
-                            byte_size: events.size_of(),
+            let dead_letter_pump =
+                dead_letter_pump.map(|pump| spawn_named(pump, dead_letter_task_name.as_ref()));
+
+            let result = sink
+                .run(
+                    rx.by_ref()
+                        .filter(|events: &EventArray| ready(filter_events_type(events, input_type)))
+                        .inspect(|events| {
+                            emit!(EventsReceived {
+                                count: events.len(),
+                                byte_size: events.size_of(),
+                            })
                         })
-                    })
-                    .take_until_if(tripwire),
-            )
-            .await
-            .map(|_| {
+                        .take_until_if(tripwire)
+                        .then(move |events| {
+                            let tracker = dead_letter_tracker.clone();
+                            async move {
+                                match tracker {
+                                    Some(tracker) => tracker.track(events).await,
+                                    None => events,
+                                }
+                            }
+                        }),
+                )
+                .await;
+
+            // Wait for the events still held by the sink to be finalized, so that the rejected
+            // ones make it to the dead-letter output.
+            if let Some(pump) = dead_letter_pump {
+                pump.await.expect("join error");
+            }
+
+            result.map(|_| {
                 debug!("Finished.");
                 TaskOutput::Sink(rx)
             })
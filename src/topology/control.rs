@@ -0,0 +1,40 @@
+//! Runtime control of a running topology.
+//!
+//! Commands are sent by the API to the application, which owns the
+//! [`RunningTopology`](super::RunningTopology) and applies them between reloads.
+
+use tokio::sync::{mpsc, oneshot};
+
+use crate::config::ComponentKey;
+
+pub type CommandTx = mpsc::UnboundedSender<CommandRequest>;
+pub type CommandRx = mpsc::UnboundedReceiver<CommandRequest>;
+
+/// A command that changes the state of the running topology.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TopologyCommand {
+    /// Stop forwarding the events of a source to its downstream components.
+    PauseSource(ComponentKey),
+    /// Resume forwarding the events of a previously paused source.
+    ResumeSource(ComponentKey),
+    /// Detach a sink from its inputs, letting it flush the events it already holds.
+    DrainSink(ComponentKey),
+    /// Reattach a previously drained sink to its inputs.
+    UndrainSink(ComponentKey),
+    /// Reload the configuration from disk.
+    Reload,
+}
+
+/// A command paired with the channel on which its outcome is reported.
+#[derive(Debug)]
+pub struct CommandRequest {
+    pub command: TopologyCommand,
+    pub reply: oneshot::Sender<Result<(), String>>,
+}
+
+impl CommandRequest {
+    pub fn new(command: TopologyCommand) -> (Self, oneshot::Receiver<Result<(), String>>) {
+        let (reply, rx) = oneshot::channel();
+        (Self { command, reply }, rx)
+    }
+}
@@ -0,0 +1,219 @@
+//! Routing of the events rejected by a sink to its dead-letter output.
+//!
+//! Only sinks with their dead-letter output enabled are tracked. Each event they receive gets its
+//! own batch notifier, and a copy of the event is kept until the sink finalizes it. Events that end
+//! up rejected are annotated with the reason of the rejection and emitted on the `dead_letter`
+//! output, from which any other component can consume them.
+//!
+//! The copies are handed to the pump over a bounded channel, so they never outnumber the events
+//! the sink itself holds by more than the capacity of that channel. A slow consumer of the
+//! dead-letter output holds back the pump, which in turn applies backpressure to the sink, the
+//! same as any other blocking output.
+
+use futures::{stream::FuturesUnordered, Future, StreamExt};
+use tokio::sync::mpsc;
+
+use super::fanout::Fanout;
+use crate::{
+    config::ComponentKey,
+    event::{
+        BatchNotifier, BatchStatus, BatchStatusReceiver, Event, EventArray, EventFinalizer,
+        EventMutRef, Finalizable,
+    },
+};
+
+/// Reason attached to rejected events when the sink didn't provide one.
+const UNKNOWN_REASON: &str = "Events were rejected by the sink.";
+
+/// Number of event arrays handed to the pump that it hasn't started tracking yet, past which the
+/// sink waits for the pump to catch up.
+const TRACKED_ARRAYS_CAPACITY: usize = 16;
+
+type Tracked = Vec<(Event, BatchStatusReceiver)>;
+
+/// Keeps track of the events received by a sink, so the rejected ones can be emitted on its
+/// dead-letter output.
+#[derive(Clone)]
+pub(super) struct DeadLetterTracker {
+    tx: mpsc::Sender<Tracked>,
+}
+
+impl DeadLetterTracker {
+    /// Attaches a batch notifier to each of the given events before they're handed to the sink.
+    ///
+    /// Waits for the pump to have room for the copies of the events first.
+    pub(super) async fn track(&self, mut events: EventArray) -> EventArray {
+        let mut tracked = Vec::with_capacity(events.len());
+        for mut event in events.iter_events_mut() {
+            let mut copy = match &event {
+                EventMutRef::Log(log) => Event::from((*log).clone()),
+                EventMutRef::Metric(metric) => Event::from((*metric).clone()),
+                EventMutRef::Trace(trace) => Event::from((*trace).clone()),
+            };
+            // The copy must not hold back the acknowledgement of the original event.
+            drop(copy.take_finalizers());
+
+            let (batch, receiver) = BatchNotifier::new_with_receiver();
+            event
+                .metadata_mut()
+                .add_finalizer(EventFinalizer::new(batch));
+            tracked.push((copy, receiver));
+        }
+
+        // The pump only goes away once the sink is done with its input.
+        let _ = self.tx.send(tracked).await;
+        events
+    }
+}
+
+/// Creates the tracker for the events received by the sink `key`, along with the pump emitting
+/// the rejected ones on `fanout`.
+///
+/// The pump completes once the tracker is dropped and all tracked events have been finalized.
+pub(super) fn dead_letter(
+    key: ComponentKey,
+    mut fanout: Fanout,
+) -> (DeadLetterTracker, impl Future<Output = ()>) {
+    let (tx, mut rx) = mpsc::channel::<Tracked>(TRACKED_ARRAYS_CAPACITY);
+
+    let pump = async move {
+        let mut pending = FuturesUnordered::new();
+
+        loop {
+            tokio::select! {
+                biased;
+
+                Some((mut event, status, reason)) = pending.next() => {
+                    if status != BatchStatus::Rejected {
+                        continue;
+                    }
+
+                    let metadata = event.metadata_mut().value_mut();
+                    metadata.insert(
+                        "dead_letter.reason",
+                        reason.unwrap_or_else(|| UNKNOWN_REASON.to_string()),
+                    );
+                    metadata.insert("dead_letter.component_id", key.id().to_string());
+
+                    // Holding the pump back here fills up the channel, until the sink waits on it.
+                    fanout.send(EventArray::from(event)).await;
+                }
+                Some(tracked) = rx.recv() => {
+                    pending.extend(tracked.into_iter().map(|(event, mut receiver)| async move {
+                        let status = (&mut receiver).await;
+                        (event, status, receiver.reason())
+                    }));
+                }
+                else => break,
+            }
+        }
+
+        debug!("Dead-letter pump finished.");
+    };
+
+    (DeadLetterTracker { tx }, pump)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroUsize, time::Duration};
+
+    use vector_buffers::{topology::builder::TopologyBuilder, WhenFull};
+
+    use super::*;
+    use crate::event::{EventContainer, EventStatus, LogEvent};
+
+    #[tokio::test]
+    async fn emits_rejected_events() {
+        let (tx, mut rx) = TopologyBuilder::standalone_memory(
+            NonZeroUsize::new(10).expect("capacity must be nonzero"),
+            WhenFull::Block,
+        )
+        .await;
+        let (mut fanout, _control) = Fanout::new();
+        fanout.add(ComponentKey::from("archive"), tx);
+
+        let (tracker, pump) = dead_letter(ComponentKey::from("out"), fanout);
+        let pump = tokio::spawn(pump);
+
+        let events = tracker
+            .track(EventArray::from(vec![
+                LogEvent::from("delivered"),
+                LogEvent::from("rejected"),
+            ]))
+            .await;
+        drop(tracker);
+
+        let mut events = events.into_events();
+        let delivered = events.next().unwrap();
+        let rejected = events.next().unwrap();
+        delivered.metadata().update_status(EventStatus::Delivered);
+        rejected
+            .metadata()
+            .finalizers()
+            .update_status_with_reason(EventStatus::Rejected, "mapping error");
+        drop((delivered, rejected));
+
+        pump.await.unwrap();
+
+        let emitted = rx.next().await.unwrap().into_events().collect::<Vec<_>>();
+        assert_eq!(emitted.len(), 1);
+        let log = emitted[0].as_log();
+        assert_eq!(log["message"], "rejected".into());
+        assert_eq!(
+            log.metadata().value().get("dead_letter.reason"),
+            Some(&"mapping error".into())
+        );
+        assert_eq!(
+            log.metadata().value().get("dead_letter.component_id"),
+            Some(&"out".into())
+        );
+        assert!(log.metadata().finalizers().is_empty());
+        assert!(rx.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn slow_consumer_applies_backpressure() {
+        let (tx, mut rx) = TopologyBuilder::standalone_memory(
+            NonZeroUsize::new(1).expect("capacity must be nonzero"),
+            WhenFull::Block,
+        )
+        .await;
+        let (mut fanout, _control) = Fanout::new();
+        fanout.add(ComponentKey::from("archive"), tx);
+
+        let (tracker, pump) = dead_letter(ComponentKey::from("out"), fanout);
+        let pump = tokio::spawn(pump);
+
+        // Reject everything right away, while nothing consumes the dead-letter output.
+        let mut tracked = 0;
+        loop {
+            let track = tracker.track(EventArray::from(LogEvent::from("rejected")));
+            match tokio::time::timeout(Duration::from_millis(100), track).await {
+                Ok(events) => {
+                    for event in events.into_events() {
+                        event
+                            .metadata()
+                            .finalizers()
+                            .update_status_with_reason(EventStatus::Rejected, "bad");
+                    }
+                    tracked += 1;
+                }
+                Err(_) => break,
+            }
+            assert!(
+                tracked <= 2 * TRACKED_ARRAYS_CAPACITY,
+                "tracker never blocked"
+            );
+        }
+
+        // Consuming the dead-letter output lets the pump drain everything.
+        drop(tracker);
+        let mut emitted = 0;
+        while let Some(events) = rx.next().await {
+            emitted += events.len();
+        }
+        pump.await.unwrap();
+        assert_eq!(emitted, tracked);
+    }
+}
@@ -10,6 +10,8 @@ pub(super) use vector_core::fanout;
 pub mod schema;
 
 pub mod builder;
+pub mod control;
+mod dead_letter;
 mod ready_arrays;
 mod running;
 mod task;
@@ -23,6 +25,7 @@ use std::{
     sync::{Arc, Mutex},
 };
 
+pub use control::{CommandRequest, CommandRx, CommandTx, TopologyCommand};
 use futures::{Future, FutureExt};
 pub(super) use running::RunningTopology;
 use tokio::sync::{mpsc, watch};
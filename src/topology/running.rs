@@ -43,6 +43,20 @@ pub struct RunningTopology {
     abort_tx: mpsc::UnboundedSender<()>,
     watch: (WatchTx, WatchRx),
     pub(crate) running: Arc<AtomicBool>,
+    paused_sources: HashSet<ComponentKey>,
+    drained_sinks: HashSet<ComponentKey>,
+    /// The fanout edges that aren't connected as the configuration describes, because of a paused
+    /// source or a drained sink.
+    controlled_edges: HashMap<(OutputId, ComponentKey), EdgeControl>,
+}
+
+/// How a fanout edge is held back from its downstream component.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum EdgeControl {
+    /// The edge is kept, but events wait until it's resumed.
+    Paused,
+    /// The edge is removed, so events aren't sent to the downstream component at all.
+    Detached,
 }
 
 impl RunningTopology {
@@ -58,6 +72,9 @@ impl RunningTopology {
             abort_tx,
             watch: watch::channel(TapResource::default()),
             running: Arc::new(AtomicBool::new(true)),
+            paused_sources: HashSet::new(),
+            drained_sinks: HashSet::new(),
+            controlled_edges: HashMap::new(),
         }
     }
 
@@ -97,9 +114,11 @@ impl RunningTopology {
     /// poll for when the tasks have completed. Once the returned future is
     /// dropped then everything from this RunningTopology instance is fully
     /// dropped.
-    pub fn stop(self) -> impl Future<Output = ()> {
+    pub fn stop(mut self) -> impl Future<Output = ()> {
         // Update the API's health endpoint to signal shutdown
         self.running.store(false, Ordering::Relaxed);
+        // Paused sources would otherwise never be able to flush their events downstream.
+        self.restore_controlled();
         // Create handy handles collections of all tasks for the subsequent
         // operations.
         let mut wait_handles = Vec::new();
@@ -206,6 +225,10 @@ impl RunningTopology {
     pub async fn reload_config_and_respawn(&mut self, new_config: Config) -> Result<bool, ()> {
         info!("Reloading running topology with new configuration.");
 
+        // The reload logic assumes that every component is connected as described by the current
+        // configuration, so undo any runtime control first.
+        self.restore_controlled();
+
         if self.config.global != new_config.global {
             error!(
                 message =
@@ -277,6 +300,172 @@ impl RunningTopology {
         Err(())
     }
 
+    /// Pauses a source by holding back its events from all of its downstream components.
+    ///
+    /// The source is backpressured until it is resumed, or until the topology is reloaded.
+    pub fn pause_source(&mut self, key: &ComponentKey) -> Result<(), String> {
+        if self.config.source(key).is_none() {
+            return Err(format!("Source \"{}\" does not exist.", key));
+        }
+        if !self.paused_sources.insert(key.clone()) {
+            return Err(format!("Source \"{}\" is already paused.", key));
+        }
+
+        self.update_edges(self.downstream_of(key));
+
+        Ok(())
+    }
+
+    /// Resumes a source previously paused with [`RunningTopology::pause_source`].
+    pub fn resume_source(&mut self, key: &ComponentKey) -> Result<(), String> {
+        if self.config.source(key).is_none() {
+            return Err(format!("Source \"{}\" does not exist.", key));
+        }
+        if !self.paused_sources.remove(key) {
+            return Err(format!("Source \"{}\" is not paused.", key));
+        }
+
+        self.update_edges(self.downstream_of(key));
+
+        Ok(())
+    }
+
+    /// Drains a sink by detaching it from all of its inputs.
+    ///
+    /// The sink keeps running and delivers the events it has already received, but events
+    /// produced upstream from then on are not sent to it. Its inputs keep sending their events to
+    /// their other downstream components as usual. The sink is reattached to its inputs once it's
+    /// undrained, or the topology is reloaded.
+    pub fn drain_sink(&mut self, key: &ComponentKey) -> Result<(), String> {
+        if self.config.sink(key).is_none() {
+            return Err(format!("Sink \"{}\" does not exist.", key));
+        }
+        if !self.drained_sinks.insert(key.clone()) {
+            return Err(format!("Sink \"{}\" is already drained.", key));
+        }
+
+        self.update_edges(self.upstream_of(key));
+
+        Ok(())
+    }
+
+    /// Reattaches a sink previously drained with [`RunningTopology::drain_sink`] to its inputs.
+    pub fn undrain_sink(&mut self, key: &ComponentKey) -> Result<(), String> {
+        if self.config.sink(key).is_none() {
+            return Err(format!("Sink \"{}\" does not exist.", key));
+        }
+        if !self.drained_sinks.remove(key) {
+            return Err(format!("Sink \"{}\" is not drained.", key));
+        }
+
+        self.update_edges(self.upstream_of(key));
+
+        Ok(())
+    }
+
+    /// Resumes all paused sources and reattaches all drained sinks, so that the topology is
+    /// connected as its configuration describes.
+    fn restore_controlled(&mut self) {
+        self.paused_sources.clear();
+        self.drained_sinks.clear();
+
+        let edges = self.controlled_edges.keys().cloned().collect();
+        self.update_edges(edges);
+    }
+
+    /// Brings the given fanout edges in line with the paused sources and drained sinks.
+    ///
+    /// Only the control messages that change the state of an edge are sent, since the fanouts
+    /// don't accept pausing an edge twice, or reattaching an edge that is already attached.
+    fn update_edges(&mut self, edges: Vec<(OutputId, ComponentKey)>) {
+        for (output_id, downstream) in edges {
+            let wanted = if self.drained_sinks.contains(&downstream) {
+                Some(EdgeControl::Detached)
+            } else if self.paused_sources.contains(&output_id.component) {
+                Some(EdgeControl::Paused)
+            } else {
+                None
+            };
+            let edge = (output_id, downstream);
+            let current = self.controlled_edges.get(&edge).copied();
+            if current == wanted {
+                continue;
+            }
+
+            let (output_id, downstream) = &edge;
+            let (output, input) = match (
+                self.outputs.get(output_id),
+                self.inputs.get(downstream).cloned(),
+            ) {
+                (Some(output), Some(input)) => (output, input),
+                _ => continue,
+            };
+
+            debug!(component = %downstream, fanout_id = %output_id, from = ?current, to = ?wanted, "Updating component input in fanout.");
+
+            let mut messages = Vec::new();
+            match current {
+                Some(EdgeControl::Paused) if wanted.is_none() => {
+                    messages.push(ControlMessage::Replace(downstream.clone(), Some(input)));
+                }
+                Some(EdgeControl::Detached) => {
+                    messages.push(ControlMessage::Add(downstream.clone(), input));
+                }
+                _ => {}
+            }
+            match wanted {
+                Some(EdgeControl::Paused) => {
+                    messages.push(ControlMessage::Replace(downstream.clone(), None));
+                }
+                // A paused edge can be removed as it is, without being resumed first.
+                Some(EdgeControl::Detached) => {
+                    messages.push(ControlMessage::Remove(downstream.clone()));
+                }
+                None => {}
+            }
+            for message in messages {
+                let _ = output.send(message);
+            }
+
+            match wanted {
+                Some(control) => self.controlled_edges.insert(edge, control),
+                None => self.controlled_edges.remove(&edge),
+            };
+        }
+    }
+
+    /// Lists the outputs of the given component along with the components consuming them.
+    fn downstream_of(&self, key: &ComponentKey) -> Vec<(OutputId, ComponentKey)> {
+        let transforms = self
+            .config
+            .transforms()
+            .map(|(downstream, transform)| (downstream, &transform.inputs));
+        let sinks = self
+            .config
+            .sinks()
+            .map(|(downstream, sink)| (downstream, &sink.inputs));
+
+        transforms
+            .chain(sinks)
+            .flat_map(|(downstream, inputs)| {
+                inputs
+                    .iter()
+                    .filter(|input| &input.component == key)
+                    .map(move |input| (input.clone(), downstream.clone()))
+            })
+            .collect()
+    }
+
+    /// Lists the inputs of the given component along with the component itself.
+    fn upstream_of(&self, key: &ComponentKey) -> Vec<(OutputId, ComponentKey)> {
+        self.config
+            .inputs_for_node(key)
+            .unwrap_or_default()
+            .iter()
+            .map(|input| (input.clone(), key.clone()))
+            .collect()
+    }
+
     pub(crate) async fn run_healthchecks(
         &mut self,
         diff: &ConfigDiff,
@@ -466,6 +655,12 @@ impl RunningTopology {
             self.remove_inputs(key, diff, new_config).await;
         }
 
+        // The dead-letter outputs of changed/removed sinks are recreated along with them, the same
+        // as the outputs of sources and transforms.
+        for key in diff.sinks.removed_and_changed() {
+            self.remove_outputs(key);
+        }
+
         // Now that we've disconnected or temporarily detached the inputs to all changed/removed
         // sinks, we can actually wait for them to shutdown before collecting any buffers that are
         // marked for reuse.
@@ -547,6 +742,18 @@ impl RunningTopology {
             self.setup_outputs(key, new_pieces).await;
         }
 
+        // Sinks only have outputs when their dead-letter output is enabled, and those must also be
+        // available before any inputs are wired up.
+        for key in diff.sinks.changed_and_added() {
+            if new_pieces.outputs.contains_key(key) {
+                debug!(component = %key, "Configuring outputs for sink.");
+                if let Some(task) = new_pieces.tasks.get(key) {
+                    tap_metadata.insert(key, ("sink", task.typetag().to_string()));
+                }
+                self.setup_outputs(key, new_pieces).await;
+            }
+        }
+
         // Now that all possible outputs are configured, we can start wiring up inputs, starting
         // with transforms.
         for key in diff.transforms.changed_and_added() {
@@ -595,6 +802,7 @@ impl RunningTopology {
                 .collect::<HashMap<_, _>>();
             let mut removals = diff.sources.to_remove.clone();
             removals.extend(diff.transforms.to_remove.iter().cloned());
+            removals.extend(diff.sinks.to_remove.iter().cloned());
             self.watch
                 .0
                 .send(TapResource {
@@ -610,8 +818,8 @@ impl RunningTopology {
                         .changed_and_added()
                         .map(|key| key.to_string())
                         .collect(),
-                    // Note, only sources and transforms are relevant. Sinks do
-                    // not have outputs to tap.
+                    // Note, sinks are only relevant when their dead-letter output
+                    // is enabled, as it's their only output to tap.
                     removals,
                 })
                 .expect("Couldn't broadcast config changes.");
@@ -875,5 +1083,14 @@ fn get_changed_outputs(diff: &ConfigDiff, output_ids: Vec<OutputId>) -> Vec<Outp
         );
     }
 
+    for sink_key in &diff.sinks.to_change {
+        changed_outputs.extend(
+            output_ids
+                .iter()
+                .filter(|id| &id.component == sink_key)
+                .cloned(),
+        );
+    }
+
     changed_outputs
 }
@@ -103,6 +103,19 @@ pub fn merged_definition(
                 ));
             }
         }
+        // If the input is the dead-letter output of a sink, the rejected events are passed through
+        // as-is, so the output has the merged definition of the sink's inputs.
+        if let Some(inputs) = config.sink_inputs(key) {
+            let sink_definition = merged_definition(inputs, config, cache);
+
+            if config.schema_enabled() {
+                definition = definition.merge(sink_definition);
+            } else {
+                definition = definition.merge(Definition::default_for_namespace(
+                    sink_definition.log_namespaces(),
+                ));
+            }
+        }
     }
     definition
 }
@@ -202,6 +215,13 @@ pub(super) fn expanded_definitions(
                 // pipeline definitions.
                 definitions.append(&mut expanded_definitions);
             }
+
+        // A sink passes the events it rejected through as-is, so each of its inputs is expanded
+        // into its own pipeline.
+        } else if let Some(inputs) = config.sink_inputs(key) {
+            for input in inputs {
+                definitions.append(&mut expanded_definitions(&[input.clone()], config, cache));
+            }
         }
     }
 
@@ -263,6 +283,12 @@ pub trait ComponentContainer {
         merged_definition: &Definition,
     ) -> Option<Vec<Output>>;
 
+    /// Gets the inputs of the sink with the given key, whose dead-letter output can feed other
+    /// components.
+    fn sink_inputs(&self, _key: &ComponentKey) -> Option<&[OutputId]> {
+        None
+    }
+
     /// Gets the transform output for the given port.
     ///
     /// Returns Err(()) if there is no transform with the given key
@@ -331,6 +357,10 @@ impl ComponentContainer for Config {
         self.transform(key)
             .map(|source| source.inner.outputs(merged_definition))
     }
+
+    fn sink_inputs(&self, key: &ComponentKey) -> Option<&[OutputId]> {
+        self.sink(key).map(|sink| sink.inputs.as_slice())
+    }
 }
 
 #[cfg(test)]
@@ -136,6 +136,7 @@ async fn buffer_drop_fan_out() {
         stages: vec![BufferType::Memory {
             max_events: MEMORY_BUFFER_DEFAULT_MAX_EVENTS,
             when_full: WhenFull::DropNewest,
+            max_age_secs: None,
         }],
     };
     config.add_sink_outer("out2", sink_outer);
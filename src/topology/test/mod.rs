@@ -14,6 +14,7 @@ use crate::{
         mock::{
             basic_sink, basic_sink_failing_healthcheck, basic_sink_with_data, basic_source,
             basic_source_with_data, basic_source_with_event_counter, basic_transform,
+            rejecting_sink,
         },
         start_topology, trace_init,
     },
@@ -320,6 +321,144 @@ async fn topology_remove_one_sink() {
     assert_eq!(Vec::<Event>::new(), res2);
 }
 
+#[tokio::test]
+async fn topology_drain_one_sink() {
+    let (mut in1, source1) = basic_source();
+    let (out1, sink1) = basic_sink(10);
+    let (out2, sink2) = basic_sink(10);
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_sink("out1", &["in1"], sink1);
+    config.add_sink("out2", &["in1"], sink2);
+
+    let (mut topology, _crash) = start_topology(config.build().unwrap(), false).await;
+
+    assert!(topology.undrain_sink(&"out2".into()).is_err());
+    topology.drain_sink(&"out2".into()).unwrap();
+    assert!(topology.drain_sink(&"out2".into()).is_err());
+    assert!(topology.drain_sink(&"in1".into()).is_err());
+
+    // The other sink keeps getting events while one is drained.
+    let mut out1 = out1.flat_map(into_event_stream).boxed();
+    let first = Event::Log(LogEvent::from("first"));
+    in1.send_event(first.clone()).await.unwrap();
+    assert_eq!(out1.next().await, Some(first.clone()));
+
+    topology.undrain_sink(&"out2".into()).unwrap();
+    let second = Event::Log(LogEvent::from("second"));
+    in1.send_event(second.clone()).await.unwrap();
+
+    topology.stop().await;
+
+    let res1 = out1.collect::<Vec<_>>().await;
+    let res2 = out2.flat_map(into_event_stream).collect::<Vec<_>>().await;
+
+    assert_eq!(vec![second.clone()], res1);
+    assert_eq!(vec![second], res2);
+}
+
+#[tokio::test]
+async fn topology_pause_source_and_drain_its_sink() {
+    let (mut in1, source1) = basic_source();
+    let (out1, sink1) = basic_sink(10);
+    let (out2, sink2) = basic_sink(10);
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_sink("out1", &["in1"], sink1);
+    config.add_sink("out2", &["in1"], sink2);
+
+    let (mut topology, _crash) = start_topology(config.build().unwrap(), false).await;
+
+    // Draining a sink fed by a paused source, and resuming the source, leaves the sink drained.
+    topology.pause_source(&"in1".into()).unwrap();
+    topology.drain_sink(&"out1".into()).unwrap();
+    topology.resume_source(&"in1".into()).unwrap();
+
+    let mut out2 = out2.flat_map(into_event_stream).boxed();
+    let first = Event::Log(LogEvent::from("first"));
+    in1.send_event(first.clone()).await.unwrap();
+    assert_eq!(out2.next().await, Some(first));
+
+    // Undraining a sink fed by a paused source leaves it paused until the source is resumed.
+    topology.pause_source(&"in1".into()).unwrap();
+    topology.undrain_sink(&"out1".into()).unwrap();
+    topology.resume_source(&"in1".into()).unwrap();
+
+    let second = Event::Log(LogEvent::from("second"));
+    in1.send_event(second.clone()).await.unwrap();
+
+    topology.stop().await;
+
+    let res1 = out1.flat_map(into_event_stream).collect::<Vec<_>>().await;
+    let res2 = out2.collect::<Vec<_>>().await;
+
+    assert_eq!(vec![second.clone()], res1);
+    assert_eq!(vec![second], res2);
+}
+
+#[tokio::test]
+async fn topology_pause_and_resume_source() {
+    let (mut in1, source1) = basic_source();
+    let (out1, sink1) = basic_sink(10);
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_sink("out1", &["in1"], sink1);
+
+    let (mut topology, _crash) = start_topology(config.build().unwrap(), false).await;
+
+    assert!(topology.resume_source(&"in1".into()).is_err());
+    topology.pause_source(&"in1".into()).unwrap();
+    assert!(topology.pause_source(&"in1".into()).is_err());
+    assert!(topology.pause_source(&"out1".into()).is_err());
+    topology.resume_source(&"in1".into()).unwrap();
+
+    let event = Event::Log(LogEvent::from("this"));
+
+    in1.send_event(event.clone()).await.unwrap();
+
+    topology.stop().await;
+
+    let res1 = out1.flat_map(into_event_stream).collect::<Vec<_>>().await;
+
+    assert_eq!(vec![event], res1);
+}
+
+#[tokio::test]
+async fn topology_dead_letter_rejected_events() {
+    let (mut in1, source1) = basic_source();
+    let (out1, sink1) = basic_sink(10);
+
+    let mut rejecting = SinkOuter::new(vec!["in1".to_string()], Box::new(rejecting_sink("bad")));
+    rejecting.dead_letter = true;
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_sink_outer("rejecting", rejecting);
+    config.add_sink("archive", &["rejecting.dead_letter"], sink1);
+
+    let (topology, _crash) = start_topology(config.build().unwrap(), false).await;
+
+    in1.send_event(Event::Log(LogEvent::from("this")))
+        .await
+        .unwrap();
+
+    topology.stop().await;
+
+    let res1 = out1.flat_map(into_event_stream).collect::<Vec<_>>().await;
+
+    assert_eq!(res1.len(), 1);
+    assert_eq!(res1[0].as_log()["message"], "this".into());
+    let metadata = res1[0].metadata().value();
+    assert_eq!(metadata.get("dead_letter.reason"), Some(&"bad".into()));
+    assert_eq!(
+        metadata.get("dead_letter.component_id"),
+        Some(&"rejecting".into())
+    );
+}
+
 #[tokio::test]
 async fn topology_remove_one_transform() {
     trace_init();
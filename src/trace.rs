@@ -4,7 +4,7 @@ use std::{
     str::FromStr,
     sync::{
         atomic::{AtomicBool, Ordering},
-        Mutex, MutexGuard,
+        Mutex, MutexGuard, RwLock, RwLockReadGuard,
     },
 };
 
@@ -17,10 +17,11 @@ use tokio::sync::{
     oneshot,
 };
 use tokio_stream::wrappers::BroadcastStream;
-use tracing::{Event, Subscriber};
+use tracing::{subscriber::Interest, Event, Metadata, Subscriber};
 use tracing_limit::RateLimitedLayer;
 use tracing_subscriber::{
-    layer::{Context, SubscriberExt},
+    filter::{LevelFilter, Targets},
+    layer::{Context, Filter, SubscriberExt},
     registry::LookupSpan,
     util::SubscriberInitExt,
     Layer,
@@ -50,21 +51,26 @@ static SUBSCRIBERS: OnceCell<Mutex<Option<Vec<oneshot::Sender<Vec<LogEvent>>>>>>
 /// has been initialized.
 static SENDER: OnceCell<Sender<LogEvent>> = OnceCell::new();
 
+/// LEVELS holds the logging filter targets applied to Vector's own log output. They are set during initialization and
+/// can be replaced at runtime with [`set_log_levels`].
+static LEVELS: OnceCell<RwLock<Targets>> = OnceCell::new();
+
 fn metrics_layer_enabled() -> bool {
     !matches!(std::env::var("DISABLE_INTERNAL_METRICS_TRACING_INTEGRATION"), Ok(x) if x == "true")
 }
 
 pub fn init(color: bool, json: bool, levels: &str) {
     let _ = BUFFER.set(Mutex::new(Some(Vec::new())));
-    let fmt_filter = tracing_subscriber::filter::Targets::from_str(levels).expect(
+    let targets = Targets::from_str(levels).expect(
         "logging filter targets were not formatted correctly or did not specify a valid level",
     );
+    let _ = LEVELS.set(RwLock::new(targets));
+    let fmt_filter = ReloadableTargets;
 
     let metrics_layer = metrics_layer_enabled()
         .then(|| MetricsLayer::new().with_filter(tracing_subscriber::filter::LevelFilter::INFO));
 
-    let broadcast_layer =
-        RateLimitedLayer::new(BroadcastLayer::new()).with_filter(fmt_filter.clone());
+    let broadcast_layer = RateLimitedLayer::new(BroadcastLayer::new()).with_filter(fmt_filter);
 
     let subscriber = tracing_subscriber::registry()
         .with(metrics_layer)
@@ -104,6 +110,50 @@ pub fn init(color: bool, json: bool, levels: &str) {
     }
 }
 
+/// Replaces the logging filter targets of the running process.
+///
+/// `levels` uses the same syntax as the `VECTOR_LOG` environment variable, e.g. `vector=debug,info`.
+pub fn set_log_levels(levels: &str) -> Result<(), String> {
+    let targets = Targets::from_str(levels).map_err(|error| error.to_string())?;
+    let lock = LEVELS
+        .get()
+        .ok_or_else(|| "Logging has not been initialized.".to_string())?;
+    *lock
+        .write()
+        .expect("Couldn't acquire lock on logging levels") = targets;
+
+    // Callsites cache whether they are enabled, so they need to be re-evaluated against the new
+    // filter.
+    tracing::callsite::rebuild_interest_cache();
+    Ok(())
+}
+
+fn current_levels() -> RwLockReadGuard<'static, Targets> {
+    LEVELS
+        .get()
+        .expect("Logging levels not initialized")
+        .read()
+        .expect("Couldn't acquire lock on logging levels")
+}
+
+/// A filter that delegates to the current [`LEVELS`], allowing them to change after the subscriber is installed.
+#[derive(Clone, Copy)]
+struct ReloadableTargets;
+
+impl<S> Filter<S> for ReloadableTargets {
+    fn enabled(&self, meta: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        Filter::<S>::enabled(&*current_levels(), meta, cx)
+    }
+
+    fn callsite_enabled(&self, meta: &'static Metadata<'static>) -> Interest {
+        Filter::<S>::callsite_enabled(&*current_levels(), meta)
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Filter::<S>::max_level_hint(&*current_levels())
+    }
+}
+
 #[cfg(test)]
 pub fn reset_early_buffer() -> Option<Vec<LogEvent>> {
     get_early_buffer().replace(Vec::new())
@@ -26,6 +26,59 @@ pub struct AggregateConfig {
     /// Over this period metrics with the same series data (name, namespace, tags, …) will be aggregated.
     #[serde(default = "default_interval_ms")]
     pub interval_ms: u64,
+
+    /// The function used to aggregate the values of metrics with the same series.
+    pub mode: AggregationMode,
+
+    /// The tags to keep on metrics before they are grouped into series.
+    ///
+    /// All other tags are removed, which reduces the cardinality of the aggregated metrics. If left unspecified, all
+    /// tags are kept. Can't be used together with `without`.
+    pub by: Option<Vec<String>>,
+
+    /// The tags to remove from metrics before they are grouped into series.
+    ///
+    /// Can't be used together with `by`.
+    pub without: Vec<String>,
+}
+
+/// The function used to aggregate the values of metrics.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregationMode {
+    /// Adds up incremental metrics, and keeps the latest value of absolute metrics.
+    Auto,
+
+    /// Adds up the values of metrics, regardless of their kind.
+    Sum,
+
+    /// Counts the number of metrics received, as an incremental counter.
+    Count,
+
+    /// Keeps the smallest value of counters and gauges, as an absolute gauge.
+    Min,
+
+    /// Keeps the largest value of counters and gauges, as an absolute gauge.
+    Max,
+
+    /// Averages the values of counters and gauges, as an absolute gauge.
+    Mean,
+
+    /// Keeps the latest value of metrics, regardless of their kind.
+    Latest,
+
+    /// Computes the change in the value of gauges, from the first to the last value received, as an incremental gauge.
+    Diff,
+
+    /// Computes the population standard deviation of the values of counters and gauges, as an absolute gauge.
+    Stdev,
+}
+
+impl Default for AggregationMode {
+    fn default() -> Self {
+        Self::Auto
+    }
 }
 
 const fn default_interval_ms() -> u64 {
@@ -42,6 +95,10 @@ impl_generate_config_from_default!(AggregateConfig);
 #[typetag::serde(name = "aggregate")]
 impl TransformConfig for AggregateConfig {
     async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        if self.by.is_some() && !self.without.is_empty() {
+            return Err("only one of `by` and `without` can be set".into());
+        }
+
         Aggregate::new(self).map(Transform::event_task)
     }
 
@@ -60,23 +117,169 @@ impl TransformConfig for AggregateConfig {
 
 type MetricEntry = (metric::MetricData, EventMetadata);
 
+/// Running statistics over the values of a series, for the modes that aren't computed by merging metrics together.
+#[derive(Debug)]
+struct Statistics {
+    count: u64,
+    first: f64,
+    last: f64,
+    min: f64,
+    max: f64,
+    mean: f64,
+    // Sum of the squared differences from the mean, updated with Welford's algorithm.
+    m2: f64,
+}
+
+impl Statistics {
+    const fn new(value: f64) -> Self {
+        Self {
+            count: 1,
+            first: value,
+            last: value,
+            min: value,
+            max: value,
+            mean: value,
+            m2: 0.0,
+        }
+    }
+
+    fn update(&mut self, value: f64) {
+        self.count += 1;
+        self.last = value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn stdev(&self) -> f64 {
+        (self.m2 / self.count as f64).sqrt()
+    }
+}
+
+type StatisticsEntry = (Statistics, metric::MetricTime, EventMetadata);
+
 #[derive(Debug)]
 pub struct Aggregate {
     interval: Duration,
+    mode: AggregationMode,
+    by: Option<Vec<String>>,
+    without: Vec<String>,
     map: BTreeMap<metric::MetricSeries, MetricEntry>,
+    statistics: BTreeMap<metric::MetricSeries, StatisticsEntry>,
 }
 
 impl Aggregate {
     pub fn new(config: &AggregateConfig) -> crate::Result<Self> {
         Ok(Self {
             interval: Duration::from_millis(config.interval_ms),
+            mode: config.mode,
+            by: config.by.clone(),
+            without: config.without.clone(),
             map: BTreeMap::new(),
+            statistics: BTreeMap::new(),
         })
     }
 
+    fn filter_tags(&self, series: &mut metric::MetricSeries) {
+        if let Some(tags) = series.tags_mut() {
+            match &self.by {
+                Some(by) => tags.retain(|key, _| by.contains(key)),
+                None => tags.retain(|key, _| !self.without.contains(key)),
+            }
+            if tags.is_empty() {
+                *series.tags_mut() = None;
+            }
+        }
+    }
+
     fn record(&mut self, event: Event) {
-        let (series, data, metadata) = event.into_metric().into_parts();
+        let (mut series, data, metadata) = event.into_metric().into_parts();
+        self.filter_tags(&mut series);
+
+        match self.mode {
+            AggregationMode::Auto => self.record_auto(series, data, metadata),
+            AggregationMode::Sum => self.record_sum(series, data, metadata),
+            AggregationMode::Latest => {
+                self.map.insert(series, (data, metadata));
+            }
+            AggregationMode::Count => self.record_statistic(series, data.time, 0.0, metadata),
+            AggregationMode::Diff => match data.value {
+                metric::MetricValue::Gauge { value } => {
+                    self.record_statistic(series, data.time, value, metadata)
+                }
+                _ => {
+                    emit!(AggregateUpdateFailed);
+                    return;
+                }
+            },
+            AggregationMode::Min
+            | AggregationMode::Max
+            | AggregationMode::Mean
+            | AggregationMode::Stdev => match data.value {
+                metric::MetricValue::Counter { value } | metric::MetricValue::Gauge { value } => {
+                    self.record_statistic(series, data.time, value, metadata)
+                }
+                _ => {
+                    emit!(AggregateUpdateFailed);
+                    return;
+                }
+            },
+        }
+
+        emit!(AggregateEventRecorded);
+    }
+
+    fn record_sum(
+        &mut self,
+        series: metric::MetricSeries,
+        data: metric::MetricData,
+        metadata: EventMetadata,
+    ) {
+        match self.map.entry(series) {
+            Entry::Occupied(mut entry) => {
+                let existing = entry.get_mut();
+                // Unlike `auto`, values are added up regardless of kind, as long as their types match
+                if existing.0.update(&data) {
+                    existing.1.merge(metadata);
+                } else {
+                    emit!(AggregateUpdateFailed);
+                    *existing = (data, metadata);
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert((data, metadata));
+            }
+        }
+    }
+
+    fn record_statistic(
+        &mut self,
+        series: metric::MetricSeries,
+        time: metric::MetricTime,
+        value: f64,
+        metadata: EventMetadata,
+    ) {
+        match self.statistics.entry(series) {
+            Entry::Occupied(mut entry) => {
+                let existing = entry.get_mut();
+                existing.0.update(value);
+                existing.1 = time;
+                existing.2.merge(metadata);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert((Statistics::new(value), time, metadata));
+            }
+        }
+    }
 
+    fn record_auto(
+        &mut self,
+        series: metric::MetricSeries,
+        data: metric::MetricData,
+        metadata: EventMetadata,
+    ) {
         match data.kind {
             metric::MetricKind::Incremental => match self.map.entry(series) {
                 Entry::Occupied(mut entry) => {
@@ -98,8 +301,6 @@ impl Aggregate {
                 self.map.insert(series, (data, metadata));
             }
         };
-
-        emit!(AggregateEventRecorded);
     }
 
     fn flush_into(&mut self, output: &mut Vec<Event>) {
@@ -109,6 +310,39 @@ impl Aggregate {
             output.push(Event::Metric(metric));
         }
 
+        let statistics = std::mem::take(&mut self.statistics);
+        for (series, (statistics, time, metadata)) in statistics.into_iter() {
+            let (kind, value) = match self.mode {
+                AggregationMode::Count => (
+                    metric::MetricKind::Incremental,
+                    metric::MetricValue::Counter {
+                        value: statistics.count as f64,
+                    },
+                ),
+                AggregationMode::Diff => (
+                    metric::MetricKind::Incremental,
+                    metric::MetricValue::Gauge {
+                        value: statistics.last - statistics.first,
+                    },
+                ),
+                mode => {
+                    let value = match mode {
+                        AggregationMode::Min => statistics.min,
+                        AggregationMode::Max => statistics.max,
+                        AggregationMode::Mean => statistics.mean,
+                        _ => statistics.stdev(),
+                    };
+                    (
+                        metric::MetricKind::Absolute,
+                        metric::MetricValue::Gauge { value },
+                    )
+                }
+            };
+            let data = metric::MetricData::from_parts(time, kind, value);
+            let metric = metric::Metric::from_parts(series, data, metadata);
+            output.push(Event::Metric(metric));
+        }
+
         emit!(AggregateFlushed);
     }
 }
@@ -153,6 +387,7 @@ impl TaskTransform<Event> for Aggregate {
 mod tests {
     use std::{collections::BTreeSet, task::Poll};
 
+    use approx::assert_relative_eq;
     use futures::{stream, SinkExt};
 
     use super::*;
@@ -175,6 +410,7 @@ mod tests {
     fn incremental() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -245,6 +481,7 @@ mod tests {
     fn absolute() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -310,6 +547,7 @@ mod tests {
     fn conflicting_value_type() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -367,6 +605,7 @@ mod tests {
     fn conflicting_kinds() {
         let mut agg = Aggregate::new(&AggregateConfig {
             interval_ms: 1000_u64,
+            ..Default::default()
         })
         .unwrap();
 
@@ -417,6 +656,189 @@ mod tests {
         assert_eq!(&summed, &out[0]);
     }
 
+    fn record_all(agg: &mut Aggregate, values: &[f64]) -> Vec<Event> {
+        for value in values {
+            agg.record(make_metric(
+                "gauge_a",
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value: *value },
+            ));
+        }
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        out
+    }
+
+    #[test]
+    fn modes() {
+        let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        for (mode, kind, value) in [
+            (
+                AggregationMode::Count,
+                metric::MetricKind::Incremental,
+                metric::MetricValue::Counter { value: 8.0 },
+            ),
+            (
+                AggregationMode::Sum,
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value: 40.0 },
+            ),
+            (
+                AggregationMode::Min,
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value: 2.0 },
+            ),
+            (
+                AggregationMode::Max,
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value: 9.0 },
+            ),
+            (
+                AggregationMode::Mean,
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value: 5.0 },
+            ),
+            (
+                AggregationMode::Latest,
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value: 9.0 },
+            ),
+            (
+                AggregationMode::Diff,
+                metric::MetricKind::Incremental,
+                metric::MetricValue::Gauge { value: 7.0 },
+            ),
+            (
+                AggregationMode::Stdev,
+                metric::MetricKind::Absolute,
+                metric::MetricValue::Gauge { value: 2.0 },
+            ),
+        ] {
+            let mut agg = Aggregate::new(&AggregateConfig {
+                interval_ms: 1000_u64,
+                mode,
+                ..Default::default()
+            })
+            .unwrap();
+
+            let out = record_all(&mut agg, &values);
+            assert_eq!(1, out.len(), "mode {:?}", mode);
+            let aggregated = out[0].as_metric();
+            assert_eq!(kind, aggregated.kind(), "mode {:?}", mode);
+            match (value, aggregated.value()) {
+                (
+                    metric::MetricValue::Counter { value: expected },
+                    metric::MetricValue::Counter { value: actual },
+                )
+                | (
+                    metric::MetricValue::Gauge { value: expected },
+                    metric::MetricValue::Gauge { value: actual },
+                ) => assert_relative_eq!(expected, *actual),
+                (expected, actual) => {
+                    panic!("mode {:?}: expected {:?}, got {:?}", mode, expected, actual)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn statistics_ignore_unsupported_values() {
+        let mut agg = Aggregate::new(&AggregateConfig {
+            interval_ms: 1000_u64,
+            mode: AggregationMode::Mean,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let mut values = BTreeSet::<String>::new();
+        values.insert("a".into());
+        agg.record(make_metric(
+            "set_a",
+            metric::MetricKind::Incremental,
+            metric::MetricValue::Set { values },
+        ));
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(0, out.len());
+    }
+
+    #[test]
+    fn filters_tags_before_grouping() {
+        let tagged = |host: &str, region: &str| {
+            let tags = [("host", host), ("region", region)]
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect();
+            Event::Metric(
+                Metric::new(
+                    "counter_a",
+                    metric::MetricKind::Incremental,
+                    metric::MetricValue::Counter { value: 1.0 },
+                )
+                .with_tags(Some(tags)),
+            )
+        };
+
+        for (by, without) in [
+            (Some(vec!["region".to_string()]), vec![]),
+            (None, vec!["host".to_string()]),
+        ] {
+            let mut agg = Aggregate::new(&AggregateConfig {
+                interval_ms: 1000_u64,
+                by,
+                without,
+                ..Default::default()
+            })
+            .unwrap();
+
+            agg.record(tagged("a", "us"));
+            agg.record(tagged("b", "us"));
+            agg.record(tagged("c", "eu"));
+            let mut out = vec![];
+            agg.flush_into(&mut out);
+
+            assert_eq!(2, out.len());
+            for event in out {
+                let aggregated = event.as_metric();
+                let tags = aggregated.tags().unwrap();
+                assert!(tags.get("host").is_none());
+                let expected = match tags["region"].as_str() {
+                    "us" => 2.0,
+                    "eu" => 1.0,
+                    region => panic!("Unexpected region {}", region),
+                };
+                assert_eq!(
+                    &metric::MetricValue::Counter { value: expected },
+                    aggregated.value()
+                );
+            }
+        }
+
+        // Tags that are all filtered out leave the metric without tags.
+        let mut agg = Aggregate::new(&AggregateConfig {
+            interval_ms: 1000_u64,
+            by: Some(vec![]),
+            ..Default::default()
+        })
+        .unwrap();
+        agg.record(tagged("a", "us"));
+        let mut out = vec![];
+        agg.flush_into(&mut out);
+        assert_eq!(None, out[0].as_metric().tags());
+    }
+
+    #[tokio::test]
+    async fn by_and_without_are_exclusive() {
+        let config = toml::from_str::<AggregateConfig>(
+            r#"
+by = ["host"]
+without = ["region"]
+"#,
+        )
+        .unwrap();
+        assert!(config.build(&TransformContext::default()).await.is_err());
+    }
+
     #[tokio::test]
     async fn transform_shutdown() {
         let agg = toml::from_str::<AggregateConfig>(
@@ -1,8 +1,20 @@
-use std::{future::ready, pin::Pin};
+use std::{
+    collections::HashMap,
+    io,
+    num::NonZeroU64,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
+use async_stream::stream;
 use bytes::Bytes;
 use futures::{Stream, StreamExt};
 use lru::LruCache;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
 use vector_config::configurable_component;
 
 use crate::{
@@ -11,7 +23,7 @@ use crate::{
         TransformDescription,
     },
     event::{Event, Value},
-    internal_events::DedupeEventDiscarded,
+    internal_events::{DedupeCacheIoError, DedupeEventDiscarded},
     schema,
     transforms::{TaskTransform, Transform},
 };
@@ -41,6 +53,21 @@ pub enum FieldMatchConfig {
 pub struct CacheConfig {
     /// Number of events to cache and use for comparing incoming events to previously seen events.
     pub num_events: usize,
+
+    /// The amount of time, in seconds, that an event is remembered for after it was first seen.
+    ///
+    /// Duplicates of an event are only discarded within this window, after which the next duplicate is forwarded as if
+    /// it was new. If left unspecified, events are remembered until they're evicted from the cache by newer ones.
+    #[serde(default)]
+    pub time_to_live_secs: Option<NonZeroU64>,
+
+    /// Whether or not to persist the cache to disk.
+    ///
+    /// When enabled, the cache is saved under the data directory every few seconds and when Vector stops, and restored
+    /// when it starts again, so that events that were already forwarded aren't forwarded again. On reload, the cache
+    /// is handed over to the new instance of the transform as is.
+    #[serde(default)]
+    pub persist: bool,
 }
 
 /// Configuration for the `dedupe` transform.
@@ -55,12 +82,36 @@ pub struct DedupeConfig {
     #[configurable(derived)]
     #[serde(default = "default_cache_config")]
     pub cache: CacheConfig,
+
+    /// The directory used to persist the cache, when `cache.persist` is enabled.
+    ///
+    /// By default, the global `data_dir` option is used. Please make sure the user Vector is running as has write permissions to this directory.
+    #[serde(default)]
+    pub data_dir: Option<PathBuf>,
 }
 
 const fn default_cache_config() -> CacheConfig {
-    CacheConfig { num_events: 5000 }
+    CacheConfig {
+        num_events: 5000,
+        time_to_live_secs: None,
+        persist: false,
+    }
 }
 
+/// The name of the file the cache is persisted to, in the data directory of the transform.
+const CACHE_FILE_NAME: &str = "dedupe_cache.json";
+
+/// How often a persisted cache is saved while the transform is running, which bounds the events that are forwarded
+/// again after a crash.
+const PERSIST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The persisted caches of the running `dedupe` transforms, by the path they're persisted to.
+///
+/// On reload, the new instance of a transform is built before the previous one has stopped, so the new instance takes
+/// over the cache of the previous one from here rather than reading a file that is about to be outdated.
+static PERSISTED_CACHES: Lazy<Mutex<HashMap<PathBuf, Weak<Mutex<Cache>>>>> =
+    Lazy::new(Default::default);
+
 impl DedupeConfig {
     /// We cannot rely on Serde to populate the default since we want it to be
     /// based on the user's configured log_schema, which we only know about
@@ -80,7 +131,25 @@ impl DedupeConfig {
 
 pub struct Dedupe {
     fields: FieldMatchConfig,
-    cache: LruCache<CacheEntry, bool>,
+    cache: Arc<Mutex<Cache>>,
+    time_to_live: Option<Duration>,
+    cache_path: Option<PathBuf>,
+}
+
+struct Cache {
+    /// Cached events, along with the time they were first seen at.
+    entries: LruCache<CacheEntry, SystemTime>,
+    /// Whether entries were added since the cache was last persisted.
+    dirty: bool,
+    /// Held while the cache is being persisted.
+    persist_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl Cache {
+    fn insert(&mut self, entry: CacheEntry, first_seen: SystemTime) {
+        self.entries.put(entry, first_seen);
+        self.dirty = true;
+    }
 }
 
 inventory::submit! {
@@ -92,6 +161,7 @@ impl GenerateConfig for DedupeConfig {
         toml::Value::try_from(Self {
             fields: None,
             cache: default_cache_config(),
+            data_dir: None,
         })
         .unwrap()
     }
@@ -100,8 +170,16 @@ impl GenerateConfig for DedupeConfig {
 #[async_trait::async_trait]
 #[typetag::serde(name = "dedupe")]
 impl TransformConfig for DedupeConfig {
-    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::event_task(Dedupe::new(self.clone())))
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        let mut dedupe = Dedupe::new(self.clone());
+        if self.cache.persist {
+            let id = context.key.as_ref().map_or("dedupe", |key| key.id());
+            let data_dir = context
+                .globals
+                .resolve_and_make_data_subdir(self.data_dir.as_ref(), id)?;
+            dedupe.load_cache(data_dir.join(CACHE_FILE_NAME)).await;
+        }
+        Ok(Transform::event_task(dedupe))
     }
 
     fn input(&self) -> Input {
@@ -142,7 +220,7 @@ type TypeId = u8;
 /// iterating over the fields of the incoming Events, we know that the
 /// CacheEntries for 2 equivalent events will always contain the fields in the
 /// same order.
-#[derive(PartialEq, Eq, Hash)]
+#[derive(PartialEq, Eq, Hash, Deserialize, Serialize)]
 enum CacheEntry {
     Match(Vec<Option<(TypeId, Bytes)>>),
     Ignore(Vec<(String, TypeId, Bytes)>),
@@ -169,19 +247,185 @@ impl Dedupe {
         let fields = config.fill_default_fields_match();
         Self {
             fields,
-            cache: LruCache::new(num_entries),
+            cache: Arc::new(Mutex::new(Cache {
+                entries: LruCache::new(num_entries),
+                dirty: false,
+                persist_lock: Default::default(),
+            })),
+            time_to_live: config
+                .cache
+                .time_to_live_secs
+                .map(|secs| Duration::from_secs(secs.get())),
+            cache_path: None,
         }
     }
 
     fn transform_one(&mut self, event: Event) -> Option<Event> {
+        self.transform_one_at(event, SystemTime::now())
+    }
+
+    fn transform_one_at(&mut self, event: Event, now: SystemTime) -> Option<Event> {
         let cache_entry = build_cache_entry(&event, &self.fields);
-        if self.cache.put(cache_entry, true).is_some() {
+        let mut cache = self.cache.lock().expect("dedupe cache lock poisoned");
+        let first_seen = cache.entries.get(&cache_entry).copied();
+        let is_duplicate = first_seen.map_or(false, |first_seen| !self.is_expired(first_seen, now));
+        if is_duplicate {
             emit!(DedupeEventDiscarded { event });
             None
         } else {
+            cache.insert(cache_entry, now);
             Some(event)
         }
     }
+
+    fn is_expired(&self, first_seen: SystemTime, now: SystemTime) -> bool {
+        self.time_to_live.map_or(false, |time_to_live| {
+            now.duration_since(first_seen)
+                .map_or(false, |elapsed| elapsed >= time_to_live)
+        })
+    }
+
+    /// Restores the cache persisted at `path`, and persists it back there while the transform runs.
+    ///
+    /// If the previous instance of the transform is still running, as is the case on reload, its cache is taken over
+    /// instead. A missing or unreadable cache isn't fatal, the transform simply starts with an empty cache.
+    async fn load_cache(&mut self, path: PathBuf) {
+        loop {
+            let loaded = if running_cache(&path).is_none() {
+                Some(read_cache(&path).await)
+            } else {
+                None
+            };
+
+            let mut caches = PERSISTED_CACHES
+                .lock()
+                .expect("dedupe cache registry lock poisoned");
+            caches.retain(|_, cache| cache.strong_count() > 0);
+
+            if let Some(cache) = caches.get(&path).and_then(Weak::upgrade) {
+                let capacity = self
+                    .cache
+                    .lock()
+                    .expect("dedupe cache lock poisoned")
+                    .entries
+                    .cap();
+                cache
+                    .lock()
+                    .expect("dedupe cache lock poisoned")
+                    .entries
+                    .resize(capacity);
+                self.cache = cache;
+                break;
+            }
+
+            // The previous instance stopped while we were checking on it, so its cache has to be read after all.
+            let loaded = match loaded {
+                Some(loaded) => loaded,
+                None => continue,
+            };
+            match loaded {
+                Ok(entries) => {
+                    let now = SystemTime::now();
+                    let mut cache = self.cache.lock().expect("dedupe cache lock poisoned");
+                    // Entries are persisted from least to most recently used, so they end up in the same order.
+                    for (entry, first_seen_ms) in entries {
+                        let first_seen = UNIX_EPOCH + Duration::from_millis(first_seen_ms);
+                        if !self.is_expired(first_seen, now) {
+                            cache.entries.put(entry, first_seen);
+                        }
+                    }
+                }
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                Err(error) => emit!(DedupeCacheIoError {
+                    error,
+                    path: &path,
+                    operation: "load",
+                }),
+            }
+            caches.insert(path.clone(), Arc::downgrade(&self.cache));
+            break;
+        }
+
+        self.cache_path = Some(path);
+    }
+
+    /// Persists the cache, if it changed since it was last persisted.
+    ///
+    /// The cache is only locked while taking a snapshot of it, the snapshot is written afterwards.
+    async fn persist_cache(&self) {
+        let path = match &self.cache_path {
+            Some(path) => path,
+            None => return,
+        };
+
+        // Instances sharing the cache persist it one at a time, so that an older snapshot can't overwrite a newer one.
+        let persist_lock = Arc::clone(
+            &self
+                .cache
+                .lock()
+                .expect("dedupe cache lock poisoned")
+                .persist_lock,
+        );
+        let _persisting = persist_lock.lock().await;
+
+        let snapshot = {
+            let mut cache = self.cache.lock().expect("dedupe cache lock poisoned");
+            if !cache.dirty {
+                return;
+            }
+            cache.dirty = false;
+            snapshot_cache(&cache.entries)
+        };
+
+        let result = match snapshot {
+            Ok(contents) => write_cache(path, contents).await,
+            Err(error) => Err(error.into()),
+        };
+        if let Err(error) = result {
+            self.cache.lock().expect("dedupe cache lock poisoned").dirty = true;
+            emit!(DedupeCacheIoError {
+                error,
+                path,
+                operation: "persist",
+            });
+        }
+    }
+}
+
+/// Gets the cache of the running instance persisting to `path`, if any.
+fn running_cache(path: &Path) -> Option<Arc<Mutex<Cache>>> {
+    PERSISTED_CACHES
+        .lock()
+        .expect("dedupe cache registry lock poisoned")
+        .get(path)
+        .and_then(Weak::upgrade)
+}
+
+async fn read_cache(path: &Path) -> io::Result<Vec<(CacheEntry, u64)>> {
+    let contents = fs::read(path).await?;
+    Ok(serde_json::from_slice(&contents)?)
+}
+
+/// Serializes the entries of the cache, from least to most recently used.
+fn snapshot_cache(entries: &LruCache<CacheEntry, SystemTime>) -> serde_json::Result<Vec<u8>> {
+    let entries = entries
+        .iter()
+        .rev()
+        .map(|(entry, first_seen)| {
+            let first_seen_ms = first_seen
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_millis() as u64);
+            (entry, first_seen_ms)
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_vec(&entries)
+}
+
+async fn write_cache(path: &Path, contents: Vec<u8>) -> io::Result<()> {
+    // Write to a temporary file first, so that a crash while persisting doesn't leave a truncated cache behind.
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, contents).await?;
+    fs::rename(&tmp_path, path).await
 }
 
 /// Takes in an Event and returns a CacheEntry to place into the LRU cache
@@ -223,13 +467,37 @@ fn build_cache_entry(event: &Event, fields: &FieldMatchConfig) -> CacheEntry {
 impl TaskTransform<Event> for Dedupe {
     fn transform(
         self: Box<Self>,
-        task: Pin<Box<dyn Stream<Item = Event> + Send>>,
+        mut task: Pin<Box<dyn Stream<Item = Event> + Send>>,
     ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
     where
         Self: 'static,
     {
         let mut inner = self;
-        Box::pin(task.filter_map(move |v| ready(inner.transform_one(v))))
+        let persist = inner.cache_path.is_some();
+        Box::pin(stream! {
+            let mut persist_interval = tokio::time::interval(PERSIST_INTERVAL);
+            loop {
+                let event = tokio::select! {
+                    event = task.next() => event,
+                    _ = persist_interval.tick(), if persist => {
+                        inner.persist_cache().await;
+                        continue;
+                    }
+                };
+
+                match event {
+                    Some(event) => {
+                        if let Some(event) = inner.transform_one(event) {
+                            yield event;
+                        }
+                    }
+                    None => break,
+                }
+            }
+
+            // The input only ends when Vector is stopping or the transform is being reloaded.
+            inner.persist_cache().await;
+        })
     }
 }
 
@@ -237,8 +505,11 @@ impl TaskTransform<Event> for Dedupe {
 mod tests {
     use std::collections::BTreeMap;
 
+    use futures::channel::mpsc::UnboundedSender;
+
     use super::*;
     use crate::{
+        config::ComponentKey,
         event::{Event, LogEvent, Value},
         transforms::dedupe::{CacheConfig, DedupeConfig, FieldMatchConfig},
     };
@@ -250,8 +521,13 @@ mod tests {
 
     fn make_match_transform(num_events: usize, fields: Vec<String>) -> Dedupe {
         Dedupe::new(DedupeConfig {
-            cache: CacheConfig { num_events },
+            cache: CacheConfig {
+                num_events,
+                time_to_live_secs: None,
+                persist: false,
+            },
             fields: Some(FieldMatchConfig::MatchFields(fields)),
+            data_dir: None,
         })
     }
 
@@ -261,8 +537,13 @@ mod tests {
         fields.extend(given_fields);
 
         Dedupe::new(DedupeConfig {
-            cache: CacheConfig { num_events },
+            cache: CacheConfig {
+                num_events,
+                time_to_live_secs: None,
+                persist: false,
+            },
             fields: Some(FieldMatchConfig::IgnoreFields(fields)),
+            data_dir: None,
         })
     }
 
@@ -406,6 +687,148 @@ mod tests {
         assert_eq!(new_event, event1);
     }
 
+    #[test]
+    fn dedupe_match_time_to_live() {
+        let mut transform = make_match_transform(5, vec!["matched".into()]);
+        transform.time_to_live = Some(Duration::from_secs(60));
+        time_to_live(transform);
+    }
+
+    #[test]
+    fn dedupe_ignore_time_to_live() {
+        let mut transform = make_ignore_transform(5, vec![]);
+        transform.time_to_live = Some(Duration::from_secs(60));
+        time_to_live(transform);
+    }
+
+    /// Test that duplicates are only discarded within the time to live of the first event.
+    fn time_to_live(mut transform: Dedupe) {
+        let mut event = Event::Log(LogEvent::from("message"));
+        event.as_mut_log().insert("matched", "some value");
+        let start = SystemTime::now();
+
+        // First event should always be passed through as-is.
+        let new_event = transform.transform_one_at(event.clone(), start).unwrap();
+        assert_eq!(new_event, event);
+
+        // A duplicate within the time to live is dropped, and doesn't extend it.
+        let within = start + Duration::from_secs(30);
+        assert_eq!(None, transform.transform_one_at(event.clone(), within));
+
+        // Once the time to live has passed, the duplicate is passed through, and remembered anew.
+        let after = start + Duration::from_secs(60);
+        let new_event = transform.transform_one_at(event.clone(), after).unwrap();
+        assert_eq!(new_event, event);
+        let within = after + Duration::from_secs(30);
+        assert_eq!(None, transform.transform_one_at(event, within));
+    }
+
+    #[tokio::test]
+    async fn dedupe_persists_cache() {
+        let path = crate::test_util::temp_dir().join(CACHE_FILE_NAME);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        let mut event1 = Event::Log(LogEvent::from("message"));
+        event1.as_mut_log().insert("matched", "some value");
+        let mut event2 = Event::Log(LogEvent::from("message"));
+        event2.as_mut_log().insert("matched", "some value2");
+
+        // Nothing has been persisted yet, so the cache starts out empty.
+        let mut transform = make_match_transform(5, vec!["matched".into()]);
+        transform.load_cache(path.clone()).await;
+        let new_event = transform.transform_one(event1.clone()).unwrap();
+        assert_eq!(new_event, event1);
+        transform.persist_cache().await;
+        drop(transform);
+
+        // After a restart, the first event is still remembered, but the second one is new.
+        let mut transform = make_match_transform(5, vec!["matched".into()]);
+        transform.load_cache(path.clone()).await;
+        assert_eq!(None, transform.transform_one(event1.clone()));
+        let new_event = transform.transform_one(event2.clone()).unwrap();
+        assert_eq!(new_event, event2);
+        drop(transform);
+
+        // Entries that expired while Vector was stopped aren't restored.
+        let mut transform = make_match_transform(5, vec!["matched".into()]);
+        transform.time_to_live = Some(Duration::from_secs(60));
+        transform.cache.lock().unwrap().insert(
+            build_cache_entry(&event2, &transform.fields),
+            SystemTime::now() - Duration::from_secs(120),
+        );
+        transform.cache_path = Some(path.clone());
+        transform.persist_cache().await;
+        drop(transform);
+
+        let mut transform = make_match_transform(5, vec!["matched".into()]);
+        transform.time_to_live = Some(Duration::from_secs(60));
+        transform.load_cache(path).await;
+        let new_event = transform.transform_one(event2.clone()).unwrap();
+        assert_eq!(new_event, event2);
+    }
+
+    async fn start(
+        config: &DedupeConfig,
+        context: &TransformContext,
+    ) -> (
+        UnboundedSender<Event>,
+        Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) {
+        let transform = config.build(context).await.unwrap().into_task();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        (tx, transform.transform_events(Box::pin(rx)))
+    }
+
+    #[tokio::test]
+    async fn dedupe_hands_cache_over_on_reload() {
+        let data_dir = crate::test_util::temp_dir();
+        std::fs::create_dir_all(&data_dir).unwrap();
+        let config = DedupeConfig {
+            cache: CacheConfig {
+                num_events: 5,
+                time_to_live_secs: None,
+                persist: true,
+            },
+            fields: Some(FieldMatchConfig::MatchFields(vec!["matched".into()])),
+            data_dir: Some(data_dir.clone()),
+        };
+        let context = TransformContext {
+            key: Some(ComponentKey::from("dedupe")),
+            ..Default::default()
+        };
+
+        let mut event1 = Event::Log(LogEvent::from("message"));
+        event1.as_mut_log().insert("matched", "some value");
+        let mut event2 = Event::Log(LogEvent::from("message"));
+        event2.as_mut_log().insert("matched", "some value2");
+
+        let (old_tx, mut old_out) = start(&config, &context).await;
+        old_tx.unbounded_send(event1.clone()).unwrap();
+        assert_eq!(old_out.next().await, Some(event1.clone()));
+
+        // On reload, the new instance is built while the old one is still running and before it has persisted
+        // anything, yet the first event is remembered.
+        let (new_tx, mut new_out) = start(&config, &context).await;
+        new_tx.unbounded_send(event1.clone()).unwrap();
+        new_tx.unbounded_send(event2.clone()).unwrap();
+        assert_eq!(new_out.next().await, Some(event2.clone()));
+
+        // The old instance stopping doesn't overwrite what the new one has seen since.
+        drop(old_tx);
+        assert_eq!(old_out.next().await, None);
+        drop(new_tx);
+        assert_eq!(new_out.next().await, None);
+        drop((old_out, new_out));
+
+        let (tx, mut out) = start(&config, &context).await;
+        let mut event3 = Event::Log(LogEvent::from("message"));
+        event3.as_mut_log().insert("matched", "some value3");
+        tx.unbounded_send(event1).unwrap();
+        tx.unbounded_send(event2).unwrap();
+        tx.unbounded_send(event3.clone()).unwrap();
+        assert_eq!(out.next().await, Some(event3));
+    }
+
     #[test]
     fn dedupe_match_type_matching() {
         let transform = make_match_transform(5, vec!["matched".into()]);
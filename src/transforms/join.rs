@@ -0,0 +1,848 @@
+use std::{
+    collections::{hash_map, HashMap, VecDeque},
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use async_stream::stream;
+use futures::{stream, Stream, StreamExt};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use vector_config::configurable_component;
+use vector_core::transform::{InnerTopology, InnerTopologyTransform};
+
+use crate::{
+    config::{
+        ComponentKey, DataType, GenerateConfig, Input, Output, TransformConfig, TransformContext,
+        TransformDescription,
+    },
+    event::{Event, LogEvent, Value},
+    internal_events::{JoinMaxEventsReached, TemplateRenderingError},
+    schema,
+    template::Template,
+    transforms::{
+        reduce::{MergeStrategy, ReduceState},
+        FunctionTransform, OutputBuffer, SyncTransform, TaskTransform, Transform,
+        TransformOutputsBuf,
+    },
+};
+
+/// The name of the output that unmatched events are sent to.
+pub(crate) const UNMATCHED_OUTPUT: &str = "unmatched";
+
+/// Metadata field used to tell which side of the join an event came from.
+const SIDE_METADATA_KEY: &str = "join_side";
+
+/// Metadata field used by the window of a join to flag the events that weren't matched, so they can be split off.
+const UNMATCHED_METADATA_KEY: &str = "join_unmatched";
+
+/// Configuration for the `join` transform.
+#[configurable_component(transform)]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct JoinConfig {
+    /// The left-hand side of the join.
+    ///
+    /// When merging a matched pair of events, the values of the left event are kept for the fields that both events
+    /// have, unless a merge strategy is set for them.
+    #[configurable(derived)]
+    pub left: JoinInputConfig,
+
+    /// The right-hand side of the join.
+    #[configurable(derived)]
+    pub right: JoinInputConfig,
+
+    /// The maximum period of time to hold an event for, waiting for a matching event from the other side, in
+    /// milliseconds.
+    ///
+    /// Once this window expires, the event is sent to the `<transform_name>.unmatched` output.
+    #[serde(default = "default_window_ms")]
+    pub window_ms: u64,
+
+    /// The interval to check for and flush any expired events, in milliseconds.
+    #[serde(default = "default_flush_period_ms")]
+    pub flush_period_ms: u64,
+
+    /// The maximum number of events to hold, waiting for a matching event from the other side.
+    ///
+    /// Once this many events are held, events that don't match any of them are sent to the
+    /// `<transform_name>.unmatched` output right away.
+    #[serde(default = "default_max_events")]
+    pub max_events: usize,
+
+    /// A map of field names to custom merge strategies.
+    ///
+    /// For each field specified, the given strategy will be used for combining matched events rather than the default
+    /// behavior, which keeps the value of the left event for the fields that both events have.
+    #[serde(default)]
+    pub merge_strategies: IndexMap<String, MergeStrategy>,
+}
+
+/// Configuration for one side of a join.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct JoinInputConfig {
+    /// A list of upstream source or transform IDs that make up this side of the join.
+    ///
+    /// Wildcards (`*`) are supported.
+    pub inputs: Vec<String>,
+
+    /// The key to join events on.
+    ///
+    /// Events from either side are matched with each other when their keys render to the same value.
+    #[configurable(metadata(templatable))]
+    pub key: Template,
+}
+
+const fn default_window_ms() -> u64 {
+    30000
+}
+
+const fn default_flush_period_ms() -> u64 {
+    1000
+}
+
+const fn default_max_events() -> usize {
+    100_000
+}
+
+inventory::submit! {
+    TransformDescription::new::<JoinConfig>("join")
+}
+
+impl GenerateConfig for JoinConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            left: JoinInputConfig {
+                inputs: vec!["requests".to_owned()],
+                key: Template::try_from("{{ request_id }}").unwrap(),
+            },
+            right: JoinInputConfig {
+                inputs: vec!["responses".to_owned()],
+                key: Template::try_from("{{ request_id }}").unwrap(),
+            },
+            window_ms: default_window_ms(),
+            flush_period_ms: default_flush_period_ms(),
+            max_events: default_max_events(),
+            merge_strategies: IndexMap::new(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "join")]
+impl TransformConfig for JoinConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Err("this transform must be expanded".into())
+    }
+
+    fn expand(
+        &mut self,
+        name: &ComponentKey,
+        inputs: &[String],
+    ) -> crate::Result<Option<InnerTopology>> {
+        if !inputs.is_empty() {
+            return Err(
+                "the inputs of a join are set with `left.inputs` and `right.inputs`, not `inputs`"
+                    .into(),
+            );
+        }
+        if self.left.inputs.is_empty() || self.right.inputs.is_empty() {
+            return Err("both `left.inputs` and `right.inputs` must be set".into());
+        }
+        if self.max_events == 0 {
+            return Err("`max_events` must be greater than zero".into());
+        }
+
+        // Events are tagged with the side they came from before they're merged into a single stream, and the
+        // unmatched ones are split off again afterwards, since a task transform only has a single output.
+        let left_name = name.join("left");
+        let right_name = name.join("right");
+        let window_name = name.join("window");
+
+        let mut result = InnerTopology {
+            inner: Default::default(),
+            outputs: vec![(
+                name.clone(),
+                vec![
+                    Output::default(DataType::Log),
+                    Output::default(DataType::Log).with_port(UNMATCHED_OUTPUT),
+                ],
+            )],
+        };
+        result.inner.insert(
+            left_name.clone(),
+            InnerTopologyTransform {
+                inputs: self.left.inputs.clone(),
+                inner: Box::new(JoinSideConfig {
+                    side: JoinSide::Left,
+                }),
+            },
+        );
+        result.inner.insert(
+            right_name.clone(),
+            InnerTopologyTransform {
+                inputs: self.right.inputs.clone(),
+                inner: Box::new(JoinSideConfig {
+                    side: JoinSide::Right,
+                }),
+            },
+        );
+        result.inner.insert(
+            window_name.clone(),
+            InnerTopologyTransform {
+                inputs: vec![left_name.into_id(), right_name.into_id()],
+                inner: Box::new(JoinWindowConfig {
+                    left_key: self.left.key.clone(),
+                    right_key: self.right.key.clone(),
+                    window_ms: self.window_ms,
+                    flush_period_ms: self.flush_period_ms,
+                    max_events: self.max_events,
+                    merge_strategies: self.merge_strategies.clone(),
+                }),
+            },
+        );
+        result.inner.insert(
+            name.clone(),
+            InnerTopologyTransform {
+                inputs: vec![window_name.into_id()],
+                inner: Box::new(JoinOutputConfig {}),
+            },
+        );
+        Ok(Some(result))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
+        vec![
+            Output::default(DataType::Log),
+            Output::default(DataType::Log).with_port(UNMATCHED_OUTPUT),
+        ]
+    }
+
+    fn transform_type(&self) -> &'static str {
+        "join"
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum JoinSide {
+    Left,
+    Right,
+}
+
+impl JoinSide {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+
+    fn take_from(event: &mut LogEvent) -> Option<Self> {
+        match take_metadata_field(event, SIDE_METADATA_KEY)? {
+            Value::Bytes(side) if side.as_ref() == b"left" => Some(Self::Left),
+            Value::Bytes(side) if side.as_ref() == b"right" => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+fn set_metadata_field(event: &mut LogEvent, key: &str, value: Value) {
+    if let Value::Object(fields) = event.metadata_mut().value_mut() {
+        fields.insert(key.to_owned(), value);
+    }
+}
+
+fn take_metadata_field(event: &mut LogEvent, key: &str) -> Option<Value> {
+    match event.metadata_mut().value_mut() {
+        Value::Object(fields) => fields.remove(key),
+        _ => None,
+    }
+}
+
+/// Tags the events of one side of a join with the side they came from.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct JoinSideConfig {
+    side: JoinSide,
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "join_side")]
+impl TransformConfig for JoinSideConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::function(JoinSideTagger { side: self.side }))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
+        vec![Output::default(DataType::Log)]
+    }
+
+    fn transform_type(&self) -> &'static str {
+        "join_side"
+    }
+
+    fn enable_concurrency(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+struct JoinSideTagger {
+    side: JoinSide,
+}
+
+impl FunctionTransform for JoinSideTagger {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        set_metadata_field(
+            event.as_mut_log(),
+            SIDE_METADATA_KEY,
+            Value::from(self.side.as_str()),
+        );
+        output.push(event);
+    }
+}
+
+/// Holds the events of both sides of a join until they're matched, or their window expires.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct JoinWindowConfig {
+    left_key: Template,
+    right_key: Template,
+    window_ms: u64,
+    flush_period_ms: u64,
+    max_events: usize,
+    merge_strategies: IndexMap<String, MergeStrategy>,
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "join_window")]
+impl TransformConfig for JoinWindowConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::event_task(Join::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
+        vec![Output::default(DataType::Log)]
+    }
+
+    fn transform_type(&self) -> &'static str {
+        "join_window"
+    }
+}
+
+/// Splits the events coming out of the window of a join into matched and unmatched ones.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct JoinOutputConfig {}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "join_output")]
+impl TransformConfig for JoinOutputConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::synchronous(JoinOutput))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
+        vec![
+            Output::default(DataType::Log),
+            Output::default(DataType::Log).with_port(UNMATCHED_OUTPUT),
+        ]
+    }
+
+    fn transform_type(&self) -> &'static str {
+        "join_output"
+    }
+
+    fn enable_concurrency(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+struct JoinOutput;
+
+impl SyncTransform for JoinOutput {
+    fn transform(&mut self, mut event: Event, output: &mut TransformOutputsBuf) {
+        if take_metadata_field(event.as_mut_log(), UNMATCHED_METADATA_KEY).is_some() {
+            output.push_named(UNMATCHED_OUTPUT, event);
+        } else {
+            output.push(event);
+        }
+    }
+}
+
+/// Events of one side of the join waiting for a match, oldest first.
+///
+/// Events are matched as soon as an event from the other side arrives, so all of the pending events of a key are always
+/// from the same side.
+#[derive(Debug)]
+struct PendingEvents {
+    side: JoinSide,
+    events: VecDeque<(Instant, LogEvent)>,
+}
+
+pub struct Join {
+    left_key: Template,
+    right_key: Template,
+    window: Duration,
+    flush_period: Duration,
+    max_events: usize,
+    merge_strategies: IndexMap<String, MergeStrategy>,
+    pending: HashMap<String, PendingEvents>,
+    /// The number of events held across all of the keys.
+    pending_count: usize,
+}
+
+impl Join {
+    fn new(config: &JoinWindowConfig) -> Self {
+        Self {
+            left_key: config.left_key.clone(),
+            right_key: config.right_key.clone(),
+            window: Duration::from_millis(config.window_ms),
+            flush_period: Duration::from_millis(config.flush_period_ms),
+            max_events: config.max_events,
+            merge_strategies: config.merge_strategies.clone(),
+            pending: HashMap::new(),
+            pending_count: 0,
+        }
+    }
+
+    fn transform_one(&mut self, output: &mut Vec<Event>, event: Event, now: Instant) {
+        let mut event = event.into_log();
+        let side = JoinSide::take_from(&mut event).unwrap_or(JoinSide::Left);
+        let template = match side {
+            JoinSide::Left => &self.left_key,
+            JoinSide::Right => &self.right_key,
+        };
+
+        let key = match template.render_string(&event) {
+            Ok(key) => key,
+            Err(error) => {
+                emit!(TemplateRenderingError {
+                    error,
+                    field: Some("key"),
+                    drop_event: false,
+                });
+                // An event without a key can never be matched.
+                output.push(unmatched(event));
+                return;
+            }
+        };
+
+        match self.pending.entry(key) {
+            hash_map::Entry::Occupied(mut entry) if entry.get().side != side => {
+                let (_, other) = entry
+                    .get_mut()
+                    .events
+                    .pop_front()
+                    .expect("pending events are never empty");
+                if entry.get().events.is_empty() {
+                    entry.remove();
+                }
+                self.pending_count -= 1;
+
+                let (left, right) = match side {
+                    JoinSide::Left => (event, other),
+                    JoinSide::Right => (other, event),
+                };
+                output.push(merge(&self.merge_strategies, left, right).into());
+            }
+            // Events that can't be held are unmatched right away.
+            _ if self.pending_count >= self.max_events => {
+                emit!(JoinMaxEventsReached);
+                output.push(unmatched(event));
+            }
+            hash_map::Entry::Occupied(mut entry) => {
+                entry.get_mut().events.push_back((now, event));
+                self.pending_count += 1;
+            }
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(PendingEvents {
+                    side,
+                    events: VecDeque::from([(now, event)]),
+                });
+                self.pending_count += 1;
+            }
+        }
+    }
+
+    fn flush_into(&mut self, output: &mut Vec<Event>, now: Instant) {
+        let window = self.window;
+        let pending_count = &mut self.pending_count;
+        self.pending.retain(|_, pending| {
+            while let Some((arrived, _)) = pending.events.front() {
+                if now.saturating_duration_since(*arrived) < window {
+                    break;
+                }
+                let (_, event) = pending.events.pop_front().expect("front was just checked");
+                output.push(unmatched(event));
+                *pending_count -= 1;
+            }
+            !pending.events.is_empty()
+        });
+    }
+
+    fn flush_all_into(&mut self, output: &mut Vec<Event>) {
+        self.pending_count = 0;
+        for (_, pending) in self.pending.drain() {
+            output.extend(
+                pending
+                    .events
+                    .into_iter()
+                    .map(|(_, event)| unmatched(event)),
+            );
+        }
+    }
+}
+
+/// Merges a matched pair of events.
+///
+/// Fields without a merge strategy keep the value of the left event, rather than being combined like in the `reduce`
+/// transform.
+fn merge(
+    merge_strategies: &IndexMap<String, MergeStrategy>,
+    left: LogEvent,
+    right: LogEvent,
+) -> LogEvent {
+    let mut strategies = merge_strategies.clone();
+    if let Value::Object(fields) = left.value() {
+        for key in fields.keys() {
+            strategies
+                .entry(key.clone())
+                .or_insert(MergeStrategy::Discard);
+        }
+    }
+
+    let mut state = ReduceState::new(left, &strategies);
+    state.add_event(right, &strategies);
+    state.flush()
+}
+
+fn unmatched(mut event: LogEvent) -> Event {
+    set_metadata_field(&mut event, UNMATCHED_METADATA_KEY, Value::Boolean(true));
+    event.into()
+}
+
+impl TaskTransform<Event> for Join {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        let mut flush_stream = tokio::time::interval(me.flush_period);
+
+        Box::pin(
+            stream! {
+              loop {
+                let mut output = Vec::new();
+                let done = tokio::select! {
+                    _ = flush_stream.tick() => {
+                      me.flush_into(&mut output, Instant::now());
+                      false
+                    }
+                    maybe_event = input_rx.next() => {
+                      match maybe_event {
+                        None => {
+                          me.flush_all_into(&mut output);
+                          true
+                        }
+                        Some(event) => {
+                          me.transform_one(&mut output, event, Instant::now());
+                          false
+                        }
+                      }
+                    }
+                };
+                yield stream::iter(output.into_iter());
+                if done { break }
+              }
+            }
+            .flatten(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::config::TransformOuter;
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<JoinConfig>();
+    }
+
+    fn config(toml: &str) -> JoinConfig {
+        toml::from_str::<JoinConfig>(toml).unwrap()
+    }
+
+    fn join() -> Join {
+        let config = config(
+            r#"
+left.inputs = ["requests"]
+left.key = "{{ request_id }}"
+right.inputs = ["responses"]
+right.key = "{{ id }}"
+window_ms = 1000
+"#,
+        );
+        Join::new(&JoinWindowConfig {
+            left_key: config.left.key,
+            right_key: config.right.key,
+            window_ms: config.window_ms,
+            flush_period_ms: config.flush_period_ms,
+            max_events: config.max_events,
+            merge_strategies: config.merge_strategies,
+        })
+    }
+
+    fn tagged(side: JoinSide, fields: &[(&str, &str)]) -> Event {
+        let mut event = LogEvent::default();
+        for (key, value) in fields {
+            event.insert(*key, *value);
+        }
+        let mut output = OutputBuffer::with_capacity(1);
+        JoinSideTagger { side }.transform(&mut output, event.into());
+        output.into_events().next().unwrap()
+    }
+
+    fn split(events: Vec<Event>) -> (Vec<LogEvent>, Vec<LogEvent>) {
+        let mut output = TransformOutputsBuf::new_with_capacity(
+            JoinOutputConfig {}.outputs(&schema::Definition::any()),
+            events.len(),
+        );
+        for event in events {
+            JoinOutput.transform(event, &mut output);
+        }
+        let matched = output.drain().map(Event::into_log).collect();
+        let unmatched = output
+            .drain_named(UNMATCHED_OUTPUT)
+            .map(Event::into_log)
+            .collect();
+        (matched, unmatched)
+    }
+
+    #[test]
+    fn joins_matching_events() {
+        let mut join = join();
+        let start = Instant::now();
+        let mut output = Vec::new();
+
+        join.transform_one(
+            &mut output,
+            tagged(
+                JoinSide::Right,
+                &[("id", "1"), ("message", "response"), ("status", "ok")],
+            ),
+            start,
+        );
+        join.transform_one(
+            &mut output,
+            tagged(JoinSide::Left, &[("request_id", "2"), ("message", "other")]),
+            start,
+        );
+        assert!(output.is_empty());
+
+        join.transform_one(
+            &mut output,
+            tagged(
+                JoinSide::Left,
+                &[("request_id", "1"), ("message", "request")],
+            ),
+            start,
+        );
+
+        let (matched, unmatched) = split(output);
+        assert!(unmatched.is_empty());
+        assert_eq!(matched.len(), 1);
+        // Fields of the left event win, regardless of which one arrived first.
+        assert_eq!(matched[0]["message"], "request".into());
+        assert_eq!(matched[0]["status"], "ok".into());
+        assert_eq!(matched[0]["id"], "1".into());
+        assert!(matches!(
+            matched[0].metadata().value(),
+            Value::Object(fields) if !fields.contains_key(SIDE_METADATA_KEY)
+        ));
+    }
+
+    #[test]
+    fn keeps_left_values_unless_merge_strategy_is_set() {
+        let mut join = join();
+        join.merge_strategies
+            .insert("retries".to_owned(), MergeStrategy::Sum);
+        let start = Instant::now();
+        let mut output = Vec::new();
+
+        for side in [JoinSide::Left, JoinSide::Right] {
+            let (key, bytes, retries) = match side {
+                JoinSide::Left => ("request_id", 100, 1),
+                JoinSide::Right => ("id", 2000, 2),
+            };
+            let mut event = tagged(side, &[(key, "1")]);
+            event.as_mut_log().insert("bytes", bytes);
+            event.as_mut_log().insert("retries", retries);
+            join.transform_one(&mut output, event, start);
+        }
+
+        let (matched, _) = split(output);
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0]["bytes"], 100.into());
+        assert_eq!(matched[0]["retries"], 3.into());
+    }
+
+    #[test]
+    fn unmatches_events_past_max_events() {
+        let mut join = join();
+        join.max_events = 2;
+        let start = Instant::now();
+        let mut output = Vec::new();
+
+        for request_id in ["1", "2", "3"] {
+            join.transform_one(
+                &mut output,
+                tagged(JoinSide::Left, &[("request_id", request_id)]),
+                start,
+            );
+        }
+        let (matched, unmatched) = split(std::mem::take(&mut output));
+        assert!(matched.is_empty());
+        assert_eq!(unmatched.len(), 1);
+        assert_eq!(unmatched[0]["request_id"], "3".into());
+
+        // Held events can still be matched, which makes room for new ones.
+        join.transform_one(&mut output, tagged(JoinSide::Right, &[("id", "1")]), start);
+        join.transform_one(
+            &mut output,
+            tagged(JoinSide::Left, &[("request_id", "4")]),
+            start,
+        );
+        let (matched, unmatched) = split(output);
+        assert_eq!(matched.len(), 1);
+        assert!(unmatched.is_empty());
+        assert_eq!(join.pending_count, 2);
+        assert!(join.pending.contains_key("4"));
+    }
+
+    #[test]
+    fn matches_events_in_order() {
+        let mut join = join();
+        let start = Instant::now();
+        let mut output = Vec::new();
+
+        for message in ["first", "second"] {
+            join.transform_one(
+                &mut output,
+                tagged(JoinSide::Left, &[("request_id", "1"), ("message", message)]),
+                start,
+            );
+        }
+        join.transform_one(&mut output, tagged(JoinSide::Right, &[("id", "1")]), start);
+
+        let (matched, _) = split(output);
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0]["message"], "first".into());
+        assert_eq!(join.pending["1"].events.len(), 1);
+    }
+
+    #[test]
+    fn flushes_expired_events_as_unmatched() {
+        let mut join = join();
+        let start = Instant::now();
+        let mut output = Vec::new();
+
+        join.transform_one(
+            &mut output,
+            tagged(JoinSide::Left, &[("request_id", "1")]),
+            start,
+        );
+        join.transform_one(
+            &mut output,
+            tagged(JoinSide::Left, &[("request_id", "2")]),
+            start + Duration::from_millis(500),
+        );
+        // An event without a key can't be matched, so it's unmatched right away.
+        join.transform_one(
+            &mut output,
+            tagged(JoinSide::Right, &[("message", "no key")]),
+            start,
+        );
+
+        join.flush_into(&mut output, start + Duration::from_millis(1000));
+        let (matched, unmatched) = split(std::mem::take(&mut output));
+        assert!(matched.is_empty());
+        assert_eq!(unmatched.len(), 2);
+        assert_eq!(unmatched[0]["message"], "no key".into());
+        assert_eq!(unmatched[1]["request_id"], "1".into());
+
+        join.flush_all_into(&mut output);
+        let (matched, unmatched) = split(output);
+        assert!(matched.is_empty());
+        assert_eq!(unmatched.len(), 1);
+        assert_eq!(unmatched[0]["request_id"], "2".into());
+        assert!(join.pending.is_empty());
+    }
+
+    #[test]
+    fn expanding() {
+        let config = JoinConfig::generate_config();
+        let config: JoinConfig = config.try_into().unwrap();
+        let outer = TransformOuter {
+            inputs: Vec::<String>::new(),
+            inner: Box::new(config),
+        };
+        let name = ComponentKey::from("foo");
+        let mut transforms = IndexMap::new();
+        let mut expansions = IndexMap::new();
+        outer
+            .expand(name, &HashSet::new(), &mut transforms, &mut expansions)
+            .unwrap();
+
+        let inputs = transforms
+            .iter()
+            .map(|(key, transform)| (key.to_string(), transform.inputs.clone()))
+            .collect::<IndexMap<String, Vec<String>>>();
+        assert_eq!(inputs["foo.left"], vec!["requests".to_string()]);
+        assert_eq!(inputs["foo.right"], vec!["responses".to_string()]);
+        assert_eq!(
+            inputs["foo.window"],
+            vec!["foo.left".to_string(), "foo.right".to_string()]
+        );
+        assert_eq!(inputs["foo"], vec!["foo.window".to_string()]);
+    }
+
+    #[test]
+    fn rejects_outer_inputs() {
+        let mut config = JoinConfig::generate_config()
+            .try_into::<JoinConfig>()
+            .unwrap();
+        assert!(config
+            .expand(&ComponentKey::from("foo"), &["requests".to_string()])
+            .is_err());
+    }
+}
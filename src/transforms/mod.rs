@@ -14,6 +14,8 @@ pub mod dedupe;
 pub mod filter;
 #[cfg(feature = "transforms-geoip")]
 pub mod geoip;
+#[cfg(feature = "transforms-join")]
+pub mod join;
 #[cfg(feature = "transforms-log_to_metric")]
 pub mod log_to_metric;
 #[cfg(feature = "transforms-lua")]
@@ -24,6 +26,8 @@ pub mod metric_to_log;
 pub mod pipelines;
 #[cfg(feature = "transforms-reduce")]
 pub mod reduce;
+#[cfg(feature = "transforms-relabel")]
+pub mod relabel;
 #[cfg(feature = "transforms-remap")]
 pub mod remap;
 #[cfg(feature = "transforms-route")]
@@ -32,6 +36,8 @@ pub mod route;
 pub mod sample;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
 pub mod tag_cardinality_limit;
+#[cfg(feature = "transforms-tail_sample")]
+pub mod tail_sample;
 #[cfg(feature = "transforms-throttle")]
 pub mod throttle;
 
@@ -80,6 +86,10 @@ pub enum Transforms {
     #[cfg(feature = "transforms-geoip")]
     Geoip(#[configurable(derived)] geoip::GeoipConfig),
 
+    /// Join.
+    #[cfg(feature = "transforms-join")]
+    Join(#[configurable(derived)] join::JoinConfig),
+
     /// Log to metric.
     #[cfg(feature = "transforms-log_to_metric")]
     LogToMetric(#[configurable(derived)] log_to_metric::LogToMetricConfig),
@@ -100,6 +110,10 @@ pub enum Transforms {
     #[cfg(feature = "transforms-reduce")]
     Reduce(#[configurable(derived)] reduce::ReduceConfig),
 
+    /// Relabel.
+    #[cfg(feature = "transforms-relabel")]
+    Relabel(#[configurable(derived)] relabel::RelabelConfig),
+
     /// Remap.
     #[cfg(feature = "transforms-remap")]
     Remap(#[configurable(derived)] remap::RemapConfig),
@@ -118,6 +132,10 @@ pub enum Transforms {
     #[cfg(feature = "transforms-tag_cardinality_limit")]
     TagCardinalityLimit(#[configurable(derived)] tag_cardinality_limit::TagCardinalityLimitConfig),
 
+    /// Tail sample.
+    #[cfg(feature = "transforms-tail_sample")]
+    TailSample(#[configurable(derived)] tail_sample::TailSampleConfig),
+
     /// Throttle.
     #[cfg(feature = "transforms-throttle")]
     Throttle(#[configurable(derived)] throttle::ThrottleConfig),
@@ -138,6 +156,8 @@ impl TransformConfig for Transforms {
             Transforms::Filter(inner) => inner.build(globals).await,
             #[cfg(feature = "transforms-geoip")]
             Transforms::Geoip(inner) => inner.build(globals).await,
+            #[cfg(feature = "transforms-join")]
+            Transforms::Join(inner) => inner.build(globals).await,
             #[cfg(feature = "transforms-log_to_metric")]
             Transforms::LogToMetric(inner) => inner.build(globals).await,
             #[cfg(feature = "transforms-lua")]
@@ -148,6 +168,8 @@ impl TransformConfig for Transforms {
             Transforms::Pipelines(inner) => inner.build(globals).await,
             #[cfg(feature = "transforms-reduce")]
             Transforms::Reduce(inner) => inner.build(globals).await,
+            #[cfg(feature = "transforms-relabel")]
+            Transforms::Relabel(inner) => inner.build(globals).await,
             #[cfg(feature = "transforms-remap")]
             Transforms::Remap(inner) => inner.build(globals).await,
             #[cfg(feature = "transforms-route")]
@@ -156,6 +178,8 @@ impl TransformConfig for Transforms {
             Transforms::Sample(inner) => inner.build(globals).await,
             #[cfg(feature = "transforms-tag_cardinality_limit")]
             Transforms::TagCardinalityLimit(inner) => inner.build(globals).await,
+            #[cfg(feature = "transforms-tail_sample")]
+            Transforms::TailSample(inner) => inner.build(globals).await,
             #[cfg(feature = "transforms-throttle")]
             Transforms::Throttle(inner) => inner.build(globals).await,
             #[allow(unreachable_patterns)]
@@ -175,6 +199,8 @@ impl TransformConfig for Transforms {
             Transforms::Filter(inner) => inner.input(),
             #[cfg(feature = "transforms-geoip")]
             Transforms::Geoip(inner) => inner.input(),
+            #[cfg(feature = "transforms-join")]
+            Transforms::Join(inner) => inner.input(),
             #[cfg(feature = "transforms-log_to_metric")]
             Transforms::LogToMetric(inner) => inner.input(),
             #[cfg(feature = "transforms-lua")]
@@ -185,6 +211,8 @@ impl TransformConfig for Transforms {
             Transforms::Pipelines(inner) => inner.input(),
             #[cfg(feature = "transforms-reduce")]
             Transforms::Reduce(inner) => inner.input(),
+            #[cfg(feature = "transforms-relabel")]
+            Transforms::Relabel(inner) => inner.input(),
             #[cfg(feature = "transforms-remap")]
             Transforms::Remap(inner) => inner.input(),
             #[cfg(feature = "transforms-route")]
@@ -193,6 +221,8 @@ impl TransformConfig for Transforms {
             Transforms::Sample(inner) => inner.input(),
             #[cfg(feature = "transforms-tag_cardinality_limit")]
             Transforms::TagCardinalityLimit(inner) => inner.input(),
+            #[cfg(feature = "transforms-tail_sample")]
+            Transforms::TailSample(inner) => inner.input(),
             #[cfg(feature = "transforms-throttle")]
             Transforms::Throttle(inner) => inner.input(),
             #[allow(unreachable_patterns)]
@@ -213,6 +243,8 @@ impl TransformConfig for Transforms {
             Transforms::Filter(inner) => inner.outputs(merged_definition),
             #[cfg(feature = "transforms-geoip")]
             Transforms::Geoip(inner) => inner.outputs(merged_definition),
+            #[cfg(feature = "transforms-join")]
+            Transforms::Join(inner) => inner.outputs(merged_definition),
             #[cfg(feature = "transforms-log_to_metric")]
             Transforms::LogToMetric(inner) => inner.outputs(merged_definition),
             #[cfg(feature = "transforms-lua")]
@@ -223,6 +255,8 @@ impl TransformConfig for Transforms {
             Transforms::Pipelines(inner) => inner.outputs(merged_definition),
             #[cfg(feature = "transforms-reduce")]
             Transforms::Reduce(inner) => inner.outputs(merged_definition),
+            #[cfg(feature = "transforms-relabel")]
+            Transforms::Relabel(inner) => inner.outputs(merged_definition),
             #[cfg(feature = "transforms-remap")]
             Transforms::Remap(inner) => inner.outputs(merged_definition),
             #[cfg(feature = "transforms-route")]
@@ -231,6 +265,8 @@ impl TransformConfig for Transforms {
             Transforms::Sample(inner) => inner.outputs(merged_definition),
             #[cfg(feature = "transforms-tag_cardinality_limit")]
             Transforms::TagCardinalityLimit(inner) => inner.outputs(merged_definition),
+            #[cfg(feature = "transforms-tail_sample")]
+            Transforms::TailSample(inner) => inner.outputs(merged_definition),
             #[cfg(feature = "transforms-throttle")]
             Transforms::Throttle(inner) => inner.outputs(merged_definition),
             #[allow(unreachable_patterns)]
@@ -250,6 +286,8 @@ impl TransformConfig for Transforms {
             Transforms::Filter(inner) => inner.transform_type(),
             #[cfg(feature = "transforms-geoip")]
             Transforms::Geoip(inner) => inner.transform_type(),
+            #[cfg(feature = "transforms-join")]
+            Transforms::Join(inner) => inner.transform_type(),
             #[cfg(feature = "transforms-log_to_metric")]
             Transforms::LogToMetric(inner) => inner.transform_type(),
             #[cfg(feature = "transforms-lua")]
@@ -260,6 +298,8 @@ impl TransformConfig for Transforms {
             Transforms::Pipelines(inner) => inner.transform_type(),
             #[cfg(feature = "transforms-reduce")]
             Transforms::Reduce(inner) => inner.transform_type(),
+            #[cfg(feature = "transforms-relabel")]
+            Transforms::Relabel(inner) => inner.transform_type(),
             #[cfg(feature = "transforms-remap")]
             Transforms::Remap(inner) => inner.transform_type(),
             #[cfg(feature = "transforms-route")]
@@ -268,6 +308,8 @@ impl TransformConfig for Transforms {
             Transforms::Sample(inner) => inner.transform_type(),
             #[cfg(feature = "transforms-tag_cardinality_limit")]
             Transforms::TagCardinalityLimit(inner) => inner.transform_type(),
+            #[cfg(feature = "transforms-tail_sample")]
+            Transforms::TailSample(inner) => inner.transform_type(),
             #[cfg(feature = "transforms-throttle")]
             Transforms::Throttle(inner) => inner.transform_type(),
             #[allow(unreachable_patterns)]
@@ -287,6 +329,8 @@ impl TransformConfig for Transforms {
             Transforms::Filter(inner) => inner.typetag_name(),
             #[cfg(feature = "transforms-geoip")]
             Transforms::Geoip(inner) => inner.typetag_name(),
+            #[cfg(feature = "transforms-join")]
+            Transforms::Join(inner) => inner.typetag_name(),
             #[cfg(feature = "transforms-log_to_metric")]
             Transforms::LogToMetric(inner) => inner.typetag_name(),
             #[cfg(feature = "transforms-lua")]
@@ -297,6 +341,8 @@ impl TransformConfig for Transforms {
             Transforms::Pipelines(inner) => inner.typetag_name(),
             #[cfg(feature = "transforms-reduce")]
             Transforms::Reduce(inner) => inner.typetag_name(),
+            #[cfg(feature = "transforms-relabel")]
+            Transforms::Relabel(inner) => inner.typetag_name(),
             #[cfg(feature = "transforms-remap")]
             Transforms::Remap(inner) => inner.typetag_name(),
             #[cfg(feature = "transforms-route")]
@@ -305,6 +351,8 @@ impl TransformConfig for Transforms {
             Transforms::Sample(inner) => inner.typetag_name(),
             #[cfg(feature = "transforms-tag_cardinality_limit")]
             Transforms::TagCardinalityLimit(inner) => inner.typetag_name(),
+            #[cfg(feature = "transforms-tail_sample")]
+            Transforms::TailSample(inner) => inner.typetag_name(),
             #[cfg(feature = "transforms-throttle")]
             Transforms::Throttle(inner) => inner.typetag_name(),
             #[allow(unreachable_patterns)]
@@ -324,6 +372,8 @@ impl TransformConfig for Transforms {
             Transforms::Filter(inner) => inner.typetag_deserialize(),
             #[cfg(feature = "transforms-geoip")]
             Transforms::Geoip(inner) => inner.typetag_deserialize(),
+            #[cfg(feature = "transforms-join")]
+            Transforms::Join(inner) => inner.typetag_deserialize(),
             #[cfg(feature = "transforms-log_to_metric")]
             Transforms::LogToMetric(inner) => inner.typetag_deserialize(),
             #[cfg(feature = "transforms-lua")]
@@ -334,6 +384,8 @@ impl TransformConfig for Transforms {
             Transforms::Pipelines(inner) => inner.typetag_deserialize(),
             #[cfg(feature = "transforms-reduce")]
             Transforms::Reduce(inner) => inner.typetag_deserialize(),
+            #[cfg(feature = "transforms-relabel")]
+            Transforms::Relabel(inner) => inner.typetag_deserialize(),
             #[cfg(feature = "transforms-remap")]
             Transforms::Remap(inner) => inner.typetag_deserialize(),
             #[cfg(feature = "transforms-route")]
@@ -342,6 +394,8 @@ impl TransformConfig for Transforms {
             Transforms::Sample(inner) => inner.typetag_deserialize(),
             #[cfg(feature = "transforms-tag_cardinality_limit")]
             Transforms::TagCardinalityLimit(inner) => inner.typetag_deserialize(),
+            #[cfg(feature = "transforms-tail_sample")]
+            Transforms::TailSample(inner) => inner.typetag_deserialize(),
             #[cfg(feature = "transforms-throttle")]
             Transforms::Throttle(inner) => inner.typetag_deserialize(),
             #[allow(unreachable_patterns)]
@@ -362,6 +416,8 @@ impl TransformConfig for Transforms {
             Transforms::Filter(inner) => inner.nestable(parents),
             #[cfg(feature = "transforms-geoip")]
             Transforms::Geoip(inner) => inner.nestable(parents),
+            #[cfg(feature = "transforms-join")]
+            Transforms::Join(inner) => inner.nestable(parents),
             #[cfg(feature = "transforms-log_to_metric")]
             Transforms::LogToMetric(inner) => inner.nestable(parents),
             #[cfg(feature = "transforms-lua")]
@@ -372,6 +428,8 @@ impl TransformConfig for Transforms {
             Transforms::Pipelines(inner) => inner.nestable(parents),
             #[cfg(feature = "transforms-reduce")]
             Transforms::Reduce(inner) => inner.nestable(parents),
+            #[cfg(feature = "transforms-relabel")]
+            Transforms::Relabel(inner) => inner.nestable(parents),
             #[cfg(feature = "transforms-remap")]
             Transforms::Remap(inner) => inner.nestable(parents),
             #[cfg(feature = "transforms-route")]
@@ -380,6 +438,8 @@ impl TransformConfig for Transforms {
             Transforms::Sample(inner) => inner.nestable(parents),
             #[cfg(feature = "transforms-tag_cardinality_limit")]
             Transforms::TagCardinalityLimit(inner) => inner.nestable(parents),
+            #[cfg(feature = "transforms-tail_sample")]
+            Transforms::TailSample(inner) => inner.nestable(parents),
             #[cfg(feature = "transforms-throttle")]
             Transforms::Throttle(inner) => inner.nestable(parents),
             #[allow(unreachable_patterns)]
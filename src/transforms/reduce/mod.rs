@@ -95,14 +95,14 @@ impl TransformConfig for ReduceConfig {
 }
 
 #[derive(Debug)]
-struct ReduceState {
+pub(crate) struct ReduceState {
     fields: HashMap<String, Box<dyn ReduceValueMerger>>,
     stale_since: Instant,
     metadata: EventMetadata,
 }
 
 impl ReduceState {
-    fn new(e: LogEvent, strategies: &IndexMap<String, MergeStrategy>) -> Self {
+    pub(crate) fn new(e: LogEvent, strategies: &IndexMap<String, MergeStrategy>) -> Self {
         let (value, metadata) = e.into_parts();
 
         let fields = if let Value::Object(fields) = value {
@@ -133,7 +133,7 @@ impl ReduceState {
         }
     }
 
-    fn add_event(&mut self, e: LogEvent, strategies: &IndexMap<String, MergeStrategy>) {
+    pub(crate) fn add_event(&mut self, e: LogEvent, strategies: &IndexMap<String, MergeStrategy>) {
         let (value, metadata) = e.into_parts();
         self.metadata.merge(metadata);
 
@@ -170,7 +170,7 @@ impl ReduceState {
         self.stale_since = Instant::now();
     }
 
-    fn flush(mut self) -> LogEvent {
+    pub(crate) fn flush(mut self) -> LogEvent {
         let mut event = LogEvent::new_with_metadata(self.metadata);
         for (k, v) in self.fields.drain() {
             if let Err(error) = v.insert_into(k, &mut event) {
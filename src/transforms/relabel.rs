@@ -0,0 +1,520 @@
+use md5::{Digest, Md5};
+use regex::{Captures, Regex};
+use snafu::{ResultExt, Snafu};
+use vector_config::configurable_component;
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, Output, TransformConfig, TransformContext,
+        TransformDescription,
+    },
+    event::{
+        metric::{Metric, MetricSeries},
+        Event,
+    },
+    internal_events::RelabelEventDiscarded,
+    schema,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+/// The label that holds the name of a metric, as in Prometheus.
+const NAME_LABEL: &str = "__name__";
+
+/// Configuration for the `relabel` transform.
+#[configurable_component(transform)]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct RelabelConfig {
+    /// The relabeling rules to apply to each metric, in order.
+    ///
+    /// These follow the semantics of Prometheus' `relabel_configs`, with the tags of a metric as its labels, and its
+    /// name as the `__name__` label.
+    pub relabel_configs: Vec<RelabelRuleConfig>,
+}
+
+/// A single relabeling rule.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct RelabelRuleConfig {
+    /// The labels whose values are concatenated, with `separator`, and matched against `regex`.
+    ///
+    /// Missing labels are treated as empty values.
+    #[serde(default)]
+    pub source_labels: Vec<String>,
+
+    /// The separator placed between the values of `source_labels`.
+    #[serde(default = "default_separator")]
+    pub separator: String,
+
+    /// The label that the result of a `replace` or `hashmod` action is written to.
+    ///
+    /// For `replace`, regex capture groups can be referenced, such as `$1`.
+    pub target_label: Option<String>,
+
+    /// The regular expression that values are matched against.
+    ///
+    /// The regular expression is anchored on both ends.
+    #[serde(default = "default_regex")]
+    pub regex: String,
+
+    /// The modulus to take of the hash of the source label values, for the `hashmod` action.
+    pub modulus: Option<u64>,
+
+    /// The value written to the target label for the `replace` action, or the new label name for the `labelmap` action.
+    ///
+    /// Regex capture groups can be referenced, such as `$1`.
+    #[serde(default = "default_replacement")]
+    pub replacement: String,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub action: RelabelAction,
+}
+
+/// The action performed by a relabeling rule.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RelabelAction {
+    /// Writes `replacement`, with capture groups expanded, to `target_label` if `regex` matches the source label
+    /// values.
+    ///
+    /// If the result is empty, `target_label` is removed instead.
+    Replace,
+
+    /// Drops metrics for which `regex` doesn't match the source label values.
+    Keep,
+
+    /// Drops metrics for which `regex` matches the source label values.
+    Drop,
+
+    /// Writes the hash of the source label values, modulo `modulus`, to `target_label`.
+    HashMod,
+
+    /// Copies the value of each label whose name matches `regex` to the label named by `replacement`, with capture
+    /// groups expanded.
+    LabelMap,
+
+    /// Removes the labels whose names match `regex`.
+    LabelDrop,
+
+    /// Removes the labels whose names don't match `regex`.
+    LabelKeep,
+}
+
+impl Default for RelabelAction {
+    fn default() -> Self {
+        Self::Replace
+    }
+}
+
+fn default_separator() -> String {
+    ";".to_owned()
+}
+
+fn default_regex() -> String {
+    "(.*)".to_owned()
+}
+
+fn default_replacement() -> String {
+    "$1".to_owned()
+}
+
+inventory::submit! {
+    TransformDescription::new::<RelabelConfig>("relabel")
+}
+
+impl GenerateConfig for RelabelConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            relabel_configs: vec![RelabelRuleConfig {
+                source_labels: vec!["instance".to_owned()],
+                separator: default_separator(),
+                target_label: Some("host".to_owned()),
+                regex: "([^:]+):.*".to_owned(),
+                modulus: None,
+                replacement: default_replacement(),
+                action: RelabelAction::Replace,
+            }],
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "relabel")]
+impl TransformConfig for RelabelConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Relabel::new(self).map(Transform::function)
+    }
+
+    fn input(&self) -> Input {
+        Input::metric()
+    }
+
+    fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
+        vec![Output::default(DataType::Metric)]
+    }
+
+    fn transform_type(&self) -> &'static str {
+        "relabel"
+    }
+
+    fn enable_concurrency(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Debug, Snafu)]
+enum BuildError {
+    #[snafu(display("Invalid regular expression in rule {}: {}", index, source))]
+    InvalidRegex { index: usize, source: regex::Error },
+
+    #[snafu(display("Rule {} is missing a `target_label`, required by its action", index))]
+    MissingTargetLabel { index: usize },
+
+    #[snafu(display(
+        "Rule {} is missing a non-zero `modulus`, required by `hashmod`",
+        index
+    ))]
+    MissingModulus { index: usize },
+}
+
+#[derive(Clone, Debug)]
+struct RelabelRule {
+    source_labels: Vec<String>,
+    separator: String,
+    target_label: String,
+    regex: Regex,
+    modulus: u64,
+    replacement: String,
+    action: RelabelAction,
+}
+
+impl RelabelRule {
+    fn new(index: usize, config: &RelabelRuleConfig) -> crate::Result<Self> {
+        let regex =
+            Regex::new(&format!("^(?:{})$", config.regex)).context(InvalidRegexSnafu { index })?;
+
+        let target_label = match (config.action, &config.target_label) {
+            (RelabelAction::Replace | RelabelAction::HashMod, None) => {
+                return Err(Box::new(BuildError::MissingTargetLabel { index }))
+            }
+            (_, target_label) => target_label.clone().unwrap_or_default(),
+        };
+
+        let modulus = match (config.action, config.modulus) {
+            (RelabelAction::HashMod, None | Some(0)) => {
+                return Err(Box::new(BuildError::MissingModulus { index }))
+            }
+            (_, modulus) => modulus.unwrap_or_default(),
+        };
+
+        Ok(Self {
+            source_labels: config.source_labels.clone(),
+            separator: config.separator.clone(),
+            target_label,
+            regex,
+            modulus,
+            replacement: config.replacement.clone(),
+            action: config.action,
+        })
+    }
+
+    fn source_value(&self, series: &MetricSeries) -> String {
+        self.source_labels
+            .iter()
+            .map(|label| label_value(series, label))
+            .collect::<Vec<_>>()
+            .join(&self.separator)
+    }
+
+    /// Applies this rule to the series of a metric, returning whether or not the metric should be kept.
+    fn apply(&self, series: &mut MetricSeries) -> bool {
+        match self.action {
+            RelabelAction::Replace => {
+                let value = self.source_value(series);
+                if let Some(captures) = self.regex.captures(&value) {
+                    let target = expand(&captures, &self.target_label);
+                    // Like Prometheus, targets that don't expand to a valid label name are skipped.
+                    if is_valid_label_name(&target) {
+                        let replacement = expand(&captures, &self.replacement);
+                        set_label(series, target, replacement);
+                    }
+                }
+                true
+            }
+            RelabelAction::Keep => self.regex.is_match(&self.source_value(series)),
+            RelabelAction::Drop => !self.regex.is_match(&self.source_value(series)),
+            RelabelAction::HashMod => {
+                let hash = Md5::digest(self.source_value(series).as_bytes());
+                let hash =
+                    u64::from_be_bytes(hash[8..].try_into().expect("md5 hashes are 16 bytes"));
+                set_label(
+                    series,
+                    self.target_label.clone(),
+                    (hash % self.modulus).to_string(),
+                );
+                true
+            }
+            RelabelAction::LabelMap => {
+                let mapped = series
+                    .tags()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|(name, value)| {
+                        self.regex
+                            .captures(name)
+                            .map(|captures| (expand(&captures, &self.replacement), value.clone()))
+                    })
+                    .collect::<Vec<_>>();
+                for (name, value) in mapped {
+                    series.insert_tag(name, value);
+                }
+                true
+            }
+            RelabelAction::LabelDrop => {
+                retain_tags(series, |name| !self.regex.is_match(name));
+                true
+            }
+            RelabelAction::LabelKeep => {
+                retain_tags(series, |name| self.regex.is_match(name));
+                true
+            }
+        }
+    }
+}
+
+fn expand(captures: &Captures<'_>, template: &str) -> String {
+    let mut expanded = String::new();
+    captures.expand(template, &mut expanded);
+    expanded
+}
+
+fn is_valid_label_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn label_value<'a>(series: &'a MetricSeries, label: &str) -> &'a str {
+    if label == NAME_LABEL {
+        &series.name.name
+    } else {
+        series
+            .tags()
+            .and_then(|tags| tags.get(label))
+            .map_or("", String::as_str)
+    }
+}
+
+fn set_label(series: &mut MetricSeries, label: String, value: String) {
+    if label == NAME_LABEL {
+        // Metrics always have a name, so it can't be removed.
+        if !value.is_empty() {
+            series.name.name = value;
+        }
+    } else if value.is_empty() {
+        series.remove_tag(&label);
+    } else {
+        series.insert_tag(label, value);
+    }
+}
+
+fn retain_tags(series: &mut MetricSeries, mut keep: impl FnMut(&str) -> bool) {
+    if let Some(tags) = series.tags_mut() {
+        tags.retain(|name, _| keep(name));
+    }
+    if series.tags().map_or(false, |tags| tags.is_empty()) {
+        *series.tags_mut() = None;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Relabel {
+    rules: Vec<RelabelRule>,
+}
+
+impl Relabel {
+    pub fn new(config: &RelabelConfig) -> crate::Result<Self> {
+        let rules = config
+            .relabel_configs
+            .iter()
+            .enumerate()
+            .map(|(index, rule)| RelabelRule::new(index, rule))
+            .collect::<crate::Result<_>>()?;
+        Ok(Self { rules })
+    }
+}
+
+impl FunctionTransform for Relabel {
+    fn transform(&mut self, output: &mut OutputBuffer, event: Event) {
+        let (mut series, data, metadata) = event.into_metric().into_parts();
+        for rule in &self.rules {
+            if !rule.apply(&mut series) {
+                emit!(RelabelEventDiscarded);
+                return;
+            }
+        }
+        output.push(Event::Metric(Metric::from_parts(series, data, metadata)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::metric::{MetricKind, MetricTags, MetricValue},
+        transforms::test::transform_one,
+    };
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<RelabelConfig>();
+    }
+
+    fn tags() -> MetricTags {
+        vec![
+            ("instance".to_owned(), "host-1:9100".to_owned()),
+            ("job".to_owned(), "node".to_owned()),
+            ("env".to_owned(), "prod".to_owned()),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn metric() -> Event {
+        Event::Metric(
+            Metric::new(
+                "cpu_seconds_total",
+                MetricKind::Absolute,
+                MetricValue::Counter { value: 1.0 },
+            )
+            .with_tags(Some(tags())),
+        )
+    }
+
+    fn relabel(config: &str) -> Relabel {
+        let config = serde_yaml::from_str::<RelabelConfig>(config).unwrap();
+        Relabel::new(&config).unwrap()
+    }
+
+    fn relabeled(config: &str) -> Option<Metric> {
+        transform_one(&mut relabel(config), metric()).map(Event::into_metric)
+    }
+
+    #[test]
+    fn replace() {
+        let metric = relabeled(
+            r#"
+relabel_configs:
+  - source_labels: [instance]
+    regex: '([^:]+):(\d+)'
+    target_label: host
+  - source_labels: [job, env]
+    separator: '-'
+    target_label: '${1}_group'
+    regex: '(\w+)-.*'
+    replacement: '$0'
+  - source_labels: [__name__]
+    regex: '(.*)_total'
+    target_label: __name__
+    replacement: 'node_$1'
+  - source_labels: [missing]
+    target_label: env
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(metric.name(), "node_cpu_seconds");
+        assert_eq!(metric.tag_value("host"), Some("host-1".to_owned()));
+        assert_eq!(metric.tag_value("node_group"), Some("node-prod".to_owned()));
+        // An empty replacement removes the target label.
+        assert_eq!(metric.tag_value("env"), None);
+    }
+
+    #[test]
+    fn keep_and_drop() {
+        let keep = |regex: &str| {
+            relabeled(&format!(
+                "relabel_configs: [{{ action: keep, source_labels: [job], regex: '{}' }}]",
+                regex
+            ))
+        };
+        assert!(keep("node").is_some());
+        assert!(keep("no").is_none());
+
+        let drop = |regex: &str| {
+            relabeled(&format!(
+                "relabel_configs: [{{ action: drop, source_labels: [job], regex: '{}' }}]",
+                regex
+            ))
+        };
+        assert!(drop("node").is_none());
+        assert!(drop("no").is_some());
+    }
+
+    #[test]
+    fn hashmod() {
+        let metric = relabeled(
+            r#"
+relabel_configs:
+  - source_labels: [instance]
+    action: hashmod
+    modulus: 8
+    target_label: shard
+"#,
+        )
+        .unwrap();
+
+        let hash = Md5::digest(b"host-1:9100");
+        let expected = u64::from_be_bytes(hash[8..].try_into().unwrap()) % 8;
+        assert_eq!(metric.tag_value("shard"), Some(expected.to_string()));
+    }
+
+    #[test]
+    fn labelmap_labeldrop_labelkeep() {
+        let metric = relabeled(
+            r#"
+relabel_configs:
+  - action: labelmap
+    regex: '(job|env)'
+    replacement: 'source_$1'
+  - action: labeldrop
+    regex: 'job|env'
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            metric.tags().unwrap().keys().collect::<Vec<_>>(),
+            vec!["instance", "source_env", "source_job"]
+        );
+        assert_eq!(metric.tag_value("source_job"), Some("node".to_owned()));
+
+        let metric = relabeled(
+            r#"
+relabel_configs:
+  - action: labelkeep
+    regex: 'missing'
+"#,
+        )
+        .unwrap();
+        assert_eq!(metric.tags(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        for config in [
+            "relabel_configs: [{ regex: '(' , target_label: foo }]",
+            "relabel_configs: [{ action: replace }]",
+            "relabel_configs: [{ action: hashmod, target_label: foo }]",
+            "relabel_configs: [{ action: hashmod, target_label: foo, modulus: 0 }]",
+        ] {
+            let config = serde_yaml::from_str::<RelabelConfig>(config).unwrap();
+            assert!(Relabel::new(&config).is_err(), "{:?}", config);
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
 use serde::{Deserialize, Serialize};
 use vector_config::configurable_component;
 
@@ -8,8 +13,9 @@ use crate::{
         TransformDescription,
     },
     event::Event,
-    internal_events::SampleEventDiscarded,
+    internal_events::{SampleEventDiscarded, TemplateRenderingError},
     schema,
+    template::Template,
     transforms::{FunctionTransform, OutputBuffer, Transform},
 };
 
@@ -21,17 +27,51 @@ pub struct SampleConfig {
     /// The rate at which events will be forwarded, expressed as `1/N`.
     ///
     /// For example, `rate = 10` means 1 out of every 10 events will be forwarded and the rest will be dropped.
-    pub rate: u64,
+    ///
+    /// Exactly one of `rate` and `adaptive` must be set.
+    pub rate: Option<u64>,
 
     /// The name of the log field whose value will be hashed to determine if the event should be passed.
     ///
     /// Consistently samples the same events. Actual rate of sampling may differ from the configured one if values in
     /// the field are not uniformly distributed. If left unspecified, or if the event doesn’t have `key_field`, events
     /// will be count rated.
+    ///
+    /// Can't be set along with `adaptive`, which groups events by its own `key_field` instead.
     pub key_field: Option<String>,
 
     /// A logical condition used to exclude events from sampling.
     pub exclude: Option<AnyCondition>,
+
+    /// Samples events at a rate that adapts to the volume of events, instead of at a fixed `rate`.
+    pub adaptive: Option<AdaptiveSampleConfig>,
+}
+
+/// Configuration for adaptive sampling.
+///
+/// The rate at which events of each key are sampled is recomputed over a sliding window, so that roughly
+/// `target_events_per_sec` events of each key are forwarded.  The effective rate is stamped on each forwarded event,
+/// in the `sample_rate` field, so that downstream counts can be corrected.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct AdaptiveSampleConfig {
+    /// The number of events to forward per second, for each key.
+    pub target_events_per_sec: f64,
+
+    /// The period of time over which the volume of events of each key is measured, in seconds.
+    #[serde(default = "default_window_secs")]
+    pub window_secs: f64,
+
+    /// The value to group events into separately sampled keys by.
+    ///
+    /// If left unspecified, or if the event can't be rendered with the template, events are sampled as a single key.
+    #[configurable(metadata(templatable))]
+    pub key_field: Option<Template>,
+}
+
+const fn default_window_secs() -> f64 {
+    60.0
 }
 
 // TODO: Deprecate the name `sampler`
@@ -46,9 +86,10 @@ inventory::submit! {
 impl GenerateConfig for SampleConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
-            rate: 10,
+            rate: Some(10),
             key_field: None,
             exclude: None::<AnyCondition>,
+            adaptive: None,
         })
         .unwrap()
     }
@@ -58,14 +99,41 @@ impl GenerateConfig for SampleConfig {
 #[typetag::serde(name = "sample")]
 impl TransformConfig for SampleConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::function(Sample::new(
-            self.rate,
-            self.key_field.clone(),
-            self.exclude
-                .as_ref()
-                .map(|condition| condition.build(&context.enrichment_tables))
-                .transpose()?,
-        )))
+        let exclude = self
+            .exclude
+            .as_ref()
+            .map(|condition| condition.build(&context.enrichment_tables))
+            .transpose()?;
+
+        let sample = match (self.rate, &self.adaptive) {
+            (Some(rate), None) => Sample::new(rate, self.key_field.clone(), exclude),
+            (None, Some(_)) if self.key_field.is_some() => {
+                return Err(
+                    "`key_field` can't be used with `adaptive`, use `adaptive.key_field` instead"
+                        .into(),
+                );
+            }
+            (None, Some(adaptive)) => {
+                let valid = |value: f64| value.is_finite() && value > 0.0;
+                if !valid(adaptive.target_events_per_sec) || !valid(adaptive.window_secs) {
+                    return Err(
+                        "`target_events_per_sec` and `window_secs` must be finite and greater than zero"
+                            .into(),
+                    );
+                }
+                Sample::new_adaptive(
+                    AdaptiveSampler::new(
+                        adaptive.target_events_per_sec,
+                        Duration::from_secs_f64(adaptive.window_secs),
+                        adaptive.key_field.clone(),
+                    ),
+                    exclude,
+                )
+            }
+            _ => return Err("exactly one of `rate` and `adaptive` must be set".into()),
+        };
+
+        Ok(Transform::function(sample))
     }
 
     fn input(&self) -> Input {
@@ -105,12 +173,128 @@ impl TransformConfig for SampleCompatConfig {
     }
 }
 
+/// Sampling state of a single key of an [`AdaptiveSampler`].
+#[derive(Clone, Debug)]
+struct KeyState {
+    window_start: Instant,
+    current_count: u64,
+    previous_count: Option<u64>,
+    count: u64,
+}
+
+impl KeyState {
+    fn new(now: Instant) -> Self {
+        Self {
+            window_start: now,
+            current_count: 0,
+            previous_count: None,
+            count: 0,
+        }
+    }
+
+    /// Moves the window forward, if `now` is past its end.
+    fn roll_window(&mut self, now: Instant, window: Duration) {
+        let elapsed = now.saturating_duration_since(self.window_start);
+        if elapsed < window {
+            return;
+        }
+
+        // If a whole window went by without any events, there's nothing left to carry over.
+        self.previous_count = Some(if elapsed < window * 2 {
+            self.current_count
+        } else {
+            0
+        });
+        self.current_count = 0;
+        self.window_start = if elapsed < window * 2 {
+            self.window_start + window
+        } else {
+            now
+        };
+    }
+
+    /// Estimates the number of events per second of this key over the last window.
+    fn events_per_sec(&self, now: Instant, window: Duration) -> f64 {
+        let elapsed = now
+            .saturating_duration_since(self.window_start)
+            .as_secs_f64();
+        match self.previous_count {
+            // The previous window is weighted by how much of it still overlaps with the sliding window.
+            Some(previous_count) => {
+                let overlap = 1.0 - elapsed / window.as_secs_f64();
+                (previous_count as f64 * overlap + self.current_count as f64) / window.as_secs_f64()
+            }
+            // Until a whole window has gone by, we only have the events seen so far to go by.
+            None => self.current_count as f64 / elapsed.max(1.0),
+        }
+    }
+}
+
+/// Samples events so that roughly a target number of events per second of each key are kept.
+#[derive(Clone, Debug)]
+pub struct AdaptiveSampler {
+    target_events_per_sec: f64,
+    window: Duration,
+    key_field: Option<Template>,
+    keys: HashMap<Option<String>, KeyState>,
+    last_pruned: Instant,
+}
+
+impl AdaptiveSampler {
+    pub fn new(target_events_per_sec: f64, window: Duration, key_field: Option<Template>) -> Self {
+        Self {
+            target_events_per_sec,
+            window,
+            key_field,
+            keys: HashMap::new(),
+            last_pruned: Instant::now(),
+        }
+    }
+
+    /// Decides whether to keep the event, returning the effective sample rate of its key if so.
+    fn sample(&mut self, event: &Event, now: Instant) -> Option<u64> {
+        let key = self.key_field.as_ref().and_then(|template| {
+            template
+                .render_string(event)
+                .map_err(|error| {
+                    emit!(TemplateRenderingError {
+                        error,
+                        field: Some("key_field"),
+                        drop_event: false,
+                    })
+                })
+                .ok()
+        });
+
+        // Forget about keys that haven't been seen in a while, so that they don't pile up.
+        if now.saturating_duration_since(self.last_pruned) >= self.window {
+            let window = self.window;
+            self.keys
+                .retain(|_, state| now.saturating_duration_since(state.window_start) < window * 2);
+            self.last_pruned = now;
+        }
+
+        let state = self.keys.entry(key).or_insert_with(|| KeyState::new(now));
+        state.roll_window(now, self.window);
+        state.current_count += 1;
+
+        let rate = (state.events_per_sec(now, self.window) / self.target_events_per_sec)
+            .ceil()
+            .max(1.0) as u64;
+
+        let keep = state.count % rate == 0;
+        state.count = state.count.wrapping_add(1);
+        keep.then(|| rate)
+    }
+}
+
 #[derive(Clone)]
 pub struct Sample {
     rate: u64,
     key_field: Option<String>,
     exclude: Option<Condition>,
     count: u64,
+    adaptive: Option<AdaptiveSampler>,
 }
 
 impl Sample {
@@ -120,30 +304,29 @@ impl Sample {
             key_field,
             exclude,
             count: 0,
+            adaptive: None,
         }
     }
-}
 
-impl FunctionTransform for Sample {
-    fn transform(&mut self, output: &mut OutputBuffer, event: Event) {
-        let mut event = {
-            if let Some(condition) = self.exclude.as_ref() {
-                let (result, event) = condition.check(event);
-                if result {
-                    output.push(event);
-                    return;
-                } else {
-                    event
-                }
-            } else {
-                event
-            }
-        };
+    pub const fn new_adaptive(adaptive: AdaptiveSampler, exclude: Option<Condition>) -> Self {
+        Self {
+            rate: 1,
+            key_field: None,
+            exclude,
+            count: 0,
+            adaptive: Some(adaptive),
+        }
+    }
+
+    fn sample(&mut self, event: &Event, now: Instant) -> Option<u64> {
+        if let Some(adaptive) = self.adaptive.as_mut() {
+            return adaptive.sample(event, now);
+        }
 
         let value = self
             .key_field
             .as_ref()
-            .and_then(|key_field| match &event {
+            .and_then(|key_field| match event {
                 Event::Log(event) => event.get(key_field.as_str()),
                 Event::Trace(event) => event.get(key_field.as_str()),
                 Event::Metric(_) => panic!("component can never receive metric events"),
@@ -158,10 +341,30 @@ impl FunctionTransform for Sample {
 
         self.count = (self.count + 1) % self.rate;
 
-        if num % self.rate == 0 {
+        (num % self.rate == 0).then(|| self.rate)
+    }
+}
+
+impl FunctionTransform for Sample {
+    fn transform(&mut self, output: &mut OutputBuffer, event: Event) {
+        let mut event = {
+            if let Some(condition) = self.exclude.as_ref() {
+                let (result, event) = condition.check(event);
+                if result {
+                    output.push(event);
+                    return;
+                } else {
+                    event
+                }
+            } else {
+                event
+            }
+        };
+
+        if let Some(rate) = self.sample(&event, Instant::now()) {
             match event {
-                Event::Log(ref mut event) => event.insert("sample_rate", self.rate.to_string()),
-                Event::Trace(ref mut event) => event.insert("sample_rate", self.rate.to_string()),
+                Event::Log(ref mut event) => event.insert("sample_rate", rate.to_string()),
+                Event::Trace(ref mut event) => event.insert("sample_rate", rate.to_string()),
                 Event::Metric(_) => panic!("component can never receive metric events"),
             };
             output.push(event);
@@ -174,6 +377,7 @@ impl FunctionTransform for Sample {
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
+    use indoc::indoc;
 
     use super::*;
     use crate::{
@@ -374,6 +578,126 @@ mod tests {
         assert_eq!(total_passed, 1);
     }
 
+    #[test]
+    fn adaptive_converges_on_target_rate_per_key() {
+        let mut sampler = AdaptiveSampler::new(
+            10.0,
+            Duration::from_secs(10),
+            Some(Template::try_from("{{ service }}").unwrap()),
+        );
+        let start = Instant::now();
+
+        let mut kept = HashMap::<&str, Vec<u64>>::new();
+        // Send 1000 events per second of `busy` and 5 events per second of `quiet`, for 30 seconds.
+        for ms in 0..30_000u64 {
+            let now = start + Duration::from_millis(ms);
+            let mut services = vec!["busy"];
+            if ms % 200 == 0 {
+                services.push("quiet");
+            }
+            for service in services {
+                let mut log = LogEvent::from("message");
+                log.insert("service", service);
+                if let Some(rate) = sampler.sample(&Event::Log(log), now) {
+                    kept.entry(service).or_default().push(rate);
+                }
+            }
+        }
+
+        // Once a whole window has gone by, `busy` is sampled down to roughly the target rate.
+        let busy = &kept["busy"];
+        assert!(busy
+            .iter()
+            .rev()
+            .take(5)
+            .all(|rate| (95..=105).contains(rate)));
+        let kept_per_sec = busy.len() as f64 / 30.0;
+        assert_relative_eq!(kept_per_sec, 10.0, epsilon = 5.0);
+
+        // `quiet` is below the target, so all of its events are kept.
+        let quiet = &kept["quiet"];
+        assert_eq!(quiet.len(), 150);
+        assert!(quiet.iter().all(|rate| *rate == 1));
+    }
+
+    #[test]
+    fn adaptive_stamps_effective_rate_on_kept_events() {
+        let mut sampler = Sample::new_adaptive(
+            AdaptiveSampler::new(10.0, Duration::from_secs(60), None),
+            None,
+        );
+
+        // Up to the target, every event is kept.
+        for _ in 0..10 {
+            let passing = transform_one(&mut sampler, Event::Log(LogEvent::from("first"))).unwrap();
+            assert_eq!(passing.as_log()["sample_rate"], "1".into());
+        }
+
+        // Past the target, some events are still kept, stamped with the rate they were sampled at.
+        let rates = (0..100)
+            .filter_map(|_| transform_one(&mut sampler, Event::Log(LogEvent::from("message"))))
+            .map(|event| {
+                event.as_log()["sample_rate"]
+                    .to_string_lossy()
+                    .parse::<u64>()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert!(!rates.is_empty());
+        assert!(rates.len() < 100);
+        assert!(rates.iter().all(|rate| *rate > 1));
+    }
+
+    #[tokio::test]
+    async fn requires_exactly_one_of_rate_and_adaptive() {
+        for config in [
+            "",
+            indoc! {r#"
+                rate = 10
+                adaptive.target_events_per_sec = 10
+            "#},
+            "adaptive.target_events_per_sec = 0",
+            indoc! {r#"
+                key_field = "service"
+                adaptive.target_events_per_sec = 10
+            "#},
+        ] {
+            let config = toml::from_str::<SampleConfig>(config).unwrap();
+            assert!(config.build(&TransformContext::default()).await.is_err());
+        }
+
+        let config = toml::from_str::<SampleConfig>(indoc! {r#"
+            adaptive.target_events_per_sec = 10
+            adaptive.key_field = "{{ service }}"
+        "#})
+        .unwrap();
+        assert!(config.build(&TransformContext::default()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_non_finite_target_events_per_sec() {
+        for value in ["inf", "-inf", "nan"] {
+            let config = toml::from_str::<SampleConfig>(&format!(
+                "adaptive.target_events_per_sec = {}",
+                value
+            ))
+            .unwrap();
+            assert!(config.build(&TransformContext::default()).await.is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_non_finite_window_secs() {
+        for value in ["inf", "-inf", "nan"] {
+            let config = toml::from_str::<SampleConfig>(&format!(
+                "adaptive.target_events_per_sec = 10\nadaptive.window_secs = {}",
+                value
+            ))
+            .unwrap();
+            assert!(config.build(&TransformContext::default()).await.is_err());
+        }
+    }
+
     fn random_events(n: usize) -> Vec<Event> {
         random_lines(10)
             .take(n)
@@ -0,0 +1,685 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+use async_stream::stream;
+use futures::{stream, Stream, StreamExt};
+use vector_config::configurable_component;
+
+use crate::{
+    conditions::{AnyCondition, Condition},
+    config::{
+        DataType, GenerateConfig, Input, Output, TransformConfig, TransformContext,
+        TransformDescription,
+    },
+    event::{Event, Value},
+    internal_events::{TailSampleTraceDecidedEarly, TailSampleTraceDiscarded},
+    schema,
+    transforms::{TaskTransform, Transform},
+};
+
+/// Configuration for the `tail_sample` transform.
+#[configurable_component(transform)]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TailSampleConfig {
+    /// The name of the field holding the trace ID of an event.
+    ///
+    /// Events without a trace ID are forwarded as-is.
+    #[serde(default = "default_trace_id_field")]
+    pub trace_id_field: String,
+
+    /// The period of time to wait after the first event of a trace is received, in milliseconds, before deciding
+    /// whether to keep or drop the trace.
+    ///
+    /// Events of a trace that arrive after the decision was made follow the same decision, as long as they arrive
+    /// within another `decision_wait_ms`.
+    #[serde(default = "default_decision_wait_ms")]
+    pub decision_wait_ms: u64,
+
+    /// The interval to check for and decide on any traces that have waited long enough, in milliseconds.
+    #[serde(default = "default_flush_period_ms")]
+    pub flush_period_ms: u64,
+
+    /// The maximum number of traces waiting for a decision.
+    ///
+    /// When the first event of a new trace arrives while this many traces are waiting, the oldest trace is decided on
+    /// right away, before its `decision_wait_ms` is over.
+    #[serde(default = "default_max_traces")]
+    pub max_traces: usize,
+
+    /// The policies used to decide whether to keep a trace.
+    ///
+    /// A trace is kept, along with all of its events, if any of the policies match it. Otherwise, the whole trace is
+    /// dropped.
+    pub policies: Vec<PolicyConfig>,
+}
+
+/// A policy used to decide whether to keep a trace.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PolicyConfig {
+    /// Keeps traces with at least one span that has an error.
+    Error(#[configurable(derived)] ErrorPolicyConfig),
+
+    /// Keeps traces with at least one span that took longer than a threshold.
+    Latency(#[configurable(derived)] LatencyPolicyConfig),
+
+    /// Keeps traces with at least one event that matches a condition.
+    Condition(#[configurable(derived)] ConditionPolicyConfig),
+
+    /// Keeps a fixed proportion of traces.
+    Probabilistic(#[configurable(derived)] ProbabilisticPolicyConfig),
+}
+
+/// Specification of a policy keeping traces with errors.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ErrorPolicyConfig {
+    /// The name of the span field flagging an error.
+    ///
+    /// A span has an error when this field is `true`, or a non-zero integer.
+    #[serde(default = "default_error_field")]
+    pub field: String,
+}
+
+/// Specification of a policy keeping slow traces.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct LatencyPolicyConfig {
+    /// The name of the span field holding the duration of the span, in nanoseconds.
+    #[serde(default = "default_duration_field")]
+    pub field: String,
+
+    /// The duration above which a span is considered slow, in milliseconds.
+    pub threshold_ms: u64,
+}
+
+/// Specification of a policy keeping traces matching a condition.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ConditionPolicyConfig {
+    /// The condition to match events of the trace against.
+    pub condition: AnyCondition,
+}
+
+/// Specification of a policy keeping a fixed proportion of traces.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ProbabilisticPolicyConfig {
+    /// The rate at which traces will be kept, expressed as `1/N`.
+    ///
+    /// The trace ID is hashed to decide, so the same traces are kept consistently.
+    pub rate: u64,
+}
+
+fn default_trace_id_field() -> String {
+    "trace_id".to_string()
+}
+
+const fn default_decision_wait_ms() -> u64 {
+    30000
+}
+
+const fn default_flush_period_ms() -> u64 {
+    1000
+}
+
+const fn default_max_traces() -> usize {
+    100_000
+}
+
+fn default_error_field() -> String {
+    "error".to_string()
+}
+
+fn default_duration_field() -> String {
+    "duration".to_string()
+}
+
+inventory::submit! {
+    TransformDescription::new::<TailSampleConfig>("tail_sample")
+}
+
+impl GenerateConfig for TailSampleConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            trace_id_field: default_trace_id_field(),
+            decision_wait_ms: default_decision_wait_ms(),
+            flush_period_ms: default_flush_period_ms(),
+            max_traces: default_max_traces(),
+            policies: vec![
+                PolicyConfig::Error(ErrorPolicyConfig {
+                    field: default_error_field(),
+                }),
+                PolicyConfig::Latency(LatencyPolicyConfig {
+                    field: default_duration_field(),
+                    threshold_ms: 500,
+                }),
+            ],
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "tail_sample")]
+impl TransformConfig for TailSampleConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        TailSample::new(self, &context.enrichment_tables).map(Transform::event_task)
+    }
+
+    fn input(&self) -> Input {
+        Input::new(DataType::Log | DataType::Trace)
+    }
+
+    fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
+        vec![Output::default(DataType::Log | DataType::Trace)]
+    }
+
+    fn transform_type(&self) -> &'static str {
+        "tail_sample"
+    }
+}
+
+enum Policy {
+    Error { field: String },
+    Latency { field: String, threshold_ns: f64 },
+    Condition(Condition),
+    Probabilistic { rate: u64 },
+}
+
+impl Policy {
+    fn build(
+        config: &PolicyConfig,
+        enrichment_tables: &enrichment::TableRegistry,
+    ) -> crate::Result<Self> {
+        Ok(match config {
+            PolicyConfig::Error(ErrorPolicyConfig { field }) => Policy::Error {
+                field: field.clone(),
+            },
+            PolicyConfig::Latency(LatencyPolicyConfig {
+                field,
+                threshold_ms,
+            }) => Policy::Latency {
+                field: field.clone(),
+                threshold_ns: *threshold_ms as f64 * 1_000_000.0,
+            },
+            PolicyConfig::Condition(ConditionPolicyConfig { condition }) => {
+                Policy::Condition(condition.build(enrichment_tables)?)
+            }
+            PolicyConfig::Probabilistic(ProbabilisticPolicyConfig { rate }) => {
+                if *rate == 0 {
+                    return Err("`rate` of a probabilistic policy must be greater than zero".into());
+                }
+                Policy::Probabilistic { rate: *rate }
+            }
+        })
+    }
+
+    /// Checks whether this policy matches the given event of a trace, handing back the event.
+    fn matches_event(&self, event: Event) -> (bool, Event) {
+        match self {
+            Policy::Error { field } => {
+                let matched = any_span(&event, field, |value| match value {
+                    Value::Boolean(error) => *error,
+                    Value::Integer(error) => *error != 0,
+                    _ => false,
+                });
+                (matched, event)
+            }
+            Policy::Latency {
+                field,
+                threshold_ns,
+            } => {
+                let matched = any_span(&event, field, |value| match value {
+                    Value::Integer(duration) => *duration as f64 > *threshold_ns,
+                    Value::Float(duration) => duration.into_inner() > *threshold_ns,
+                    _ => false,
+                });
+                (matched, event)
+            }
+            Policy::Condition(condition) => condition.check(event),
+            Policy::Probabilistic { .. } => (false, event),
+        }
+    }
+
+    /// Checks whether this policy matches the trace with the given ID.
+    fn matches_trace(&self, trace_id: &str) -> bool {
+        match self {
+            Policy::Probabilistic { rate } => seahash::hash(trace_id.as_bytes()) % rate == 0,
+            _ => false,
+        }
+    }
+}
+
+/// Checks whether `predicate` holds for `field` of any span of the event.
+///
+/// Trace events hold their spans in the `spans` field, whereas any other event is considered a span on its own.
+fn any_span(event: &Event, field: &str, predicate: impl Fn(&Value) -> bool) -> bool {
+    let spans = match event {
+        Event::Log(log) => return log.get(field).map_or(false, predicate),
+        Event::Trace(trace) => match trace.get("spans") {
+            Some(Value::Array(spans)) => spans,
+            _ => return trace.get(field).map_or(false, predicate),
+        },
+        Event::Metric(_) => panic!("component can never receive metric events"),
+    };
+
+    spans.iter().any(|span| match span {
+        Value::Object(span) => span.get(field).map_or(false, &predicate),
+        _ => false,
+    })
+}
+
+struct TraceState {
+    events: Vec<Event>,
+    first_seen: Instant,
+    keep: bool,
+}
+
+struct Decision {
+    keep: bool,
+    decided_at: Instant,
+}
+
+pub struct TailSample {
+    trace_id_field: String,
+    decision_wait: Duration,
+    flush_period: Duration,
+    max_traces: usize,
+    policies: Vec<Policy>,
+    traces: HashMap<String, TraceState>,
+    /// The IDs of the traces waiting for a decision, from the oldest to the newest.
+    order: VecDeque<String>,
+    decisions: HashMap<String, Decision>,
+}
+
+impl TailSample {
+    pub fn new(
+        config: &TailSampleConfig,
+        enrichment_tables: &enrichment::TableRegistry,
+    ) -> crate::Result<Self> {
+        let policies = config
+            .policies
+            .iter()
+            .map(|policy| Policy::build(policy, enrichment_tables))
+            .collect::<crate::Result<Vec<_>>>()?;
+        if config.max_traces == 0 {
+            return Err("`max_traces` must be greater than zero".into());
+        }
+
+        Ok(TailSample {
+            trace_id_field: config.trace_id_field.clone(),
+            decision_wait: Duration::from_millis(config.decision_wait_ms),
+            flush_period: Duration::from_millis(config.flush_period_ms),
+            max_traces: config.max_traces,
+            policies,
+            traces: HashMap::new(),
+            order: VecDeque::new(),
+            decisions: HashMap::new(),
+        })
+    }
+
+    fn trace_id(&self, event: &Event) -> Option<String> {
+        let value = match event {
+            Event::Log(log) => log.get(self.trace_id_field.as_str()),
+            Event::Trace(trace) => trace.get(self.trace_id_field.as_str()),
+            Event::Metric(_) => panic!("component can never receive metric events"),
+        };
+        value.map(|value| value.to_string_lossy())
+    }
+
+    fn matches_any_policy(&self, mut event: Event) -> (bool, Event) {
+        for policy in &self.policies {
+            let (matched, returned) = policy.matches_event(event);
+            if matched {
+                return (true, returned);
+            }
+            event = returned;
+        }
+        (false, event)
+    }
+
+    fn decide(&self, trace_id: &str, state: &TraceState) -> bool {
+        state.keep
+            || self
+                .policies
+                .iter()
+                .any(|policy| policy.matches_trace(trace_id))
+    }
+
+    fn flush_into(&mut self, output: &mut Vec<Event>) {
+        let decision_wait = self.decision_wait;
+        self.decisions
+            .retain(|_, decision| decision.decided_at.elapsed() < decision_wait);
+
+        // Traces are waiting in the order they were first seen, so the ones that waited long enough come first.
+        while self
+            .order
+            .front()
+            .and_then(|trace_id| self.traces.get(trace_id))
+            .map_or(false, |state| state.first_seen.elapsed() >= decision_wait)
+        {
+            self.finish_oldest_trace(output);
+        }
+    }
+
+    fn flush_all_into(&mut self, output: &mut Vec<Event>) {
+        while !self.order.is_empty() {
+            self.finish_oldest_trace(output);
+        }
+    }
+
+    fn finish_oldest_trace(&mut self, output: &mut Vec<Event>) {
+        if let Some(trace_id) = self.order.pop_front() {
+            if let Some(state) = self.traces.remove(&trace_id) {
+                self.finish_trace(trace_id, state, output);
+            }
+        }
+    }
+
+    fn finish_trace(&mut self, trace_id: String, state: TraceState, output: &mut Vec<Event>) {
+        let keep = self.decide(&trace_id, &state);
+        if keep {
+            output.extend(state.events);
+        } else {
+            emit!(TailSampleTraceDiscarded {
+                count: state.events.len()
+            });
+        }
+        self.decisions.insert(
+            trace_id,
+            Decision {
+                keep,
+                decided_at: Instant::now(),
+            },
+        );
+    }
+
+    fn transform_one(&mut self, output: &mut Vec<Event>, event: Event) {
+        let trace_id = match self.trace_id(&event) {
+            Some(trace_id) => trace_id,
+            None => {
+                output.push(event);
+                return;
+            }
+        };
+
+        // Events of a trace which was already decided on follow the same decision.
+        if let Some(decision) = self.decisions.get(&trace_id) {
+            if decision.keep {
+                output.push(event);
+            } else {
+                emit!(TailSampleTraceDiscarded { count: 1 });
+            }
+            return;
+        }
+
+        let (matched, event) = if self.traces.get(&trace_id).map_or(false, |state| state.keep) {
+            // No need to check the policies again if the trace is already going to be kept.
+            (true, event)
+        } else {
+            self.matches_any_policy(event)
+        };
+
+        if !self.traces.contains_key(&trace_id) {
+            if self.traces.len() >= self.max_traces {
+                emit!(TailSampleTraceDecidedEarly);
+                self.finish_oldest_trace(output);
+            }
+            self.order.push_back(trace_id.clone());
+        }
+        let state = self.traces.entry(trace_id).or_insert_with(|| TraceState {
+            events: Vec::new(),
+            first_seen: Instant::now(),
+            keep: false,
+        });
+        state.keep |= matched;
+        state.events.push(event);
+    }
+}
+
+impl TaskTransform<Event> for TailSample {
+    fn transform(
+        self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut me = self;
+
+        let mut flush_stream = tokio::time::interval(me.flush_period);
+
+        Box::pin(
+            stream! {
+              loop {
+                let mut output = Vec::new();
+                let done = tokio::select! {
+                    _ = flush_stream.tick() => {
+                      me.flush_into(&mut output);
+                      false
+                    }
+                    maybe_event = input_rx.next() => {
+                      match maybe_event {
+                        None => {
+                          me.flush_all_into(&mut output);
+                          true
+                        }
+                        Some(event) => {
+                          me.transform_one(&mut output, event);
+                          false
+                        }
+                      }
+                    }
+                };
+                yield stream::iter(output.into_iter());
+                if done { break }
+              }
+            }
+            .flatten(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::event::{LogEvent, TraceEvent};
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<TailSampleConfig>();
+    }
+
+    async fn build(config: &str) -> Box<TailSample> {
+        let config = toml::from_str::<TailSampleConfig>(config).unwrap();
+        Box::new(TailSample::new(&config, &Default::default()).unwrap())
+    }
+
+    fn span(trace_id: i64, error: i64, duration: i64) -> Event {
+        let mut span = BTreeMap::new();
+        span.insert("trace_id".to_string(), Value::from(trace_id));
+        span.insert("error".to_string(), Value::from(error));
+        span.insert("duration".to_string(), Value::from(duration));
+
+        let mut trace = TraceEvent::default();
+        trace.insert("trace_id", trace_id);
+        trace.insert("spans", vec![Value::from(span)]);
+        trace.into()
+    }
+
+    fn trace_ids(events: &[Event]) -> Vec<i64> {
+        let mut ids = events
+            .iter()
+            .map(|event| match event.as_trace().get("trace_id") {
+                Some(Value::Integer(id)) => *id,
+                _ => panic!("trace event should have a trace ID"),
+            })
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[tokio::test]
+    async fn keeps_or_drops_whole_traces() {
+        let mut sampler = build(
+            r#"
+            [[policies]]
+            type = "error"
+
+            [[policies]]
+            type = "latency"
+            threshold_ms = 100
+            "#,
+        )
+        .await;
+
+        let mut output = Vec::new();
+        // Trace 1 has an error in its second span, trace 2 is slow, and trace 3 is neither.
+        sampler.transform_one(&mut output, span(1, 0, 1_000));
+        sampler.transform_one(&mut output, span(2, 0, 1_000));
+        sampler.transform_one(&mut output, span(3, 0, 1_000));
+        sampler.transform_one(&mut output, span(1, 1, 1_000));
+        sampler.transform_one(&mut output, span(2, 0, 200_000_000));
+        sampler.transform_one(&mut output, span(3, 0, 1_000));
+        assert!(output.is_empty());
+
+        sampler.flush_all_into(&mut output);
+        assert_eq!(trace_ids(&output), vec![1, 1, 2, 2]);
+    }
+
+    #[tokio::test]
+    async fn late_events_follow_the_decision() {
+        let mut sampler = build(
+            r#"
+            decision_wait_ms = 0
+
+            [[policies]]
+            type = "error"
+            "#,
+        )
+        .await;
+
+        let mut output = Vec::new();
+        sampler.transform_one(&mut output, span(1, 1, 1_000));
+        sampler.transform_one(&mut output, span(2, 0, 1_000));
+        sampler.flush_into(&mut output);
+        assert_eq!(trace_ids(&output), vec![1]);
+
+        // The late span of trace 2 has an error, but the trace was already dropped.
+        output.clear();
+        sampler.decision_wait = Duration::from_secs(60);
+        sampler.transform_one(&mut output, span(1, 0, 1_000));
+        sampler.transform_one(&mut output, span(2, 1, 1_000));
+        assert_eq!(trace_ids(&output), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn condition_and_probabilistic_policies() {
+        let mut sampler = build(
+            r#"
+            trace_id_field = "request_id"
+
+            [[policies]]
+            type = "condition"
+            condition = '.level == "error"'
+
+            [[policies]]
+            type = "probabilistic"
+            rate = 1
+            "#,
+        )
+        .await;
+
+        let mut output = Vec::new();
+        let mut log = LogEvent::from("no trace");
+        sampler.transform_one(&mut output, log.clone().into());
+        assert_eq!(output.len(), 1, "events without a trace ID are forwarded");
+
+        log.insert("request_id", "abc");
+        sampler.transform_one(&mut output, log.into());
+        assert_eq!(output.len(), 1);
+
+        // A rate of 1 keeps every trace.
+        sampler.flush_all_into(&mut output);
+        assert_eq!(output.len(), 2);
+
+        // With a rate that practically never matches, only the condition keeps traces.
+        let mut sampler = build(
+            r#"
+            trace_id_field = "request_id"
+
+            [[policies]]
+            type = "condition"
+            condition = '.level == "error"'
+
+            [[policies]]
+            type = "probabilistic"
+            rate = 9223372036854775807
+            "#,
+        )
+        .await;
+
+        let mut output = Vec::new();
+        let mut error = LogEvent::from("failed");
+        error.insert("request_id", "abc");
+        error.insert("level", "error");
+        let mut info = LogEvent::from("succeeded");
+        info.insert("request_id", "def");
+        info.insert("level", "info");
+        sampler.transform_one(&mut output, error.into());
+        sampler.transform_one(&mut output, info.into());
+        sampler.flush_all_into(&mut output);
+        assert_eq!(output.len(), 1);
+        assert_eq!(
+            output[0].as_log().get("request_id"),
+            Some(&Value::from("abc"))
+        );
+    }
+
+    #[tokio::test]
+    async fn decides_oldest_trace_early_when_full() {
+        let mut sampler = build(
+            r#"
+            max_traces = 2
+
+            [[policies]]
+            type = "error"
+            "#,
+        )
+        .await;
+
+        let mut output = Vec::new();
+        sampler.transform_one(&mut output, span(1, 1, 1_000));
+        sampler.transform_one(&mut output, span(2, 0, 1_000));
+        assert!(output.is_empty());
+
+        // Trace 3 makes room for itself by deciding on trace 1, which is kept.
+        sampler.transform_one(&mut output, span(3, 0, 1_000));
+        assert_eq!(trace_ids(&output), vec![1]);
+
+        // Trace 4 makes room for itself by deciding on trace 2, which is dropped.
+        sampler.transform_one(&mut output, span(4, 1, 1_000));
+        assert_eq!(trace_ids(&output), vec![1]);
+        assert_eq!(sampler.traces.len(), 2);
+
+        // Late events of the traces decided early follow their decision.
+        sampler.transform_one(&mut output, span(1, 0, 1_000));
+        sampler.transform_one(&mut output, span(2, 1, 1_000));
+        assert_eq!(trace_ids(&output), vec![1, 1]);
+
+        sampler.flush_all_into(&mut output);
+        assert_eq!(trace_ids(&output), vec![1, 1, 4]);
+    }
+}
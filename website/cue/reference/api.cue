@@ -47,6 +47,115 @@ api: {
 				of the address set using the `bind` parameter.
 				"""
 		}
+		auth_token: {
+			common:   false
+			required: false
+			type: string: {
+				default: null
+				examples: ["${VECTOR_API_TOKEN}"]
+			}
+			description: """
+				The token that GraphQL mutations must present in an
+				`Authorization: Bearer <token>` header. Mutations, which pause and
+				resume sources, drain sinks, reload the configuration and change the
+				log level, are disabled unless this option is set. So is the
+				`configSnapshot` query, used by `vector config diff`.
+				"""
+		}
+		auth: {
+			common:      false
+			required:    false
+			description: """
+				Credentials that clients must present to use the `/graphql` and
+				`/playground` endpoints, both over HTTP and WebSockets. The
+				`auth_token` is accepted in their place. The `/health` endpoint
+				never requires credentials. `vector top` and `vector tap` present
+				credentials given with `--token`, or `--user` and `--password`.
+				"""
+			type: object: options: {
+				strategy: {
+					required:    true
+					description: "The authentication strategy to use."
+					type: string: enum: {
+						basic:  "The [basic authentication strategy](\(urls.basic_auth))."
+						bearer: "The bearer token authentication strategy."
+					}
+				}
+				user: {
+					required:      true
+					relevant_when: "strategy = \"basic\""
+					description:   "The basic authentication username."
+					type: string: examples: ["${API_USERNAME}", "username"]
+				}
+				password: {
+					required:      true
+					relevant_when: "strategy = \"basic\""
+					description:   "The basic authentication password."
+					type: string: examples: ["${API_PASSWORD}", "password"]
+				}
+				token: {
+					required:      true
+					relevant_when: "strategy = \"bearer\""
+					description:   "The token to use for bearer authentication."
+					type: string: examples: ["${API_TOKEN}"]
+				}
+			}
+		}
+		tls: {
+			common:      false
+			required:    false
+			description: """
+				Configures TLS for the API server. When enabled, the API is served
+				over HTTPS and secure WebSockets, using the certificate set with
+				`crt_file` and `key_file`. When that certificate is self-signed or
+				issued by a private CA, pass the CA certificate to `vector top` and
+				`vector tap` with `--ca-file`.
+				"""
+			type: object: options: {
+				enabled: {
+					required:    false
+					description: "Whether to serve the API over TLS."
+					type: bool: default: false
+				}
+				crt_file: {
+					required:    false
+					description: "Absolute path to the certificate file, in DER or PEM format."
+					type: string: {
+						default: null
+						examples: ["/path/to/host_certificate.crt"]
+					}
+				}
+				key_file: {
+					required:    false
+					description: "Absolute path to the certificate's private key file, in DER or PEM format."
+					type: string: {
+						default: null
+						examples: ["/path/to/host_certificate.key"]
+					}
+				}
+				key_pass: {
+					required:    false
+					description: "Passphrase used to unlock the encrypted key file."
+					type: string: {
+						default: null
+						examples: ["${KEY_PASS_ENV_VAR}"]
+					}
+				}
+				ca_file: {
+					required:    false
+					description: "Absolute path to an additional CA certificate file, used to verify client certificates."
+					type: string: {
+						default: null
+						examples: ["/path/to/certificate_authority.crt"]
+					}
+				}
+				verify_certificate: {
+					required:    false
+					description: "Whether to require clients to present a valid certificate."
+					type: bool: default: false
+				}
+			}
+		}
 	}
 
 	endpoints: {
@@ -179,6 +179,92 @@ cli: {
 
 			options: _core_options
 		}
+		"buffer": {
+			description: """
+				Inspect, export or replay the contents of a disk buffer, without running Vector. Takes one of
+				the `inspect`, `export` or `replay` subcommands:
+
+				* `inspect` reports the state of the buffer's ledger, the number and size of unread records
+				  in each data file, and any corrupted records.
+				* `export` writes the unread events as JSON, one per line.
+				* `replay` sends the unread events to the sink given with `--sink`, built from the given
+				  configuration.
+
+				The buffer is only ever read, so it is left exactly as it was found. Disk buffers are stored
+				in `<data_dir>/buffer/v2/<sink ID>`, and `disk_v1` buffers in `<data_dir>/<sink ID>_id`.
+				`disk_v1` buffers are copied to a temporary directory and read from the copy, since LevelDB
+				locks the databases it opens, so reading them needs as much free space as the buffer takes.
+				"""
+
+			example: "vector buffer export /var/lib/vector/buffer/v2/my_sink --output events.json"
+
+			flags: _default_flags
+
+			options: _core_config_options & {
+				"encryption-key-file": {
+					description: "Files containing the keys the buffer was encrypted with, if it is encrypted"
+					type:        "string"
+				}
+				"output": {
+					_short:      "o"
+					description: "File to export the events to. Defaults to standard output"
+					type:        "string"
+				}
+				"sink": {
+					description: "ID of the sink to replay the events to"
+					type:        "string"
+				}
+			}
+
+			args: {
+				path: {
+					description: "Path to the buffer directory"
+					type:        "string"
+					required:    true
+				}
+			}
+		}
+		"config diff": {
+			description: """
+				Show what reloading a running configuration into a new one would change, without reloading it.
+				The running configuration is either given with `--from`, or fetched from the [API](/docs/reference/api/)
+				of a running Vector instance, which requires the API's `auth_token` to be given with `--token`. For each
+				added, changed or removed component, the output tells:
+
+				* whether the component would be started, restarted or stopped. Components that didn't change
+				  keep running, even when the components they read from are restarted.
+				* whether a restarted sink would keep its buffer, along with the events in it. A sink keeps its
+				  buffer unless its `buffer` options changed.
+				* which ports would be bound, rebound or released.
+
+				Global options can't be changed by a reload, so any change to them is reported as well, as such a
+				configuration is only applied by restarting Vector.
+				"""
+
+			example: "vector config diff --to /etc/vector/vector.new.toml"
+
+			flags: _default_flags & {
+				"json": {
+					description: "Output the changes as JSON"
+				}
+			}
+
+			options: {
+				"from": {
+					description: "The running configuration, as one or more config files or directories. If none are given, it's fetched from the API"
+					type:        "string"
+				}
+				"to": {
+					description: "The new configuration, as one or more config files or directories"
+					type:        "string"
+				}
+				"url": {
+					_short:      "u"
+					description: "The URL for the GraphQL endpoint of the running Vector instance"
+					type:        "string"
+				}
+			}
+		}
 		"generate": {
 			description: "Generate a Vector configuration containing a list of components"
 
@@ -205,6 +291,26 @@ cli: {
 			}
 		}
 
+		"generate-schema": {
+			description: """
+				Generate the JSON Schema of the Vector configuration, covering every component compiled
+				into this build. Editors and linters can use it to validate and complete configuration
+				files, for example with `# yaml-language-server: $schema=vector.schema.json` at the top of
+				a YAML configuration file.
+				"""
+
+			flags: _default_flags
+
+			options: {
+				"output-path": {
+					_short:      "o"
+					description: "File path to write the schema to. Writes to standard output if not set"
+					type:        "string"
+					example:     "/etc/vector/vector.schema.json"
+				}
+			}
+		}
+
 		"help": {
 			description: "Prints this message or the help of the given subcommand(s)"
 		}
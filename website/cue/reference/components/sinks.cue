@@ -67,6 +67,23 @@ components: sinks: [Name=string]: {
 			}
 		}
 
+		dead_letter: {
+			common:      false
+			description: """
+				Whether to emit the events rejected by this sink on its `dead_letter` output,
+				which other components consume by referencing `<sink_id>.dead_letter` in their
+				`inputs`. The reason for the rejection is stored in the `dead_letter.reason`
+				metadata field of each event, and the ID of the sink in
+				`dead_letter.component_id`.
+
+				A copy of each event is kept until the sink is done with it, which roughly doubles
+				the memory used by the events held by the sink. A slow consumer of the
+				`dead_letter` output applies backpressure to the sink.
+				"""
+			required: false
+			type: bool: default: false
+		}
+
 		buffer: {
 			common:      false
 			description: "Configures the sink specific buffer behavior."
@@ -74,6 +91,96 @@ components: sinks: [Name=string]: {
 			type: object: {
 				examples: []
 				options: {
+					compression: {
+						common:        false
+						description: """
+							Compresses the records of the buffer before writing them to disk. Records
+							are only stored compressed when it makes them smaller, and `max_size`
+							applies to their compressed size. Records written with a different, or
+							without, compression remain readable.
+							"""
+						required:      false
+						relevant_when: "type = \"disk\""
+						type: string: {
+							default: null
+							enum: {
+								zstd: "[Zstandard](\(urls.zstd)) compression, favoring compression ratio."
+								lz4:  "[LZ4](\(urls.lz4)) compression, favoring throughput."
+							}
+						}
+					}
+					encryption: {
+						common:        false
+						description: """
+							Encrypts the records of the buffer at rest. Records written before
+							encryption was enabled remain readable. To rotate keys, move the
+							current key to `previous_keys` or `previous_key_files`, so records
+							encrypted with it can still be read.
+							"""
+						required:      false
+						relevant_when: "type = \"disk\""
+						type: object: options: {
+							algorithm: {
+								description: "The algorithm used to encrypt new records."
+								required:    false
+								type: string: {
+									default: "aes_256_gcm"
+									enum: {
+										aes_256_gcm:       "AES-256 in Galois/Counter Mode."
+										chacha20_poly1305: "ChaCha20-Poly1305."
+									}
+								}
+							}
+							key: {
+								description: """
+									The base64-encoded, 32 byte long key used to encrypt new records.
+									Either `key` or `key_file` must be set.
+									"""
+								required: false
+								type: string: {
+									default: null
+									examples: ["SECRET[vault.buffer_key]", "${BUFFER_KEY}"]
+								}
+							}
+							key_file: {
+								description: "Path to a file containing the base64-encoded, 32 byte long key used to encrypt new records."
+								required:    false
+								type: string: {
+									default: null
+									examples: ["/etc/vector/buffer.key"]
+								}
+							}
+							previous_keys: {
+								description: "Keys that were previously used to encrypt records, used only to decrypt them."
+								required:    false
+								type: array: {
+									default: []
+									items: type: string: examples: ["SECRET[vault.previous_buffer_key]"]
+								}
+							}
+							previous_key_files: {
+								description: "Paths to files containing keys that were previously used to encrypt records."
+								required:    false
+								type: array: {
+									default: []
+									items: type: string: examples: ["/etc/vector/buffer.key.old"]
+								}
+							}
+						}
+					}
+					max_age_secs: {
+						common:      false
+						description: """
+							Discards buffered events that are older than this, instead of sending them.
+							For disk buffers, only events written while this is set are ever discarded.
+							"""
+						required: false
+						type: uint: {
+							default: null
+							examples: [300]
+							unit: "seconds"
+						}
+					}
 					max_events: {
 						common:        true
 						description:   "The maximum number of [events](\(urls.vector_data_model)) allowed in the buffer."
@@ -122,6 +229,7 @@ components: sinks: [Name=string]: {
 							enum: {
 								block:       "Applies back pressure when the buffer is full. This prevents data loss, but will cause data to pile up on the edge."
 								drop_newest: "Drops new data as it's received. This data is lost. This should be used when performance is the highest priority."
+								drop_oldest: "Drops the oldest buffered data to make room for new data. This data is lost. This should be used when the freshest data matters most. For disk buffers, whole data files the sink hasn't started reading yet are dropped."
 							}
 						}
 					}
@@ -193,9 +301,15 @@ components: sinks: [Name=string]: {
 											if codec == "json" {
 												json: "JSON encoded event."
 											}
+											if codec == "msgpack" {
+												msgpack: "[MessagePack](\(urls.msgpack)) encoded event."
+											}
 											if codec == "avro" {
 												avro: "Avro encoded event with a given schema."
 											}
+											if codec == "csv" {
+												csv: "CSV encoded event, with the configured fields as columns."
+											}
 										}
 									}
 								}
@@ -227,6 +341,63 @@ components: sinks: [Name=string]: {
 								}
 							}
 
+							if features.send.encoding.codec.enabled {
+								for codec in features.send.encoding.codec.enum {
+									if codec == "csv" {
+										csv: {
+											description:   "Options for the `csv` codec."
+											required:      true
+											relevant_when: "codec = `csv`"
+											type: object: options: {
+												fields: {
+													description: "The fields to write, in column order. Missing fields are written as empty columns."
+													required:    true
+													type: array: items: type: string: examples: ["timestamp", "message"]
+												}
+												delimiter: {
+													description: "The character that separates fields."
+													required:    false
+													common:      false
+													type: string: default: ","
+												}
+												quote_style: {
+													description: "When to quote fields."
+													required:    false
+													common:      false
+													type: string: {
+														default: "necessary"
+														enum: {
+															always:      "Always quote every field."
+															necessary:   "Only quote fields which contain the delimiter, the quote character or a line terminator."
+															non_numeric: "Quote every field which is not a number."
+															never:       "Never quote fields."
+														}
+													}
+												}
+												quote: {
+													description: "The character used to quote fields."
+													required:    false
+													common:      false
+													type: string: default: "\""
+												}
+												escape: {
+													description: "The character used to escape quotes inside of quoted fields. When it is the same as `quote`, quotes are doubled."
+													required:    false
+													common:      false
+													type: string: default: "\""
+												}
+												include_header: {
+													description: "Whether to write a header line with the field names once at the start of every output file or object."
+													required:    false
+													common:      false
+													type: bool: default: false
+												}
+											}
+										}
+									}
+								}
+							}
+
 							except_fields: {
 								common:      false
 								description: "Prevent the sink from encoding the specified fields."
@@ -345,6 +345,10 @@ components: sinks: elasticsearch: {
 				due to Elasticsearch index mapping errors, where data keys aren't consistently
 				typed. To change this behavior, refer to the Elasticsearch [`ignore_malformed`
 				setting](\(urls.elasticsearch_ignore_malformed)).
+
+				Only the documents that failed are rejected, each with the reason Elasticsearch
+				gave for it, so with `dead_letter` enabled they're emitted on the `dead_letter`
+				output while the rest of the bulk request is delivered.
 				"""
 		}
 
@@ -21,7 +21,7 @@ components: sinks: nats: {
 				enabled: true
 				codec: {
 					enabled: true
-					enum: ["json", "text"]
+					enum: ["json", "msgpack", "text"]
 				}
 			}
 			request: enabled: false
@@ -0,0 +1,133 @@
+package metadata
+
+components: sinks: opentelemetry: {
+	_port: 4317
+
+	title: "OpenTelemetry"
+
+	description: """
+		Sends logs, metrics, and traces to any OTLP-compatible backend, over gRPC or HTTP.
+		"""
+
+	classes: {
+		commonly_used: false
+		delivery:      "at_least_once"
+		development:   "beta"
+		egress_method: "batch"
+		service_providers: []
+		stateful: false
+	}
+	features: {
+		acknowledgements: true
+		healthcheck: enabled: false
+		send: {
+			batch: {
+				enabled:      true
+				common:       false
+				max_bytes:    10_000_000
+				timeout_secs: 1.0
+			}
+			compression: enabled:       false
+			encoding: enabled:          false
+			send_buffer_bytes: enabled: false
+			keepalive: enabled:         true
+			request: {
+				enabled: true
+				headers: false
+			}
+
+			tls: {
+				enabled:                true
+				can_verify_certificate: true
+				can_verify_hostname:    true
+				enabled_default:        false
+			}
+			to: {
+				service: services.opentelemetry
+
+				interface: {
+					socket: {
+						direction: "outgoing"
+						protocols: ["http"]
+						ssl: "optional"
+					}
+				}
+			}
+		}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	input: {
+		logs: true
+		metrics: {
+			counter:      true
+			distribution: false
+			gauge:        true
+			histogram:    true
+			summary:      true
+			set:          false
+		}
+		traces: true
+	}
+
+	configuration: {
+		endpoint: {
+			description: """
+				The OTLP endpoint to send data to. With the `http` protocol, the `/v1/logs`, `/v1/metrics`, and
+				`/v1/traces` paths are appended to it, depending on the type of the events sent.
+				"""
+			required: true
+			type: string: {
+				examples: ["http://127.0.0.1:\(_port)"]
+			}
+		}
+		protocol: {
+			description: "The transport used to send data to the OTLP endpoint."
+			common:      true
+			required:    false
+			type: string: {
+				enum: {
+					grpc: "OTLP over gRPC."
+					http: "OTLP over HTTP, with binary protobuf encoded payloads."
+				}
+				default: "grpc"
+			}
+		}
+		compression: {
+			description: "Enable compression of requests with gzip."
+			common:      true
+			required:    false
+			type: bool: default: false
+		}
+	}
+
+	how_it_works: {
+		batching: {
+			title: "Batching"
+			body: """
+				Events are batched by type. Logs and traces are additionally batched by their `resources` field, which
+				becomes the resource of the OTLP export request, so that every request carries a single resource.
+				"""
+		}
+		metrics: {
+			title: "Metric conversion"
+			body: """
+				Counters are sent as monotonic sums, absolute gauges as gauges, and incremental gauges as
+				non-monotonic delta sums. Aggregated histograms and summaries are sent as histograms and summaries.
+				Metric tags become data point attributes. Sets, distributions, and sketches have no OTLP
+				equivalent and are dropped.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		component_sent_bytes_total:       components.sources.internal_metrics.output.metrics.component_sent_bytes_total
+		component_sent_events_total:      components.sources.internal_metrics.output.metrics.component_sent_events_total
+		component_sent_event_bytes_total: components.sources.internal_metrics.output.metrics.component_sent_event_bytes_total
+	}
+}
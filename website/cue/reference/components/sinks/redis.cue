@@ -26,7 +26,7 @@ components: sinks: redis: {
 				enabled: true
 				codec: {
 					enabled: true
-					enum: ["json", "text"]
+					enum: ["json", "msgpack", "text"]
 				}
 			}
 			request: {
@@ -166,11 +166,66 @@ components: sources: [Name=string]: {
 							type: string: {
 								default: "bytes"
 								enum: {
+									avro:        "Events being parsed from an [Apache Avro](\(urls.apache_avro)) datum."
 									bytes:       "Events containing the byte frame as-is."
 									json:        "Events being parsed from a JSON string."
+									msgpack:     "Events being parsed from [MessagePack](\(urls.msgpack)) maps."
 									syslog:      "Events being parsed from a Syslog message."
 									native:      "Events being parsed from Vector's [native protobuf format](\(urls.native_proto_schema)) ([EXPERIMENTAL](/highlights/2022-03-31-native-event-codecs))."
 									native_json: "Events being parsed from Vector's [native JSON format](\(urls.native_json_schema)) ([EXPERIMENTAL](/highlights/2022-03-31-native-event-codecs))."
+									protobuf:    "Events being parsed from a Protobuf message of a user-provided type."
+								}
+							}
+						}
+						avro: {
+							description:   "Options for the `avro` codec."
+							required:      true
+							relevant_when: "codec = `avro`"
+							type: object: options: {
+								schema: {
+									description: "The Avro schema the data was written with. Mutually exclusive with `schema_file`."
+									required:    false
+									common:      true
+									type: string: {
+										default: null
+										examples: [
+											"""
+											{ "type": "record", "name": "log", "fields": [{ "name": "message", "type": "string" }] }
+											""",
+										]
+									}
+								}
+								schema_file: {
+									description: "Path to a file containing the Avro schema the data was written with. Mutually exclusive with `schema`."
+									required:    false
+									common:      true
+									type: string: {
+										default: null
+										examples: ["/etc/vector/schemas/log.avsc"]
+									}
+								}
+								strip_schema_id_prefix: {
+									description: "Whether to strip the Confluent Schema Registry wire format prefix (a magic byte followed by a 4-byte schema ID) before decoding."
+									required:    false
+									common:      false
+									type: bool: default: false
+								}
+							}
+						}
+						protobuf: {
+							description:   "Options for the `protobuf` codec."
+							required:      true
+							relevant_when: "codec = `protobuf`"
+							type: object: options: {
+								desc_file: {
+									description: "Path to the compiled Protobuf descriptor set, as produced by `protoc --include_imports --descriptor_set_out`."
+									required:    true
+									type: string: examples: ["/etc/vector/protos/events.desc"]
+								}
+								message_type: {
+									description: "The fully qualified name of the message type to decode."
+									required:    true
+									type: string: examples: ["package.Message"]
 								}
 							}
 						}
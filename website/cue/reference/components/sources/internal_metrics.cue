@@ -334,6 +334,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		join_max_events_reached_total: {
+			description:       "The total number of events sent to the `unmatched` output of a join right away, because `max_events` events were already waiting for a match."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		k8s_format_picker_edge_cases_total: {
 			description:       "The total number of edge cases encountered while picking format of the Kubernetes log message."
 			type:              "counter"
@@ -1058,6 +1064,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		traces_decided_early_total: {
+			description:       "The total number of traces decided on before their decision wait was over, to stay within `max_traces`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		uptime_seconds: {
 			description:       "The total number of seconds the Vector instance has been up."
 			type:              "gauge"
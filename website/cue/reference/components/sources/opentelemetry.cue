@@ -6,7 +6,7 @@ components: sources: opentelemetry: {
 	title: "OpenTelemetry"
 
 	description: """
-		Collect OpenTelemetry data over gRPC (currently, only logs are supported).
+		Collect OpenTelemetry logs, metrics, and traces over gRPC and HTTP.
 		"""
 
 	classes: {
@@ -61,6 +61,29 @@ components: sources: opentelemetry: {
 				examples: ["0.0.0.0:\(_port)"]
 			}
 		}
+		http: {
+			common:      false
+			description: """
+				Configures an OTLP/HTTP endpoint, in addition to the gRPC one. Logs, metrics, and traces are accepted
+				as binary protobuf payloads, optionally gzip compressed, on `/v1/logs`, `/v1/metrics`, and `/v1/traces`.
+				"""
+			required: false
+			type: object: options: {
+				address: {
+					description: """
+						The HTTP address to listen for connections on. It _must_ include a port.
+						"""
+					required: true
+					type: string: {
+						examples: ["0.0.0.0:4318"]
+					}
+				}
+				tls: configuration._tls_accept & {_args: {
+					can_verify_certificate: true
+					enabled_default:        false
+				}}
+			}
+		}
 	}
 
 	outputs: [
@@ -70,6 +93,18 @@ components: sources: opentelemetry: {
 				Received log events will go to this output stream. Use `<component_id>.logs` as an input to downstream transforms and sinks.
 				"""
 		},
+		{
+			name: "metrics"
+			description: """
+				Received metric events will go to this output stream. Use `<component_id>.metrics` as an input to downstream transforms and sinks.
+				"""
+		},
+		{
+			name: "traces"
+			description: """
+				Received trace events will go to this output stream. Use `<component_id>.traces` as an input to downstream transforms and sinks.
+				"""
+		},
 	]
 
 	output: {
@@ -172,6 +207,22 @@ components: sources: opentelemetry: {
 		}
 	}
 
+	how_it_works: {
+		metrics: {
+			title: "Metric conversion"
+			body: """
+				OpenTelemetry data points are converted to Vector metrics as follows, with the resource and data point
+				attributes becoming tags:
+
+				* Gauges become absolute gauges.
+				* Monotonic sums become counters, incremental for delta temporality and absolute for cumulative
+				  temporality. Non-monotonic sums become gauges.
+				* Histograms and exponential histograms become aggregated histograms.
+				* Summaries become aggregated summaries.
+				"""
+		}
+	}
+
 	telemetry: metrics: {
 		component_discarded_events_total:     components.sources.internal_metrics.output.metrics.component_discarded_events_total
 		component_errors_total:               components.sources.internal_metrics.output.metrics.component_errors_total
@@ -27,6 +27,53 @@ components: transforms: aggregate: {
 	}
 
 	configuration: {
+		by: {
+			common: false
+			description: """
+				The tags to keep on metrics before they are grouped into series. All other tags are removed, which
+				reduces the cardinality of the aggregated metrics. If left unspecified, all tags are kept. Can't be
+				used together with `without`.
+				"""
+			required: false
+			type: array: {
+				default: null
+				items: type: string: {
+					examples: ["host", "service"]
+				}
+			}
+		}
+		mode: {
+			common:      true
+			description: "The function used to aggregate the values of metrics with the same series."
+			required:    false
+			type: string: {
+				default: "auto"
+				enum: {
+					auto:   "Adds up incremental metrics, and keeps the latest value of absolute metrics."
+					sum:    "Adds up the values of metrics, regardless of their kind."
+					count:  "Counts the number of metrics received, as an incremental counter."
+					min:    "Keeps the smallest value of counters and gauges, as an absolute gauge."
+					max:    "Keeps the largest value of counters and gauges, as an absolute gauge."
+					mean:   "Averages the values of counters and gauges, as an absolute gauge."
+					latest: "Keeps the latest value of metrics, regardless of their kind."
+					diff:   "Computes the change in the value of gauges, from the first to the last value received, as an incremental gauge."
+					stdev:  "Computes the population standard deviation of the values of counters and gauges, as an absolute gauge."
+				}
+			}
+		}
+		without: {
+			common: false
+			description: """
+				The tags to remove from metrics before they are grouped into series. Can't be used together with `by`.
+				"""
+			required: false
+			type: array: {
+				default: []
+				items: type: string: {
+					examples: ["host", "pod"]
+				}
+			}
+		}
 		interval_ms: {
 			common: true
 			description: """
@@ -186,6 +233,26 @@ components: transforms: aggregate: {
 				"""
 		}
 
+		aggregation_modes: {
+			title: "Aggregation Modes"
+			body: """
+				The behavior above is the default `auto` mode. Other values of `mode` aggregate every metric in a
+				series with the same function instead. `sum` and `latest` work with any type of metric, and `count`
+				counts metrics of any type. `min`, `max`, `mean` and `stdev` only apply to `counter` and `gauge`
+				metrics, and `diff` only to `gauge` metrics; any other metrics are dropped and counted as failed
+				updates.
+				"""
+		}
+
+		reducing_cardinality: {
+			title: "Reducing Cardinality"
+			body: """
+				Tags are removed from metrics before they are grouped into series, either by listing the tags to keep in
+				`by`, or the tags to remove in `without`. Metrics that only differ by the removed tags are then
+				aggregated together, like a `by` or `without` clause in a query language.
+				"""
+		}
+
 		advantages: {
 			title: "Advantages of Use"
 			body: """
@@ -40,9 +40,35 @@ components: transforms: dedupe: {
 							unit:    null
 						}
 					}
+					persist: {
+						common:      false
+						description: "Whether or not to persist the cache to disk, under `data_dir`. The cache is saved every 5 seconds and when Vector stops, and restored when it starts again, so that Events that were already forwarded aren't forwarded again. On reload, the cache is handed over to the new instance of the transform."
+						required:    false
+						type: bool: default: false
+					}
+					time_to_live_secs: {
+						common:      false
+						description: "The amount of time an Event is remembered for after it was first seen. Duplicates of an Event are only discarded within this window. If left unspecified, Events are remembered until they're evicted from the cache by newer ones."
+						required:    false
+						type: uint: {
+							default: null
+							examples: [60, 3600]
+							unit: "seconds"
+						}
+					}
 				}
 			}
 		}
+		data_dir: {
+			common:      false
+			description: "The directory used to persist the cache, when `cache.persist` is enabled. By default, the global `data_dir` option is used. Please make sure the Vector project has write permissions to this dir."
+			required:    false
+			type: string: {
+				default: null
+				examples: ["/var/lib/vector"]
+				syntax: "file_system_path"
+			}
+		}
 		fields: {
 			description: "Options controlling what fields to match against."
 			required:    true
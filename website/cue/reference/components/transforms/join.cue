@@ -0,0 +1,138 @@
+package metadata
+
+components: transforms: join: {
+	title: "Join"
+
+	description: """
+		Joins events from two sets of inputs into a single event, by matching them on a key within a
+		window of time.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		reduce: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: {
+		flush_period_ms: {
+			common:      false
+			description: "Controls the frequency that Vector checks for (and flushes) events whose window has expired."
+			required:    false
+			type: uint: {
+				default: 1000
+				unit:    "milliseconds"
+			}
+		}
+		left: {
+			description: """
+				The left-hand side of the join. When merging a matched pair of events, the left event comes first,
+				so its values are kept for fields that keep the first value.
+				"""
+			required: true
+			type: object: options: {
+				inputs: {
+					description: """
+						A list of upstream [source](\(urls.vector_sources)) or [transform](\(urls.vector_transforms))
+						IDs that make up this side of the join. Wildcards (`*`) are supported.
+						"""
+					required: true
+					type: array: items: type: string: {
+						examples: ["requests", "nginx-*"]
+					}
+				}
+				key: {
+					description: """
+						A [template string](/docs/reference/configuration/template-syntax/) rendered to the key to join
+						events on. Events from either side are matched with each other when their keys render to the
+						same value. Events whose key can't be rendered are sent to the `unmatched` output right away.
+						"""
+					required: true
+					type: string: {
+						examples: ["{{ request_id }}", "{{ user }}-{{ session }}"]
+						syntax: "template"
+					}
+				}
+			}
+		}
+		max_events: {
+			common: false
+			description: """
+				The maximum number of events to hold, waiting for a matching event from the other side. Once this many
+				events are held, events that don't match any of them are sent to the `unmatched` output right away.
+				"""
+			required: false
+			type: uint: {
+				default: 100000
+				unit:    "events"
+			}
+		}
+		merge_strategies: components.transforms.reduce.configuration.merge_strategies
+		right: {
+			description: "The right-hand side of the join."
+			required:    true
+			type: object: options: left.type.object.options
+		}
+		window_ms: {
+			common: true
+			description: """
+				The maximum period of time to hold an event for, waiting for a matching event from the other side.
+				Once this window expires, the event is sent to the `unmatched` output.
+				"""
+			required: false
+			type: uint: {
+				default: 30000
+				unit:    "milliseconds"
+			}
+		}
+	}
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  false
+	}
+
+	how_it_works: {
+		matching: {
+			title: "Matching"
+			body: """
+				Each event is held, keyed by the rendered `key` of its side, until an event with the same key arrives
+				from the other side. The two events are then merged into a single event and sent to the default
+				output. For the fields that both events have, the value of the left event is kept, unless a merge
+				strategy is set for the field in `merge_strategies`. If several events of one side are waiting with
+				the same key, the oldest one is matched first.
+				"""
+		}
+		unmatched_events: {
+			title: "Unmatched events"
+			body: """
+				Events that aren't matched within `window_ms` are sent, as-is, to the `<transform_name>.unmatched`
+				output. So are any events still waiting when Vector stops or the transform is reloaded, and any events
+				that arrive while `max_events` events are already waiting, unless they match one of them.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		join_max_events_reached_total: components.sources.internal_metrics.output.metrics.join_max_events_reached_total
+	}
+
+	outputs: [
+		{
+			name:        "unmatched"
+			description: "Events that weren't matched within the window can be referenced as an input by other components with the name `<transform_name>.unmatched`."
+		},
+	]
+}
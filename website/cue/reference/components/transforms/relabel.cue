@@ -0,0 +1,140 @@
+package metadata
+
+components: transforms: relabel: {
+	title: "Relabel"
+
+	description: """
+		Rewrites the names and tags of metrics, and drops metrics, with the semantics of Prometheus'
+		`relabel_configs`.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {
+		filter: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: {
+		relabel_configs: {
+			description: """
+				The relabeling rules to apply to each metric, in order. The tags of a metric are its labels, and its
+				name is the `__name__` label.
+				"""
+			required: true
+			type: array: items: type: object: options: {
+				action: {
+					common:      true
+					description: "The action performed by the rule."
+					required:    false
+					type: string: {
+						default: "replace"
+						enum: {
+							replace:   "Writes `replacement`, with capture groups expanded, to `target_label` if `regex` matches the source label values. If the result is empty, `target_label` is removed instead."
+							keep:      "Drops metrics for which `regex` doesn't match the source label values."
+							drop:      "Drops metrics for which `regex` matches the source label values."
+							hashmod:   "Writes the hash of the source label values, modulo `modulus`, to `target_label`."
+							labelmap:  "Copies the value of each label whose name matches `regex` to the label named by `replacement`, with capture groups expanded."
+							labeldrop: "Removes the labels whose names match `regex`."
+							labelkeep: "Removes the labels whose names don't match `regex`."
+						}
+					}
+				}
+				modulus: {
+					common:      false
+					description: "The modulus to take of the hash of the source label values. Required by the `hashmod` action."
+					required:    false
+					type: uint: {
+						default: null
+						examples: [8]
+						unit: null
+					}
+				}
+				regex: {
+					common:      true
+					description: "The regular expression that values, or label names, are matched against. It's anchored on both ends."
+					required:    false
+					type: string: {
+						default: "(.*)"
+						examples: ["([^:]+):.*", "node_.*"]
+					}
+				}
+				replacement: {
+					common:      false
+					description: "The value written by the `replace` action, or the new label name for the `labelmap` action. Regex capture groups can be referenced, such as `$1`."
+					required:    false
+					type: string: {
+						default: "$1"
+						examples: ["$1", "source_${1}"]
+					}
+				}
+				separator: {
+					common:      false
+					description: "The separator placed between the values of `source_labels`."
+					required:    false
+					type: string: {
+						default: ";"
+					}
+				}
+				source_labels: {
+					common:      true
+					description: "The labels whose values are concatenated, with `separator`, and matched against `regex`. Missing labels are treated as empty values."
+					required:    false
+					type: array: {
+						default: []
+						items: type: string: {
+							examples: ["instance", "__name__"]
+						}
+					}
+				}
+				target_label: {
+					common:      true
+					description: "The label that the result of the `replace` or `hashmod` action is written to. Required by both actions."
+					required:    false
+					type: string: {
+						default: null
+						examples: ["host", "shard"]
+					}
+				}
+			}
+		}
+	}
+
+	input: {
+		logs: false
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			set:          true
+			summary:      true
+		}
+		traces: false
+	}
+
+	how_it_works: {
+		differences_from_prometheus: {
+			title: "Differences from Prometheus"
+			body: """
+				The name of a metric can be rewritten by targeting the `__name__` label, but it can't be removed, so
+				rules that would leave it empty are ignored. The `labeldrop` and `labelkeep` actions only apply to
+				tags, never to the name. The namespace of a metric isn't exposed as a label.
+				"""
+		}
+	}
+
+	telemetry: metrics: {
+		events_discarded_total: components.sources.internal_metrics.output.metrics.events_discarded_total
+	}
+}
@@ -25,6 +25,48 @@ components: transforms: sample: {
 	}
 
 	configuration: {
+		adaptive: {
+			common: false
+			description: """
+				Samples events at a rate that adapts to the volume of events, instead of at a fixed `rate`. Exactly one
+				of `rate` and `adaptive` must be set.
+				"""
+			required: false
+			type: object: options: {
+				key_field: {
+					common: false
+					description: """
+						A [template string](/docs/reference/configuration/template-syntax/) that will be rendered to
+						group events into keys that are sampled separately.
+
+						If left unspecified, or if the event can't be rendered with the template, events are sampled as
+						a single key. For example, to target a rate per service use `{{ service }}`.
+						"""
+					required: false
+					type: string: {
+						default: null
+						examples: ["{{ service }}", "{{ hostname }}"]
+						syntax: "template"
+					}
+				}
+				target_events_per_sec: {
+					description: "The number of events to forward per second, for each key."
+					required:    true
+					type: float: {
+						examples: [10.0, 100.0]
+					}
+				}
+				window_secs: {
+					common:      false
+					description: "The period of time over which the volume of events of each key is measured."
+					required:    false
+					type: float: {
+						default: 60.0
+						unit:    "seconds"
+					}
+				}
+			}
+		}
 		key_field: {
 			common: false
 			description: """
@@ -33,6 +75,8 @@ components: transforms: sample: {
 				Consistently samples the same events. Actual rate of sampling may differ from the configured one if
 				values in the field are not uniformly distributed. If left unspecified, or if the event doesn't have
 				`key_field`, events will be count rated.
+
+				Can't be set along with `adaptive`, which groups events by its own `key_field` instead.
 				"""
 			required: false
 			type: string: {
@@ -51,10 +95,12 @@ components: transforms: sample: {
 		rate: {
 			description: """
 				The rate at which events will be forwarded, expressed as 1/N. For example,
-				`rate = 10` means 1 out of every 10 events will be forwarded and the rest will be dropped.
+				`rate = 10` means 1 out of every 10 events will be forwarded and the rest will be dropped. Exactly one
+				of `rate` and `adaptive` must be set.
 				"""
-			required: true
+			required: false
 			type: uint: {
+				default: null
 				examples: [10]
 				unit: null
 			}
@@ -67,6 +113,21 @@ components: transforms: sample: {
 		traces:  true
 	}
 
+	how_it_works: {
+		adaptive_sampling: {
+			title: "Adaptive sampling"
+			body: """
+				With `adaptive` set, the rate at which events are sampled is recomputed for each key as events arrive,
+				based on the volume of events of that key over the last `window_secs`, so that roughly
+				`target_events_per_sec` events of each key are forwarded. Keys with less traffic than the target aren't
+				sampled at all.
+
+				The effective rate is stamped on each forwarded event, in the `sample_rate` field, so that downstream
+				counts can be corrected by multiplying by it.
+				"""
+		}
+	}
+
 	telemetry: metrics: {
 		events_discarded_total: components.sources.internal_metrics.output.metrics.events_discarded_total
 	}
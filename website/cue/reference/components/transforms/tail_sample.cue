@@ -0,0 +1,148 @@
+package metadata
+
+components: transforms: tail_sample: {
+	title: "Tail Sample"
+
+	description: """
+		Samples whole traces, keeping or dropping all of the events of a trace together based on a set of policies.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      true
+	}
+
+	features: {
+		filter: {}
+	}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: {
+		decision_wait_ms: {
+			common: true
+			description: """
+				The period of time to wait after the first event of a trace is received before deciding whether to
+				keep or drop the trace. Events of a trace that arrive after the decision was made follow the same
+				decision, as long as they arrive within another `decision_wait_ms`.
+				"""
+			required: false
+			type: uint: {
+				default: 30000
+				unit:    "milliseconds"
+			}
+		}
+		flush_period_ms: {
+			common:      false
+			description: "Controls the frequency that Vector checks for (and decides on) traces that have waited long enough."
+			required:    false
+			type: uint: {
+				default: 1000
+				unit:    "milliseconds"
+			}
+		}
+		max_traces: {
+			common: false
+			description: """
+				The maximum number of traces waiting for a decision. When the first event of a new trace arrives while
+				this many traces are waiting, the oldest trace is decided on right away, before `decision_wait_ms` is
+				over.
+				"""
+			required: false
+			type: uint: {
+				default: 100000
+				unit:    null
+			}
+		}
+		policies: {
+			common: true
+			description: """
+				The policies used to decide whether to keep a trace. A trace is kept, along with all of its events, if
+				any of the policies match it. Otherwise, the whole trace is dropped.
+				"""
+			required: true
+			type: array: items: type: object: options: {
+				condition: {
+					description:   "The condition to match events of the trace against."
+					required:      true
+					relevant_when: "type = \"condition\""
+					type: condition: {}
+				}
+				field: {
+					common: false
+					description: """
+						The name of the span field to check. For `error` policies, a span has an error when this field
+						is `true`, or a non-zero integer. For `latency` policies, this field holds the duration of the
+						span, in nanoseconds.
+						"""
+					required:      false
+					relevant_when: "type = \"error\" or type = \"latency\""
+					type: string: {
+						default: null
+						examples: ["error", "duration"]
+					}
+				}
+				rate: {
+					description: """
+						The rate at which traces will be kept, expressed as 1/N. The trace ID is hashed to decide, so
+						the same traces are kept consistently.
+						"""
+					required:      true
+					relevant_when: "type = \"probabilistic\""
+					type: uint: {
+						examples: [10]
+						unit: null
+					}
+				}
+				threshold_ms: {
+					description:   "The duration above which a span is considered slow."
+					required:      true
+					relevant_when: "type = \"latency\""
+					type: uint: {
+						examples: [500]
+						unit: "milliseconds"
+					}
+				}
+				type: {
+					description: "The type of policy."
+					required:    true
+					type: string: enum: {
+						condition:     "Keeps traces with at least one event that matches `condition`."
+						error:         "Keeps traces with at least one span that has an error."
+						latency:       "Keeps traces with at least one span that took longer than `threshold_ms`."
+						probabilistic: "Keeps one out of every `rate` traces."
+					}
+				}
+			}
+		}
+		trace_id_field: {
+			common: false
+			description: """
+				The name of the field holding the trace ID of an event. Trace events hold their spans in the `spans`
+				field, whereas a log event is considered a span on its own. Events without a trace ID are forwarded
+				as-is.
+				"""
+			required: false
+			type: string: {
+				default: "trace_id"
+			}
+		}
+	}
+
+	input: {
+		logs:    true
+		metrics: null
+		traces:  true
+	}
+
+	telemetry: metrics: {
+		events_discarded_total:     components.sources.internal_metrics.output.metrics.events_discarded_total
+		traces_decided_early_total: components.sources.internal_metrics.output.metrics.traces_decided_early_total
+	}
+}
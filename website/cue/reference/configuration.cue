@@ -306,7 +306,7 @@ configuration: {
 			common: false
 			description: """
 				Configuration options to retrieve secrets from external backend in order to avoid storing secrets in plaintext
-				in Vector config. Currently, only the exec backend is supported. Multiple backends can be configured. To signify
+				in Vector config. Multiple backends can be configured. To signify
 				Vector that it should look for a secret to retrieve use the `SECRET[<backend_name>.<secret_key>]`. This placeholder
 				will then be replaced by the secret retrieved from the relevant backend.
 				"""
@@ -361,6 +361,240 @@ configuration: {
 						}
 					}
 				}
+				file: {
+					required: true
+					description: """
+						Read secrets from a file containing a map of secret keys to their values, in JSON or YAML,
+						as determined by the file extension.
+
+						The file is read again when Vector receives a `SIGHUP` signal triggering its configuration
+						reload process.
+						"""
+					type: object: options: {
+						path: {
+							description: "The path of the file to read secrets from."
+							required:    true
+							type: string: {
+								examples: ["/etc/vector/secrets.json", "/etc/vector/secrets.yaml"]
+							}
+						}
+					}
+				}
+				directory: {
+					required: true
+					description: """
+						Read secrets from a directory containing one file per secret, named after the secret key,
+						such as the secrets that Kubernetes or Docker mount into containers.
+
+						The files are read again when Vector receives a `SIGHUP` signal triggering its configuration
+						reload process.
+						"""
+					type: object: options: {
+						path: {
+							description: "The path of the directory to read secrets from."
+							required:    true
+							type: string: {
+								examples: ["/run/secrets", "/var/run/secrets/vector"]
+							}
+						}
+						remove_trailing_whitespace: {
+							description: "Whether to remove trailing whitespace, such as a final newline, from the secrets."
+							required:    false
+							common:      false
+							type: bool: default: false
+						}
+					}
+				}
+				vault: {
+					required: true
+					description: """
+						Retrieve secrets from a [HashiCorp Vault](https://www.vaultproject.io/) KV secrets engine,
+						version 1 or 2.
+
+						The last segment of a secret key is the field to read from a secret, and any preceding
+						segments are the path of the secret, relative to `path`. For example, with the default `secret`
+						mount, `SECRET[vault_backend.db.password]` reads the `password` field of the `secret/db` secret.
+
+						Secrets will be loaded when Vector starts or if Vector receives a `SIGHUP` signal triggering its
+						configuration reload process. If the secrets have a lease, Vector also reloads its configuration
+						after two thirds of the shortest lease, retrieving the secrets again and restarting the components
+						that use any secret that changed. If retrieving them fails, it's tried again every 30 seconds.
+						Secrets of version 2 of the KV secrets engine never have a lease, so they are never renewed this
+						way. The lease of the token obtained from the `approle` or `kubernetes` auth methods doesn't cause
+						any reload. The token is reused across retrievals until it's about to expire, at which point a new
+						token is obtained and the previous one is revoked.
+						"""
+					type: object: options: {
+						address: {
+							description: "The address of the Vault server."
+							required:    true
+							type: string: {
+								examples: ["https://vault.example.com:8200"]
+							}
+						}
+						auth: {
+							description: "The method used to authenticate with Vault."
+							required:    true
+							type: object: options: {
+								strategy: {
+									description: "The authentication strategy to use."
+									required:    true
+									type: string: enum: {
+										token:      "Use a Vault token directly."
+										approle:    "Log in with an AppRole role ID and secret ID."
+										kubernetes: "Log in with the token of the Kubernetes service account of the Vector pod."
+									}
+								}
+								token: {
+									description:   "The Vault token to use."
+									relevant_when: "strategy = \"token\""
+									required:      true
+									type: string: examples: ["${VAULT_TOKEN}"]
+								}
+								role_id: {
+									description:   "The AppRole role ID."
+									relevant_when: "strategy = \"approle\""
+									required:      true
+									type: string: examples: ["${VAULT_ROLE_ID}"]
+								}
+								secret_id: {
+									description:   "The AppRole secret ID."
+									relevant_when: "strategy = \"approle\""
+									required:      true
+									type: string: examples: ["${VAULT_SECRET_ID}"]
+								}
+								role: {
+									description:   "The Vault role to log in as."
+									relevant_when: "strategy = \"kubernetes\""
+									required:      true
+									type: string: examples: ["vector"]
+								}
+								token_path: {
+									common:        false
+									description:   "The path of the Kubernetes service account token."
+									relevant_when: "strategy = \"kubernetes\""
+									required:      false
+									type: string: default: "/var/run/secrets/kubernetes.io/serviceaccount/token"
+								}
+								mount: {
+									common:        false
+									description:   "The path the auth method is mounted at. Defaults to `approle` or `kubernetes`, depending on the strategy."
+									relevant_when: "strategy = \"approle\" or strategy = \"kubernetes\""
+									required:      false
+									type: string: default: null
+								}
+							}
+						}
+						kv_version: {
+							common:      false
+							description: "The version of the KV secrets engine, either `1` or `2`."
+							required:    false
+							type: uint: {
+								default: 2
+								unit:    null
+							}
+						}
+						mount: {
+							common:      true
+							description: "The path the KV secrets engine is mounted at."
+							required:    false
+							type: string: default: "secret"
+						}
+						namespace: {
+							common:      false
+							description: "The Vault Enterprise namespace to use."
+							required:    false
+							type: string: {
+								default: null
+								examples: ["teams/observability"]
+							}
+						}
+						path: {
+							common:      true
+							description: "The path, relative to `mount`, that secret keys are relative to."
+							required:    false
+							type: string: {
+								default: null
+								examples: ["vector/production"]
+							}
+						}
+						timeout: {
+							description: "The amount of time Vector will wait for Vault to respond."
+							required:    false
+							common:      false
+							type: uint: {
+								default: 5
+								unit:    "seconds"
+							}
+						}
+						tls: {
+							common:      false
+							description: "Configures the TLS options for connections to Vault."
+							required:    false
+							type: object: options: {
+								ca_file: {
+									common:      false
+									description: "Absolute path to an additional CA certificate file, in DER or PEM format (X.509), or an inline CA certificate in PEM format."
+									required:    false
+									type: string: {
+										default: null
+										examples: ["/path/to/certificate_authority.crt"]
+									}
+								}
+								crt_file: {
+									common:      false
+									description: "Absolute path to a certificate file used to identify this connection, in DER or PEM format (X.509) or PKCS#12, or an inline certificate in PEM format. If this is set and is not a PKCS#12 archive, `key_file` must also be set."
+									required:    false
+									type: string: {
+										default: null
+										examples: ["/path/to/host_certificate.crt"]
+									}
+								}
+								key_file: {
+									common:      false
+									description: "Absolute path to a private key file used to identify this connection, in DER or PEM format (PKCS#8), or an inline private key in PEM format. If this is set, `crt_file` must also be set."
+									required:    false
+									type: string: {
+										default: null
+										examples: ["/path/to/host_certificate.key"]
+									}
+								}
+								verify_certificate: {
+									common:      false
+									description: "If `true` (the default), Vector will validate the TLS certificate of Vault."
+									required:    false
+									type: bool: default: true
+								}
+								verify_hostname: {
+									common:      false
+									description: "If `true` (the default), Vector will validate that the hostname of Vault matches its TLS certificate."
+									required:    false
+									type: bool: default: true
+								}
+							}
+						}
+					}
+				}
+				env: {
+					required: true
+					description: """
+						Read secrets from environment variables, named after the secret key with a `prefix`.
+
+						The variables are read again when Vector receives a `SIGHUP` signal triggering its
+						configuration reload process.
+						"""
+					type: object: options: {
+						prefix: {
+							description: "The prefix of the environment variables to read secrets from."
+							required:    false
+							common:      true
+							type: string: {
+								default: ""
+								examples: ["VECTOR_SECRET_"]
+							}
+						}
+					}
+				}
 			}
 		}
 
@@ -491,8 +725,9 @@ configuration: {
 				sensitive token are configured in a dedicated section (`secret`). In the rest of the configuration you should use
 				the `SECRET[<backend_name>.<secret_key>]` notation to interpolate the secret. Interpolation will happen immediately after
 				environment variables interpolation. While Vector supports multiple commands to retrieve secrets, a
-				secret backend cannot use the secret interpolation feature for its own configuration. Currently the only supported
-				kind of secret backend is the the `exec` one that runs an external command to retrieve secrets.
+				secret backend cannot use the secret interpolation feature for its own configuration. Secrets can be retrieved by
+				running an external command (`exec`), or read from a file (`file`), a directory of files (`directory`), or
+				environment variables (`env`).
 
 				The following example shows a simple configuration with two backends defined:
 
@@ -590,6 +825,54 @@ configuration: {
 				```
 				"""
 		}
+		imports: {
+			title: "Imports"
+			body: """
+				A configuration file can pull in other files with the `imports` key, a list of paths or
+				[glob patterns](\(urls.globbing)) relative to the directory of the file. Imported files can be in any
+				supported format, can themselves import files, and are merged before the importing file in the same way
+				as multiple configuration files are.
+
+				```toml title="vector.toml"
+				imports = ["sources/*.toml", "../shared/templates.yaml"]
+				```
+
+				Imported files are read again when Vector reloads its configuration, and are watched along with the
+				configuration paths with `--watch-config`, so that changes to them also trigger a reload. Files matching
+				an import pattern are only picked up by the next reload, as the patterns aren't watched themselves.
+				"""
+		}
+		templates: {
+			title: "Component templates"
+			body: """
+				Components that only differ by a few options can be instantiated from a template, defined in the
+				`templates` section of the same file or of a file it imports. A component instantiates a template by
+				naming it in its `template` key, and provides values for the `PARAM[<name>]` placeholders of the
+				template in its `params` table. A template can provide default values for its parameters in its own
+				`params` table. Any other options of the component are merged into the template in the same way as
+				separate configuration files: nested tables such as `encoding` are merged option by option, arrays are
+				appended to, and other values replace the values of the same name in the template.
+
+				```toml title="vector.toml"
+				[templates.team_sink]
+				type = "http"
+				inputs = "PARAM[inputs]"
+				uri = "https://PARAM[team].logs.example.com"
+				encoding.codec = "json"
+
+				[sinks.team_a]
+				template = "team_sink"
+				params = { team = "a", inputs = ["team_a_logs"] }
+
+				[sinks.team_b]
+				template = "team_sink"
+				params = { team = "b", inputs = ["team_b_logs"] }
+				```
+
+				A value that consists of a single placeholder is replaced by the value of the parameter as-is, so
+				parameters can also be numbers, booleans or lists, such as `inputs` above.
+				"""
+		}
 		automatic_namespacing: {
 			title: "Automatic namespacing of component files"
 			body: """
@@ -7,6 +7,7 @@ urls: {
 	amazon_linux:                               "https://aws.amazon.com/amazon-linux-ami/"
 	ansi_escape_codes:                          "\(wikipedia)/wiki/ANSI_escape_code"
 	apache:                                     "https://httpd.apache.org"
+	apache_avro:                                "https://avro.apache.org"
 	apache_common:                              "\(apache)/docs/current/logs.html#common"
 	apache_combined:                            "\(apache)/docs/current/logs.html#combined"
 	apache_error:                               "\(apache)/docs/current/logs.html#errorlog"
@@ -335,6 +336,7 @@ urls: {
 	memory_safety_bugs:                         "https://thenewstack.io/microsoft-rust-is-the-industrys-best-chance-at-safe-systems-programming/"
 	metric_event_source:                        "\(vector_repo)/blob/master/src/event/metric.rs"
 	mlua:                                       "\(github)/khvzak/mlua"
+	msgpack:                                    "https://msgpack.org"
 	mongodb:                                    "https://www.mongodb.com"
 	mongodb_command_server_status:              "https://docs.mongodb.com/manual/reference/command/serverStatus/"
 	mongodb_connection_string_uri_format:       "https://docs.mongodb.com/manual/reference/connection-string/"